@@ -0,0 +1,87 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    hint::black_box,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use easy_sgr::{CapableWriter, Color::*, EasySGR, SGRBuilder, SGRString, Style::*};
+
+/// Counts allocations, to compare builders by how often they allocate
+struct Counting;
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+}
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+/// Discards what is written, so only building sequences is measured
+struct Sink;
+impl CapableWriter for Sink {
+    type Writer = ();
+    type Error = std::fmt::Error;
+    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+        black_box(s);
+        Ok(())
+    }
+    fn get_writer(self) -> Self::Writer {}
+}
+/// Writes the sequence placing `style` through a [`SGRBuilder`]
+fn inline(style: &SGRString) {
+    let mut builder = SGRBuilder::default();
+    style.place_all(&mut builder);
+    builder.write_to(&mut Sink).unwrap();
+}
+/// Writes the sequence placing `style` as it was before storing codes inline,
+/// collecting codes into a `Vec` & the sequence into a `String`
+fn heap(style: &SGRString) {
+    let mut builder = SGRBuilder::default();
+    style.place_all(&mut builder);
+    let codes = Vec::from(builder);
+    let mut seq = String::with_capacity(codes.len() * 4 + 2);
+    seq.push_str("\x1b[");
+    for (i, code) in codes.iter().enumerate() {
+        if i != 0 {
+            seq.push(';');
+        }
+        seq.push_str(&code.to_string());
+    }
+    seq.push('m');
+    Sink.write(black_box(&seq)).unwrap();
+}
+/// Returns the number of allocations made writing `styles` a thousand times
+fn allocations(styles: &[SGRString], write: fn(&SGRString)) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    for _ in 0..1000 {
+        styles.iter().for_each(write);
+    }
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+fn builder(c: &mut Criterion) {
+    let styles = [
+        RedFg.style(Bold),
+        ByteFg(208).color(BlueBg).style(Italic),
+        RgbFg(1, 2, 3).color(RgbBg(4, 5, 6)).style(Underline),
+    ];
+    println!(
+        "allocations writing {} styles 1000 times: inline {}, heap {}",
+        styles.len(),
+        allocations(&styles, inline),
+        allocations(&styles, heap)
+    );
+
+    let mut group = c.benchmark_group("builder");
+    group.bench_function("inline", |b| b.iter(|| styles.iter().for_each(inline)));
+    group.bench_function("heap", |b| b.iter(|| styles.iter().for_each(heap)));
+    group.finish();
+}
+
+criterion_group!(benches, builder);
+criterion_main!(benches);
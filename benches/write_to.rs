@@ -0,0 +1,53 @@
+use std::{
+    fs::{File, OpenOptions},
+    hint::black_box,
+};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use easy_sgr::{CapableWriter, IoWriter, SGRBuilder};
+
+/// Writes sequences the way they were before being buffered,
+/// one write per piece of the sequence
+struct PerPiece(IoWriter<File>);
+impl CapableWriter for PerPiece {
+    type Writer = File;
+    type Error = std::io::Error;
+    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.0.write(s)
+    }
+    fn write_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        self.write("\x1b[")?;
+        for (i, code) in codes.iter().enumerate() {
+            if i != 0 {
+                self.write(";")?;
+            }
+            self.write(&code.to_string())?;
+        }
+        self.write("m")
+    }
+    fn get_writer(self) -> Self::Writer {
+        self.0.get_writer()
+    }
+}
+fn null() -> File {
+    OpenOptions::new()
+        .write(true)
+        .open(if cfg!(windows) { "NUL" } else { "/dev/null" })
+        .expect("null device should be writable")
+}
+fn write_to(c: &mut Criterion) {
+    let builder = || SGRBuilder::from(vec![1, 3, 38, 5, 208, 48, 2, 10, 20, 30]);
+    let mut group = c.benchmark_group("write_to");
+    group.bench_function("single write", |b| {
+        let mut writer = IoWriter(null());
+        b.iter(|| builder().write_to(black_box(&mut writer)));
+    });
+    group.bench_function("write per piece", |b| {
+        let mut writer = PerPiece(IoWriter(null()));
+        b.iter(|| builder().write_to(black_box(&mut writer)));
+    });
+    group.finish();
+}
+
+criterion_group!(benches, write_to);
+criterion_main!(benches);
@@ -0,0 +1,57 @@
+//! Types implementing the traits of
+//! [easy-sgr](https://crates.io/crates/easy-sgr) from outside of it
+//!
+//! Only built as part of the workspace, to catch orphan & coherence
+//! errors a change to `easy-sgr` would cause other crates
+//!
+//! Empty with `macro-only`, as `easy-sgr` then only has its macros
+#![cfg(not(feature = "macro-only"))]
+#![forbid(unsafe_code)]
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, missing_docs)]
+
+use std::fmt::{self, Display};
+
+use easy_sgr::{CodeSink, Color, DiscreteSGR, EasySGR, EasyWrite, SGRBuilder, SGRString, Style};
+
+/// A color & a style written together, both as a [`DiscreteSGR`] & [`EasyWrite`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Accent {
+    /// The color written first
+    pub color: Color,
+    /// The style written after the color
+    pub style: Style,
+}
+impl DiscreteSGR for Accent {
+    fn write(&self, builder: &mut impl CodeSink) {
+        self.color.write(builder);
+        self.style.write(builder);
+    }
+}
+impl EasyWrite for Accent {
+    fn sgr(&self, builder: &mut SGRBuilder) {
+        self.write(builder);
+    }
+}
+impl Display for Accent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.standard_display(f)
+    }
+}
+impl From<Accent> for SGRString {
+    fn from(value: Accent) -> Self {
+        value.color.style(value.style)
+    }
+}
+/// Styles written only as an [`EasyWrite`], each undone before the next
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Blend(pub Vec<Style>);
+impl EasyWrite for Blend {
+    fn sgr(&self, builder: &mut SGRBuilder) {
+        for (i, style) in self.0.iter().enumerate() {
+            if i != 0 {
+                self.0[i - 1].undo().sgr(builder);
+            }
+            style.sgr(builder);
+        }
+    }
+}
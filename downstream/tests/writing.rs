@@ -0,0 +1,25 @@
+#![cfg(not(feature = "macro-only"))]
+use easy_sgr::{Color::*, DiscreteSGR, EasySGR, SGRWriter, Style::*};
+use easy_sgr_downstream::{Accent, Blend};
+
+#[test]
+fn accent() -> Result<(), std::fmt::Error> {
+    let accent = Accent {
+        color: RedFg,
+        style: Bold,
+    };
+    let mut writer = SGRWriter::from(String::new());
+    writer.sgr(&accent)?;
+    writer.inline_sgr(&accent)?;
+    writer.sgr_string(&accent.clone().text("text"))?;
+    assert_eq!(writer.internal(), "\x1b[31;1m\x1b[31;1m\x1b[31;1mtext");
+    assert_eq!(accent.params().to_string(), "31;1");
+    Ok(())
+}
+#[test]
+fn blend() -> Result<(), std::fmt::Error> {
+    let mut writer = SGRWriter::from(String::new());
+    writer.styled(&Blend(vec![Bold, Italic, Underline]), "text")?;
+    assert_eq!(writer.internal(), "\x1b[1;22;3;23;4mtext\x1b[0m");
+    Ok(())
+}
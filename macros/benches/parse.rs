@@ -0,0 +1,59 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+// proc-macro crates only export their macros, so the parser is included directly
+#[allow(dead_code)]
+#[path = "../src/parse.rs"]
+mod parse;
+
+/// Templates resembling those passed to the macros
+const TEMPLATES: [(&str, &str); 5] = [
+    (
+        "plain",
+        "Nothing but plain text, as many format strings are {}",
+    ),
+    (
+        "keywords",
+        "{[bold red]}error{[!bold default]}: {[italic on-blue]}{}{[]} failed {[underline]}{}{[]}",
+    ),
+    (
+        "colors",
+        "{[208 on-15]}byte{[]} {[15,115,215 on-0,0,0]}rgb{[]} {[#0f on-#0f73d7]}hex{[]}",
+    ),
+    (
+        "escapes",
+        r#"tab\tnewline\n\"quoted\" \x1b[0m \u{1f604}\
+            continued {{literal}} {named} {0:>8}"#,
+    ),
+    (
+        "long",
+        "{[bold]}{}{[]} {[red]}{}{[]} {[green]}{}{[]} {[yellow]}{}{[]} {[blue]}{}{[]} \
+         {[magenta]}{}{[]} {[cyan]}{}{[]} {[white]}{}{[]} {[1,2,3]}{}{[]} {[on-#ffffff]}{}{[]}",
+    ),
+];
+fn check_curly(ch: char) -> Option<&'static str> {
+    match ch {
+        '}' => Some("{}"),
+        '{' => Some("{{"),
+        _ => None,
+    }
+}
+fn sgr_string(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sgr_string");
+    for (name, template) in TEMPLATES {
+        group.bench_function(name, |b| {
+            b.iter(|| parse::sgr_string(black_box(template), check_curly));
+        });
+    }
+    group.finish();
+}
+fn unwrap_string(c: &mut Criterion) {
+    let raw = format!("r#\"{}\"#", TEMPLATES[1].1);
+    c.bench_function("unwrap_string", |b| {
+        b.iter(|| parse::unwrap_string(black_box(&raw)));
+    });
+}
+
+criterion_group!(benches, sgr_string, unwrap_string);
+criterion_main!(benches);
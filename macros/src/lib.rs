@@ -21,7 +21,7 @@ use proc_macro::{
     TokenTree,
 };
 
-use crate::parse::{create_raw_string, sgr_string, unwrap_string, UnwrappedLiteral};
+use crate::parse::{sgr_string, strip_sgr, unwrap_string, visible_len, UnwrappedLiteral};
 
 /// Contains strictly string parsing implementation
 mod parse;
@@ -182,6 +182,26 @@ def_macros!(
     ///println!("{}", args);
     ///```
     format_args : FormatArgs,
+    /// Constructs parameters for the other string-formatting macros,
+    /// SGR keywords substituted.
+    ///
+    /// The same as [`format_args!`], under a name not shadowing [`std::format_args!`].
+    /// Nothing is allocated, the expansion being a call to [`std::format_args!`]
+    /// with the keywords substituted within its literal,
+    /// so the arguments are borrowed & live as long as they do there.
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///# use easy_sgr_macros::sgr_args;
+    ///use std::io::Write;
+    ///
+    ///let mut log = Vec::new();
+    ///log.write_fmt(sgr_args!("{[dim]}{ts}{[]} {msg}", ts = 12, msg = "started"))?;
+    ///assert_eq!(log, b"\x1b[2m12\x1b[0m started");
+    ///# Ok::<(), std::io::Error>(())
+    ///```
+    sgr_args : FormatArgs,
     /// Creates a string literal,
     /// SGR keywords substituted.
     ///
@@ -191,8 +211,104 @@ def_macros!(
     ///# use easy_sgr_macros::sgr;
     ///let my_string = sgr!("{[italic red]}This should be italic & red!{[]}");
     ///```
-    sgr : Sgr
+    sgr : Sgr,
+    /// Creates a `usize` literal of the number of visible chars in a string,
+    /// SGR keywords substituted.
+    ///
+    /// Keywords & escape sequences take up no chars, escaped brackets such as `{{` take up one.
+    /// Format placeholders are an error, their length being unknown.
+    /// Raw strings are left as they are by [`sgr!`], so each of their chars is counted.
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///# use easy_sgr_macros::sgr_len;
+    ///const GUTTER: usize = sgr_len!("{[red bold]}ERROR{[]}") + 2;
+    ///assert_eq!(GUTTER, 7);
+    ///let padding = [' '; sgr_len!("\x1b[2m{{ok}}")];
+    ///assert_eq!(padding.len(), 4);
+    ///```
+    sgr_len : SgrLen
 );
+/// Creates both a string literal & a copy without its SGR sequences,
+/// SGR keywords substituted.
+///
+/// Returns a tuple of `(styled, plain)`, `styled` being what [`sgr!`] returns.
+/// Both keep the same format placeholders, so may be given the same arguments,
+/// e.g. to write to both a terminal & a log file.
+/// Runtime arguments within keywords, such as `{[(color)]}`,
+/// aren't supported since removing them would leave their arguments unused.
+///
+/// Raw strings are returned as they are for both.
+///
+/// # Examples
+///
+///```rust
+///# use easy_sgr_macros::sgr_both;
+///let (styled, plain) = sgr_both!("{[red]}fail{[]}: {code}");
+///assert_eq!(styled, "\x1b[31mfail\x1b[0m: {code}");
+///assert_eq!(plain, "fail: {code}");
+///```
+#[doc = include_str!("../keywords.md")]
+#[proc_macro]
+pub fn sgr_both(input: TokenStream) -> TokenStream {
+    match (
+        build_args::<true>(MacroKind::Sgr, input.clone()),
+        build_args::<true>(MacroKind::SgrPlain, input),
+    ) {
+        (Ok(styled), Ok(plain)) => build_stream!(Group::new(
+            Delimiter::Parenthesis,
+            build_stream!(styled, Punct::new(',', Spacing::Alone), plain)
+        )),
+        (Err(e), _) | (_, Err(e)) => e,
+    }
+}
+/// Creates a string literal with its escape sequences made visible,
+/// SGR keywords substituted.
+///
+/// Each ESC byte of what [`sgr!`] would return is written as the characters `\x1b`,
+/// or `\e` when followed by `, e`. All else is left as is,
+/// e.g. for comparing output against golden files or logging the sequences written.
+///
+/// Raw strings are returned as they are.
+///
+/// # Examples
+///
+///```rust
+///# use easy_sgr_macros::sgr_escaped;
+///assert_eq!(sgr_escaped!("{[red]}fail{[]}"), r"\x1b[31mfail\x1b[0m");
+///assert_eq!(sgr_escaped!("{[bold]}{name}", e), r"\e[1m{name}");
+///```
+#[doc = include_str!("../keywords.md")]
+#[proc_macro]
+pub fn sgr_escaped(input: TokenStream) -> TokenStream {
+    let mut tokens: Vec<_> = input.into_iter().collect();
+    let escape = match tokens.as_slice() {
+        [.., TokenTree::Punct(p), TokenTree::Ident(i)] if *p == ',' && i.to_string() == "e" => {
+            tokens.truncate(tokens.len() - 2);
+            Escape::Short
+        }
+        _ => Escape::Hex,
+    };
+    build_macro(MacroKind::SgrEscaped(escape), tokens.into_iter().collect())
+}
+/// How [`MacroKind::SgrEscaped`] writes the ESC byte
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Escape {
+    /// As `\x1b`
+    Hex,
+    /// As `\e`
+    Short,
+}
+impl Escape {
+    /// Returns the characters written in place of the ESC byte
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Hex => "\\x1b",
+            Self::Short => "\\e",
+        }
+    }
+}
 /// The type of macro
 ///
 /// Is used to differentiate how to go about parsing
@@ -207,6 +323,12 @@ enum MacroKind {
     Print,
     Println,
     Sgr,
+    /// [`MacroKind::Sgr`] with its SGR sequences removed
+    SgrPlain,
+    /// The visible length of [`MacroKind::Sgr`]
+    SgrLen,
+    /// [`MacroKind::Sgr`] with its ESC bytes written visibly
+    SgrEscaped(Escape),
     Write,
     Writeln,
 }
@@ -222,7 +344,7 @@ impl MacroKind {
             FormatArgs => "format_args",
             Print => "print",
             Println => "println",
-            Sgr => "",
+            Sgr | SgrPlain | SgrLen | SgrEscaped(_) => "",
             Write => "write",
             Writeln => "writeln",
         }
@@ -256,7 +378,13 @@ impl MacroKind {
 /// which will then be picked up by the compiler to report the relevant error.
 fn build_macro(kind: MacroKind, input: TokenStream) -> TokenStream {
     match kind {
-        MacroKind::Sgr => match build_args::<true>(kind, input) {
+        MacroKind::Sgr | MacroKind::SgrPlain | MacroKind::SgrEscaped(_) => {
+            match build_args::<true>(kind, input) {
+                Ok(tokens) | Err(tokens) => tokens,
+            }
+        }
+        // placeholders are told apart from escaped brackets by keeping `{{` as is
+        MacroKind::SgrLen => match build_args::<false>(kind, input) {
             Ok(tokens) | Err(tokens) => tokens,
         },
         _ => match build_args::<false>(kind, input) {
@@ -277,17 +405,14 @@ fn build_args<const MERGE_CURLY: bool>(
     input: TokenStream,
 ) -> Result<TokenStream, TokenStream> {
     let tokens = input.into_iter();
-    let stream = match StreamParts::from_parts(kind, tokens) {
-        Ok(stream) => stream,
-        Err(tokens) => return Err(tokens),
-    };
+    let stream = StreamParts::from_parts(kind, tokens)?;
 
     let (literal, parsed_literal) = match &stream.kind {
         StreamKind::Standard(literal) | StreamKind::Writer(_, Some((_, literal))) => (
             Some(literal),
             unwrap_string(&literal.to_string()).map_or_else(
                 || ParsedLiteral::InvalidToken(TokenTree::from(literal.clone())),
-                |unwrapped| ParsedLiteral::parse::<MERGE_CURLY>(&unwrapped),
+                |unwrapped| ParsedLiteral::parse::<MERGE_CURLY>(literal, &unwrapped, kind),
             ),
         ),
         StreamKind::Writer(_, None) | StreamKind::Empty => (None, ParsedLiteral::Empty),
@@ -318,7 +443,11 @@ fn build_args<const MERGE_CURLY: bool>(
                 build_stream!(writer, punct, stream.tokens)
             }
             StreamKind::Writer(writer, None) => build_stream!(writer),
-            _ if kind == MacroKind::Sgr => {
+            _ if matches!(
+                kind,
+                MacroKind::Sgr | MacroKind::SgrPlain | MacroKind::SgrLen | MacroKind::SgrEscaped(_)
+            ) =>
+            {
                 return Err(compile_error(Span::mixed_site(), "missing string literal"))
             }
             _ => build_stream!(),
@@ -337,7 +466,17 @@ impl ParsedLiteral {
     ///
     /// A constant bool is used to indicate whether to merge open curly brackets.
     /// This means wether `{{` should be turned into `{` or `{{`
-    fn parse<const MERGE_CURLY: bool>(unwrapped: &UnwrappedLiteral) -> Self {
+    ///
+    /// SGR sequences are removed for [`MacroKind::SgrPlain`],
+    /// the visible length being counted for [`MacroKind::SgrLen`]
+    /// & ESC bytes being made visible for [`MacroKind::SgrEscaped`]
+    ///
+    /// Raw strings are left as they are, so `literal` is reused as is
+    fn parse<const MERGE_CURLY: bool>(
+        literal: &Literal,
+        unwrapped: &UnwrappedLiteral,
+        kind: MacroKind,
+    ) -> Self {
         use UnwrappedLiteral::*;
         let check_curly = |ch| match ch {
             '}' => Some("{}"),
@@ -345,16 +484,24 @@ impl ParsedLiteral {
             _ => None,
         };
         match unwrapped {
-            String(s) => match sgr_string(s, check_curly) {
-                Ok(s) => Self::String(Literal::string(&s)),
-                Err(e) => Self::InvalidString(e),
-            },
-            // using FromStr is the only way to return a raw string
-            RawString(s, i) => Self::RawString(
-                create_raw_string(s, *i)
-                    .parse()
-                    .expect("Raw string parsing failed, should never fail"),
-            ),
+            String(s) => {
+                match sgr_string(s, check_curly).and_then(|s| match kind {
+                    MacroKind::SgrPlain => strip_sgr(&s).map(|s| Literal::string(&s)),
+                    MacroKind::SgrLen => visible_len(&s).map(Literal::usize_unsuffixed),
+                    MacroKind::SgrEscaped(escape) => {
+                        Ok(Literal::string(&s.replace('\x1b', escape.as_str())))
+                    }
+                    _ => Ok(Literal::string(&s)),
+                }) {
+                    Ok(literal) => Self::String(literal),
+                    Err(e) => Self::InvalidString(e),
+                }
+            }
+            // raw strings are written as they are, each char included
+            RawString(s, _) if kind == MacroKind::SgrLen => {
+                Self::String(Literal::usize_unsuffixed(s.chars().count()))
+            }
+            RawString(..) => Self::RawString(TokenTree::from(literal.clone()).into()),
         }
     }
 }
@@ -417,13 +564,12 @@ impl StreamKind {
         use MacroKind::*;
         use StreamKind::*;
         match kind {
-            EPrint | EPrintln | Format | FormatArgs | Print | Println | Sgr => {
-                match tokens.next() {
-                    Some(TokenTree::Literal(literal)) => Ok(Standard(literal)),
-                    Some(t) => Err(build_stream!(t)),
-                    None => Ok(Empty),
-                }
-            }
+            EPrint | EPrintln | Format | FormatArgs | Print | Println | Sgr | SgrPlain | SgrLen
+            | SgrEscaped(_) => match tokens.next().map(unwrap_none_groups) {
+                Some(TokenTree::Literal(literal)) => Ok(Standard(literal)),
+                Some(t) => Err(build_stream!(t)),
+                None => Ok(Empty),
+            },
             Write | Writeln => {
                 fn find_punct(writer: &mut Vec<TokenTree>, tokens: &mut IntoIter) -> Option<Punct> {
                     for token in tokens.by_ref() {
@@ -438,9 +584,9 @@ impl StreamKind {
                 }
                 let mut writer = Vec::new();
                 let Some(punct) = find_punct(&mut writer, tokens) else {
-                    return Ok(Writer(writer, None))
+                    return Ok(Writer(writer, None));
                 };
-                match tokens.next() {
+                match tokens.next().map(unwrap_none_groups) {
                     Some(TokenTree::Literal(literal)) => Ok(Writer(writer, Some((punct, literal)))),
                     Some(t) => Err(build_stream!(writer, punct, t)),
                     None => Err(build_stream!(writer, punct)),
@@ -449,6 +595,22 @@ impl StreamKind {
         }
     }
 }
+/// Unwraps the invisible groups around a single token
+///
+/// Tokens forwarded through `macro_rules!`, such as `$s:expr` captures,
+/// arrive wrapped in [`Delimiter::None`] groups, once for each layer of forwarding
+fn unwrap_none_groups(token: TokenTree) -> TokenTree {
+    match token {
+        TokenTree::Group(group) if group.delimiter() == Delimiter::None => {
+            let mut inner = group.stream().into_iter();
+            match (inner.next(), inner.next()) {
+                (Some(token), None) => unwrap_none_groups(token),
+                _ => TokenTree::Group(group),
+            }
+        }
+        token => token,
+    }
+}
 /// creates a [`TokenStream`] of a [`std`] macro
 /// with the given [`Span`] & stream (used within a [`Group`])
 pub(crate) fn create_macro(macro_call: &str, span: Span, stream: TokenStream) -> TokenStream {
@@ -494,6 +656,23 @@ impl Error {
             ),
             MissingBracket => compile_error(span, "Missing a close bracket"),
             InvalidColorLen => compile_error(span, "Incorrect number of digits found"),
+            InvalidArgument => compile_error(
+                span,
+                "invalid runtime argument, expected `()`, `(index)` or `(name)`",
+            ),
+            InvalidPlaceholder => compile_error(
+                span,
+                "invalid styled placeholder, expected `{name[keywords]}` or `{name[keywords]:spec}`",
+            ),
+            NoInverse => compile_error(
+                span,
+                "keyword can't be undone, so can't style a placeholder",
+            ),
+            Placeholder => compile_error(span, "format placeholders have no known length"),
+            RuntimePlain => compile_error(
+                span,
+                "runtime arguments within keywords can't be removed from the plain string",
+            ),
             CompilerPassOff => {
                 literal.map_or_else(|| build_stream!(), |literal| build_stream!(literal.clone()))
             }
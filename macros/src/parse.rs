@@ -10,13 +10,13 @@ pub enum UnwrappedLiteral<'a> {
 }
 /// [Unwraps](UnwrappedLiteral) string, returning `None`
 /// when string is invalid
-pub fn unwrap_string(s: &str) -> Option<UnwrappedLiteral> {
+pub fn unwrap_string(s: &str) -> Option<UnwrappedLiteral<'_>> {
     use UnwrappedLiteral::*;
     match s.strip_prefix('r') {
         Some(s) => {
-            let len = s.as_bytes().len();
+            let len = s.len();
             let s = s.trim_matches('#');
-            let diff = len - s.as_bytes().len();
+            let diff = len - s.len();
             if diff % 2 == 0 {
                 s.strip_prefix('"')?
                     .strip_suffix('"')
@@ -28,24 +28,17 @@ pub fn unwrap_string(s: &str) -> Option<UnwrappedLiteral> {
         None => s.strip_prefix('"')?.strip_suffix('"').map(String),
     }
 }
-/// Creates a raw string to be parsed and turned into a `TokenStream`
-pub fn create_raw_string(s: &str, i: usize) -> String {
-    // add space for r#".."#
-    let mut buf = String::with_capacity(s.len() + i * 2 + 3);
-    buf.push('r');
-    (0..i).for_each(|_| buf.push('#'));
-    buf.push('"');
-    buf.push_str(s);
-    buf.push('"');
-    (0..i).for_each(|_| buf.push('#'));
-    buf
-}
 /// An error ran into while parsing
 #[derive(Debug, PartialEq, Eq)]
 pub enum Error {
     ParseInt(ParseIntError),
     MissingBracket,
     InvalidColorLen,
+    InvalidArgument,
+    InvalidPlaceholder,
+    NoInverse,
+    Placeholder,
+    RuntimePlain,
     CompilerPassOff,
 }
 impl From<ParseIntError> for Error {
@@ -64,7 +57,7 @@ impl From<ParseIntError> for Error {
 /// - Unclosed bracket
 /// - Invalid keyword
 ///
-/// Invalid strings can also be occasionally returned with an Ok(), in
+/// Invalid strings can also be occasionally returned with an `Ok()`, in
 /// these cases the string will continue being parsed> When returned the
 /// compiler is expected to deal with the error.
 /// The spots where these cases occur be annotated by the comment:
@@ -73,11 +66,13 @@ pub fn sgr_string<F>(s: &str, check_curly: F) -> Result<String, Error>
 where
     F: Fn(char) -> Option<&'static str>,
 {
+    // parsed strings are rarely longer than `s`, reserving
+    // for the longest possible params measured slower
     let mut buf = String::with_capacity(s.len());
     let chars = &mut s.char_indices();
     let mut next: Option<(usize, char)> = chars.next();
 
-    while let Some((_, ch)) = next {
+    while let Some((i, ch)) = next {
         match ch {
             // should never be ran into outside of testing
             '\\' => {
@@ -101,18 +96,142 @@ where
                     continue;
                 }
             },
-            ch => buf.push(ch),
+            // plain text is copied a run at a time
+            _ => {
+                next = chars.find(|(_, ch)| matches!(ch, '\\' | '{' | '}'));
+                buf.push_str(&s[i..next.map_or(s.len(), |(end, _)| end)]);
+                continue;
+            }
         }
         next = chars.next();
     }
+    Ok(normalize_sequences(buf))
+}
+/// Removes the SGR sequences of a string parsed by [`sgr_string`],
+/// leaving other escape sequences & format placeholders
+///
+/// # Errors
+///
+/// Returns [`Error::RuntimePlain`] when a sequence holds a runtime argument,
+/// as removing it would leave its format argument unused
+pub fn strip_sgr(s: &str) -> Result<String, Error> {
+    let mut buf = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find("\x1b[") {
+        buf.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let mut runtime = false;
+        let mut chars = rest.char_indices().skip(2);
+        let end = loop {
+            match chars.next() {
+                Some((_, '0'..='9' | ';')) => (),
+                Some((_, '{')) => {
+                    runtime = true;
+                    if !chars.any(|(_, ch)| ch == '}') {
+                        break None;
+                    }
+                }
+                Some((i, 'm')) => break Some(i),
+                _ => break None,
+            }
+        };
+        match end {
+            Some(_) if runtime => return Err(Error::RuntimePlain),
+            Some(end) => rest = &rest[end + 1..],
+            None => {
+                buf.push_str("\x1b[");
+                rest = &rest[2..];
+            }
+        }
+    }
+    buf.push_str(rest);
     Ok(buf)
 }
+/// Counts the visible chars of a string parsed by [`sgr_string`]
+/// without merging `{{`, escape sequences taking up none
+///
+/// # Errors
+///
+/// Returns [`Error::Placeholder`] for any format placeholder,
+/// including runtime arguments within keywords
+pub fn visible_len(s: &str) -> Result<usize, Error> {
+    let mut len = 0;
+    let mut chars = s.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\x1b' => match chars.next() {
+                // a control sequence ends with its final byte
+                Some('[') => loop {
+                    match chars.next() {
+                        Some('{') => return Err(Error::Placeholder),
+                        Some('@'..='~') | None => break,
+                        Some(_) => (),
+                    }
+                },
+                // an operating system command ends with `BEL` or `ST`
+                Some(']') => loop {
+                    match chars.next() {
+                        Some('\x07') | None => break,
+                        Some('\x1b') => {
+                            chars.next_if_eq(&'\\');
+                            break;
+                        }
+                        Some(_) => (),
+                    }
+                },
+                _ => (),
+            },
+            '{' | '}' if chars.next_if_eq(&ch).is_some() => len += 1,
+            '{' => return Err(Error::Placeholder),
+            _ => len += 1,
+        }
+    }
+    Ok(len)
+}
+/// Rewrites the SGR sequences written within `s` as keyword groups are written,
+/// so `\x1b[01;031m` becomes `\x1b[1;31m` & `\x1b[m` becomes `\x1b[0m`
+///
+/// Sequences with parameters other than codes, such as runtime arguments
+/// or subparameters, are left as they are
+fn normalize_sequences(s: String) -> String {
+    if !s.contains('\x1b') {
+        return s;
+    }
+    let mut buf = String::with_capacity(s.len());
+    let mut rest = s.as_str();
+    while let Some(start) = rest.find("\x1b[") {
+        buf.push_str(&rest[..start]);
+        buf.push_str("\x1b[");
+        rest = &rest[start + 2..];
+        let end = rest
+            .find(|ch: char| !matches!(ch, '0'..='9' | ';'))
+            .filter(|&end| rest[end..].starts_with('m'));
+        let Some(end) = end else {
+            continue;
+        };
+        // checked before writing, so invalid sequences are left whole
+        if rest[..end]
+            .split(';')
+            .all(|param| param.is_empty() || param.parse::<u8>().is_ok())
+        {
+            for param in rest[..end].split(';') {
+                param.parse().unwrap_or(0).append_to(&mut buf);
+                buf.push(';');
+            }
+            buf.pop();
+            buf.push('m');
+            rest = &rest[end + 1..];
+        }
+    }
+    buf.push_str(rest);
+    buf
+}
 /// Checks the `char` after an escape
 ///
 /// # Returns
 ///
 /// - `Ok(Some(_))` when parsing has succeeded, returned value is
-/// meant to be used as the next `char` for parsing
+///   meant to be used as the next `char` for parsing
 /// - Ok(None) when parsing has succeeded, parsing to continue as normal
 /// - Err(Error) when an unrecoverable invalid string has been detected
 fn parse_escape(
@@ -160,7 +279,7 @@ fn parse_escape(
 /// - `next_char`: the index, char pair after the opening brace
 /// - `s`: the full string to parse
 /// - `chars`: the string's `char_indices`,
-/// with `chars.next()` being the char after `next_char`
+///   with `chars.next()` being the char after `next_char`
 /// - `buf`: the string buf to append and return
 /// - `check_curly`: fn to check if char is curly
 ///
@@ -176,7 +295,7 @@ fn parse_param(
 ) -> Result<(), Error> {
     let Some((start, ch)) = next_char else {
         // INVALID HERE
-        buf.push( '{');
+        buf.push('{');
         return Ok(());
     };
     if let Some(s) = check_curly(ch) {
@@ -186,10 +305,13 @@ fn parse_param(
 
     // INVALID RETURN
     let Some(end) = chars.find(|ch| ch.1 == '}') else {
-        buf.push_str( &s[start-1..]);
+        buf.push_str(&s[start - 1..]);
         return Ok(());
     };
     let end = end.0;
+    if styled_placeholder(&s[start..end], buf)? {
+        return Ok(());
+    }
     if ch == '[' {
         buf.push_str("\x1b[");
         for s in s[start + 1..end]
@@ -210,6 +332,81 @@ fn parse_param(
     }
     Ok(())
 }
+/// Parses a format placeholder styled by keywords, returning whether `param` was one
+///
+/// The keywords come between the argument & any format spec:
+///
+/// - `{name[..]}` -> `\x1b[..m{name}\x1b[..m`
+/// - `{0[..]:>10}` -> `\x1b[..m{0:>10}\x1b[..m`
+/// - `{[..]:}` -> `\x1b[..m{}\x1b[..m`
+///
+/// The placeholder is followed by the codes undoing each keyword,
+/// so keywords that can't be undone are an error
+fn styled_placeholder(param: &str, buf: &mut String) -> Result<bool, Error> {
+    let Some(open) = param.find('[') else {
+        return Ok(false);
+    };
+    let arg = &param[..open];
+    if !arg.chars().all(|ch| ch == '_' || ch.is_alphanumeric()) {
+        return Ok(false);
+    }
+    let close = open + param[open..].find(']').ok_or(Error::MissingBracket)?;
+    let spec = &param[close + 1..];
+    // a keyword group, `{[..]}`
+    if arg.is_empty() && spec.is_empty() {
+        return Ok(false);
+    }
+    if !(spec.is_empty() || spec.starts_with(':')) {
+        return Err(Error::InvalidPlaceholder);
+    }
+    let keywords = &param[open + 1..close];
+    if keywords.split_whitespace().next().is_none() {
+        return Err(Error::NoInverse);
+    }
+    let mut undo: Vec<&str> = Vec::new();
+    buf.push_str("\x1b[");
+    for keyword in keywords.split_whitespace() {
+        parse_sgr(keyword, buf)?;
+        buf.push(';');
+        let inverse = inverse(keyword)?;
+        if !undo.contains(&inverse) {
+            undo.push(inverse);
+        }
+    }
+    buf.pop();
+    buf.push_str("m{");
+    buf.push_str(arg);
+    buf.push_str(spec);
+    buf.push_str("}\x1b[");
+    for (i, inverse) in undo.iter().rev().enumerate() {
+        if i != 0 {
+            buf.push(';');
+        }
+        buf.push_str(inverse);
+    }
+    buf.push('m');
+    Ok(true)
+}
+/// Returns the code undoing a valid keyword
+///
+/// Resets, undoing keywords & runtime arguments can't be undone
+fn inverse(keyword: &str) -> Result<&'static str, Error> {
+    match parse_common(keyword) {
+        Some(1 | 2) => Ok("22"),
+        Some(3) => Ok("23"),
+        Some(4) => Ok("24"),
+        Some(5) => Ok("25"),
+        Some(7) => Ok("27"),
+        Some(8) => Ok("28"),
+        Some(9) => Ok("29"),
+        Some(30..=39) => Ok("39"),
+        Some(40..=49) => Ok("49"),
+        Some(_) => Err(Error::NoInverse),
+        None if keyword.starts_with('(') => Err(Error::NoInverse),
+        None if keyword.starts_with("on-") => Ok("49"),
+        None => Ok("39"),
+    }
+}
 /// Parses 7bit escape(`\x..`) into a char
 fn parse_7bit(chars: &mut CharIndices, s: &str) -> Option<char> {
     let (end, _) = chars.nth(1)?;
@@ -228,15 +425,34 @@ fn parse_24bit(chars: &mut CharIndices, s: &str) -> Option<char> {
 ///
 /// - `Err(ParseError)` if `s` is an invalid keyword
 ///
-/// First [`parse_common`] is used, if it fails [`complex_color`] is used
+/// First [`parse_common`] is used, then [`runtime_arg`] for keywords
+/// within parentheses, if both fail [`complex_color`] is used
 fn parse_sgr(s: &str, buf: &mut String) -> Result<(), Error> {
     if let Some(n) = parse_common(s) {
         n.append_to(buf);
         Ok(())
+    } else if let Some(arg) = s.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+        runtime_arg(arg, buf)
     } else {
         complex_color(s, buf)
     }
 }
+/// Parses an argument given at runtime into a format placeholder
+///
+/// The argument is expected to be one of the following:
+///
+/// - `()` -> `{}`
+/// - `(0)` -> `{0}`
+/// - `(name)` -> `{name}`
+fn runtime_arg(arg: &str, buf: &mut String) -> Result<(), Error> {
+    if !arg.chars().all(|ch| ch == '_' || ch.is_alphanumeric()) {
+        return Err(Error::InvalidArgument);
+    }
+    buf.push('{');
+    buf.push_str(arg);
+    buf.push('}');
+    Ok(())
+}
 /// Parses common keywords
 fn parse_common(s: &str) -> Option<u8> {
     match s {
@@ -322,11 +538,17 @@ fn complex_color(s: &str, buf: &mut String) -> Result<(), Error> {
             _ => return Err(Error::InvalidColorLen),
         }
     } else {
-        let parts = s
-            .split(',')
-            .map(std::str::FromStr::from_str)
-            .collect::<Result<Vec<u8>, _>>()?;
-        match parts[..] {
+        // errors match collecting every part, without collecting into a `Vec`
+        let mut parts = [0; 3];
+        let mut len = 0;
+        for part in s.split(',') {
+            let n = part.parse()?;
+            if let Some(slot) = parts.get_mut(len) {
+                *slot = n;
+            }
+            len += 1;
+        }
+        match *parts.get(..len).unwrap_or_default() {
             [n] => {
                 buf.push_str("5;");
                 n.append_to(buf);
@@ -354,14 +576,8 @@ trait AppendToString {
     /// Appends self converted to a string to an existing string
     fn append_to(&self, s: &mut String);
 }
-// this would be cool
-// impl<AppendToString> ToString for A {
-//     fn to_string(&self) -> String {
-//         let mut buf = String::new();
-//         self.append_to(&mut buf);
-//         buf
-//     }
-// }
+// a copy of `easy_sgr::append::AppendToString`,
+// as `easy-sgr` depends on this crate rather than the other way round
 impl AppendToString for u8 {
     fn append_to(&self, s: &mut String) {
         let mut n = *self;
@@ -1,4 +1,5 @@
-use crate::parse::{create_raw_string, sgr_string, unwrap_string, Error, UnwrappedLiteral};
+#![allow(clippy::needless_raw_string_hashes)]
+use crate::parse::{sgr_string, strip_sgr, unwrap_string, visible_len, Error, UnwrappedLiteral};
 
 #[test]
 fn unwrap_str() {
@@ -18,20 +19,6 @@ fn unwrap_str() {
     }
 }
 
-#[test]
-fn raw_string() {
-    assert_eq!(create_raw_string("", 0), r#"r"""#);
-    assert_eq!(
-        create_raw_string("This one has text", 0),
-        r#"r"This one has text""#
-    );
-    assert_eq!(
-        create_raw_string("🚋 🏥 🤐 💷 🌛", 0),
-        r#"r"🚋 🏥 🤐 💷 🌛""#
-    );
-    assert_eq!(create_raw_string("", 5), r######"r#####""#####"######);
-}
-
 #[test]
 fn sgr_string_general() {
     for (test, result) in [
@@ -40,7 +27,7 @@ fn sgr_string_general() {
         ("\x1b", Ok("\x1b")),
         ("\u{1f604} ☀ ☁ ☂", Ok("\u{1f604} ☀ ☁ ☂")),
     ] {
-        test_eq(test, result)
+        test_eq(test, result);
     }
 }
 
@@ -52,7 +39,7 @@ fn compiler_pass_off_undetected() {
         ("{with text", Ok("{with text")),
         ("with text{with text", Ok("with text{with text")),
     ] {
-        test_eq(test, result)
+        test_eq(test, result);
     }
 }
 #[test]
@@ -66,7 +53,7 @@ fn compiler_pass_off() {
         (r"\x", Err(CompilerPassOff)),
         (r"\x'", Err(CompilerPassOff)),
     ] {
-        test_eq(test, result)
+        test_eq(test, result);
     }
 }
 
@@ -94,7 +81,7 @@ fn skip_whitespace() {
             Ok("\n\r\t"),
         ),
     ] {
-        test_eq(test, result)
+        test_eq(test, result);
     }
 }
 
@@ -105,7 +92,7 @@ fn escapes() {
         "#,
         Ok("\' \" \x00 \n \r \t \\ \0 \u{0}\
         "),
-    )
+    );
 }
 
 #[test]
@@ -127,9 +114,106 @@ fn param_errors() {
         "no num{[#0]}",
         "comma error {[0,0]}",
         "bracket {[yeah}",
+        "arg {[(a b)]}",
+        "arg {[(a.b)]}",
+        "arg {[(a]}",
     ] {
         let result = sgr_string(test, check_curly);
-        assert!(result.is_err(), "Unexpected value: {result:#?}")
+        assert!(result.is_err(), "Unexpected value: {result:#?}");
+    }
+}
+#[test]
+fn runtime_args() {
+    for (test, result) in [
+        ("{[()]}", Ok("\x1b[{}m")),
+        ("{[(0)]}text", Ok("\x1b[{0}mtext")),
+        ("{[bold (color) on-red]}", Ok("\x1b[1;{color};41m")),
+        ("{[(fg) (bg)]}", Ok("\x1b[{fg};{bg}m")),
+    ] {
+        test_eq(test, result);
+    }
+}
+#[test]
+// the placeholders are what's being parsed
+#[allow(clippy::literal_string_with_formatting_args)]
+fn styled_placeholders() {
+    for (test, result) in [
+        ("{path[cyan underline]}", Ok("\x1b[36;4m{path}\x1b[24;39m")),
+        ("{0[bold]:>10}!", Ok("\x1b[1m{0:>10}\x1b[22m!")),
+        (
+            "{[on-208 dim bold]:?}",
+            Ok("\x1b[48;5;208;2;1m{:?}\x1b[22;49m"),
+        ),
+        (
+            "{x[#ff0000 on-red]:x<4}",
+            Ok("\x1b[38;2;255;0;0;41m{x:x<4}\x1b[49;39m"),
+        ),
+        // not styled placeholders
+        ("{x:[>5}{[red]}", Ok("{x:[>5}\x1b[31m")),
+    ] {
+        test_eq(test, result);
+    }
+    for (test, result) in [
+        ("{x[]}", Error::NoInverse),
+        ("{x[reset]}", Error::NoInverse),
+        ("{x[!bold]}", Error::NoInverse),
+        ("{x[(color)]}", Error::NoInverse),
+        ("{x[red]>5}", Error::InvalidPlaceholder),
+        ("{x[red}", Error::MissingBracket),
+        ("{x[#0]}", Error::InvalidColorLen),
+    ] {
+        assert_eq!(sgr_string(test, check_curly), Err(result), "{test}");
+    }
+}
+#[test]
+fn raw_sequences() {
+    for (test, result) in [
+        (r"\x1b[31mtext", Ok("\x1b[31mtext")),
+        (r"\x1b[01;031m\u{1b}[m", Ok("\x1b[1;31m\x1b[0m")),
+        (r"\x1b[;1m{[bold]}\x1b[0;m", Ok("\x1b[0;1m\x1b[1m\x1b[0;0m")),
+        ("\x1b[4m{[]}", Ok("\x1b[4m\x1b[0m")),
+        // left as is, not being made of codes
+        (
+            r"\x1b[256m \x1b[38:5:1m \x1b[2J",
+            Ok("\x1b[256m \x1b[38:5:1m \x1b[2J"),
+        ),
+        (r"\x1b[1 \x1b[ \x1b", Ok("\x1b[1 \x1b[ \x1b")),
+        (r"{[(arg)]} \x1b[{}m", Ok("\x1b[{arg}m \x1b[{}m")),
+    ] {
+        test_eq(test, result);
+    }
+}
+#[test]
+fn strip() {
+    for (test, result) in [
+        ("", Ok("")),
+        ("\x1b[1;31mtext\x1b[0m", Ok("text")),
+        ("{name} \x1b[3m{}\x1b[23m", Ok("{name} {}")),
+        // not SGR, so left as is
+        (
+            "\x1b[2J \x1b[38:5:1m \x1b[1 \x1b[ \x1b",
+            Ok("\x1b[2J \x1b[38:5:1m \x1b[1 \x1b[ \x1b"),
+        ),
+        ("\x1b[1;{arg}m", Err(Error::RuntimePlain)),
+        ("\x1b[{}m", Err(Error::RuntimePlain)),
+        ("\x1b[{m", Ok("\x1b[{m")),
+    ] {
+        assert_eq!(strip_sgr(test).as_deref(), result.as_deref(), "{test:?}");
+    }
+}
+#[test]
+fn len() {
+    for (test, result) in [
+        ("", Ok(0)),
+        ("text", Ok(4)),
+        ("\x1b[1;31mtext\x1b[0m", Ok(4)),
+        ("\x1b[2J\x1b7\x1b]0;title\x07\x1b]8;;\x1b\\", Ok(0)),
+        ("{{}}", Ok(2)),
+        ("{name}", Err(Error::Placeholder)),
+        ("{}", Err(Error::Placeholder)),
+        ("\x1b[{arg}m", Err(Error::Placeholder)),
+    ] {
+        assert_eq!(visible_len(test), result, "{test:?}");
     }
 }
 fn test_eq(test: &str, result: Result<&str, Error>) {
@@ -139,7 +223,7 @@ fn test_eq(test: &str, result: Result<&str, Error>) {
             Err(result) => panic!("\"{test}\" does not eq {result:#?}"),
         },
         Err(test) => match result {
-            Ok(result) => panic!("{test:#?} does not eq {result}",),
+            Ok(result) => panic!("{test:#?} does not eq {result}"),
             Err(result) => assert_eq!(test, result),
         },
     }
@@ -6,7 +6,8 @@ python ./scripts/copy_readme.py
 cargo check --workspace --verbose
 cargo build --workspace --verbose
 cargo doc --workspace --verbose
-cargo test -F=macros --workspace --verbose
+cargo doc --no-deps -F=macros,terminfo,log,tracing,env-logger,width,test-util,ansi-term,anstyle,clap,termcolor,owo-colors,crossterm,ratatui,ufmt,serde,from-str --workspace --verbose
+cargo test -F=macros,terminfo,log,tracing,env-logger,width,test-util,ansi-term,anstyle,clap,termcolor,owo-colors,crossterm,ratatui,ufmt,serde,from-str --workspace --verbose
 cargo test -F=partial partial --verbose  
 cargo clippy --workspace --verbose
 
@@ -16,7 +17,7 @@ python ./scripts/copy_librs.py
 
 cargo llvm-cov clean --workspace # remove artifacts that may affect the coverage results
 cargo llvm-cov --no-report --workspace
-cargo llvm-cov --no-report -F=macros,partial
+cargo llvm-cov --no-report -F=macros,partial,terminfo,log,tracing,env-logger,width,test-util,ansi-term,anstyle,clap,termcolor,owo-colors,crossterm,ratatui,ufmt,serde,from-str
 cargo llvm-cov report # generate report without tests
 
 echo -e "\033[0;32mExecution Complete\033[0m"
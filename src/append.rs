@@ -0,0 +1,113 @@
+use crate::{writing::push_codes, Color, DiscreteSGR, SGRBuilder, Style};
+
+/// A trait for appending self to a given string
+///
+/// Similar to [`ToString`] but appends to an existing string
+/// instead of allocating a new one
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{append::AppendToString, Color::*};
+///
+///let mut s = String::from("\x1b[");
+///ByteFg(208).append_to(&mut s);
+///s.push(';');
+///1_u8.append_to(&mut s);
+///'m'.append_to(&mut s);
+///assert_eq!(s, "\x1b[38;5;208;1m");
+///```
+pub trait AppendToString {
+    /// Appends self converted to a string to an existing string
+    fn append_to(&self, s: &mut String);
+    /// Returns self converted to a new string, as [`ToString::to_string`] does
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{append::AppendToString, Style::*};
+    ///
+    ///assert_eq!(65535_u16.to_appended_string(), "65535");
+    ///assert_eq!(Bold.to_appended_string(), "1");
+    ///```
+    fn to_appended_string(&self) -> String {
+        let mut s = String::new();
+        self.append_to(&mut s);
+        s
+    }
+}
+/// Implements [`AppendToString`] for unsigned integers,
+/// writing digits to the stack before appending them at once
+macro_rules! append_unsigned {
+    ($($ty:ty),*) => {
+        $(
+            impl AppendToString for $ty {
+                fn append_to(&self, s: &mut String) {
+                    // enough digits for the largest `u64`
+                    let mut digits = [0; 20];
+                    let mut start = digits.len();
+                    let mut n = *self;
+                    loop {
+                        start -= 1;
+                        // always a single digit
+                        #[allow(clippy::cast_possible_truncation)]
+                        {
+                            digits[start] = b'0' + (n % 10) as u8;
+                        }
+                        n /= 10;
+                        if n == 0 {
+                            break;
+                        }
+                    }
+                    s.push_str(
+                        std::str::from_utf8(&digits[start..])
+                            .expect("digits should only be made of ASCII"),
+                    );
+                }
+            }
+        )*
+    };
+}
+append_unsigned!(u16, u32, usize);
+impl AppendToString for u8 {
+    fn append_to(&self, s: &mut String) {
+        let mut n = *self;
+        if n >= 10 {
+            if n >= 100 {
+                s.push(char::from(b'0' + n / 100));
+                n %= 100;
+            }
+            s.push(char::from(b'0' + n / 10));
+            n %= 10;
+        }
+        s.push(char::from(b'0' + n));
+    }
+}
+impl AppendToString for &str {
+    fn append_to(&self, s: &mut String) {
+        s.push_str(self);
+    }
+}
+impl AppendToString for char {
+    fn append_to(&self, s: &mut String) {
+        s.push(*self);
+    }
+}
+/// Appends the codes of the color separated by `;`, without the escape or end
+impl AppendToString for Color {
+    fn append_to(&self, s: &mut String) {
+        append_discrete(self, s);
+    }
+}
+/// Appends the code of the style, without the escape or end
+impl AppendToString for Style {
+    fn append_to(&self, s: &mut String) {
+        append_discrete(self, s);
+    }
+}
+/// Appends the codes of `sgr` separated by `;`
+fn append_discrete(sgr: &impl DiscreteSGR, s: &mut String) {
+    let mut builder = SGRBuilder::default();
+    sgr.write(&mut builder);
+    push_codes(s, builder.codes());
+}
@@ -0,0 +1,167 @@
+/// Resets all styles & colors, the `reset` keyword
+pub const RESET: &str = "\x1b[0m";
+/// Bold text, the `bold` keyword
+pub const BOLD: &str = "\x1b[1m";
+/// Dimmed text, the `dim` keyword
+pub const DIM: &str = "\x1b[2m";
+/// Italic text, the `italic` keyword
+pub const ITALIC: &str = "\x1b[3m";
+/// Underlined text, the `underline` keyword
+pub const UNDERLINE: &str = "\x1b[4m";
+/// Blinking text, the `blink` keyword
+pub const BLINKING: &str = "\x1b[5m";
+/// Swaps the foreground & background, the `inverse` keyword
+pub const INVERSE: &str = "\x1b[7m";
+/// Hidden text, the `hide` keyword
+pub const HIDDEN: &str = "\x1b[8m";
+/// Struck through text, the `strike` keyword
+pub const STRIKETHROUGH: &str = "\x1b[9m";
+/// Cleans bold text, also cleaning dim text, the `!bold` keyword
+pub const NO_BOLD: &str = "\x1b[22m";
+/// Cleans dim text, also cleaning bold text, the `!dim` keyword
+pub const NO_DIM: &str = "\x1b[22m";
+/// Cleans italic text, the `!italic` keyword
+pub const NO_ITALIC: &str = "\x1b[23m";
+/// Cleans underlined text, the `!underline` keyword
+pub const NO_UNDERLINE: &str = "\x1b[24m";
+/// Cleans blinking text, the `!blink` keyword
+pub const NO_BLINKING: &str = "\x1b[25m";
+/// Cleans inverse text, the `!inverse` keyword
+pub const NO_INVERSE: &str = "\x1b[27m";
+/// Cleans hidden text, the `!hide` keyword
+pub const NO_HIDDEN: &str = "\x1b[28m";
+/// Cleans struck through text, the `!strike` keyword
+pub const NO_STRIKETHROUGH: &str = "\x1b[29m";
+/// Black foreground, the `black` keyword
+pub const BLACK_FG: &str = "\x1b[30m";
+/// Red foreground, the `red` keyword
+pub const RED_FG: &str = "\x1b[31m";
+/// Green foreground, the `green` keyword
+pub const GREEN_FG: &str = "\x1b[32m";
+/// Yellow foreground, the `yellow` keyword
+pub const YELLOW_FG: &str = "\x1b[33m";
+/// Blue foreground, the `blue` keyword
+pub const BLUE_FG: &str = "\x1b[34m";
+/// Magenta foreground, the `magenta` keyword
+pub const MAGENTA_FG: &str = "\x1b[35m";
+/// Cyan foreground, the `cyan` keyword
+pub const CYAN_FG: &str = "\x1b[36m";
+/// White foreground, the `white` keyword
+pub const WHITE_FG: &str = "\x1b[37m";
+/// The default foreground, the `default` keyword
+pub const DEFAULT_FG: &str = "\x1b[39m";
+/// Black background, the `on-black` keyword
+pub const BLACK_BG: &str = "\x1b[40m";
+/// Red background, the `on-red` keyword
+pub const RED_BG: &str = "\x1b[41m";
+/// Green background, the `on-green` keyword
+pub const GREEN_BG: &str = "\x1b[42m";
+/// Yellow background, the `on-yellow` keyword
+pub const YELLOW_BG: &str = "\x1b[43m";
+/// Blue background, the `on-blue` keyword
+pub const BLUE_BG: &str = "\x1b[44m";
+/// Magenta background, the `on-magenta` keyword
+pub const MAGENTA_BG: &str = "\x1b[45m";
+/// Cyan background, the `on-cyan` keyword
+pub const CYAN_BG: &str = "\x1b[46m";
+/// White background, the `on-white` keyword
+pub const WHITE_BG: &str = "\x1b[47m";
+/// The default background, the `on-default` keyword
+pub const DEFAULT_BG: &str = "\x1b[49m";
+/// Returns the sequence setting the foreground to the indexed color `N`,
+/// built at compile time
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::consts::fg_256;
+///
+///assert_eq!(fg_256::<208>(), "\x1b[38;5;208m");
+///```
+#[must_use]
+pub const fn fg_256<const N: u8>() -> &'static str {
+    Indexed::<38, N>::SEQUENCE.as_str()
+}
+/// Returns the sequence setting the background to the indexed color `N`,
+/// built at compile time
+#[must_use]
+pub const fn bg_256<const N: u8>() -> &'static str {
+    Indexed::<48, N>::SEQUENCE.as_str()
+}
+/// Returns the sequence setting the foreground to the RGB color `R`, `G`, `B`,
+/// built at compile time
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::consts::fg_rgb;
+///
+///assert_eq!(fg_rgb::<255, 128, 0>(), "\x1b[38;2;255;128;0m");
+///```
+#[must_use]
+pub const fn fg_rgb<const R: u8, const G: u8, const B: u8>() -> &'static str {
+    Rgb::<38, R, G, B>::SEQUENCE.as_str()
+}
+/// Returns the sequence setting the background to the RGB color `R`, `G`, `B`,
+/// built at compile time
+#[must_use]
+pub const fn bg_rgb<const R: u8, const G: u8, const B: u8>() -> &'static str {
+    Rgb::<48, R, G, B>::SEQUENCE.as_str()
+}
+/// Holds the sequence of an indexed color
+struct Indexed<const CODE: u8, const N: u8>;
+impl<const CODE: u8, const N: u8> Indexed<CODE, N> {
+    /// The sequence setting the color
+    const SEQUENCE: &'static Sequence = &Sequence::new(&[CODE, 5, N]);
+}
+/// Holds the sequence of an RGB color
+struct Rgb<const CODE: u8, const R: u8, const G: u8, const B: u8>;
+impl<const CODE: u8, const R: u8, const G: u8, const B: u8> Rgb<CODE, R, G, B> {
+    /// The sequence setting the color
+    const SEQUENCE: &'static Sequence = &Sequence::new(&[CODE, 2, R, G, B]);
+}
+/// A SGR sequence built in a const context, long enough for five codes
+struct Sequence {
+    bytes: [u8; 23],
+    len: usize,
+}
+impl Sequence {
+    /// Builds the sequence made up of `codes`, which must be at most five
+    const fn new(codes: &[u8]) -> Self {
+        let mut seq = Self {
+            bytes: [0; 23],
+            len: 0,
+        };
+        seq.push(0x1b);
+        seq.push(b'[');
+        let mut i = 0;
+        while i < codes.len() {
+            if i != 0 {
+                seq.push(b';');
+            }
+            let n = codes[i];
+            if n >= 100 {
+                seq.push(b'0' + n / 100);
+            }
+            if n >= 10 {
+                seq.push(b'0' + n / 10 % 10);
+            }
+            seq.push(b'0' + n % 10);
+            i += 1;
+        }
+        seq.push(b'm');
+        seq
+    }
+    /// Adds `byte` to the end of the sequence
+    const fn push(&mut self, byte: u8) {
+        self.bytes[self.len] = byte;
+        self.len += 1;
+    }
+    /// Returns the sequence, only ever made up of ASCII
+    const fn as_str(&'static self) -> &'static str {
+        match std::str::from_utf8(self.bytes.split_at(self.len).0) {
+            Ok(s) => s,
+            Err(_) => unreachable!(),
+        }
+    }
+}
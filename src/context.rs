@@ -0,0 +1,66 @@
+use std::cell::RefCell;
+
+use crate::SGRString;
+
+thread_local! {
+    /// The default styles of the current thread, the innermost last
+    static STACK: RefCell<Vec<SGRString>> = const { RefCell::new(Vec::new()) };
+}
+/// Calls `f` with `style` as the default style of the current thread
+///
+/// While `f` runs, [`SGRString`]s & [`SGRText`](crate::SGRText)s rendered
+/// through [`Display`](std::fmt::Display) on this thread take any color, style
+/// or clean they leave unset from `style`, fields set on the string winning.
+/// Only the styling of `style` is used, its text being ignored.
+///
+/// Calls may be nested, an inner style being merged over the outer one.
+/// The outer style is restored once `f` returns, even if it panics.
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{context::with_default_style, Color::*, EasySGR, Style::*};
+///
+///let quiet = with_default_style(Dim, || {
+///    format!("{} {}", "plain".to_sgr(), RedFg.text("red"))
+///});
+///assert_eq!(quiet, "\x1b[2mplain \x1b[31;2mred");
+///assert_eq!(RedFg.text("red").to_string(), "\x1b[31mred");
+///```
+pub fn with_default_style<R>(style: impl Into<SGRString>, f: impl FnOnce() -> R) -> R {
+    let style = style.into();
+    STACK.with_borrow_mut(|stack| {
+        let style = match stack.last() {
+            Some(outer) => merge(&style, outer),
+            None => style,
+        };
+        stack.push(style);
+    });
+    let _guard = Guard;
+    f()
+}
+/// Returns the default style of the current thread, if within [`with_default_style`]
+#[must_use]
+pub fn default_style() -> Option<SGRString> {
+    STACK.with_borrow(|stack| stack.last().cloned())
+}
+/// Calls `f` with `sgr` merged over the default style of the current thread
+pub(crate) fn with_applied<R>(sgr: &SGRString, f: impl FnOnce(&SGRString) -> R) -> R {
+    let merged = STACK.with_borrow(|stack| stack.last().map(|base| merge(sgr, base)));
+    f(merged.as_ref().unwrap_or(sgr))
+}
+/// Pops the innermost default style when dropped, including while unwinding
+struct Guard;
+impl Drop for Guard {
+    fn drop(&mut self) {
+        STACK.with_borrow_mut(Vec::pop);
+    }
+}
+/// Returns `sgr` with the fields it leaves unset taken from `base`,
+/// keeping the text of `sgr` even when empty
+fn merge(sgr: &SGRString, base: &SGRString) -> SGRString {
+    SGRString {
+        text: sgr.text.clone(),
+        ..base.merge(sgr)
+    }
+}
@@ -0,0 +1,225 @@
+use crate::{Color, ColorKind, EasySGR, SGRBuilder, SGRString, SgrState};
+
+/// The colors used when exporting styles as CSS
+///
+/// Only the 16 named colors depend on the palette,
+/// the rest of the 256 colors follow the standard xterm color cube & grayscale ramp
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{css::Palette, Color::*, EasySGR};
+///
+///let mut palette = Palette::XTERM;
+///palette.colors[1] = (0xff, 0x55, 0x55);
+///assert_eq!(RedFg.to_sgr().to_css_with(&palette), "color:#ff5555");
+///```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    /// The 16 named colors, i.e. [`ColorKind::Black`] to [`ColorKind::White`]
+    /// followed by their bright variants, [`ColorKind::Byte`]s `8` to `15`
+    pub colors: [(u8, u8, u8); 16],
+    /// The default foreground, only used when swapped with the background by inverse
+    pub foreground: (u8, u8, u8),
+    /// The default background, only used when swapped with the foreground by inverse
+    pub background: (u8, u8, u8),
+}
+impl Palette {
+    /// The default colors of xterm, light text on a black background
+    pub const XTERM: Self = Self {
+        colors: [
+            (0x00, 0x00, 0x00),
+            (0xcd, 0x00, 0x00),
+            (0x00, 0xcd, 0x00),
+            (0xcd, 0xcd, 0x00),
+            (0x00, 0x00, 0xee),
+            (0xcd, 0x00, 0xcd),
+            (0x00, 0xcd, 0xcd),
+            (0xe5, 0xe5, 0xe5),
+            (0x7f, 0x7f, 0x7f),
+            (0xff, 0x00, 0x00),
+            (0x00, 0xff, 0x00),
+            (0xff, 0xff, 0x00),
+            (0x5c, 0x5c, 0xff),
+            (0xff, 0x00, 0xff),
+            (0x00, 0xff, 0xff),
+            (0xff, 0xff, 0xff),
+        ],
+        foreground: (0xe5, 0xe5, 0xe5),
+        background: (0x00, 0x00, 0x00),
+    };
+    /// Returns the red, green & blue of `color`
+    ///
+    /// Returns `None` for [`ColorKind::None`] & [`ColorKind::Default`]
+    #[must_use]
+    pub const fn rgb(&self, color: &ColorKind) -> Option<(u8, u8, u8)> {
+        /// The levels of each channel in the color cube
+        const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+        Some(match *color {
+            ColorKind::None | ColorKind::Default => return None,
+            ColorKind::Black => self.colors[0],
+            ColorKind::Red => self.colors[1],
+            ColorKind::Green => self.colors[2],
+            ColorKind::Yellow => self.colors[3],
+            ColorKind::Blue => self.colors[4],
+            ColorKind::Magenta => self.colors[5],
+            ColorKind::Cyan => self.colors[6],
+            ColorKind::White => self.colors[7],
+            ColorKind::Byte(n @ 0..=15) => self.colors[n as usize],
+            ColorKind::Byte(n @ 16..=231) => {
+                let n = (n - 16) as usize;
+                (LEVELS[n / 36], LEVELS[n / 6 % 6], LEVELS[n % 6])
+            }
+            ColorKind::Byte(n) => {
+                let level = 8 + (n - 232) * 10;
+                (level, level, level)
+            }
+            ColorKind::Rgb(r, g, b) => (r, g, b),
+        })
+    }
+    /// Returns `color` as a CSS hex color, e.g. `#ff0000`
+    ///
+    /// Returns `None` for [`ColorKind::None`] & [`ColorKind::Default`]
+    #[must_use]
+    pub fn hex(&self, color: &ColorKind) -> Option<String> {
+        self.rgb(color).map(hex)
+    }
+}
+impl Default for Palette {
+    fn default() -> Self {
+        Self::XTERM
+    }
+}
+impl SGRString {
+    /// Returns the CSS declarations styling text as this does, using [`Palette::XTERM`]
+    ///
+    /// See [`SgrState::to_css_with`]
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color::*, EasySGR, Style::*};
+    ///
+    ///assert_eq!(
+    ///    RgbFg(255, 0, 0).style(Bold).style(Underline).to_css(),
+    ///    "color:#ff0000;font-weight:bold;text-decoration:underline"
+    ///);
+    ///```
+    #[must_use]
+    pub fn to_css(&self) -> String {
+        self.to_css_with(&Palette::XTERM)
+    }
+    /// Returns the CSS declarations styling text as this does, using `palette`
+    ///
+    /// Uses the state placing this leaves a terminal in,
+    /// so only placed styles & colors are exported
+    ///
+    /// See [`SgrState::to_css_with`]
+    #[must_use]
+    pub fn to_css_with(&self, palette: &Palette) -> String {
+        let mut builder = SGRBuilder::default();
+        self.place_all(&mut builder);
+        let mut state = SgrState::new();
+        state.apply(builder.codes());
+        state.to_css_with(palette)
+    }
+}
+impl SgrState {
+    /// Returns the CSS declarations styling text as this does, using [`Palette::XTERM`]
+    ///
+    /// See [`SgrState::to_css_with`]
+    #[must_use]
+    pub fn to_css(&self) -> String {
+        self.to_css_with(&Palette::XTERM)
+    }
+    /// Returns the CSS declarations styling text as this does, using `palette`
+    ///
+    /// Declarations are separated by `;`, with none following the last:
+    ///
+    /// - Colors become `color` & `background-color`, default colors are left unset
+    /// - Inverse swaps the colors, using the default colors of `palette` for unset ones
+    /// - Dim becomes `opacity:0.5`, fading text into whatever is behind it
+    /// - Bold, italic & hidden become `font-weight`, `font-style` & `visibility`
+    /// - Underline, strikethrough & blinking share `text-decoration`
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::SgrState;
+    ///
+    ///let mut state = SgrState::new();
+    ///state.apply(&[31, 7, 2]);
+    ///assert_eq!(
+    ///    state.to_css(),
+    ///    "color:#000000;background-color:#cd0000;opacity:0.5"
+    ///);
+    ///```
+    #[must_use]
+    pub fn to_css_with(&self, palette: &Palette) -> String {
+        let (mut foreground, mut background) =
+            (palette.rgb(&self.foreground), palette.rgb(&self.background));
+        if self.inverse {
+            (foreground, background) = (
+                Some(background.unwrap_or(palette.background)),
+                Some(foreground.unwrap_or(palette.foreground)),
+            );
+        }
+        let mut declarations = Vec::new();
+        if let Some(color) = foreground {
+            declarations.push(format!("color:{}", hex(color)));
+        }
+        if let Some(color) = background {
+            declarations.push(format!("background-color:{}", hex(color)));
+        }
+        for (applied, declaration) in [
+            (self.bold, "font-weight:bold"),
+            (self.dim, "opacity:0.5"),
+            (self.italic, "font-style:italic"),
+            (self.hidden, "visibility:hidden"),
+        ] {
+            if applied {
+                declarations.push(declaration.to_owned());
+            }
+        }
+        let decorations: Vec<_> = [
+            (self.underline, "underline"),
+            (self.strikethrough, "line-through"),
+            (self.blinking || self.rapid_blinking, "blink"),
+        ]
+        .into_iter()
+        .filter_map(|(applied, decoration)| applied.then_some(decoration))
+        .collect();
+        if !decorations.is_empty() {
+            declarations.push(format!("text-decoration:{}", decorations.join(" ")));
+        }
+        declarations.join(";")
+    }
+}
+impl Color {
+    /// Returns this color as a CSS hex color using [`Palette::XTERM`], e.g. `#cd0000`
+    ///
+    /// Returns `None` for [`Color::DefaultFg`] & [`Color::DefaultBg`]
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::Color::*;
+    ///
+    ///assert_eq!(RgbBg(255, 128, 0).to_css_hex().as_deref(), Some("#ff8000"));
+    ///assert_eq!(DefaultFg.to_css_hex(), None);
+    ///```
+    #[must_use]
+    pub fn to_css_hex(&self) -> Option<String> {
+        let sgr = self.clone().to_sgr();
+        let color = if sgr.foreground == ColorKind::None {
+            sgr.background
+        } else {
+            sgr.foreground
+        };
+        Palette::XTERM.hex(&color)
+    }
+}
+/// Formats a color as `#rrggbb`
+fn hex((r, g, b): (u8, u8, u8)) -> String {
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
@@ -0,0 +1,231 @@
+use std::{
+    env,
+    io::{self, IsTerminal},
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+/// Reads terminal capabilities from the terminfo database
+#[cfg(feature = "terminfo")]
+pub mod terminfo;
+
+/// The level of color a terminal is able to display
+///
+/// Levels are ordered, so a terminal supporting [`ColorLevel::Ansi256`]
+/// also supports [`ColorLevel::Ansi16`]
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::ColorLevel;
+///
+///assert!(ColorLevel::TrueColor > ColorLevel::Ansi256);
+///assert!(ColorLevel::Ansi16 > ColorLevel::None);
+///```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorLevel {
+    /// No colors, SGR styles may still be supported
+    #[default]
+    None,
+    /// The 8 standard colors and their bright counterparts
+    Ansi16,
+    /// The 256 color palette, i.e. [`Color::ByteFg`](crate::Color::ByteFg)
+    Ansi256,
+    /// 24 bit colors, i.e. [`Color::RgbFg`](crate::Color::RgbFg)
+    TrueColor,
+}
+impl ColorLevel {
+    /// Returns the level able to display `n` colors
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::ColorLevel;
+    ///
+    ///assert_eq!(ColorLevel::from_max_colors(256), ColorLevel::Ansi256);
+    ///assert_eq!(ColorLevel::from_max_colors(2), ColorLevel::None);
+    ///```
+    #[must_use]
+    pub const fn from_max_colors(n: u32) -> Self {
+        match n {
+            0..=7 => Self::None,
+            8..=255 => Self::Ansi16,
+            256..=0x00ff_ffff => Self::Ansi256,
+            _ => Self::TrueColor,
+        }
+    }
+    /// Detects the level of color from the environment variables
+    ///
+    /// Whether stdout is a terminal is used when `TERM` is unset,
+    /// see [`ColorLevel::from_env`]
+    #[must_use]
+    pub fn detect_from_env() -> Self {
+        Self::from_env(|name| env::var(name).ok(), io::stdout().is_terminal())
+    }
+    /// Detects the level of color from the environment variables read through `var`
+    ///
+    /// - [`ColorLevel::None`] when `TERM` is `dumb`, or is unset & not writing to a terminal
+    /// - [`ColorLevel::TrueColor`] when `COLORTERM` is `truecolor` or `24bit`
+    /// - [`ColorLevel::Ansi256`] when `TERM` contains `256color`
+    /// - [`ColorLevel::Ansi16`] otherwise
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::ColorLevel;
+    ///
+    ///let level = ColorLevel::from_env(
+    ///    |name| (name == "TERM").then(|| "xterm-256color".to_string()),
+    ///    true,
+    ///);
+    ///assert_eq!(level, ColorLevel::Ansi256);
+    ///```
+    pub fn from_env(var: impl Fn(&str) -> Option<String>, is_terminal: bool) -> Self {
+        let term = var("TERM").unwrap_or_default();
+        if term == "dumb" || term.is_empty() && !is_terminal {
+            Self::None
+        } else if matches!(var("COLORTERM").as_deref(), Some("truecolor" | "24bit")) {
+            Self::TrueColor
+        } else {
+            term_level(&term)
+        }
+    }
+}
+/// Returns the level of color a terminal likely supports from only its name
+fn term_level(term: &str) -> ColorLevel {
+    if term.contains("256color") {
+        ColorLevel::Ansi256
+    } else {
+        ColorLevel::Ansi16
+    }
+}
+/// Returns whether the user asked for colors to be disabled
+///
+/// Follows the [`NO_COLOR`](https://no-color.org) convention:
+/// the `NO_COLOR` environment variable being set to a non-empty value
+///
+/// # Examples
+///
+///```rust
+///let styled = !easy_sgr::no_color();
+///```
+#[must_use]
+pub fn no_color() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
+/// Whether SGR codes are written when rendering without a configured writer
+///
+/// Set globally through [`set_color_choice`]
+///
+/// The default is [`ColorChoice::Auto`], what is asked for when the user has no preference.
+/// Yet until a choice is set, rendering behaves as [`ColorChoice::Always`],
+/// which [`color_choice`] returns, while writers over stdout & stderr detect for themselves
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{set_color_choice, ColorChoice, Color::*, EasySGR};
+///
+///set_color_choice(ColorChoice::Never);
+///assert_eq!(RedFg.text("plain").to_string(), "plain");
+///# set_color_choice(ColorChoice::Always);
+///```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ColorChoice {
+    /// Decided by whether stdout is a terminal & the environment,
+    /// as for [`SGRWriter::stdout`](crate::SGRWriter::stdout)
+    #[default]
+    Auto,
+    /// SGR codes are always written
+    Always,
+    /// SGR codes are never written, only plain text
+    Never,
+}
+/// The global [`ColorChoice`], one of the states below
+static COLOR_CHOICE: AtomicU8 = AtomicU8::new(UNSET);
+/// No choice was made, codes are written though writers detect for themselves
+const UNSET: u8 = 0;
+/// [`ColorChoice::Always`]
+const ALWAYS: u8 = 1;
+/// [`ColorChoice::Never`]
+const NEVER: u8 = 2;
+/// [`ColorChoice::Auto`], yet to be resolved
+const AUTO: u8 = 3;
+/// [`ColorChoice::Auto`], resolved to writing codes
+const AUTO_ALWAYS: u8 = 4;
+/// [`ColorChoice::Auto`], resolved to not writing codes
+const AUTO_NEVER: u8 = 5;
+/// Sets whether SGR codes are written by every rendering path
+/// not going through a configured [`SGRWriter`](crate::SGRWriter)
+///
+/// Followed by the [`Display`](std::fmt::Display) implementations of
+/// [`SGRString`](crate::SGRString), [`SGRText`](crate::SGRText) & the
+/// [`DiscreteSGR`](crate::DiscreteSGR) types, along with what is built on them such as the
+/// [`quick`](crate::quick) functions. Writers created by [`SGRWriter::stdout`](crate::SGRWriter::stdout),
+/// [`SGRWriter::stderr`](crate::SGRWriter::stderr) & the [`print`](mod@crate::print) functions
+/// follow [`ColorChoice::Always`] & [`ColorChoice::Never`], detecting for themselves otherwise.
+///
+/// Settings made on a writer, such as through [`SGRWriter::set_enabled`](crate::SGRWriter::set_enabled),
+/// always win over this.
+/// Until a choice is set, codes are always written when rendering
+pub fn set_color_choice(choice: ColorChoice) {
+    COLOR_CHOICE.store(
+        match choice {
+            ColorChoice::Auto => AUTO,
+            ColorChoice::Always => ALWAYS,
+            ColorChoice::Never => NEVER,
+        },
+        Ordering::Relaxed,
+    );
+}
+/// Returns whether SGR codes are written when rendering, see [`set_color_choice`]
+///
+/// [`ColorChoice::Auto`] is resolved to [`ColorChoice::Always`] or [`ColorChoice::Never`]
+/// the first time it is queried, by whether stdout is a terminal & the environment.
+/// [`ColorChoice::Always`] is returned when no choice was set
+#[must_use]
+pub fn color_choice() -> ColorChoice {
+    match COLOR_CHOICE.load(Ordering::Relaxed) {
+        NEVER | AUTO_NEVER => ColorChoice::Never,
+        AUTO => {
+            let resolved = if enabled_for(io::stdout().is_terminal()) {
+                AUTO_ALWAYS
+            } else {
+                AUTO_NEVER
+            };
+            // another choice may have been set meanwhile
+            let _ =
+                COLOR_CHOICE.compare_exchange(AUTO, resolved, Ordering::Relaxed, Ordering::Relaxed);
+            color_choice()
+        }
+        _ => ColorChoice::Always,
+    }
+}
+/// Returns whether a writer over an output writes SGR codes,
+/// by the global [`ColorChoice`] when set or else by detecting it
+pub(crate) fn writer_enabled(is_terminal: bool) -> bool {
+    match COLOR_CHOICE.load(Ordering::Relaxed) {
+        ALWAYS => true,
+        NEVER => false,
+        _ => enabled_for(is_terminal),
+    }
+}
+/// Returns whether a formatter writing to an output it doesn't own writes SGR codes,
+/// as [`writer_enabled`] does, with the detection made once & cached in `detected`
+#[cfg(any(feature = "tracing", feature = "env-logger"))]
+pub(crate) fn output_enabled(
+    detected: &std::sync::OnceLock<bool>,
+    is_terminal: impl FnOnce() -> bool,
+) -> bool {
+    match COLOR_CHOICE.load(Ordering::Relaxed) {
+        ALWAYS => true,
+        NEVER => false,
+        _ => *detected.get_or_init(|| enabled_for(is_terminal())),
+    }
+}
+/// Returns whether SGR codes are written to an output,
+/// when it is a terminal, [`no_color`] is `false`, the terminal isn't
+/// [`ColorLevel::None`] & [`enable_vt`](crate::windows::enable_vt) succeeds
+fn enabled_for(is_terminal: bool) -> bool {
+    let level = ColorLevel::from_env(|name| env::var(name).ok(), is_terminal);
+    is_terminal && !no_color() && level != ColorLevel::None && crate::windows::enable_vt().is_ok()
+}
@@ -0,0 +1,326 @@
+use std::{
+    env,
+    error::Error,
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::ColorLevel;
+
+/// The capabilities of a terminal
+///
+/// Found through the terminfo database, falling back
+/// to the `TERM` & `COLORTERM` environment variables
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{terminfo::Capabilities, ColorLevel};
+///
+///let caps = Capabilities::detect();
+///if caps.color_level >= ColorLevel::Ansi256 {
+///    println!("\x1b[38;5;208mOrange!\x1b[0m");
+///}
+///```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Capabilities {
+    /// The level of color supported
+    pub color_level: ColorLevel,
+    /// Whether SGR codes are supported at all
+    ///
+    /// Is `false` for terminals such as `dumb`
+    pub sgr: bool,
+    /// Whether [OSC 8](crate::SGRWriter::open_hyperlink) hyperlinks are likely supported
+    ///
+    /// terminfo has no entry for hyperlinks, so this is a best guess
+    /// made from the terminal's name & environment variables
+    pub hyperlinks: bool,
+}
+impl Capabilities {
+    /// Detects the capabilities of the current terminal
+    ///
+    /// Reads the terminfo entry named by the `TERM` environment variable,
+    /// when no entry is found `TERM` & `COLORTERM` are used instead
+    #[must_use]
+    pub fn detect() -> Self {
+        let term = env::var("TERM").unwrap_or_default();
+        let mut caps = find_entry(&term)
+            .and_then(|bytes| Self::from_terminfo(&bytes).ok())
+            .unwrap_or_else(|| Self::from_term_name(&term));
+        if caps.sgr {
+            if matches!(env::var("COLORTERM").as_deref(), Ok("truecolor" | "24bit")) {
+                caps.color_level = ColorLevel::TrueColor;
+            }
+            caps.hyperlinks |= hyperlinks_from_env();
+        }
+        caps
+    }
+    /// Reads capabilities from a compiled terminfo entry
+    ///
+    /// Both the legacy & the extended number formats are supported,
+    /// the `RGB` & `Tc` extended capabilities are taken to mean true color support
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` is not a valid terminfo entry
+    pub fn from_terminfo(bytes: &[u8]) -> Result<Self, TerminfoError> {
+        let entry = Entry::parse(bytes)?;
+        let sgr = entry.string(SGR0) || entry.string(SET_A_FOREGROUND);
+        let color_level = if !sgr {
+            ColorLevel::None
+        } else if entry.extended_bool("RGB") || entry.extended_bool("Tc") {
+            ColorLevel::TrueColor
+        } else {
+            ColorLevel::from_max_colors(entry.number(MAX_COLORS).unwrap_or(0))
+        };
+        Ok(Self {
+            color_level,
+            sgr,
+            hyperlinks: sgr && entry.names.split('|').any(hyperlinks_from_name),
+        })
+    }
+    /// Guesses capabilities from only the name of a terminal
+    fn from_term_name(term: &str) -> Self {
+        match term {
+            "" | "dumb" => Self::default(),
+            term => Self {
+                color_level: super::term_level(term),
+                sgr: true,
+                hyperlinks: hyperlinks_from_name(term),
+            },
+        }
+    }
+}
+/// An error encountered while trying to read a terminfo entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerminfoError {
+    /// The entry does not start with a known magic number
+    Magic,
+    /// The entry ends before all of its sections do
+    Truncated,
+}
+impl Display for TerminfoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Magic => write!(f, "Invalid terminfo magic number"),
+            Self::Truncated => write!(f, "Terminfo entry is truncated"),
+        }
+    }
+}
+impl Error for TerminfoError {}
+
+/// Index of the `colors` number capability
+const MAX_COLORS: usize = 13;
+/// Index of the `sgr0` string capability
+const SGR0: usize = 39;
+/// Index of the `setaf` string capability
+const SET_A_FOREGROUND: usize = 359;
+
+/// The sections of a compiled terminfo entry that are needed
+struct Entry<'a> {
+    /// The names of the terminal, separated by `|`
+    names: &'a str,
+    /// The bytes of the number section
+    numbers: &'a [u8],
+    /// The byte width of a single number
+    number_width: usize,
+    /// The offsets of the string section
+    strings: &'a [u8],
+    /// The names of the extended booleans that are set
+    extended_bools: Vec<&'a str>,
+}
+impl<'a> Entry<'a> {
+    /// Splits a compiled entry into its sections
+    ///
+    /// See `term(5)` for the format
+    fn parse(bytes: &'a [u8]) -> Result<Self, TerminfoError> {
+        let mut reader = Reader { bytes, pos: 0 };
+        let number_width = match reader.short()? {
+            0o432 => 2,
+            0o1036 => 4,
+            _ => return Err(TerminfoError::Magic),
+        };
+        let names_size = reader.count()?;
+        let bool_count = reader.count()?;
+        let number_count = reader.count()?;
+        let string_count = reader.count()?;
+        let table_size = reader.count()?;
+
+        let names = reader.take(names_size)?;
+        let names = std::str::from_utf8(names)
+            .unwrap_or_default()
+            .trim_end_matches('\0');
+        reader.take(bool_count)?;
+        reader.align();
+        let numbers = reader.take(number_count * number_width)?;
+        let strings = reader.take(string_count * 2)?;
+        reader.take(table_size)?;
+        reader.align();
+
+        Ok(Self {
+            names,
+            numbers,
+            number_width,
+            strings,
+            // the extended section is optional
+            extended_bools: Self::extended_bools(&mut reader, number_width).unwrap_or_default(),
+        })
+    }
+    /// Reads the names of the set booleans in the extended section
+    fn extended_bools(
+        reader: &mut Reader<'a>,
+        number_width: usize,
+    ) -> Result<Vec<&'a str>, TerminfoError> {
+        let bool_count = reader.count()?;
+        let number_count = reader.count()?;
+        let string_count = reader.count()?;
+        let _ = reader.count()?; // number of offsets in the table
+        let table_size = reader.count()?;
+
+        let bools = reader.take(bool_count)?;
+        reader.align();
+        reader.take(number_count * number_width)?;
+        let mut value_offsets = Vec::with_capacity(string_count);
+        for _ in 0..string_count {
+            value_offsets.push(reader.short()?);
+        }
+        let mut name_offsets = Vec::with_capacity(bool_count);
+        for _ in 0..bool_count + number_count + string_count {
+            name_offsets.push(reader.short()?);
+        }
+        let table = reader.take(table_size)?;
+
+        // names come directly after the last string value
+        let names_start = value_offsets
+            .iter()
+            .filter_map(|&offset| usize::try_from(offset).ok())
+            .filter_map(|offset| Some(offset + nul_terminated(table.get(offset..)?).len() + 1))
+            .max()
+            .unwrap_or(0);
+        let names = table.get(names_start..).ok_or(TerminfoError::Truncated)?;
+
+        Ok(bools
+            .iter()
+            .zip(name_offsets)
+            .filter(|(&set, _)| set == 1)
+            .filter_map(|(_, offset)| names.get(usize::try_from(offset).ok()?..))
+            .filter_map(|name| std::str::from_utf8(nul_terminated(name)).ok())
+            .collect())
+    }
+    /// Returns the number at `index`, `None` when absent
+    fn number(&self, index: usize) -> Option<u32> {
+        let start = index * self.number_width;
+        let bytes = self.numbers.get(start..start + self.number_width)?;
+        let n = if self.number_width == 2 {
+            i32::from(i16::from_le_bytes([bytes[0], bytes[1]]))
+        } else {
+            i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+        };
+        u32::try_from(n).ok()
+    }
+    /// Returns whether the string at `index` is present
+    fn string(&self, index: usize) -> bool {
+        self.strings
+            .get(index * 2..index * 2 + 2)
+            .is_some_and(|bytes| i16::from_le_bytes([bytes[0], bytes[1]]) >= 0)
+    }
+    /// Returns whether the extended boolean `name` is set
+    fn extended_bool(&self, name: &str) -> bool {
+        self.extended_bools.contains(&name)
+    }
+}
+/// Reads little endian values from a compiled entry
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+impl<'a> Reader<'a> {
+    /// Reads the next `n` bytes
+    fn take(&mut self, n: usize) -> Result<&'a [u8], TerminfoError> {
+        let bytes = self
+            .bytes
+            .get(self.pos..self.pos + n)
+            .ok_or(TerminfoError::Truncated)?;
+        self.pos += n;
+        Ok(bytes)
+    }
+    /// Reads the next two bytes as an `i16`
+    fn short(&mut self) -> Result<i16, TerminfoError> {
+        let bytes = self.take(2)?;
+        Ok(i16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+    /// Reads the next two bytes as a count, negative counts are taken as `0`
+    fn count(&mut self) -> Result<usize, TerminfoError> {
+        Ok(usize::try_from(self.short()?).unwrap_or(0))
+    }
+    /// Skips a byte if not on an even boundary
+    const fn align(&mut self) {
+        self.pos += self.pos % 2;
+    }
+}
+/// Returns the bytes up until the first nul
+fn nul_terminated(bytes: &[u8]) -> &[u8] {
+    bytes
+        .iter()
+        .position(|&b| b == 0)
+        .map_or(bytes, |end| &bytes[..end])
+}
+/// Returns the terminfo entry for `term`
+///
+/// Searches the same directories as ncurses
+fn find_entry(term: &str) -> Option<Vec<u8>> {
+    let first = term.chars().next()?;
+    if term.contains(['/', '\\']) {
+        return None;
+    }
+    let mut dirs = Vec::new();
+    if let Some(dir) = env::var_os("TERMINFO") {
+        dirs.push(PathBuf::from(dir));
+    }
+    if let Some(home) = env::var_os("HOME") {
+        dirs.push(Path::new(&home).join(".terminfo"));
+    }
+    if let Ok(list) = env::var("TERMINFO_DIRS") {
+        dirs.extend(
+            list.split(':')
+                .filter(|dir| !dir.is_empty())
+                .map(PathBuf::from),
+        );
+    }
+    dirs.extend(
+        ["/etc/terminfo", "/lib/terminfo", "/usr/share/terminfo"]
+            .iter()
+            .map(PathBuf::from),
+    );
+    dirs.iter().find_map(|dir| {
+        // macOS uses the hex of the first char for the directory name
+        fs::read(dir.join(first.to_string()).join(term))
+            .or_else(|_| fs::read(dir.join(format!("{:x}", u32::from(first))).join(term)))
+            .ok()
+    })
+}
+/// Returns whether a terminal of the given name likely supports hyperlinks
+fn hyperlinks_from_name(name: &str) -> bool {
+    [
+        "kitty",
+        "wezterm",
+        "foot",
+        "alacritty",
+        "contour",
+        "ghostty",
+    ]
+    .iter()
+    .any(|known| name.contains(known))
+}
+/// Returns whether the environment hints at hyperlink support
+fn hyperlinks_from_env() -> bool {
+    matches!(
+        env::var("TERM_PROGRAM").as_deref(),
+        Ok("iTerm.app" | "WezTerm" | "vscode" | "ghostty")
+    ) || env::var_os("WT_SESSION").is_some()
+        || env::var("VTE_VERSION")
+            .ok()
+            .and_then(|v| v.parse::<u32>().ok())
+            .is_some_and(|v| v >= 5000)
+}
@@ -0,0 +1,128 @@
+use crate::{CleanKind, Color::*, EasySGR, SGRString, SGRText};
+
+/// The styles [`inline_diff`] highlights changes with
+///
+/// Only the styling of each is used, any text being ignored
+#[derive(Debug, Clone)]
+pub struct DiffTheme {
+    /// The style of text removed from the old string
+    pub removed: SGRString,
+    /// The style of text added to the new string
+    pub added: SGRString,
+}
+/// A red background for removed text & a green one for added text
+impl Default for DiffTheme {
+    fn default() -> Self {
+        Self {
+            removed: RedBg.clean(CleanKind::Reverse),
+            added: GreenBg.clean(CleanKind::Reverse),
+        }
+    }
+}
+/// Returns `old` with the words removed from it highlighted,
+/// along with `new` with the words added to it highlighted
+///
+/// Words & the whitespace between them are compared by their longest common
+/// subsequence, text common to both being left unstyled.
+/// Identical prefixes & suffixes are skipped before comparing,
+/// so only what lies between them is ever styled.
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::diff::{inline_diff, DiffTheme};
+///
+///let (old, new) = inline_diff("the quick fox", "the slow fox", &DiffTheme::default());
+///assert_eq!(old.to_string(), "the \x1b[41mquick\x1b[49m fox");
+///assert_eq!(new.to_string(), "the \x1b[42mslow\x1b[49m fox");
+///```
+#[must_use]
+pub fn inline_diff(old: &str, new: &str, theme: &DiffTheme) -> (SGRText, SGRText) {
+    let old = words(old);
+    let new = words(new);
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let mut old_changed = vec![false; old.len()];
+    let mut new_changed = vec![false; new.len()];
+    mark_changes(
+        &old[prefix..old.len() - suffix],
+        &new[prefix..new.len() - suffix],
+        &mut old_changed[prefix..old.len() - suffix],
+        &mut new_changed[prefix..new.len() - suffix],
+    );
+    (
+        highlight(&old, &old_changed, &theme.removed),
+        highlight(&new, &new_changed, &theme.added),
+    )
+}
+/// Splits `s` into runs of whitespace & runs of everything else
+fn words(s: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut start = 0;
+    let mut chars = s.char_indices().peekable();
+    while let Some((_, c)) = chars.next() {
+        let end = match chars.peek() {
+            Some(&(i, next)) if next.is_whitespace() != c.is_whitespace() => i,
+            Some(_) => continue,
+            None => s.len(),
+        };
+        words.push(&s[start..end]);
+        start = end;
+    }
+    words
+}
+/// Marks the words of `old` & `new` not part of their longest common subsequence
+fn mark_changes(old: &[&str], new: &[&str], old_changed: &mut [bool], new_changed: &mut [bool]) {
+    let width = new.len() + 1;
+    // the length of the common subsequence of `old[i..]` & `new[j..]`
+    let mut lengths = vec![0_usize; (old.len() + 1) * width];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i * width + j] = if old[i] == new[j] {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            i += 1;
+            j += 1;
+        } else if lengths[(i + 1) * width + j] >= lengths[i * width + j + 1] {
+            old_changed[i] = true;
+            i += 1;
+        } else {
+            new_changed[j] = true;
+            j += 1;
+        }
+    }
+    old_changed[i..].fill(true);
+    new_changed[j..].fill(true);
+}
+/// Joins `words` into spans, styling those changed with `style`
+fn highlight(words: &[&str], changed: &[bool], style: &SGRString) -> SGRText {
+    let mut text = SGRText::new();
+    let mut run = String::new();
+    for (i, word) in words.iter().enumerate() {
+        run.push_str(word);
+        if changed.get(i + 1) != Some(&changed[i]) {
+            let run = std::mem::take(&mut run);
+            text.push(if changed[i] {
+                SGRString {
+                    text: run,
+                    ..style.clone()
+                }
+            } else {
+                SGRString::from(run)
+            });
+        }
+    }
+    text
+}
@@ -16,6 +16,12 @@ impl FromStr for Seq {
 /// An error encountered while trying to parse a string into a [`Seq`]
 #[derive(Debug, PartialEq, Eq)]
 pub struct ParseSeqError;
+impl Display for ParseSeqError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid sequence")
+    }
+}
+impl Error for ParseSeqError {}
 impl FromStr for Style {
     type Err = ParseStyleError;
 
@@ -45,6 +51,12 @@ impl FromStr for Style {
 /// An error encountered while trying to parse a string into a [`Style`]
 #[derive(Debug, PartialEq, Eq)]
 pub struct ParseStyleError;
+impl Display for ParseStyleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid style")
+    }
+}
+impl Error for ParseStyleError {}
 impl FromStr for Color {
     type Err = ParseColorError;
 
@@ -126,6 +138,49 @@ impl Display for ParseColorError {
     }
 }
 impl Error for ParseColorError {}
+/// An error encountered while trying to parse any of the discrete types
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// See [`ParseSeqError`]
+    Seq(ParseSeqError),
+    /// See [`ParseStyleError`]
+    Style(ParseStyleError),
+    /// See [`ParseColorError`]
+    Color(ParseColorError),
+}
+impl From<ParseSeqError> for ParseError {
+    fn from(value: ParseSeqError) -> Self {
+        Self::Seq(value)
+    }
+}
+impl From<ParseStyleError> for ParseError {
+    fn from(value: ParseStyleError) -> Self {
+        Self::Style(value)
+    }
+}
+impl From<ParseColorError> for ParseError {
+    fn from(value: ParseColorError) -> Self {
+        Self::Color(value)
+    }
+}
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Seq(e) => write!(f, "Error parsing sequence: {e}"),
+            Self::Style(e) => write!(f, "Error parsing style: {e}"),
+            Self::Color(e) => write!(f, "Error parsing color: {e}"),
+        }
+    }
+}
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Seq(e) => Some(e),
+            Self::Style(e) => Some(e),
+            Self::Color(e) => Some(e),
+        }
+    }
+}
 fn resolve_byte(s: &str) -> Result<u8, ParseColorError> {
     s.get(6..)
         .ok_or_else(|| ParseColorError::MissingNum(s.to_string()))
@@ -1,6 +1,9 @@
 use std::fmt::Display;
 
-use crate::{EasySGR, SGRBuilder, SGRWriter};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{CodeSink, FmtWriter, SGRBuilder};
 
 /// Implements [`FromStr`](std::str::FromStr) for the [`discrete`](crate::discrete) module
 #[cfg(feature = "from-str")]
@@ -36,6 +39,14 @@ impl Display for Seq {
 }
 /// An SGR style code
 ///
+/// [`Display`] writes the complete sequence, e.g. `\x1b[1m`,
+/// or only the code with the `partial` feature enabled.
+/// [`DiscreteSGR::params`] always writes only the code, e.g. `1`,
+/// for building a sequence of several codes.
+///
+/// Styles may be added, so matching on them needs a wildcard arm,
+/// the code of any style being given by [`Style::code`]
+///
 /// # Examples
 ///
 ///```rust
@@ -50,6 +61,8 @@ impl Display for Seq {
 ///);
 ///```
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
 pub enum Style {
     /// Represents the SGR code `0`
     ///
@@ -92,15 +105,42 @@ pub enum Style {
     /// Represents the SGR code `29`
     NotStrikethrough,
 }
-impl Display for Style {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.standard_display(f)
-    }
-}
-impl DiscreteSGR for Style {
-    fn write(&self, builder: &mut SGRBuilder) {
+impl Style {
+    /// Every style, in the order they're declared
+    pub(crate) const ALL: [Self; 17] = [
+        Self::Reset,
+        Self::Bold,
+        Self::Dim,
+        Self::Italic,
+        Self::Underline,
+        Self::Blinking,
+        Self::Inverse,
+        Self::Hidden,
+        Self::Strikethrough,
+        Self::NotBold,
+        Self::NotDim,
+        Self::NotItalic,
+        Self::NotUnderline,
+        Self::NotBlinking,
+        Self::NotInverse,
+        Self::NotHidden,
+        Self::NotStrikethrough,
+    ];
+    /// Returns the SGR code of this style, e.g. `1` for [`Style::Bold`]
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::Style;
+    ///
+    ///assert_eq!(Style::Italic.code(), 3);
+    ///assert_eq!(Style::NotDim.code(), 22);
+    ///```
+    #[must_use]
+    pub const fn code(&self) -> u8 {
         use Style::*;
-        builder.write_code(match self {
+        // matched without a wildcard, so a new variant can't be missed
+        match self {
             Reset => 0,
             Bold => 1,
             Dim => 2,
@@ -117,11 +157,79 @@ impl DiscreteSGR for Style {
             NotInverse => 27,
             NotHidden => 28,
             NotStrikethrough => 29,
-        });
+        }
+    }
+    /// Returns the SGR code undoing this style, the code of [`Style::undo`]
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::Style;
+    ///
+    ///assert_eq!(Style::Bold.clear_code(), 22);
+    ///assert_eq!(Style::NotUnderline.clear_code(), 4);
+    ///assert_eq!(Style::Reset.clear_code(), 0);
+    ///```
+    #[must_use]
+    pub const fn clear_code(&self) -> u8 {
+        self.undo().code()
+    }
+    /// Returns the style undoing this one, e.g. [`Style::NotBold`] for [`Style::Bold`]
+    /// & [`Style::Bold`] for [`Style::NotBold`]
+    ///
+    /// [`Style::Reset`] can't be undone, so is returned as is
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::Style;
+    ///
+    ///# #[cfg(not(feature = "partial"))]
+    ///assert_eq!(
+    ///    format!("{}warning{}", Style::Bold, Style::Bold.undo()),
+    ///    "\x1b[1mwarning\x1b[22m"
+    ///);
+    ///assert_eq!(Style::Italic.undo().undo(), Style::Italic);
+    ///```
+    #[must_use]
+    pub const fn undo(&self) -> Self {
+        use Style::*;
+        match self {
+            Reset => Reset,
+            Bold => NotBold,
+            Dim => NotDim,
+            Italic => NotItalic,
+            Underline => NotUnderline,
+            Blinking => NotBlinking,
+            Inverse => NotInverse,
+            Hidden => NotHidden,
+            Strikethrough => NotStrikethrough,
+            NotBold => Bold,
+            NotDim => Dim,
+            NotItalic => Italic,
+            NotUnderline => Underline,
+            NotBlinking => Blinking,
+            NotInverse => Inverse,
+            NotHidden => Hidden,
+            NotStrikethrough => Strikethrough,
+        }
+    }
+}
+impl Display for Style {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.standard_display(f)
+    }
+}
+impl DiscreteSGR for Style {
+    fn write(&self, builder: &mut impl CodeSink) {
+        builder.write_style(self);
     }
 }
 /// An SGR color code
 ///
+/// Colors may be added, so matching on them needs a wildcard arm,
+/// the codes of any color being given by [`Color::codes`]
+///
 /// # Examples
 ///
 ///```rust
@@ -133,6 +241,8 @@ impl DiscreteSGR for Style {
 ///println!("{DefaultFg}Finally normal text");
 ///```
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
 pub enum Color {
     /// Represents the SGR code `30`
     BlackFg,
@@ -188,66 +298,299 @@ pub enum Color {
     /// Represents the SGR code `49`
     DefaultBg,
 }
+/// The 16 colors of the basic palette & the defaults, as foregrounds
+/// & backgrounds prefixed by `ON_`
+///
+/// Bright colors are the 256-colors `8` to `15`,
+/// the same as converted to from other crates' bright colors
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::Color;
+///
+///const ERROR: Color = Color::BRIGHT_RED;
+///assert_eq!(ERROR, Color::ByteFg(9));
+///assert_eq!(Color::ON_RED, Color::RedBg);
+///```
+impl Color {
+    /// The foreground black, [`Color::BlackFg`]
+    pub const BLACK: Self = Self::BlackFg;
+    /// The foreground red, [`Color::RedFg`]
+    pub const RED: Self = Self::RedFg;
+    /// The foreground green, [`Color::GreenFg`]
+    pub const GREEN: Self = Self::GreenFg;
+    /// The foreground yellow, [`Color::YellowFg`]
+    pub const YELLOW: Self = Self::YellowFg;
+    /// The foreground blue, [`Color::BlueFg`]
+    pub const BLUE: Self = Self::BlueFg;
+    /// The foreground magenta, [`Color::MagentaFg`]
+    pub const MAGENTA: Self = Self::MagentaFg;
+    /// The foreground cyan, [`Color::CyanFg`]
+    pub const CYAN: Self = Self::CyanFg;
+    /// The foreground white, [`Color::WhiteFg`]
+    pub const WHITE: Self = Self::WhiteFg;
+    /// The foreground bright black, the 256-color `8`
+    pub const BRIGHT_BLACK: Self = Self::ByteFg(8);
+    /// The foreground bright red, the 256-color `9`
+    pub const BRIGHT_RED: Self = Self::ByteFg(9);
+    /// The foreground bright green, the 256-color `10`
+    pub const BRIGHT_GREEN: Self = Self::ByteFg(10);
+    /// The foreground bright yellow, the 256-color `11`
+    pub const BRIGHT_YELLOW: Self = Self::ByteFg(11);
+    /// The foreground bright blue, the 256-color `12`
+    pub const BRIGHT_BLUE: Self = Self::ByteFg(12);
+    /// The foreground bright magenta, the 256-color `13`
+    pub const BRIGHT_MAGENTA: Self = Self::ByteFg(13);
+    /// The foreground bright cyan, the 256-color `14`
+    pub const BRIGHT_CYAN: Self = Self::ByteFg(14);
+    /// The foreground bright white, the 256-color `15`
+    pub const BRIGHT_WHITE: Self = Self::ByteFg(15);
+    /// The default foreground, [`Color::DefaultFg`]
+    pub const DEFAULT: Self = Self::DefaultFg;
+
+    /// The background black, [`Color::BlackBg`]
+    pub const ON_BLACK: Self = Self::BlackBg;
+    /// The background red, [`Color::RedBg`]
+    pub const ON_RED: Self = Self::RedBg;
+    /// The background green, [`Color::GreenBg`]
+    pub const ON_GREEN: Self = Self::GreenBg;
+    /// The background yellow, [`Color::YellowBg`]
+    pub const ON_YELLOW: Self = Self::YellowBg;
+    /// The background blue, [`Color::BlueBg`]
+    pub const ON_BLUE: Self = Self::BlueBg;
+    /// The background magenta, [`Color::MagentaBg`]
+    pub const ON_MAGENTA: Self = Self::MagentaBg;
+    /// The background cyan, [`Color::CyanBg`]
+    pub const ON_CYAN: Self = Self::CyanBg;
+    /// The background white, [`Color::WhiteBg`]
+    pub const ON_WHITE: Self = Self::WhiteBg;
+    /// The background bright black, the 256-color `8`
+    pub const ON_BRIGHT_BLACK: Self = Self::ByteBg(8);
+    /// The background bright red, the 256-color `9`
+    pub const ON_BRIGHT_RED: Self = Self::ByteBg(9);
+    /// The background bright green, the 256-color `10`
+    pub const ON_BRIGHT_GREEN: Self = Self::ByteBg(10);
+    /// The background bright yellow, the 256-color `11`
+    pub const ON_BRIGHT_YELLOW: Self = Self::ByteBg(11);
+    /// The background bright blue, the 256-color `12`
+    pub const ON_BRIGHT_BLUE: Self = Self::ByteBg(12);
+    /// The background bright magenta, the 256-color `13`
+    pub const ON_BRIGHT_MAGENTA: Self = Self::ByteBg(13);
+    /// The background bright cyan, the 256-color `14`
+    pub const ON_BRIGHT_CYAN: Self = Self::ByteBg(14);
+    /// The background bright white, the 256-color `15`
+    pub const ON_BRIGHT_WHITE: Self = Self::ByteBg(15);
+    /// The default background, [`Color::DefaultBg`]
+    pub const ON_DEFAULT: Self = Self::DefaultBg;
+}
+impl Color {
+    /// Every color without parameters, in the order they're declared
+    pub(crate) const NAMED: [Self; 18] = [
+        Self::BlackFg,
+        Self::RedFg,
+        Self::GreenFg,
+        Self::YellowFg,
+        Self::BlueFg,
+        Self::MagentaFg,
+        Self::CyanFg,
+        Self::WhiteFg,
+        Self::DefaultFg,
+        Self::BlackBg,
+        Self::RedBg,
+        Self::GreenBg,
+        Self::YellowBg,
+        Self::BlueBg,
+        Self::MagentaBg,
+        Self::CyanBg,
+        Self::WhiteBg,
+        Self::DefaultBg,
+    ];
+    /// Returns the plane this color is written to
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color, Plane};
+    ///
+    ///assert_eq!(Color::RedFg.plane(), Plane::Foreground);
+    ///assert_eq!(Color::ByteBg(208).plane(), Plane::Background);
+    ///```
+    #[must_use]
+    pub const fn plane(&self) -> Plane {
+        self.split().0
+    }
+    /// Returns the SGR codes writing this color to `plane`,
+    /// whichever plane it's written to itself
+    ///
+    /// 256-colors & RGB colors are written by the `38`, `48` or `58` code
+    /// followed by their parameters.
+    /// As there are no codes for the 8 basic underline colors,
+    /// they're written as the 256-colors of the same index
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color, Plane};
+    ///
+    ///assert!(Color::RedFg.codes(Plane::Background).eq([41]));
+    ///assert!(Color::RedFg.codes(Plane::Underline).eq([58, 5, 1]));
+    ///assert!(Color::RgbBg(1, 2, 3).codes(Plane::Foreground).eq([38, 2, 1, 2, 3]));
+    ///assert!(Color::DefaultFg.codes(Plane::Underline).eq([59]));
+    ///```
+    pub fn codes(&self, plane: Plane) -> impl Iterator<Item = u8> {
+        let (codes, len) = self.code_array(plane);
+        codes.into_iter().take(len)
+    }
+    /// Returns the codes of [`Color::codes`] & how many there are
+    pub(crate) const fn code_array(&self, plane: Plane) -> ([u8; 5], usize) {
+        let base = match plane {
+            Plane::Foreground => 30,
+            Plane::Background => 40,
+            Plane::Underline => 50,
+        };
+        let mut codes = [0; 5];
+        let len = match (self.split().1, plane) {
+            (Shade::Basic(index) | Shade::Byte(index), Plane::Underline)
+            | (Shade::Byte(index), _) => {
+                codes = [base + 8, 5, index, 0, 0];
+                3
+            }
+            (Shade::Basic(index), _) => {
+                codes[0] = base + index;
+                1
+            }
+            (Shade::Rgb(r, g, b), _) => {
+                codes = [base + 8, 2, r, g, b];
+                5
+            }
+            (Shade::Default, _) => {
+                codes[0] = base + 9;
+                1
+            }
+        };
+        (codes, len)
+    }
+    /// Returns the plane & shade of this color
+    ///
+    /// Matched without a wildcard, so a new variant can't be missed
+    const fn split(&self) -> (Plane, Shade) {
+        use Color::*;
+        use Plane::{Background as Bg, Foreground as Fg};
+        match *self {
+            BlackFg => (Fg, Shade::Basic(0)),
+            RedFg => (Fg, Shade::Basic(1)),
+            GreenFg => (Fg, Shade::Basic(2)),
+            YellowFg => (Fg, Shade::Basic(3)),
+            BlueFg => (Fg, Shade::Basic(4)),
+            MagentaFg => (Fg, Shade::Basic(5)),
+            CyanFg => (Fg, Shade::Basic(6)),
+            WhiteFg => (Fg, Shade::Basic(7)),
+            ByteFg(n) => (Fg, Shade::Byte(n)),
+            RgbFg(r, g, b) => (Fg, Shade::Rgb(r, g, b)),
+            DefaultFg => (Fg, Shade::Default),
+
+            BlackBg => (Bg, Shade::Basic(0)),
+            RedBg => (Bg, Shade::Basic(1)),
+            GreenBg => (Bg, Shade::Basic(2)),
+            YellowBg => (Bg, Shade::Basic(3)),
+            BlueBg => (Bg, Shade::Basic(4)),
+            MagentaBg => (Bg, Shade::Basic(5)),
+            CyanBg => (Bg, Shade::Basic(6)),
+            WhiteBg => (Bg, Shade::Basic(7)),
+            ByteBg(n) => (Bg, Shade::Byte(n)),
+            RgbBg(r, g, b) => (Bg, Shade::Rgb(r, g, b)),
+            DefaultBg => (Bg, Shade::Default),
+        }
+    }
+}
+/// Where a color is written, each having its own codes
+///
+/// See [`Color::codes`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Plane {
+    /// The text, written by the codes `30` to `39`
+    Foreground,
+    /// The background, written by the codes `40` to `49`
+    Background,
+    /// The underline, written by the codes `58` & `59`
+    Underline,
+}
+/// A [`Color`] without its [`Plane`]
+#[derive(Clone, Copy)]
+enum Shade {
+    /// One of the 8 basic colors by its index
+    Basic(u8),
+    Byte(u8),
+    Rgb(u8, u8, u8),
+    Default,
+}
 impl Display for Color {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         self.standard_display(f)
     }
 }
 impl DiscreteSGR for Color {
-    fn write(&self, builder: &mut SGRBuilder) {
-        use Color::*;
-        match self {
-            BlackFg => builder.write_code(30),
-            RedFg => builder.write_code(31),
-            GreenFg => builder.write_code(32),
-            YellowFg => builder.write_code(33),
-            BlueFg => builder.write_code(34),
-            MagentaFg => builder.write_code(35),
-            CyanFg => builder.write_code(36),
-            WhiteFg => builder.write_code(37),
-            ByteFg(n) => builder.write_codes(&[38, 5, *n]),
-            RgbFg(r, g, b) => builder.write_codes(&[38, 2, *r, *g, *b]),
-            DefaultFg => builder.write_code(39),
-
-            BlackBg => builder.write_code(40),
-            RedBg => builder.write_code(41),
-            GreenBg => builder.write_code(42),
-            YellowBg => builder.write_code(43),
-            BlueBg => builder.write_code(44),
-            MagentaBg => builder.write_code(45),
-            CyanBg => builder.write_code(46),
-            WhiteBg => builder.write_code(47),
-            ByteBg(n) => builder.write_codes(&[48, 5, *n]),
-            RgbBg(r, g, b) => builder.write_codes(&[48, 2, *r, *g, *b]),
-            DefaultBg => builder.write_code(49),
-        }
+    fn write(&self, builder: &mut impl CodeSink) {
+        builder.write_color(self, self.plane());
     }
 }
 /// Represents SGR sequences that can be used discretely.
 ///
 /// This means it doesn't exist in terms of a [`SGRString`](crate::SGRString),
 /// though it can be used in conjunction with one
+///
+/// Neither [`Display`] nor [`EasySGR`](crate::EasySGR) are required,
+/// so tuples of any [`DiscreteSGR`] implement it,
+/// though this crate's other types display as [`DiscreteSGR::standard_display`].
+/// Both were supertraits up to `0.1.1`: generic code needs bounds of its own,
+/// or [`DiscreteSGR::params`] to display a `T: DiscreteSGR`
 #[allow(clippy::module_name_repetitions)]
-pub trait DiscreteSGR: Sized + Display + EasySGR {
-    /// Writes a set of SGR codes to the given [`SGRWriter`]
+pub trait DiscreteSGR: Sized {
+    /// Writes a set of SGR codes to the given [`CodeSink`]
     ///
     /// Writing is not an IO operation, instead writing
-    /// pushes codes to the [`SGRBuilder`]'s buffer
-    fn write(&self, writer: &mut SGRBuilder);
+    /// pushes codes to the buffer of a builder,
+    /// either a [`SGRBuilder`] or a fixed-capacity [`ArrayBuilder`](crate::ArrayBuilder)
+    fn write(&self, builder: &mut impl CodeSink);
+    /// Returns an adapter displaying only the codes of this,
+    /// without the sequence escape & end
+    ///
+    /// Codes are displayed this way whether or not the `partial` feature
+    /// is enabled, so they can be given to the runtime arguments of the
+    /// macros' templates, e.g. `{[bold (color)]}`
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color::*, DiscreteSGR};
+    ///
+    ///let color = RgbFg(255, 0, 0);
+    ///assert_eq!(format!("\x1b[1;{}m", color.params()), "\x1b[1;38;2;255;0;0m");
+    ///```
+    #[inline]
+    fn params(&self) -> SGRParams<'_, Self> {
+        SGRParams(self)
+    }
     /// Writes an SGR sequence to the given [`Formatter`](std::fmt::Formatter)
     ///
+    /// Nothing is written while the [`ColorChoice`](crate::ColorChoice) is never
+    ///
     /// # Errors
     ///
     /// Return an error if writing to the [`Formatter`](std::fmt::Formatter) fails
     #[inline]
     #[cfg(not(feature = "partial"))]
     fn standard_display(&self, f: &mut impl std::fmt::Write) -> std::fmt::Result {
-        SGRWriter::from(f).inline_sgr(self)
+        let mut writer = crate::SGRWriter::from(FmtWriter(f));
+        writer.set_enabled(crate::color_choice() == crate::ColorChoice::Always);
+        writer.inline_sgr(self)
     }
     /// Writes an SGR sequence to the given [`Formatter`](std::fmt::Formatter)
     ///
-    /// Uses [`SGRWriter::partial_sgr`], so the sequence end & escape strings
-    /// are not written
+    /// Uses [`SGRBuilder::write_partial`], so the sequence end & escape strings
+    /// are not written.
+    /// Nothing is written while the [`ColorChoice`](crate::ColorChoice) is never
     ///
     /// # Errors
     ///
@@ -255,6 +598,118 @@ pub trait DiscreteSGR: Sized + Display + EasySGR {
     #[inline]
     #[cfg(feature = "partial")]
     fn standard_display(&self, f: &mut impl std::fmt::Write) -> std::fmt::Result {
-        SGRWriter::from(f).partial_sgr(self)
+        if crate::color_choice() == crate::ColorChoice::Never {
+            return Ok(());
+        }
+        let mut builder = SGRBuilder::default();
+        self.write(&mut builder);
+        builder.write_partial(&mut FmtWriter(f))
+    }
+}
+/// Writes the codes of each element in order, e.g. a color then a style
+///
+/// Implemented for pairs & triples of any [`DiscreteSGR`], tuples included,
+/// so writing a one-off composite needs neither an allocation nor several calls
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{AnySgr, Color::*, SGRWriter, Style::*};
+///
+///let mut writer = SGRWriter::from(String::new());
+///writer.inline_sgr(&(RedFg, Bold))?;
+///writer.inline_sgr(&(BlueFg, Italic, Underline))?;
+///writer.inline_sgr(&(WhiteFg, RedBg))?;
+///writer.inline_sgr(&(AnySgr::Raw(53), Dim))?;
+///writer.inline_sgr(&((GreenFg, Bold), Italic))?;
+///assert_eq!(
+///    writer.internal(),
+///    "\x1b[31;1m\x1b[34;3;4m\x1b[37;41m\x1b[53;2m\x1b[32;1;3m"
+///);
+///# Ok::<(), std::fmt::Error>(())
+///```
+impl<A: DiscreteSGR, B: DiscreteSGR> DiscreteSGR for (A, B) {
+    fn write(&self, builder: &mut impl CodeSink) {
+        self.0.write(builder);
+        self.1.write(builder);
+    }
+}
+/// Writes the codes of each element in order, see the impl for pairs
+impl<A: DiscreteSGR, B: DiscreteSGR, C: DiscreteSGR> DiscreteSGR for (A, B, C) {
+    fn write(&self, builder: &mut impl CodeSink) {
+        self.0.write(builder);
+        self.1.write(builder);
+        self.2.write(builder);
+    }
+}
+/// Any SGR code, for lists mixing colors, styles & raw codes
+///
+/// With the `serde` feature, this serializes to & from an object
+/// keyed by the kind of code, e.g. `{"color":"RedFg"}` or `{"raw":53}`
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{AnySgr, Color::*, DiscreteSGR, SGRBuilder, Style::*};
+///
+///let codes: Vec<AnySgr> = vec![Bold.into(), RedFg.into(), 53.into()];
+///let mut builder = SGRBuilder::default();
+///codes.iter().for_each(|code| code.write(&mut builder));
+///assert_eq!(builder.to_string(), "\x1b[1;31;53m");
+///```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum AnySgr {
+    /// See [`Color`]
+    Color(Color),
+    /// See [`Style`]
+    Style(Style),
+    /// A code written as is, e.g. one this crate has no type for
+    Raw(u8),
+}
+impl From<Color> for AnySgr {
+    fn from(value: Color) -> Self {
+        Self::Color(value)
+    }
+}
+impl From<Style> for AnySgr {
+    fn from(value: Style) -> Self {
+        Self::Style(value)
+    }
+}
+impl From<u8> for AnySgr {
+    fn from(value: u8) -> Self {
+        Self::Raw(value)
+    }
+}
+impl Display for AnySgr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.standard_display(f)
+    }
+}
+impl DiscreteSGR for AnySgr {
+    fn write(&self, builder: &mut impl CodeSink) {
+        match self {
+            Self::Color(color) => color.write(builder),
+            Self::Style(style) => style.write(builder),
+            Self::Raw(code) => builder.write_code(*code),
+        }
+    }
+}
+/// Displays the codes of a [`DiscreteSGR`] separated by `;`,
+/// without the sequence escape & end
+///
+/// Returned by [`DiscreteSGR::params`]
+#[derive(Debug, Clone, Copy)]
+pub struct SGRParams<'a, D: DiscreteSGR>(&'a D);
+impl<D: DiscreteSGR> Display for SGRParams<'_, D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut builder = SGRBuilder::default();
+        self.0.write(&mut builder);
+        builder.write_partial(&mut FmtWriter(f))
     }
 }
@@ -0,0 +1,184 @@
+use std::{
+    io::{self, IsTerminal, Write},
+    sync::OnceLock,
+};
+
+use ::env_logger::fmt::Formatter;
+use ::log::{Level, Record};
+
+use crate::{
+    theme::write_styled, CleanKind, Color::*, EasySGR, IoWriter, SGRString, SGRWriter, Style::*,
+    Theme,
+};
+
+/// An [`env_logger`] format styling records with a [`Theme`]
+///
+/// Records are written as `TIMESTAMP LEVEL target: message`, using the entries:
+///
+/// - `"timestamp"` for the timestamp, when written
+/// - `"error"`, `"warn"`, `"info"`, `"debug"` & `"trace"` for the level badge
+/// - `"target"` for the target
+/// - `"gutter"` for the gutter continuation lines of a message are indented by
+///
+/// Missing entries are written plainly.
+///
+/// Colors follow the [`ColorChoice`](crate::ColorChoice) rather than
+/// `env_logger`'s own write style, detecting for stderr as
+/// [`SGRWriter::stderr`] does while none is set, keeping them consistent
+/// with the rest of this crate.
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{env_logger::StyledFormat, Color::*};
+///use log::Level;
+///
+///let format = StyledFormat::new().level_style(Level::Info, CyanFg);
+///env_logger::Builder::new()
+///    .format(move |buf, record| format.format(buf, record))
+///    .build();
+///```
+#[derive(Debug, Clone)]
+pub struct StyledFormat {
+    theme: Theme,
+    /// Whether styles are used, `None` following the [`ColorChoice`](crate::ColorChoice)
+    color: Option<bool>,
+    timestamp: bool,
+}
+impl StyledFormat {
+    /// Creates a format using [`default_theme`]
+    ///
+    /// Timestamps are written, colors following the [`ColorChoice`](crate::ColorChoice)
+    /// unless set through [`StyledFormat::color`]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            theme: default_theme(),
+            color: None,
+            timestamp: true,
+        }
+    }
+    /// Sets the theme records are styled with
+    #[must_use]
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+    /// Sets the style of the badge of `level`
+    #[must_use]
+    pub fn level_style(mut self, level: Level, style: impl Into<SGRString>) -> Self {
+        self.theme.set(level_name(level), style);
+        self
+    }
+    /// Sets whether records start with a timestamp
+    #[must_use]
+    pub const fn timestamp(mut self, timestamp: bool) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+    /// Sets whether records are styled at all
+    #[must_use]
+    pub const fn color(mut self, color: bool) -> Self {
+        self.color = Some(color);
+        self
+    }
+    /// Writes `record` to `buf`, the signature expected by
+    /// [`Builder::format`](env_logger::Builder::format)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `buf` fails
+    pub fn format(&self, buf: &mut Formatter, record: &Record<'_>) -> io::Result<()> {
+        let timestamp = self.timestamp.then(|| buf.timestamp().to_string());
+        self.write_record(buf, timestamp.as_deref(), record)
+    }
+    /// Turns this into a closure to be passed to
+    /// [`Builder::format`](env_logger::Builder::format)
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::env_logger::StyledFormat;
+    ///
+    ///env_logger::Builder::new()
+    ///    .format(StyledFormat::new().timestamp(false).into_fn())
+    ///    .build();
+    ///```
+    pub fn into_fn(
+        self,
+    ) -> impl Fn(&mut Formatter, &Record<'_>) -> io::Result<()> + Send + Sync + 'static {
+        move |buf, record| self.format(buf, record)
+    }
+    /// Writes `record`, following `timestamp` when there is one
+    fn write_record(
+        &self,
+        buf: &mut impl Write,
+        timestamp: Option<&str>,
+        record: &Record<'_>,
+    ) -> io::Result<()> {
+        let mut writer = SGRWriter::from(IoWriter(buf));
+        if let Some(timestamp) = timestamp {
+            write_styled(&mut writer, self.style("timestamp"), timestamp)?;
+            writer.write_inner(" ")?;
+        }
+        let level = record.level();
+        write_styled(&mut writer, self.style(level_name(level)), level.as_str())?;
+        // padding is kept outside of the badge's escapes
+        writer.write_inner(&"      "[level.as_str().len()..])?;
+        write_styled(&mut writer, self.style("target"), record.target())?;
+        writer.write_inner(": ")?;
+
+        let message = record.args().to_string();
+        let mut lines = message.lines();
+        writer.write_inner(lines.next().unwrap_or_default())?;
+        for line in lines {
+            writer.write_inner("\n  ")?;
+            write_styled(&mut writer, self.style("gutter"), "|")?;
+            writer.write_inner(" ")?;
+            writer.write_inner(line)?;
+        }
+        writer.write_inner("\n")
+    }
+    /// Returns the style named `name`, `None` when not coloring
+    fn style(&self, name: &str) -> Option<&SGRString> {
+        self.theme.get(name).filter(|_| self.colored())
+    }
+    /// Returns whether records are styled, detecting for stderr unless set
+    fn colored(&self) -> bool {
+        static DETECTED: OnceLock<bool> = OnceLock::new();
+        self.color.unwrap_or_else(|| {
+            crate::detect::output_enabled(&DETECTED, || io::stderr().is_terminal())
+        })
+    }
+}
+impl Default for StyledFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+/// Returns the theme used by default
+///
+/// Errors are bold red, warnings yellow, info green, debug blue & trace dimmed,
+/// the timestamp, target & gutter being dimmed as well
+#[must_use]
+pub fn default_theme() -> Theme {
+    Theme::new()
+        .with("error", RedFg.style(Bold).clean(CleanKind::Reverse))
+        .with("warn", YellowFg.clean(CleanKind::Reverse))
+        .with("info", GreenFg.clean(CleanKind::Reverse))
+        .with("debug", BlueFg.clean(CleanKind::Reverse))
+        .with("trace", Dim.clean(CleanKind::Reverse))
+        .with("timestamp", Dim.clean(CleanKind::Reverse))
+        .with("target", Dim.clean(CleanKind::Reverse))
+        .with("gutter", Dim.clean(CleanKind::Reverse))
+}
+/// Returns the name a level's style is found under
+const fn level_name(level: Level) -> &'static str {
+    match level {
+        Level::Error => "error",
+        Level::Warn => "warn",
+        Level::Info => "info",
+        Level::Debug => "debug",
+        Level::Trace => "trace",
+    }
+}
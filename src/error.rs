@@ -0,0 +1,295 @@
+use std::{convert::Infallible, error::Error, fmt, io};
+
+#[cfg(feature = "from-str")]
+use crate::discrete::from_str::{ParseColorError, ParseError, ParseSeqError, ParseStyleError};
+use crate::{CapableWriter, SharedError, TeeError};
+
+/// An error from any of the writers or parsers of this crate
+///
+/// Lets `?` be used in code mixing writers with differing
+/// [`CapableWriter::Error`]s, see [`ErasedWriter`]
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{Color::*, ErasedWriter, SGRError, SGRWriter};
+///
+///fn write_both() -> Result<(), SGRError> {
+///    let mut string = SGRWriter::from(ErasedWriter::new(String::new()));
+///    let mut bytes = SGRWriter::from(ErasedWriter::new(Vec::new()));
+///    string.styled(&RedFg, "text")?;
+///    bytes.styled(&RedFg, "text")?;
+///    Ok(())
+///}
+///write_both().unwrap();
+///```
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SGRError {
+    /// See [`io::Error`]
+    Io(io::Error),
+    /// See [`fmt::Error`]
+    Fmt(fmt::Error),
+    /// See [`ParseError`]
+    #[cfg(feature = "from-str")]
+    Parse(ParseError),
+    /// See [`InvalidEscape`]
+    Escape(InvalidEscape),
+    /// See [`SgrParseError`]
+    Sgr(SgrParseError),
+    /// Any other error, such as from a custom [`CapableWriter`]
+    Other(Box<dyn Error + Send + Sync>),
+}
+impl SGRError {
+    /// Creates an error from any other error
+    pub fn other(error: impl Into<Box<dyn Error + Send + Sync>>) -> Self {
+        Self::Other(error.into())
+    }
+}
+impl From<io::Error> for SGRError {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+impl From<fmt::Error> for SGRError {
+    fn from(value: fmt::Error) -> Self {
+        Self::Fmt(value)
+    }
+}
+impl From<Infallible> for SGRError {
+    fn from(value: Infallible) -> Self {
+        match value {}
+    }
+}
+#[cfg(feature = "from-str")]
+impl From<ParseError> for SGRError {
+    fn from(value: ParseError) -> Self {
+        Self::Parse(value)
+    }
+}
+#[cfg(feature = "from-str")]
+impl From<ParseSeqError> for SGRError {
+    fn from(value: ParseSeqError) -> Self {
+        Self::Parse(value.into())
+    }
+}
+#[cfg(feature = "from-str")]
+impl From<ParseStyleError> for SGRError {
+    fn from(value: ParseStyleError) -> Self {
+        Self::Parse(value.into())
+    }
+}
+#[cfg(feature = "from-str")]
+impl From<ParseColorError> for SGRError {
+    fn from(value: ParseColorError) -> Self {
+        Self::Parse(value.into())
+    }
+}
+impl From<InvalidEscape> for SGRError {
+    fn from(value: InvalidEscape) -> Self {
+        Self::Escape(value)
+    }
+}
+impl From<SgrParseError> for SGRError {
+    fn from(value: SgrParseError) -> Self {
+        Self::Sgr(value)
+    }
+}
+impl<E: Into<Self>> From<EscapeError<E>> for SGRError {
+    fn from(value: EscapeError<E>) -> Self {
+        match value {
+            EscapeError::Invalid(e) => Self::Escape(e),
+            EscapeError::Write(e) => e.into(),
+        }
+    }
+}
+impl<A, B> From<TeeError<A, B>> for SGRError
+where
+    A: Error + Send + Sync + 'static,
+    B: Error + Send + Sync + 'static,
+{
+    fn from(value: TeeError<A, B>) -> Self {
+        Self::Other(Box::new(value))
+    }
+}
+impl<E: Into<Self>> From<SharedError<E>> for SGRError {
+    fn from(value: SharedError<E>) -> Self {
+        match value {
+            SharedError::Poisoned => Self::Other(Box::new(SharedError::<Infallible>::Poisoned)),
+            SharedError::Write(e) => e.into(),
+        }
+    }
+}
+impl fmt::Display for SGRError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "IO error: {e}"),
+            Self::Fmt(e) => write!(f, "Formatting error: {e}"),
+            #[cfg(feature = "from-str")]
+            Self::Parse(e) => write!(f, "Parsing error: {e}"),
+            Self::Escape(e) => write!(f, "Escape error: {e}"),
+            Self::Sgr(e) => write!(f, "SGR parsing error: {e}"),
+            Self::Other(e) => write!(f, "{e}"),
+        }
+    }
+}
+impl Error for SGRError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Fmt(e) => Some(e),
+            #[cfg(feature = "from-str")]
+            Self::Parse(e) => Some(e),
+            Self::Escape(e) => Some(e),
+            Self::Sgr(e) => Some(e),
+            Self::Other(e) => Some(&**e),
+        }
+    }
+}
+/// Why a sequence given to [`SGRWriter::write_escape`](crate::SGRWriter::write_escape)
+/// was rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidEscape {
+    /// The sequence doesn't start with `ESC`
+    MissingEscape,
+    /// The sequence ends before its terminator
+    Unterminated,
+    /// The byte at this index can't be part of the sequence,
+    /// e.g. a stray control character
+    InvalidByte(usize),
+    /// Something follows the terminator, starting at this index
+    Trailing(usize),
+}
+impl fmt::Display for InvalidEscape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingEscape => write!(f, "Sequence doesn't start with ESC"),
+            Self::Unterminated => write!(f, "Sequence is missing its terminator"),
+            Self::InvalidByte(i) => write!(f, "Invalid byte in sequence at index {i}"),
+            Self::Trailing(i) => write!(f, "Text follows the sequence at index {i}"),
+        }
+    }
+}
+impl Error for InvalidEscape {}
+/// Why a string given to [`SGRBuilder::parse`](crate::SGRBuilder::parse) was rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SgrParseError {
+    /// The string isn't a single well-formed escape sequence
+    Escape(InvalidEscape),
+    /// The string is a well-formed sequence, though not a SGR sequence
+    NotSgr,
+    /// The parameter starting at this index isn't a code from `0` to `255`
+    InvalidParam(usize),
+}
+impl fmt::Display for SgrParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Escape(e) => write!(f, "Invalid escape sequence: {e}"),
+            Self::NotSgr => write!(f, "Sequence isn't a SGR sequence"),
+            Self::InvalidParam(i) => write!(f, "Invalid parameter in sequence at index {i}"),
+        }
+    }
+}
+impl Error for SgrParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Escape(e) => Some(e),
+            Self::NotSgr | Self::InvalidParam(_) => None,
+        }
+    }
+}
+/// The codes given to an [`ArrayBuilder`](crate::ArrayBuilder) don't fit within its capacity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError;
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Codes don't fit within the builder's capacity")
+    }
+}
+impl Error for CapacityError {}
+/// An error returned by [`SGRWriter::write_escape`](crate::SGRWriter::write_escape)
+#[derive(Debug)]
+pub enum EscapeError<E> {
+    /// The sequence wasn't well-formed, nothing was written
+    Invalid(InvalidEscape),
+    /// Writing the sequence failed
+    Write(E),
+}
+impl<E: fmt::Display> fmt::Display for EscapeError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(e) => write!(f, "Invalid escape sequence: {e}"),
+            Self::Write(e) => write!(f, "Writing escape sequence failed: {e}"),
+        }
+    }
+}
+impl<E: Error + 'static> Error for EscapeError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Invalid(e) => Some(e),
+            Self::Write(e) => Some(e),
+        }
+    }
+}
+/// A writer converting the errors of another into [`SGRError`]
+///
+/// Writers with differing errors can be used together once wrapped
+#[derive(Debug, Clone)]
+pub struct ErasedWriter<W: CapableWriter>(pub W);
+impl<W: CapableWriter> ErasedWriter<W>
+where
+    W::Error: Into<SGRError>,
+{
+    /// Creates a writer converting the errors of `writer`
+    #[must_use]
+    pub const fn new(writer: W) -> Self {
+        Self(writer)
+    }
+    /// Returns the writer whose errors are converted
+    pub fn into_inner(self) -> W {
+        self.0
+    }
+    /// Returns a reference to the writer whose errors are converted
+    #[must_use]
+    pub const fn get_ref(&self) -> &W {
+        &self.0
+    }
+    /// Returns a mutable reference to the writer whose errors are converted
+    pub const fn get_mut(&mut self) -> &mut W {
+        &mut self.0
+    }
+}
+impl<W: CapableWriter> CapableWriter for ErasedWriter<W>
+where
+    W::Error: Into<SGRError>,
+{
+    type Writer = W::Writer;
+    type Error = SGRError;
+    #[inline]
+    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.0.write(s).map_err(Into::into)
+    }
+    #[inline]
+    fn write_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        self.0.write_sgr(codes).map_err(Into::into)
+    }
+    #[inline]
+    fn write_partial_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        self.0.write_partial_sgr(codes).map_err(Into::into)
+    }
+    #[inline]
+    fn write_escape(&mut self, seq: &str) -> Result<(), Self::Error> {
+        self.0.write_escape(seq).map_err(Into::into)
+    }
+    #[inline]
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> Result<(), Self::Error> {
+        self.0.write_fmt(args).map_err(Into::into)
+    }
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.0.flush().map_err(Into::into)
+    }
+    fn get_writer(self) -> Self::Writer {
+        self.0.get_writer()
+    }
+}
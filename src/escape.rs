@@ -0,0 +1,254 @@
+use std::ops::Range;
+
+use crate::InvalidEscape;
+
+/// Splits text apart from the escape sequences embedded within it
+///
+/// Keeps its state between calls to [`EscapeScanner::scan`],
+/// so sequences split over several writes are still recognized
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EscapeScanner {
+    state: State,
+    /// The parameters of the current Control Sequence
+    params: String,
+}
+/// Where the scanner is within a sequence
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum State {
+    /// Outside of any sequence
+    #[default]
+    Text,
+    /// After an `ESC`
+    Escape,
+    /// Within a Control Sequence, e.g. `\x1b[31m`
+    Csi,
+    /// Within an Operating System Command, e.g. `\x1b]8;;url\x1b\\`
+    Osc,
+    /// After an `ESC` within an Operating System Command
+    OscEscape,
+}
+/// A part of what is given to [`EscapeScanner::scan_parts`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Part<'a> {
+    /// A run of text lying outside of an escape sequence, by its range
+    Text(Range<usize>),
+    /// The codes of a complete SGR sequence
+    Sgr(&'a [u8]),
+    /// A complete escape sequence, SGR or not, by its range
+    ///
+    /// Follows the [`Part::Sgr`] of SGR sequences. Sequences started
+    /// in an earlier call are given from the start of `s`
+    Escape(Range<usize>),
+}
+impl EscapeScanner {
+    /// Calls `text` with each run of `s` lying outside of an escape sequence
+    pub fn scan<'a>(&mut self, s: &'a str, mut text: impl FnMut(&'a str)) {
+        self.scan_parts(s, |part| {
+            if let Part::Text(range) = part {
+                text(&s[range]);
+            }
+        });
+    }
+    /// Calls `part` with each run of text & each sequence completed within `s`
+    pub fn scan_parts(&mut self, s: &str, part: impl FnMut(Part<'_>)) {
+        self.scan_bytes(s.as_bytes(), part);
+    }
+    /// [`EscapeScanner::scan_parts`] for bytes that may not be UTF-8
+    pub fn scan_bytes(&mut self, s: &[u8], mut part: impl FnMut(Part<'_>)) {
+        let mut start = 0;
+        for (i, &byte) in s.iter().enumerate() {
+            // only printable ASCII can end an `ESC` sequence, anything else,
+            // e.g. the lead byte of a char, ends it unconsumed & is read as text
+            if self.state == State::Escape && !matches!(byte, 0x1b | 0x20..=0x7e) {
+                part(Part::Escape(start..i));
+                start = i;
+                self.state = State::Text;
+            }
+            let in_text = self.state == State::Text;
+            self.state = match (self.state, byte) {
+                // intermediate bytes are skipped, e.g. the `(` of `\x1b(B`
+                (State::Text | State::Escape | State::Csi, 0x1b) | (State::Escape, 0x20..=0x2f) => {
+                    State::Escape
+                }
+                (State::Text, _) => State::Text,
+                (State::Escape, b'[') => {
+                    self.params.clear();
+                    State::Csi
+                }
+                (State::Escape, b']') => State::Osc,
+                // the final byte of a sequence
+                (State::Escape, _)
+                | (State::Csi, 0x40..=0x7e)
+                | (State::Osc, 0x07)
+                | (State::OscEscape, b'\\') => {
+                    if self.state == State::Csi && byte == b'm' {
+                        if let Ok(codes) = sgr_codes(&self.params) {
+                            part(Part::Sgr(&codes));
+                        }
+                    }
+                    part(Part::Escape(start..i + 1));
+                    start = i + 1;
+                    State::Text
+                }
+                (State::Csi, _) => {
+                    self.params.push(char::from(byte));
+                    State::Csi
+                }
+                (State::Osc | State::OscEscape, 0x1b) => State::OscEscape,
+                (State::Osc | State::OscEscape, _) => State::Osc,
+            };
+            if in_text && self.state != State::Text {
+                if start < i {
+                    part(Part::Text(start..i));
+                }
+                start = i;
+            }
+        }
+        if self.state == State::Text && start < s.len() {
+            part(Part::Text(start..s.len()));
+        }
+    }
+}
+/// Parses the parameters of an SGR sequence into its codes
+///
+/// Empty parameters are read as `0`. Extended colors written with subparameters,
+/// e.g. `38:5:1` or `38:2::1:2:3`, are read as their `;` form,
+/// the color space id before their RGB components being dropped
+///
+/// # Errors
+///
+/// Returns the index within `params` of the first parameter that isn't a code,
+/// holds subparameters other than an extended color,
+/// or starts an extended color missing its parameters
+pub fn sgr_codes(params: &str) -> Result<Vec<u8>, usize> {
+    /// Reads a single parameter or subparameter
+    fn code(param: &str) -> Option<u8> {
+        match param {
+            "" => Some(0),
+            _ if param.bytes().all(|byte| byte.is_ascii_digit()) => param.parse().ok(),
+            _ => None,
+        }
+    }
+    let mut codes = Vec::new();
+    // the index of the parameter each code was read from
+    let mut starts = Vec::new();
+    let mut start = 0;
+    for param in params.split(';') {
+        let subs = param.split(':').map(code).collect::<Option<Vec<_>>>();
+        match subs.as_deref() {
+            Some(&[code]) => codes.push(code),
+            Some(&[kind @ (38 | 48 | 58), 5, n]) => codes.extend([kind, 5, n]),
+            Some(
+                &([kind @ (38 | 48 | 58), 2, _, r, g, b] | [kind @ (38 | 48 | 58), 2, r, g, b]),
+            ) => {
+                codes.extend([kind, 2, r, g, b]);
+            }
+            _ => return Err(start),
+        }
+        starts.resize(codes.len(), start);
+        start += param.len() + 1;
+    }
+    // extended colors need all of their parameters
+    let mut i = 0;
+    while let Some(&code) = codes.get(i) {
+        let len = match (code, codes.get(i + 1)) {
+            (38 | 48 | 58, Some(5)) => 3,
+            (38 | 48 | 58, Some(2)) => 5,
+            (38 | 48 | 58, _) => return Err(starts[i]),
+            _ => 1,
+        };
+        if i + len > codes.len() {
+            return Err(starts[i]);
+        }
+        i += len;
+    }
+    Ok(codes)
+}
+/// Checks that `seq` is a single well-formed escape sequence
+///
+/// Control Sequences end with a final byte, strings such as
+/// Operating System Commands end with `BEL` or `ESC \\`,
+/// and other sequences with a byte following their intermediates
+pub fn validate(seq: &str) -> Result<(), InvalidEscape> {
+    let bytes = seq.as_bytes();
+    if bytes.first() != Some(&0x1b) {
+        return Err(InvalidEscape::MissingEscape);
+    }
+    let end = match bytes.get(1) {
+        None => return Err(InvalidEscape::Unterminated),
+        Some(b'[') => csi_end(bytes)?,
+        Some(b']' | b'P' | b'X' | b'^' | b'_') => string_end(bytes)?,
+        Some(_) => {
+            let mut i = 1;
+            while let Some(0x20..=0x2f) = bytes.get(i) {
+                i += 1;
+            }
+            match bytes.get(i) {
+                Some(0x30..=0x7e) => i + 1,
+                Some(_) => return Err(InvalidEscape::InvalidByte(i)),
+                None => return Err(InvalidEscape::Unterminated),
+            }
+        }
+    };
+    if end < bytes.len() {
+        Err(InvalidEscape::Trailing(end))
+    } else {
+        Ok(())
+    }
+}
+/// Returns the index after the final byte of a Control Sequence
+fn csi_end(bytes: &[u8]) -> Result<usize, InvalidEscape> {
+    let mut intermediate = false;
+    for (i, &byte) in bytes.iter().enumerate().skip(2) {
+        match byte {
+            0x30..=0x3f if !intermediate => (),
+            0x20..=0x2f => intermediate = true,
+            0x40..=0x7e => return Ok(i + 1),
+            _ => return Err(InvalidEscape::InvalidByte(i)),
+        }
+    }
+    Err(InvalidEscape::Unterminated)
+}
+/// Returns the index after the terminator of a string, e.g. an Operating System Command
+fn string_end(bytes: &[u8]) -> Result<usize, InvalidEscape> {
+    let mut i = 2;
+    while let Some(&byte) = bytes.get(i) {
+        match byte {
+            0x07 => return Ok(i + 1),
+            0x1b if bytes.get(i + 1) == Some(&b'\\') => return Ok(i + 2),
+            0x1b if i + 1 == bytes.len() => return Err(InvalidEscape::Unterminated),
+            0x00..=0x1f | 0x7f => return Err(InvalidEscape::InvalidByte(i)),
+            _ => i += 1,
+        }
+    }
+    Err(InvalidEscape::Unterminated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EscapeScanner;
+
+    /// The parts of each of `chunks`, scanned in turn
+    fn parts(chunks: &[&[u8]]) -> Vec<String> {
+        let mut scanner = EscapeScanner::default();
+        let mut parts = Vec::new();
+        for chunk in chunks {
+            scanner.scan_bytes(chunk, |part| parts.push(format!("{part:?}")));
+        }
+        parts
+    }
+
+    #[test]
+    fn escape_before_char() {
+        let mut text = Vec::new();
+        EscapeScanner::default().scan("a\x1bé\x1b(Bb", |s| text.push(s));
+        assert_eq!(text, ["a", "é", "b"]);
+
+        assert_eq!(parts(&["\x1bé".as_bytes()]), ["Escape(0..1)", "Text(1..3)"]);
+        // split over two calls
+        assert_eq!(
+            parts(&[b"\x1b", "é".as_bytes()]),
+            ["Escape(0..0)", "Text(0..2)"]
+        );
+    }
+}
@@ -1,6 +1,10 @@
 use std::fmt::{Debug, Display};
 
-use crate::{Color, SGRBuilder, SGRWriter, Style};
+use crate::{
+    escape::{EscapeScanner, Part},
+    wrappers::char_width,
+    AnySgr, CodeSink, Color, SGRBuilder, SGRWriter, SgrState, Style,
+};
 
 /// A String encapsulating the usage of SGR codes
 ///
@@ -10,6 +14,11 @@ use crate::{Color, SGRBuilder, SGRWriter, Style};
 ///
 /// Writing is done through the use of the [`writing`](crate::writing) module
 ///
+/// Compared with a `str`, only the visible text is compared, so
+/// `assert_eq!(styled, "text")` holds however it is styled.
+/// `SGRString`s aren't comparable with each other, for that compare their
+/// [`SGRString::text`] or what they display
+///
 /// # Examples
 ///
 ///```rust
@@ -21,7 +30,21 @@ use crate::{Color, SGRBuilder, SGRWriter, Style};
 ///string.foreground = ColorKind::Red;
 ///println!("{string}");
 ///```
-#[derive(Default, Debug, Clone)]
+///
+///```rust
+///use easy_sgr::{Color::*, EasySGR, Style::*};
+///
+///assert_eq!(RedFg.text("done"), "done");
+///assert_eq!(*"done", Bold.text("done"));
+///assert_ne!(RedFg.text("done"), "\x1b[31mdone");
+///```
+///
+///```rust,compile_fail
+///use easy_sgr::{Color::*, EasySGR};
+///
+///assert!(RedFg.text("done") != BlueFg.text("done"));
+///```
+#[derive(Default, Clone)]
 pub struct SGRString {
     /// The actual text
     pub text: String,
@@ -29,6 +52,18 @@ pub struct SGRString {
     ///
     /// By default [`CleanKind::None`], meaning nothing is done
     pub clean: CleanKind,
+    /// Overrides [`SGRString::clean`] for the foreground
+    ///
+    /// By default `None`, meaning [`SGRString::clean`] is followed
+    pub foreground_clean: Option<CleanKind>,
+    /// Overrides [`SGRString::clean`] for the background
+    ///
+    /// By default `None`, meaning [`SGRString::clean`] is followed
+    pub background_clean: Option<CleanKind>,
+    /// Overrides [`SGRString::clean`] for styles
+    ///
+    /// By default `None`, meaning [`SGRString::clean`] is followed
+    pub styles_clean: Option<CleanKind>,
 
     /// Any custom codes added
     ///
@@ -40,6 +75,15 @@ pub struct SGRString {
     /// These codes are written after the string when
     /// the [`Display`] trait is called
     pub custom_cleans: Vec<u8>,
+    /// Sequences written as they are before the SGR codes,
+    /// such as an OSC 8 hyperlink or a cursor shape
+    ///
+    /// Written by [`SGRWriter`] as escapes, so they are dropped by
+    /// [`StripWriter`](crate::StripWriter)
+    pub raw_places: Vec<String>,
+    /// Sequences written as they are after the SGR codes,
+    /// undoing those of [`SGRString::raw_places`]
+    pub raw_cleans: Vec<String>,
 
     /// The color of the foreground
     ///
@@ -56,7 +100,7 @@ pub struct SGRString {
 
     /// Determines whether the clear code `0` is to be applied to the beginning
     ///
-    /// Not be confused with [`SGRString.clean`], this effects [`SGRString::place_all`]
+    /// Not be confused with [`SGRString::clean`], this effects [`SGRString::place_all`]
     pub reset: bool,
     /// Refer to [`StyleKind`]
     pub bold: StyleKind,
@@ -76,12 +120,284 @@ pub struct SGRString {
     pub strikethrough: StyleKind,
 }
 impl SGRString {
+    /// Returns this with its text replaced by `f` applied to it,
+    /// leaving the colors & styles untouched
+    ///
+    /// `f` only sees the text, never the escapes written around it
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color::*, EasySGR};
+    ///
+    ///let header = RedFg.text("header").map_text(str::to_uppercase);
+    ///assert_eq!(header.to_string(), "\x1b[31mHEADER");
+    ///```
+    #[must_use]
+    pub fn map_text(self, f: impl FnOnce(&str) -> String) -> Self {
+        Self {
+            text: f(&self.text),
+            ..self
+        }
+    }
+    /// Applies `f` to the text in place, leaving the colors & styles untouched
+    ///
+    /// Unlike [`SGRString::map_text`], the text's allocation is reused
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color::*, EasySGR};
+    ///
+    ///let mut path = RedFg.text("/a/long/path");
+    ///path.map_text_in_place(|text| text.truncate(2));
+    ///assert_eq!(path.to_string(), "\x1b[31m/a");
+    ///```
+    pub fn map_text_in_place(&mut self, f: impl FnOnce(&mut String)) {
+        f(&mut self.text);
+    }
+    /// Creates an unstyled string with space for `capacity` bytes of text
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color::*, EasySGR, SGRString};
+    ///
+    ///let mut line = SGRString::with_capacity(80).color(RedFg);
+    ///for row in 0..3 {
+    ///    line.clear_text();
+    ///    line.push_str("row ");
+    ///    line.push(char::from(b'0' + row));
+    ///    assert_eq!(line.to_string(), format!("\x1b[31mrow {row}"));
+    ///}
+    ///assert!(line.text.capacity() >= 80);
+    ///```
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        String::with_capacity(capacity).into()
+    }
+    /// Reserves space for at least `additional` more bytes of text
+    pub fn reserve(&mut self, additional: usize) {
+        self.text.reserve(additional);
+    }
+    /// Appends `s` to the text, leaving the colors & styles untouched
+    pub fn push_str(&mut self, s: &str) {
+        self.text.push_str(s);
+    }
+    /// Appends `ch` to the text, leaving the colors & styles untouched
+    pub fn push(&mut self, ch: char) {
+        self.text.push(ch);
+    }
+    /// Inserts `s` into the text at the byte index `idx`,
+    /// leaving the colors & styles untouched
+    ///
+    /// # Panics
+    ///
+    /// `idx` is larger than the length of the text, or doesn't lie on a char boundary
+    pub fn insert_str(&mut self, idx: usize, s: &str) {
+        self.text.insert_str(idx, s);
+    }
+    /// Shortens the text to `len` bytes, leaving the colors & styles untouched
+    ///
+    /// Does nothing when `len` is larger than the length of the text
+    ///
+    /// # Panics
+    ///
+    /// `len` doesn't lie on a char boundary
+    pub fn truncate(&mut self, len: usize) {
+        self.text.truncate(len);
+    }
+    /// Removes all text, keeping the colors, styles & the text's allocation
+    pub fn clear_text(&mut self) {
+        self.text.clear();
+    }
+    /// Returns the length of the text in bytes
+    ///
+    /// Escapes embedded within the text are counted as well,
+    /// so for the columns it takes up see [`measure_width`](crate::measure_width),
+    /// the same only for plain ASCII text
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.text.len()
+    }
+    /// Returns whether the text is empty, whatever the colors & styles
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+    /// Splits this in two at the visible column `idx`,
+    /// both halves keeping every color & style so each renders alone
+    ///
+    /// `idx` is clamped to the width of the text.
+    /// Escapes embedded in the text take up no columns,
+    /// those lying at the split going to the right half.
+    /// A wide character straddling `idx` goes to the right half entirely,
+    /// moving the split left of it as a terminal would.
+    ///
+    /// With the `width` feature characters are measured by the
+    /// columns they take up, otherwise each visible character is 1 column.
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color::*, EasySGR};
+    ///
+    ///let (left, right) = RedFg.text("column").split_at_visible(3);
+    ///assert_eq!(left.to_string(), "\x1b[31mcol");
+    ///assert_eq!(right.to_string(), "\x1b[31mumn");
+    ///```
+    #[must_use]
+    pub fn split_at_visible(self, idx: usize) -> (Self, Self) {
+        let mut column = 0;
+        let mut split = 0;
+        let mut done = false;
+        EscapeScanner::default().scan_parts(&self.text, |part| {
+            let Part::Text(range) = part else { return };
+            if done {
+                return;
+            }
+            for (i, c) in self.text[range.clone()].char_indices() {
+                column += char_width(c);
+                if column > idx {
+                    done = true;
+                    return;
+                }
+                split = range.start + i + c.len_utf8();
+            }
+        });
+        let mut left = self;
+        let right = Self {
+            text: left.text.split_off(split),
+            ..left.clone()
+        };
+        (left, right)
+    }
+    /// Returns `over` layered on top of this
+    ///
+    /// Fields set on `over` win, those it leaves unset being taken from this:
+    /// - colors are unset when [`ColorKind::None`], so [`ColorKind::Default`] still overrides
+    /// - each style is unset when [`StyleKind::None`], so styles placed by either are kept
+    ///   unless `over` cleans them
+    /// - [`SGRString::clean`] is unset when [`CleanKind::None`],
+    ///   the per-part cleans when `None`, so `Some(CleanKind::None)` still overrides
+    /// - custom codes & raw sequences are unset when empty, being replaced rather than joined
+    /// - [`SGRString::reset`] is applied if either applies it
+    /// - the text is that of `over`, unless it's empty
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color::*, ColorKind, EasySGR, Style::*};
+    ///
+    ///let base = RedFg.style(Bold).style(Italic).text("base");
+    ///let over = DefaultFg.style(NotItalic).style(Underline);
+    ///
+    ///let merged = base.merge(&over);
+    ///assert_eq!(merged.foreground, ColorKind::Default);
+    ///assert_eq!(merged.to_string(), "\x1b[39;1;23;4mbase");
+    ///```
+    #[must_use]
+    pub fn merge(&self, over: &Self) -> Self {
+        let mut merged = self.clone();
+        merged.merge_into(over);
+        merged
+    }
+    /// Layers `over` on top of this in place
+    ///
+    /// See [`SGRString::merge`]
+    pub fn merge_into(&mut self, over: &Self) {
+        fn set<T: Clone + PartialEq>(field: &mut T, over: &T, unset: &T) {
+            if over != unset {
+                field.clone_from(over);
+            }
+        }
+        set(&mut self.text, &over.text, &String::new());
+        set(&mut self.clean, &over.clean, &CleanKind::None);
+        set(&mut self.foreground_clean, &over.foreground_clean, &None);
+        set(&mut self.background_clean, &over.background_clean, &None);
+        set(&mut self.styles_clean, &over.styles_clean, &None);
+        set(&mut self.custom_places, &over.custom_places, &Vec::new());
+        set(&mut self.custom_cleans, &over.custom_cleans, &Vec::new());
+        set(&mut self.raw_places, &over.raw_places, &Vec::new());
+        set(&mut self.raw_cleans, &over.raw_cleans, &Vec::new());
+        set(&mut self.foreground, &over.foreground, &ColorKind::None);
+        set(&mut self.background, &over.background, &ColorKind::None);
+        self.reset |= over.reset;
+        for (style, over) in [
+            (&mut self.bold, &over.bold),
+            (&mut self.dim, &over.dim),
+            (&mut self.italic, &over.italic),
+            (&mut self.underline, &over.underline),
+            (&mut self.blinking, &over.blinking),
+            (&mut self.inverse, &over.inverse),
+            (&mut self.hidden, &over.hidden),
+            (&mut self.strikethrough, &over.strikethrough),
+        ] {
+            set(style, over, &StyleKind::None);
+        }
+    }
+    /// Returns an iterator over the chars of the text,
+    /// each along with the same [`SgrState`]
+    ///
+    /// See [`CharsStyled`]
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color::*, ColorKind, EasySGR, Style::*};
+    ///
+    ///let warning = YellowFg.style(Bold).text("hi");
+    ///for (ch, state) in warning.chars_styled() {
+    ///    assert!(state.bold && state.foreground == ColorKind::Yellow);
+    ///}
+    ///```
+    #[must_use]
+    pub fn chars_styled(&self) -> CharsStyled<'_> {
+        CharsStyled {
+            spans: [].iter(),
+            chars: self.text.chars(),
+            state: SgrState::from(self),
+        }
+    }
+    /// Removes all colors, styles, cleans & custom codes, keeping only the text
+    ///
+    /// Displaying this afterwards writes the text alone,
+    /// e.g. once a `--plain` flag is known
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color::*, CleanKind, EasySGR, Style::*};
+    ///
+    ///let mut status = RedFg.style(Bold).text("failed").clean(CleanKind::Reset);
+    ///status.clear_styles();
+    ///assert_eq!(status.to_string(), "failed");
+    ///```
+    pub fn clear_styles(&mut self) {
+        *self = Self {
+            text: std::mem::take(&mut self.text),
+            ..Self::default()
+        };
+    }
+    /// Returns the text, dropping all styling
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color::*, EasySGR};
+    ///
+    ///assert_eq!(RedFg.text("failed").into_plain(), "failed");
+    ///```
+    #[must_use]
+    pub fn into_plain(self) -> String {
+        self.text
+    }
     /// Writes all contained SGR codes to the given [`SGRBuilder`]
     ///
     /// Does not perform any IO operations
     pub fn place_all(&self, builder: &mut SGRBuilder) {
         if self.reset {
-            builder.write_code(0);
+            builder.write_style(&Style::Reset);
         }
         self.place_colors(builder);
         self.place_styles(builder);
@@ -91,58 +407,39 @@ impl SGRString {
     ///
     /// Does not perform any IO operations
     pub fn place_colors(&self, builder: &mut SGRBuilder) {
-        use ColorKind::*;
-        match self.foreground {
-            Black => builder.write_code(30),
-            Red => builder.write_code(31),
-            Green => builder.write_code(32),
-            Yellow => builder.write_code(33),
-            Blue => builder.write_code(34),
-            Magenta => builder.write_code(35),
-            Cyan => builder.write_code(36),
-            White => builder.write_code(37),
-            Byte(n) => builder.write_codes(&[38, 5, n]),
-            Rgb(r, g, b) => builder.write_codes(&[38, 2, r, g, b]),
-            Default => builder.write_code(39),
-            ColorKind::None => (),
-        };
-        match self.background {
-            Black => builder.write_code(40),
-            Red => builder.write_code(41),
-            Green => builder.write_code(42),
-            Yellow => builder.write_code(43),
-            Blue => builder.write_code(44),
-            Magenta => builder.write_code(45),
-            Cyan => builder.write_code(46),
-            White => builder.write_code(47),
-            Byte(n) => builder.write_codes(&[48, 5, n]),
-            Rgb(r, g, b) => builder.write_codes(&[48, 2, r, g, b]),
-            Default => builder.write_code(49),
-            ColorKind::None => (),
-        };
+        if let Some(color) = self.foreground.to_color() {
+            builder.write_fg(&color);
+        }
+        if let Some(color) = self.background.to_color() {
+            builder.write_bg(&color);
+        }
     }
     /// Writes SGR style codes to the given [`SGRWriter`]
     ///
     /// Does not perform any IO operations
     pub fn place_styles(&self, builder: &mut SGRBuilder) {
-        use StyleKind::*;
-        for (kind, place, not) in [
-            (&self.bold, 1, 22),
-            (&self.dim, 2, 22),
-            (&self.italic, 3, 23),
-            (&self.underline, 4, 24),
-            (&self.blinking, 5, 25),
-            (&self.inverse, 7, 27),
-            (&self.hidden, 8, 28),
-            (&self.strikethrough, 9, 29),
-        ] {
+        for (kind, style) in self.style_kinds() {
             match kind {
-                None => (),
-                Place => builder.write_code(place),
-                Clean => builder.write_code(not),
+                StyleKind::None => (),
+                StyleKind::Place => builder.write_style(&style),
+                StyleKind::Clean => builder.clear_style(&style),
             }
         }
     }
+    /// Returns each style's [`StyleKind`] along with the style it places
+    const fn style_kinds(&self) -> [(&StyleKind, Style); 8] {
+        use Style::*;
+        [
+            (&self.bold, Bold),
+            (&self.dim, Dim),
+            (&self.italic, Italic),
+            (&self.underline, Underline),
+            (&self.blinking, Blinking),
+            (&self.inverse, Inverse),
+            (&self.hidden, Hidden),
+            (&self.strikethrough, Strikethrough),
+        ]
+    }
     /// Writes custom SGR codes to the given [`SGRWriter`]
     ///
     /// Does not perform any IO operations
@@ -151,17 +448,45 @@ impl SGRString {
     }
     /// Writes contained SGR codes to the given [`SGRWriter`]
     ///
-    /// Reverses the effects of [`SGRString::place_all`]
+    /// Reverses the effects of [`SGRString::place_all`],
+    /// the foreground, background & styles each following their own clean
+    /// when set, or else [`SGRString::clean`].
+    /// Should any of them be [`CleanKind::Reset`] everything is reset.
     ///
     /// Does not perform any IO operations
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{CleanKind, Color::*, EasySGR, SGRBuilder};
+    ///
+    ///let status = RedFg
+    ///    .color(WhiteBg)
+    ///    .clean(CleanKind::Reverse)
+    ///    .clean_bg(CleanKind::None);
+    ///let mut builder = SGRBuilder::default();
+    ///status.clean_all(&mut builder);
+    ///assert_eq!(builder.codes(), [39]);
+    ///```
     pub fn clean_all(&self, builder: &mut SGRBuilder) {
-        match self.clean {
-            CleanKind::Reset => builder.write_code(0),
-            CleanKind::Reverse => {
-                self.clean_colors(builder);
+        let [foreground, background, styles] = [
+            &self.foreground_clean,
+            &self.background_clean,
+            &self.styles_clean,
+        ]
+        .map(|kind| kind.as_ref().unwrap_or(&self.clean));
+        if [foreground, background, styles].contains(&&CleanKind::Reset) {
+            builder.write_code(0);
+        } else {
+            if *foreground == CleanKind::Reverse && self.foreground != ColorKind::None {
+                builder.write_code(39);
+            }
+            if *background == CleanKind::Reverse && self.background != ColorKind::None {
+                builder.write_code(49);
+            }
+            if *styles == CleanKind::Reverse {
                 self.clean_styles(builder);
             }
-            CleanKind::None => (),
         }
         self.clean_custom(builder);
     }
@@ -182,24 +507,69 @@ impl SGRString {
     ///
     /// Reverses the effects of [`SGRString::place_styles`]
     ///
+    /// Bold & dim are both ended by `22`, so cleaning either also ends the other
+    /// when it was applied before this was placed, e.g. by surrounding text.
+    /// `22` is written before any bold or dim cleaned by this is placed again,
+    /// so those it applies itself are kept.
+    /// Use [`SGRString::clean_within`] when what was applied before is known
+    ///
     /// Does not perform any IO operations
     pub fn clean_styles(&self, builder: &mut SGRBuilder) {
-        for (kind, place, not) in [
-            (&self.bold, 22, 1),
-            (&self.dim, 22, 2),
-            (&self.italic, 23, 3),
-            (&self.underline, 24, 4),
-            (&self.blinking, 25, 5),
-            (&self.inverse, 27, 7),
-            (&self.hidden, 28, 8),
-            (&self.strikethrough, 29, 9),
-        ] {
-            match kind {
-                StyleKind::None => (),
-                StyleKind::Place => builder.write_code(place),
-                StyleKind::Clean => builder.write_code(not),
-            }
+        let intensity = [(&self.bold, 1), (&self.dim, 2)];
+        if intensity.iter().any(|(kind, _)| **kind == StyleKind::Place) {
+            builder.write_code(22);
         }
+        builder.write_codes_iter(
+            intensity
+                .into_iter()
+                .filter_map(|(kind, code)| (*kind == StyleKind::Clean).then_some(code)),
+        );
+        builder.write_codes_iter(
+            [
+                (&self.italic, 23, 3),
+                (&self.underline, 24, 4),
+                (&self.blinking, 25, 5),
+                (&self.inverse, 27, 7),
+                (&self.hidden, 28, 8),
+                (&self.strikethrough, 29, 9),
+            ]
+            .into_iter()
+            .filter_map(|(kind, place, not)| match kind {
+                StyleKind::None => None,
+                StyleKind::Place => Some(place),
+                StyleKind::Clean => Some(not),
+            }),
+        );
+    }
+    /// Writes the fewest codes returning to `outer` after this was placed within it,
+    /// followed by [`SGRString::custom_cleans`]
+    ///
+    /// Unlike [`SGRString::clean_all`], knowing what was applied before
+    /// lets styles sharing a clean code be restored,
+    /// e.g. cleaning bold within dimmed text writes `22;2` as `22` ends both.
+    /// [`SGRString::clean`] isn't followed, as `outer` is always returned to
+    ///
+    /// Does not perform any IO operations
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{EasySGR, SGRBuilder, SgrState, Style::*};
+    ///
+    ///let mut outer = SgrState::new();
+    ///outer.apply(&[2]);
+    ///
+    ///let mut builder = SGRBuilder::default();
+    ///Bold.to_sgr().clean_within(&outer, &mut builder);
+    ///assert_eq!(builder.codes(), [22, 2]);
+    ///```
+    pub fn clean_within(&self, outer: &SgrState, builder: &mut SGRBuilder) {
+        let mut placed = SGRBuilder::default();
+        self.place_all(&mut placed);
+        let mut inner = *outer;
+        inner.apply(placed.codes());
+        inner.transition(outer, builder);
+        self.clean_custom(builder);
     }
     /// Writes SGR codes to the given [`SGRWriter`]
     ///
@@ -220,6 +590,52 @@ impl From<Style> for SGRString {
         Self::default().style(value)
     }
 }
+/// Both colors, usually a foreground & a background, without any text
+///
+/// Each color sets the foreground or background it is for,
+/// as by [`EasySGR::color`]
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{Color::*, EasySGR, SGRString};
+///
+///let s = SGRString::from((RedFg, WhiteBg)).text("alert");
+///assert_eq!(s.to_string(), "\x1b[31;47malert");
+///```
+impl From<(Color, Color)> for SGRString {
+    fn from((first, second): (Color, Color)) -> Self {
+        Self::default().color(first).color(second)
+    }
+}
+/// The color & style, without any text
+impl From<(Color, Style)> for SGRString {
+    fn from((color, style): (Color, Style)) -> Self {
+        Self::default().color(color).style(style)
+    }
+}
+/// The color & both styles, without any text
+impl From<(Color, Style, Style)> for SGRString {
+    fn from((color, first, second): (Color, Style, Style)) -> Self {
+        Self::default().color(color).style(first).style(second)
+    }
+}
+/// The styles, without any text
+impl<const N: usize> From<[Style; N]> for SGRString {
+    fn from(styles: [Style; N]) -> Self {
+        styles.into_iter().fold(Self::default(), EasySGR::style)
+    }
+}
+/// Raw codes are added as [`SGRString::custom_places`]
+impl From<AnySgr> for SGRString {
+    fn from(value: AnySgr) -> Self {
+        match value {
+            AnySgr::Color(color) => color.into(),
+            AnySgr::Style(style) => style.into(),
+            AnySgr::Raw(code) => Self::default().custom(code),
+        }
+    }
+}
 impl From<&str> for SGRString {
     fn from(value: &str) -> Self {
         Self {
@@ -244,12 +660,406 @@ impl From<&String> for SGRString {
         }
     }
 }
+/// The color as the foreground of the text
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{ColorKind, SGRString};
+///
+///let s: SGRString = (ColorKind::Red, "error").into();
+///assert_eq!(s.to_string(), "\x1b[31merror");
+///```
+impl<C: Into<ColorKind>, T: Into<String>> From<(C, T)> for SGRString {
+    fn from((foreground, text): (C, T)) -> Self {
+        Self {
+            text: text.into(),
+            foreground: foreground.into(),
+            ..Default::default()
+        }
+    }
+}
+/// The colors as the foreground & background of the text
+impl<C: Into<ColorKind>, T: Into<String>> From<(C, C, T)> for SGRString {
+    fn from((foreground, background, text): (C, C, T)) -> Self {
+        Self {
+            text: text.into(),
+            foreground: foreground.into(),
+            background: background.into(),
+            ..Default::default()
+        }
+    }
+}
+/// The style applied to the text
+impl<T: Into<String>> From<(Style, T)> for SGRString {
+    fn from((style, text): (Style, T)) -> Self {
+        style.text(text)
+    }
+}
+/// The styles applied to the text
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{SGRString, Style::*};
+///
+///let s = SGRString::from(([Bold, Italic], "text"));
+///assert_eq!(s.to_string(), "\x1b[1;3mtext");
+///```
+impl<T: Into<String>, const N: usize> From<([Style; N], T)> for SGRString {
+    fn from((styles, text): ([Style; N], T)) -> Self {
+        styles
+            .into_iter()
+            .fold(Self::from(text.into()), EasySGR::style)
+    }
+}
+/// The color as the foreground of the text, with the style applied
+impl<C: Into<ColorKind>, T: Into<String>> From<(C, Style, T)> for SGRString {
+    fn from((foreground, style, text): (C, Style, T)) -> Self {
+        Self::from((foreground, text)).style(style)
+    }
+}
+/// The color as the foreground of the text, with the styles applied
+impl<C: Into<ColorKind>, T: Into<String>, const N: usize> From<(C, [Style; N], T)> for SGRString {
+    fn from((foreground, styles, text): (C, [Style; N], T)) -> Self {
+        styles
+            .into_iter()
+            .fold(Self::from((foreground, text)), EasySGR::style)
+    }
+}
 impl Display for SGRString {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut fmt = SGRWriter::from(f);
-        fmt.place_sgr(self)?;
-        fmt.write_inner(&self.text)?;
-        fmt.clean_sgr(self)
+        // any default style of the current thread fills in unset fields
+        crate::context::with_applied(self, |sgr| {
+            let mut fmt = SGRWriter::from(f);
+            fmt.set_enabled(crate::color_choice() == crate::ColorChoice::Always);
+            fmt.place_sgr(sgr)?;
+            fmt.write_inner(&sgr.text)?;
+            fmt.clean_sgr(sgr)
+        })
+    }
+}
+/// Appends to [`SGRString::text`], keeping the styling as is
+///
+/// # Examples
+///
+///```rust
+///use std::fmt::Write;
+///
+///use easy_sgr::{Color::*, EasySGR};
+///
+///let mut progress = GreenFg.text("done: ");
+///write!(progress, "{} of {}", 3, 5)?;
+///assert_eq!(progress.to_string(), "\x1b[32mdone: 3 of 5");
+///# Ok::<(), std::fmt::Error>(())
+///```
+impl std::fmt::Write for SGRString {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.text.push_str(s);
+        Ok(())
+    }
+}
+/// Implements `PartialEq` between the type & `str`, `&str`, both ways,
+/// comparing with its visible text through `$eq`
+macro_rules! eq_visible_text {
+    ($ty:ty, $eq:expr) => {
+        impl PartialEq<str> for $ty {
+            fn eq(&self, other: &str) -> bool {
+                $eq(self, other)
+            }
+        }
+        impl PartialEq<&str> for $ty {
+            fn eq(&self, other: &&str) -> bool {
+                $eq(self, other)
+            }
+        }
+        impl PartialEq<$ty> for str {
+            fn eq(&self, other: &$ty) -> bool {
+                $eq(other, self)
+            }
+        }
+        impl PartialEq<$ty> for &str {
+            fn eq(&self, other: &$ty) -> bool {
+                $eq(other, self)
+            }
+        }
+    };
+}
+// Only the visible text is compared with strings.
+// Neither implements `PartialEq<Self>`: whether two differently styled
+// values with the same text are equal is left to comparing their fields
+// or what they display, so the two notions can't silently disagree
+eq_visible_text!(SGRString, |sgr: &SGRString, other: &str| sgr.text == other);
+eq_visible_text!(SGRText, |text: &SGRText, mut other: &str| {
+    text.spans.iter().all(|span| {
+        other
+            .strip_prefix(span.text.as_str())
+            .map(|rest| other = rest)
+            .is_some()
+    }) && other.is_empty()
+});
+/// Shows the text & what is applied to it, leaving out fields that do nothing
+///
+/// Colors are shown by name, or in hex when RGB,
+/// & styles by the [`Style`] they apply, e.g.
+/// `SGRString { text: "error", fg: Red, styles: Bold|Underline, clean: Reverse }`
+// the style fields are shown together as `styles`
+#[allow(clippy::missing_fields_in_debug)]
+impl Debug for SGRString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut debug = f.debug_struct("SGRString");
+        debug.field("text", &self.text);
+        for (name, color) in [("fg", &self.foreground), ("bg", &self.background)] {
+            if *color != ColorKind::None {
+                debug.field(name, &ColorName(color));
+            }
+        }
+        if self.reset {
+            debug.field("reset", &self.reset);
+        }
+        let styles = styles(self);
+        if !styles.is_empty() {
+            debug.field("styles", &StyleNames(&styles));
+        }
+        if self.clean != CleanKind::None {
+            debug.field("clean", &self.clean);
+        }
+        for (name, clean) in [
+            ("fg_clean", &self.foreground_clean),
+            ("bg_clean", &self.background_clean),
+            ("styles_clean", &self.styles_clean),
+        ] {
+            if let Some(clean) = clean {
+                debug.field(name, clean);
+            }
+        }
+        for (name, codes) in [
+            ("custom_places", &self.custom_places),
+            ("custom_cleans", &self.custom_cleans),
+        ] {
+            if !codes.is_empty() {
+                debug.field(name, codes);
+            }
+        }
+        for (name, raws) in [
+            ("raw_places", &self.raw_places),
+            ("raw_cleans", &self.raw_cleans),
+        ] {
+            if !raws.is_empty() {
+                debug.field(name, raws);
+            }
+        }
+        debug.finish()
+    }
+}
+/// Returns the styles `sgr` places or cleans, in the order written
+fn styles(sgr: &SGRString) -> Vec<Style> {
+    use Style::*;
+    [
+        (&sgr.bold, Bold, NotBold),
+        (&sgr.dim, Dim, NotDim),
+        (&sgr.italic, Italic, NotItalic),
+        (&sgr.underline, Underline, NotUnderline),
+        (&sgr.blinking, Blinking, NotBlinking),
+        (&sgr.inverse, Inverse, NotInverse),
+        (&sgr.hidden, Hidden, NotHidden),
+        (&sgr.strikethrough, Strikethrough, NotStrikethrough),
+    ]
+    .into_iter()
+    .filter_map(|(kind, place, clean)| match kind {
+        StyleKind::None => None,
+        StyleKind::Place => Some(place),
+        StyleKind::Clean => Some(clean),
+    })
+    .collect()
+}
+/// Shows a color by name, or in hex when RGB
+struct ColorName<'a>(&'a ColorKind);
+impl Debug for ColorName<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            ColorKind::Rgb(r, g, b) => write!(f, "#{r:02x}{g:02x}{b:02x}"),
+            color => Debug::fmt(color, f),
+        }
+    }
+}
+/// Shows styles joined by `|`
+struct StyleNames<'a>(&'a [Style]);
+impl Debug for StyleNames<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, style) in self.0.iter().enumerate() {
+            if i != 0 {
+                f.write_str("|")?;
+            }
+            Debug::fmt(style, f)?;
+        }
+        Ok(())
+    }
+}
+/// Text made up of several [`SGRString`]s, each styled by its own
+///
+/// Spans are written one after another, each placing & cleaning its own codes
+///
+/// Compared with a `str`, only the text of the spans joined is compared,
+/// as by [`SGRText::plain_text`]
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{Color::*, CleanKind, EasySGR, SGRText};
+///
+///let mut text = SGRText::new();
+///text.push("status: ");
+///text.push(GreenFg.text("ok").clean(CleanKind::Reverse));
+///assert_eq!(text.to_string(), "status: \x1b[32mok\x1b[39m");
+///assert_eq!(text.plain_text(), "status: ok");
+///assert_eq!(text, "status: ok");
+///```
+#[derive(Default, Clone)]
+pub struct SGRText {
+    /// The spans making up the text, in the order written
+    pub spans: Vec<SGRString>,
+}
+impl SGRText {
+    /// Creates text without any spans
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { spans: Vec::new() }
+    }
+    /// Adds `span` to the end of the text
+    pub fn push(&mut self, span: impl Into<SGRString>) {
+        self.spans.push(span.into());
+    }
+    /// Returns the text of every span joined, without any styling
+    #[must_use]
+    pub fn plain_text(&self) -> String {
+        self.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+    /// Returns an iterator over the chars of every span,
+    /// each along with the [`SgrState`] of its span
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color::*, ColorKind, EasySGR, SGRText, Style::*};
+    ///
+    ///let text: SGRText = [RedFg.text("a"), Bold.text("b")].into_iter().collect();
+    ///let mut chars = text.chars_styled();
+    ///assert!(matches!(chars.next(), Some(('a', state)) if state.foreground == ColorKind::Red));
+    ///assert!(matches!(chars.next(), Some(('b', state)) if state.bold && !state.italic));
+    ///assert!(chars.next().is_none());
+    ///```
+    #[must_use]
+    pub fn chars_styled(&self) -> CharsStyled<'_> {
+        CharsStyled {
+            spans: self.spans.iter(),
+            chars: "".chars(),
+            state: SgrState::new(),
+        }
+    }
+    /// Removes the styling of every span, keeping the spans & their text
+    ///
+    /// See [`SGRString::clear_styles`]
+    pub fn clear_styles(&mut self) {
+        self.spans.iter_mut().for_each(SGRString::clear_styles);
+    }
+    /// Returns the text of every span joined, dropping all styling
+    ///
+    /// Unlike [`SGRText::plain_text`], the first span's text is reused
+    #[must_use]
+    pub fn into_plain(self) -> String {
+        let mut spans = self.spans.into_iter();
+        let mut plain = spans.next().map(SGRString::into_plain).unwrap_or_default();
+        spans.for_each(|span| plain.push_str(&span.text));
+        plain
+    }
+}
+/// Iterates over the chars of an [`SGRString`] or [`SGRText`],
+/// each along with the [`SgrState`] it is displayed in
+///
+/// The state is the same for every char of a span,
+/// changing only at the start of the next non-empty one.
+/// It is that which placing the span leaves the default state in,
+/// so [`SGRString::clean`] & [`SGRString::reset`] make no difference
+///
+/// Returned by [`SGRString::chars_styled`] & [`SGRText::chars_styled`]
+#[derive(Debug, Clone)]
+pub struct CharsStyled<'a> {
+    /// The spans after the current one
+    spans: std::slice::Iter<'a, SGRString>,
+    /// The remaining chars of the current span
+    chars: std::str::Chars<'a>,
+    /// The state of the current span
+    state: SgrState,
+}
+impl Iterator for CharsStyled<'_> {
+    type Item = (char, SgrState);
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(ch) = self.chars.next() {
+                return Some((ch, self.state));
+            }
+            let span = self.spans.find(|span| !span.text.is_empty())?;
+            self.chars = span.text.chars();
+            self.state = SgrState::from(span);
+        }
+    }
+}
+impl From<Vec<SGRString>> for SGRText {
+    fn from(spans: Vec<SGRString>) -> Self {
+        Self { spans }
+    }
+}
+impl<S: Into<SGRString>> FromIterator<S> for SGRText {
+    fn from_iter<T: IntoIterator<Item = S>>(iter: T) -> Self {
+        Self {
+            spans: iter.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+impl<S: Into<SGRString>> Extend<S> for SGRText {
+    fn extend<T: IntoIterator<Item = S>>(&mut self, iter: T) {
+        self.spans.extend(iter.into_iter().map(Into::into));
+    }
+}
+/// Shows each span as [`SGRString`] does, e.g. `SGRText[SGRString { text: "a" }]`
+impl Debug for SGRText {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SGRText")?;
+        f.debug_list().entries(&self.spans).finish()
+    }
+}
+/// Appends to the text of the last span, in its styling,
+/// starting an unstyled span when there are none
+///
+/// To write unstyled text after a styled span, push an empty one first
+///
+/// # Examples
+///
+///```rust
+///use std::fmt::Write;
+///
+///use easy_sgr::{CleanKind, Color::*, EasySGR, SGRText};
+///
+///let mut text = SGRText::new();
+///write!(text, "{}: ", "status")?;
+///text.push(GreenFg.text("").clean(CleanKind::Reset));
+///write!(text, "{}/{}", 5, 5)?;
+///assert_eq!(text.to_string(), "status: \x1b[32m5/5\x1b[0m");
+///# Ok::<(), std::fmt::Error>(())
+///```
+impl std::fmt::Write for SGRText {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        match self.spans.last_mut() {
+            Some(span) => span.text.push_str(s),
+            None => self.spans.push(s.into()),
+        }
+        Ok(())
+    }
+}
+impl Display for SGRText {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.spans.iter().try_for_each(|span| Display::fmt(span, f))
     }
 }
 /// Component of [`SGRString`]; the type of clean
@@ -279,7 +1089,7 @@ pub enum StyleKind {
 /// Component of [`SGRString`]; the type of color
 ///
 /// Used for both foreground and background
-#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 #[allow(missing_docs)]
 pub enum ColorKind {
     /// Does nothing
@@ -298,6 +1108,80 @@ pub enum ColorKind {
     /// Applies the default `SGR` color
     Default,
 }
+/// The 16 colors of the basic palette & the default,
+/// usable as either the foreground or background
+///
+/// Bright colors are the 256-colors `8` to `15`,
+/// the same as converted to from other crates' bright colors
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{ColorKind, SGRString};
+///
+///const WARNING: ColorKind = ColorKind::BRIGHT_YELLOW;
+///let s = SGRString {
+///    foreground: WARNING,
+///    background: ColorKind::BLACK,
+///    ..Default::default()
+///};
+///assert_eq!(s.to_string(), "\x1b[38;5;11;40m");
+///```
+impl ColorKind {
+    /// Black, [`ColorKind::Black`]
+    pub const BLACK: Self = Self::Black;
+    /// Red, [`ColorKind::Red`]
+    pub const RED: Self = Self::Red;
+    /// Green, [`ColorKind::Green`]
+    pub const GREEN: Self = Self::Green;
+    /// Yellow, [`ColorKind::Yellow`]
+    pub const YELLOW: Self = Self::Yellow;
+    /// Blue, [`ColorKind::Blue`]
+    pub const BLUE: Self = Self::Blue;
+    /// Magenta, [`ColorKind::Magenta`]
+    pub const MAGENTA: Self = Self::Magenta;
+    /// Cyan, [`ColorKind::Cyan`]
+    pub const CYAN: Self = Self::Cyan;
+    /// White, [`ColorKind::White`]
+    pub const WHITE: Self = Self::White;
+    /// Bright black, the 256-color `8`
+    pub const BRIGHT_BLACK: Self = Self::Byte(8);
+    /// Bright red, the 256-color `9`
+    pub const BRIGHT_RED: Self = Self::Byte(9);
+    /// Bright green, the 256-color `10`
+    pub const BRIGHT_GREEN: Self = Self::Byte(10);
+    /// Bright yellow, the 256-color `11`
+    pub const BRIGHT_YELLOW: Self = Self::Byte(11);
+    /// Bright blue, the 256-color `12`
+    pub const BRIGHT_BLUE: Self = Self::Byte(12);
+    /// Bright magenta, the 256-color `13`
+    pub const BRIGHT_MAGENTA: Self = Self::Byte(13);
+    /// Bright cyan, the 256-color `14`
+    pub const BRIGHT_CYAN: Self = Self::Byte(14);
+    /// Bright white, the 256-color `15`
+    pub const BRIGHT_WHITE: Self = Self::Byte(15);
+    /// The default color, [`ColorKind::Default`]
+    pub const DEFAULT: Self = Self::Default;
+}
+impl ColorKind {
+    /// Returns this as a foreground [`Color`], `None` for [`ColorKind::None`]
+    pub(crate) const fn to_color(self) -> Option<Color> {
+        Some(match self {
+            Self::None => return None,
+            Self::Black => Color::BlackFg,
+            Self::Red => Color::RedFg,
+            Self::Green => Color::GreenFg,
+            Self::Yellow => Color::YellowFg,
+            Self::Blue => Color::BlueFg,
+            Self::Magenta => Color::MagentaFg,
+            Self::Cyan => Color::CyanFg,
+            Self::White => Color::WhiteFg,
+            Self::Byte(n) => Color::ByteFg(n),
+            Self::Rgb(r, g, b) => Color::RgbFg(r, g, b),
+            Self::Default => Color::DefaultFg,
+        })
+    }
+}
 impl<I: Into<SGRString>> EasySGR for I {}
 /// Allows for chaining SGR sequence types
 ///
@@ -411,6 +1295,33 @@ pub trait EasySGR: Into<SGRString> {
         this.clean = clean.into();
         this
     }
+    /// Sets the [`CleanKind`] the foreground of the returned [`SGRString`] follows,
+    /// overriding [`EasySGR::clean`]
+    #[must_use]
+    #[inline]
+    fn clean_fg(self, clean: impl Into<CleanKind>) -> SGRString {
+        let mut this = self.into();
+        this.foreground_clean = Some(clean.into());
+        this
+    }
+    /// Sets the [`CleanKind`] the background of the returned [`SGRString`] follows,
+    /// overriding [`EasySGR::clean`]
+    #[must_use]
+    #[inline]
+    fn clean_bg(self, clean: impl Into<CleanKind>) -> SGRString {
+        let mut this = self.into();
+        this.background_clean = Some(clean.into());
+        this
+    }
+    /// Sets the [`CleanKind`] the styles of the returned [`SGRString`] follow,
+    /// overriding [`EasySGR::clean`]
+    #[must_use]
+    #[inline]
+    fn clean_style(self, clean: impl Into<CleanKind>) -> SGRString {
+        let mut this = self.into();
+        this.styles_clean = Some(clean.into());
+        this
+    }
     /// Adds a custom code to be written before the returned [`SGRString`]'s text
     #[must_use]
     #[inline]
@@ -427,4 +1338,35 @@ pub trait EasySGR: Into<SGRString> {
         this.custom_cleans.push(code.into());
         this
     }
+    /// Adds a sequence to be written as is before the returned [`SGRString`]'s codes
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color::*, EasySGR};
+    ///
+    ///let link = BlueFg
+    ///    .text("docs")
+    ///    .raw_place("\x1b]8;;https://docs.rs\x1b\\")
+    ///    .raw_clean("\x1b]8;;\x1b\\");
+    ///assert_eq!(
+    ///    link.to_string(),
+    ///    "\x1b]8;;https://docs.rs\x1b\\\x1b[34mdocs\x1b]8;;\x1b\\"
+    ///);
+    ///```
+    #[must_use]
+    #[inline]
+    fn raw_place(self, seq: impl Into<String>) -> SGRString {
+        let mut this = self.into();
+        this.raw_places.push(seq.into());
+        this
+    }
+    /// Adds a sequence to be written as is after the returned [`SGRString`]'s codes
+    #[must_use]
+    #[inline]
+    fn raw_clean(self, seq: impl Into<String>) -> SGRString {
+        let mut this = self.into();
+        this.raw_cleans.push(seq.into());
+        this
+    }
 }
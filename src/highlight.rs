@@ -0,0 +1,119 @@
+use std::{error::Error, fmt, ops::Range};
+
+use crate::{SGRString, SGRText};
+
+/// Returns `text` with each of `ranges` styled by `style`
+///
+/// Ranges are byte ranges, they may be given in any order
+/// & those overlapping or touching are merged. Empty ranges are ignored.
+/// The spans returned alternate between unstyled text & text styled by `style`,
+/// a single unstyled span being returned when nothing is highlighted.
+///
+/// Only the styling of `style` is used, any text being ignored
+///
+/// # Errors
+///
+/// Returns [`HighlightError::OutOfBounds`] for a range ending past `text` or before its start,
+/// or [`HighlightError::NotCharBoundary`] for a range not lying on char boundaries
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{highlight::highlight_ranges, CleanKind, Color::*, EasySGR};
+///
+///let style = YellowBg.clean(CleanKind::Reverse);
+///let line = highlight_ranges("fn main() {}", &[3..7, 0..2], &style).unwrap();
+///assert_eq!(line.to_string(), "\x1b[43mfn\x1b[49m \x1b[43mmain\x1b[49m() {}");
+///```
+pub fn highlight_ranges(
+    text: &str,
+    ranges: &[Range<usize>],
+    style: &SGRString,
+) -> Result<SGRText, HighlightError> {
+    let mut sorted = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        if range.start > range.end || range.end > text.len() {
+            return Err(HighlightError::OutOfBounds(range.clone()));
+        }
+        if let Some(&i) = [range.start, range.end]
+            .iter()
+            .find(|&&i| !text.is_char_boundary(i))
+        {
+            return Err(HighlightError::NotCharBoundary(i));
+        }
+        if !range.is_empty() {
+            sorted.push(range.clone());
+        }
+    }
+    sorted.sort_unstable_by_key(|range| range.start);
+    Ok(spans(text, sorted, style))
+}
+/// Returns `text` with each occurrence of `needle` styled by `style`
+///
+/// Occurrences are found from the start, without overlapping one another,
+/// & those touching are styled as one.
+/// A single unstyled span is returned when `needle` is empty or isn't found
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{highlight::highlight_matches, CleanKind, Style::*, EasySGR};
+///
+///let line = highlight_matches("a needle, another needle", "needle", &Bold.clean(CleanKind::Reverse));
+///assert_eq!(line.to_string(), "a \x1b[1mneedle\x1b[22m, another \x1b[1mneedle\x1b[22m");
+///```
+#[must_use]
+pub fn highlight_matches(text: &str, needle: &str, style: &SGRString) -> SGRText {
+    let ranges: Vec<_> = if needle.is_empty() {
+        Vec::new()
+    } else {
+        text.match_indices(needle)
+            .map(|(i, found)| i..i + found.len())
+            .collect()
+    };
+    spans(text, ranges, style)
+}
+/// Splits `text` into spans, styling the sorted `ranges` with `style`,
+/// merging those overlapping or touching
+fn spans(text: &str, ranges: Vec<Range<usize>>, style: &SGRString) -> SGRText {
+    let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    let mut spans = SGRText::new();
+    let mut end = 0;
+    for range in merged {
+        if end < range.start {
+            spans.push(&text[end..range.start]);
+        }
+        spans.push(SGRString {
+            text: text[range.clone()].to_string(),
+            ..style.clone()
+        });
+        end = range.end;
+    }
+    if end < text.len() || spans.spans.is_empty() {
+        spans.push(&text[end..]);
+    }
+    spans
+}
+/// Why the ranges given to [`highlight_ranges`] were rejected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HighlightError {
+    /// The range ends past the end of the text, or before its start
+    OutOfBounds(Range<usize>),
+    /// The byte index doesn't lie on a char boundary
+    NotCharBoundary(usize),
+}
+impl fmt::Display for HighlightError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfBounds(range) => write!(f, "Range {range:?} is out of bounds"),
+            Self::NotCharBoundary(i) => write!(f, "Index {i} isn't on a char boundary"),
+        }
+    }
+}
+impl Error for HighlightError {}
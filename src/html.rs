@@ -0,0 +1,256 @@
+use std::fmt::Write;
+
+use crate::{
+    css::Palette,
+    escape::{EscapeScanner, Part},
+    ColorKind, SgrState,
+};
+
+/// How [`ansi_to_html`] writes HTML
+///
+/// By default styles are written inline using [`Palette::XTERM`],
+/// all wrapped in a `<pre>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HtmlOptions {
+    /// The colors written by inline styles
+    pub palette: Palette,
+    /// Whether styles are written as classes, e.g. `class="sgr-fg-red sgr-bold"`,
+    /// rather than inline
+    ///
+    /// Colors without a name, i.e. 256-colors past `15` & RGB colors,
+    /// are still written inline. Inverse is only written as `sgr-inverse`,
+    /// swapping the colors being left to the stylesheet
+    pub classes: bool,
+    /// Whether the HTML is wrapped in `<pre>`
+    pub pre: bool,
+}
+impl Default for HtmlOptions {
+    fn default() -> Self {
+        Self {
+            palette: Palette::XTERM,
+            classes: false,
+            pre: true,
+        }
+    }
+}
+/// Converts text with embedded escape sequences into HTML
+///
+/// Each run of text written in the same [`SgrState`] becomes a `<span>`,
+/// unstyled text being written without one. Spans are never nested,
+/// so the HTML is balanced whatever the input.
+/// OSC 8 hyperlinks become `<a href>`s, only `http`, `https`, `mailto` & `file`
+/// links being kept so captured output can't inject scripts.
+/// Text is HTML escaped & all other escape sequences are dropped,
+/// as is a sequence left unfinished at the end of `input`
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::html::{ansi_to_html, HtmlOptions};
+///
+///let input = "\x1b[1;31merror\x1b[0m: <unknown>\x1b[2K";
+///assert_eq!(
+///    ansi_to_html(input, &HtmlOptions::default()),
+///    "<pre><span style=\"color:#cd0000;font-weight:bold\">error</span>: &lt;unknown&gt;</pre>"
+///);
+///
+///let options = HtmlOptions { classes: true, pre: false, ..Default::default() };
+///assert_eq!(
+///    ansi_to_html("\x1b[4;38;5;208mwarn", &options),
+///    "<span class=\"sgr-underline\" style=\"color:#ff8700\">warn</span>"
+///);
+///```
+#[must_use]
+pub fn ansi_to_html(input: &str, options: &HtmlOptions) -> String {
+    let mut html = Html {
+        out: String::new(),
+        options,
+        state: SgrState::new(),
+        span: false,
+        link: false,
+    };
+    if options.pre {
+        html.out.push_str("<pre>");
+    }
+    EscapeScanner::default().scan_parts(input, |part| match part {
+        Part::Text(range) => html.text(&input[range]),
+        Part::Sgr(codes) => html.sgr(codes),
+        Part::Escape(range) => {
+            if let Some(uri) = hyperlink(&input[range]) {
+                html.link(uri);
+            }
+        }
+    });
+    html.close_span();
+    html.close_link();
+    if options.pre {
+        html.out.push_str("</pre>");
+    }
+    html.out
+}
+/// The HTML being written by [`ansi_to_html`]
+struct Html<'a> {
+    out: String,
+    options: &'a HtmlOptions,
+    state: SgrState,
+    /// Whether a `<span>` is open
+    span: bool,
+    /// Whether an `<a>` is open
+    link: bool,
+}
+impl Html<'_> {
+    /// Writes escaped text, opening a span for the current state if needed
+    fn text(&mut self, text: &str) {
+        if !self.span && !self.state.is_default() {
+            self.open_span();
+        }
+        escape(&mut self.out, text);
+    }
+    /// Applies `codes`, closing the span when the state changes
+    fn sgr(&mut self, codes: &[u8]) {
+        let mut state = self.state;
+        state.apply(codes);
+        if state != self.state {
+            self.close_span();
+            self.state = state;
+        }
+    }
+    /// Opens a link to `uri`, or closes the open one when `uri` is empty
+    fn link(&mut self, uri: &str) {
+        self.close_span();
+        self.close_link();
+        if allowed_link(uri) {
+            self.out.push_str("<a href=\"");
+            escape(&mut self.out, uri);
+            self.out.push_str("\">");
+            self.link = true;
+        }
+    }
+    fn open_span(&mut self) {
+        let (classes, style) = if self.options.classes {
+            self.classes()
+        } else {
+            (String::new(), self.state.to_css_with(&self.options.palette))
+        };
+        self.out.push_str("<span");
+        for (name, value) in [("class", classes), ("style", style)] {
+            if !value.is_empty() {
+                // attribute values never contain quotes
+                write!(self.out, " {name}=\"{value}\"").expect("writing to a String can't fail");
+            }
+        }
+        self.out.push('>');
+        self.span = true;
+    }
+    fn close_span(&mut self) {
+        if self.span {
+            self.out.push_str("</span>");
+            self.span = false;
+        }
+    }
+    fn close_link(&mut self) {
+        if self.link {
+            self.out.push_str("</a>");
+            self.link = false;
+        }
+    }
+    /// Returns the classes of the current state,
+    /// along with the inline style of colors without a class
+    fn classes(&self) -> (String, String) {
+        let mut classes = Vec::new();
+        let mut style = Vec::new();
+        for (color, kind, property) in [
+            (self.state.foreground, "fg", "color"),
+            (self.state.background, "bg", "background-color"),
+        ] {
+            match color_name(color) {
+                Some(name) => classes.push(format!("sgr-{kind}-{name}")),
+                None => {
+                    if let Some(hex) = self.options.palette.hex(&color) {
+                        style.push(format!("{property}:{hex}"));
+                    }
+                }
+            }
+        }
+        for (applied, class) in [
+            (self.state.bold, "sgr-bold"),
+            (self.state.dim, "sgr-dim"),
+            (self.state.italic, "sgr-italic"),
+            (self.state.underline, "sgr-underline"),
+            (self.state.blinking, "sgr-blink"),
+            (self.state.rapid_blinking, "sgr-rapid-blink"),
+            (self.state.inverse, "sgr-inverse"),
+            (self.state.hidden, "sgr-hidden"),
+            (self.state.strikethrough, "sgr-strike"),
+        ] {
+            if applied {
+                classes.push(class.to_owned());
+            }
+        }
+        (classes.join(" "), style.join(";"))
+    }
+}
+/// Pushes `text` to `html`, escaping the characters HTML gives meaning to
+fn escape(html: &mut String, text: &str) {
+    for ch in text.chars() {
+        match ch {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#39;"),
+            ch => html.push(ch),
+        }
+    }
+}
+/// Returns the class name of one of the 16 named colors, e.g. `red` or `bright-red`
+fn color_name(color: ColorKind) -> Option<&'static str> {
+    const NAMES: [&str; 16] = [
+        "black",
+        "red",
+        "green",
+        "yellow",
+        "blue",
+        "magenta",
+        "cyan",
+        "white",
+        "bright-black",
+        "bright-red",
+        "bright-green",
+        "bright-yellow",
+        "bright-blue",
+        "bright-magenta",
+        "bright-cyan",
+        "bright-white",
+    ];
+    let index = match color {
+        ColorKind::Black => 0,
+        ColorKind::Red => 1,
+        ColorKind::Green => 2,
+        ColorKind::Yellow => 3,
+        ColorKind::Blue => 4,
+        ColorKind::Magenta => 5,
+        ColorKind::Cyan => 6,
+        ColorKind::White => 7,
+        ColorKind::Byte(n @ 0..=15) => n,
+        _ => return None,
+    };
+    Some(NAMES[usize::from(index)])
+}
+/// Returns the URI of an OSC 8 hyperlink, empty when closing one
+fn hyperlink(seq: &str) -> Option<&str> {
+    let rest = seq.strip_prefix("\x1b]8;")?;
+    let rest = rest
+        .strip_suffix('\x07')
+        .or_else(|| rest.strip_suffix("\x1b\\"))?;
+    // the parameters, such as an `id`, are followed by the URI
+    rest.split_once(';').map(|(_, uri)| uri)
+}
+/// Returns whether a link to `uri` is safe to write
+fn allowed_link(uri: &str) -> bool {
+    uri.split_once(':').is_some_and(|(scheme, _)| {
+        ["http", "https", "mailto", "file"]
+            .iter()
+            .any(|allowed| scheme.eq_ignore_ascii_case(allowed))
+    })
+}
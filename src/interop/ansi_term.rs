@@ -0,0 +1,113 @@
+//! Conversions from [`ansi_term`](https://docs.rs/ansi_term) types, easing migration
+//!
+//! - [`ansi_term::Style`] to [`SGRString`]
+//! - [`Colour`] to [`ColorKind`], or to a [`SGRString`] coloring the foreground
+//! - [`Paint`], mirroring `ansi_term`'s `paint`
+//!
+//! Every part of a [`ansi_term::Style`] has an equivalent,
+//! [`Colour::Purple`] becoming [`ColorKind::Magenta`],
+//! [`Colour::Fixed`] a [`ColorKind::Byte`] & [`Colour::RGB`] a [`ColorKind::Rgb`].
+//!
+//! Converted styles leave a terminal in the same state,
+//! though codes may be written in a different order:
+//! `ansi_term` writes styles before colors, [`SGRString`] colors before styles.
+//!
+//! # Examples
+//!
+//!```rust
+//!use ansi_term::{Colour, Style};
+//!use easy_sgr::{interop::ansi_term::Paint, SGRString};
+//!
+//!let style = Style::new().fg(Colour::Red).bold();
+//!assert_eq!(SGRString::from(style).to_string(), "\x1b[31;1m");
+//!
+//!// `ansi_term`'s own `paint` takes precedence on its types
+//!let painted = Paint::paint(style, "text");
+//!assert_eq!(painted.to_string(), "\x1b[31;1mtext\x1b[0m");
+//!```
+use ::ansi_term::{Colour, Style};
+
+use crate::{CleanKind, ColorKind, SGRBuilder, SGRString, StyleKind};
+
+impl From<Style> for SGRString {
+    fn from(value: Style) -> Self {
+        let kind = |applied: bool| {
+            if applied {
+                StyleKind::Place
+            } else {
+                StyleKind::None
+            }
+        };
+        Self {
+            foreground: value.foreground.map_or(ColorKind::None, ColorKind::from),
+            background: value.background.map_or(ColorKind::None, ColorKind::from),
+            bold: kind(value.is_bold),
+            dim: kind(value.is_dimmed),
+            italic: kind(value.is_italic),
+            underline: kind(value.is_underline),
+            blinking: kind(value.is_blink),
+            inverse: kind(value.is_reverse),
+            hidden: kind(value.is_hidden),
+            strikethrough: kind(value.is_strikethrough),
+            ..Default::default()
+        }
+    }
+}
+/// Colors the foreground, as [`Colour::normal`] does
+impl From<Colour> for SGRString {
+    fn from(value: Colour) -> Self {
+        Self {
+            foreground: value.into(),
+            ..Default::default()
+        }
+    }
+}
+impl From<Colour> for ColorKind {
+    fn from(value: Colour) -> Self {
+        match value {
+            Colour::Black => Self::Black,
+            Colour::Red => Self::Red,
+            Colour::Green => Self::Green,
+            Colour::Yellow => Self::Yellow,
+            Colour::Blue => Self::Blue,
+            Colour::Purple => Self::Magenta,
+            Colour::Cyan => Self::Cyan,
+            Colour::White => Self::White,
+            Colour::Fixed(n) => Self::Byte(n),
+            Colour::RGB(r, g, b) => Self::Rgb(r, g, b),
+        }
+    }
+}
+/// Mirrors `ansi_term`'s `paint`, returning a [`SGRString`]
+///
+/// The text is cleaned by a reset just as `ansi_term` does,
+/// unless nothing is placed
+///
+/// `ansi_term`'s types have their own `paint` method taking precedence,
+/// call [`Paint::paint`] directly on those
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{interop::ansi_term::Paint, Color::*};
+///
+///assert_eq!(RedFg.paint("error").to_string(), "\x1b[31merror\x1b[0m");
+///```
+pub trait Paint: Into<SGRString> {
+    /// Returns `text` styled with this
+    fn paint(self, text: impl Into<String>) -> SGRString {
+        let sgr: SGRString = self.into();
+        let mut builder = SGRBuilder::default();
+        sgr.place_all(&mut builder);
+        SGRString {
+            text: text.into(),
+            clean: if builder.is_empty() {
+                CleanKind::None
+            } else {
+                CleanKind::Reset
+            },
+            ..sgr
+        }
+    }
+}
+impl<I: Into<SGRString>> Paint for I {}
@@ -0,0 +1,153 @@
+//! Conversions to & from [`anstyle`](https://docs.rs/anstyle) types
+//!
+//! - [`anstyle::Style`] to & from [`SGRString`]
+//! - [`anstyle::Color`], [`AnsiColor`], [`Ansi256Color`] & [`RgbColor`] to [`ColorKind`]
+//! - [`ColorKind`] to `Option<anstyle::Color>`
+//!
+//! Conversions are exact for what both sides share, the rest is dropped:
+//!
+//! - Bright [`AnsiColor`]s become [`ColorKind::Byte`]s `8` to `15`,
+//!   so come back as [`Ansi256Color`]s
+//! - Double, curly, dotted & dashed underlines become plain underlines
+//! - Underline colors are dropped
+//! - The text, [`SGRString::clean`], [`SGRString::reset`], custom codes &
+//!   styles set to [`StyleKind::Clean`] of a [`SGRString`] are dropped
+//! - [`ColorKind::None`] & [`ColorKind::Default`] both become `None`
+//!
+//! # Examples
+//!
+//!```rust
+//!use anstyle::{AnsiColor, Style};
+//!use easy_sgr::{Color::*, EasySGR, SGRString, Style::*};
+//!
+//!let style = Style::new().bold().fg_color(Some(AnsiColor::Red.into()));
+//!let sgr = SGRString::from(style);
+//!assert_eq!(sgr.to_string(), "\x1b[31;1m");
+//!assert_eq!(Style::from(&RedFg.style(Bold)), style);
+//!```
+use ::anstyle::{Ansi256Color, AnsiColor, Effects, RgbColor};
+
+use crate::{ColorKind, SGRString, StyleKind};
+
+/// Every kind of underline, all mapping to a plain underline
+const UNDERLINES: [Effects; 5] = [
+    Effects::UNDERLINE,
+    Effects::DOUBLE_UNDERLINE,
+    Effects::CURLY_UNDERLINE,
+    Effects::DOTTED_UNDERLINE,
+    Effects::DASHED_UNDERLINE,
+];
+
+impl From<::anstyle::Style> for SGRString {
+    fn from(value: ::anstyle::Style) -> Self {
+        let effects = value.get_effects();
+        let kind = |applied: bool| {
+            if applied {
+                StyleKind::Place
+            } else {
+                StyleKind::None
+            }
+        };
+        Self {
+            foreground: value
+                .get_fg_color()
+                .map_or(ColorKind::None, ColorKind::from),
+            background: value
+                .get_bg_color()
+                .map_or(ColorKind::None, ColorKind::from),
+            bold: kind(effects.contains(Effects::BOLD)),
+            dim: kind(effects.contains(Effects::DIMMED)),
+            italic: kind(effects.contains(Effects::ITALIC)),
+            underline: kind(
+                UNDERLINES
+                    .iter()
+                    .any(|&underline| effects.contains(underline)),
+            ),
+            blinking: kind(effects.contains(Effects::BLINK)),
+            inverse: kind(effects.contains(Effects::INVERT)),
+            hidden: kind(effects.contains(Effects::HIDDEN)),
+            strikethrough: kind(effects.contains(Effects::STRIKETHROUGH)),
+            ..Default::default()
+        }
+    }
+}
+impl From<&SGRString> for ::anstyle::Style {
+    fn from(value: &SGRString) -> Self {
+        let effects = [
+            (&value.bold, Effects::BOLD),
+            (&value.dim, Effects::DIMMED),
+            (&value.italic, Effects::ITALIC),
+            (&value.underline, Effects::UNDERLINE),
+            (&value.blinking, Effects::BLINK),
+            (&value.inverse, Effects::INVERT),
+            (&value.hidden, Effects::HIDDEN),
+            (&value.strikethrough, Effects::STRIKETHROUGH),
+        ]
+        .into_iter()
+        .filter(|(kind, _)| **kind == StyleKind::Place)
+        .fold(Effects::new(), |effects, (_, effect)| {
+            effects.insert(effect)
+        });
+        Self::new()
+            .fg_color(value.foreground.into())
+            .bg_color(value.background.into())
+            .effects(effects)
+    }
+}
+impl From<SGRString> for ::anstyle::Style {
+    fn from(value: SGRString) -> Self {
+        Self::from(&value)
+    }
+}
+impl From<::anstyle::Color> for ColorKind {
+    fn from(value: ::anstyle::Color) -> Self {
+        match value {
+            ::anstyle::Color::Ansi(color) => color.into(),
+            ::anstyle::Color::Ansi256(color) => color.into(),
+            ::anstyle::Color::Rgb(color) => color.into(),
+        }
+    }
+}
+impl From<AnsiColor> for ColorKind {
+    fn from(value: AnsiColor) -> Self {
+        match value {
+            AnsiColor::Black => Self::Black,
+            AnsiColor::Red => Self::Red,
+            AnsiColor::Green => Self::Green,
+            AnsiColor::Yellow => Self::Yellow,
+            AnsiColor::Blue => Self::Blue,
+            AnsiColor::Magenta => Self::Magenta,
+            AnsiColor::Cyan => Self::Cyan,
+            AnsiColor::White => Self::White,
+            bright => Self::Byte(Ansi256Color::from_ansi(bright).index()),
+        }
+    }
+}
+impl From<Ansi256Color> for ColorKind {
+    fn from(value: Ansi256Color) -> Self {
+        Self::Byte(value.0)
+    }
+}
+impl From<RgbColor> for ColorKind {
+    fn from(value: RgbColor) -> Self {
+        Self::Rgb(value.0, value.1, value.2)
+    }
+}
+impl From<ColorKind> for Option<::anstyle::Color> {
+    fn from(value: ColorKind) -> Self {
+        let ansi = |color: AnsiColor| Some(color.into());
+        match value {
+            ColorKind::None | ColorKind::Default => None,
+            ColorKind::Black => ansi(AnsiColor::Black),
+            ColorKind::Red => ansi(AnsiColor::Red),
+            ColorKind::Green => ansi(AnsiColor::Green),
+            ColorKind::Yellow => ansi(AnsiColor::Yellow),
+            ColorKind::Blue => ansi(AnsiColor::Blue),
+            ColorKind::Magenta => ansi(AnsiColor::Magenta),
+            ColorKind::Cyan => ansi(AnsiColor::Cyan),
+            ColorKind::White => ansi(AnsiColor::White),
+            ColorKind::Byte(n) => Some(Ansi256Color(n).into()),
+            ColorKind::Rgb(r, g, b) => Some(RgbColor(r, g, b).into()),
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! [`clap`](https://docs.rs/clap) help styles built from a [`Theme`]
+//!
+//! - [`Theme::to_clap_styles`] to [`Styles`]
+//! - [`Theme::from_clap_styles`] from [`Styles`]
+//!
+//! Each style slot of [`Styles`] is looked up by the entry of the same name,
+//! converted as described by the [`anstyle`](super::anstyle) conversions:
+//!
+//! | Slot          | Entries                    |
+//! |---------------|----------------------------|
+//! | `header`      | `"header"`                 |
+//! | `error`       | `"error"`                  |
+//! | `usage`       | `"usage"`, then `"header"` |
+//! | `literal`     | `"literal"`                |
+//! | `placeholder` | `"placeholder"`            |
+//! | `valid`       | `"valid"`                  |
+//! | `invalid`     | `"invalid"`, then `"warn"` |
+//!
+//! Slots without an entry keep the style of [`Styles::styled`]
+//!
+//! # Examples
+//!
+//!```rust
+//!use easy_sgr::{Color::*, EasySGR, Style::*, Theme};
+//!
+//!let theme = Theme::new()
+//!    .with("header", YellowFg.style(Bold))
+//!    .with("literal", CyanFg);
+//!let styles = theme.to_clap_styles();
+//!
+//!assert_eq!(styles.get_literal().render().to_string(), "\x1b[36m");
+//!assert_eq!(styles.get_usage(), styles.get_header());
+//!```
+use ::clap::builder::Styles;
+
+use crate::{SGRString, Theme};
+
+/// Sets a slot of [`Styles`], e.g. [`Styles::header`]
+type Setter = fn(Styles, ::anstyle::Style) -> Styles;
+
+/// The entries looked up for each slot, in order, along with the slot's setter
+const SLOTS: [(&[&str], Setter); 7] = [
+    (&["header"], Styles::header),
+    (&["error"], Styles::error),
+    (&["usage", "header"], Styles::usage),
+    (&["literal"], Styles::literal),
+    (&["placeholder"], Styles::placeholder),
+    (&["valid"], Styles::valid),
+    (&["invalid", "warn"], Styles::invalid),
+];
+
+impl Theme {
+    /// Returns the [`Styles`] of `clap`'s help & errors, see the [module](self) docs
+    #[must_use]
+    pub fn to_clap_styles(&self) -> Styles {
+        SLOTS.iter().fold(Styles::styled(), |styles, (names, set)| {
+            match names.iter().find_map(|name| self.get(name)) {
+                Some(style) => set(styles, style.into()),
+                None => styles,
+            }
+        })
+    }
+    /// Creates a theme with an entry for each slot of `styles`,
+    /// named as in the [module](self) docs
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use anstyle::{AnsiColor, Style};
+    ///use clap::builder::Styles;
+    ///use easy_sgr::{ColorKind, Theme};
+    ///
+    ///let styles = Styles::plain().error(Style::new().fg_color(Some(AnsiColor::Red.into())));
+    ///let theme = Theme::from_clap_styles(&styles);
+    ///assert_eq!(theme.get("error").unwrap().foreground, ColorKind::Red);
+    ///assert_eq!(theme.get("usage").unwrap().to_string(), "");
+    ///```
+    #[must_use]
+    pub fn from_clap_styles(styles: &Styles) -> Self {
+        [
+            ("header", styles.get_header()),
+            ("error", styles.get_error()),
+            ("usage", styles.get_usage()),
+            ("literal", styles.get_literal()),
+            ("placeholder", styles.get_placeholder()),
+            ("valid", styles.get_valid()),
+            ("invalid", styles.get_invalid()),
+        ]
+        .into_iter()
+        .fold(Self::new(), |theme, (name, style)| {
+            theme.with(name, SGRString::from(*style))
+        })
+    }
+}
@@ -0,0 +1,229 @@
+//! Conversions to & from [`crossterm`](https://docs.rs/crossterm) types
+//!
+//! - [`ContentStyle`] to & from [`SGRString`]
+//! - [`crossterm::style::Color`] to [`ColorKind`]
+//! - [`ColorKind`] to `Option<Color>`
+//! - [`Command`] for [`SGRString`], so it can be written through [`crossterm::queue!`]
+//!
+//! Colors map onto the ones `crossterm` writes the same codes for:
+//!
+//! - The dark named colors & [`Color::Grey`] become the named [`ColorKind`]s
+//! - The bright named colors & [`Color::DarkGrey`] become [`ColorKind::Byte`]s `8` to `15`,
+//!   which come back as those named colors
+//! - [`Color::Reset`] becomes [`ColorKind::Default`]
+//!
+//! Attributes turning a style off, such as [`Attribute::NoItalic`], become
+//! styles set to [`StyleKind::Clean`], [`Attribute::NormalIntensity`] cleaning both
+//! bold & dim text. [`Attribute::Reset`] maps to [`SGRString::reset`].
+//! [`Attribute::Fraktur`], [`Attribute::NoBold`], [`Attribute::Framed`],
+//! [`Attribute::Encircled`], [`Attribute::OverLined`], [`Attribute::NotFramedOrEncircled`]
+//! & [`Attribute::NotOverLined`] have no equivalent, so are kept as custom codes.
+//!
+//! The rest is dropped:
+//!
+//! - Double, curled, dotted & dashed underlines become plain underlines
+//! - Rapid blinking becomes blinking
+//! - Underline colors are dropped
+//! - The text, [`SGRString::clean`], custom cleans & custom codes
+//!   without an [`Attribute`] of a [`SGRString`] are dropped
+//!
+//! # Examples
+//!
+//!```rust
+//!use crossterm::style::{Attribute, Color, ContentStyle};
+//!use easy_sgr::SGRString;
+//!
+//!let mut style = ContentStyle::new();
+//!style.foreground_color = Some(Color::DarkRed);
+//!style.attributes.set(Attribute::Bold);
+//!
+//!let sgr = SGRString::from(style);
+//!assert_eq!(sgr.to_string(), "\x1b[31;1m");
+//!assert_eq!(ContentStyle::from(&sgr), style);
+//!
+//!let mut out = Vec::new();
+//!crossterm::queue!(out, sgr).unwrap();
+//!assert_eq!(out, b"\x1b[31;1m");
+//!```
+use std::fmt;
+
+use ::crossterm::{
+    style::{Attribute, Attributes, Color, ContentStyle},
+    Command,
+};
+
+use crate::{ColorKind, SGRString, StyleKind};
+
+/// Attributes kept as custom codes, by their code
+const CUSTOM: [(Attribute, u8); 7] = [
+    (Attribute::Fraktur, 20),
+    (Attribute::NoBold, 21),
+    (Attribute::Framed, 51),
+    (Attribute::Encircled, 52),
+    (Attribute::OverLined, 53),
+    (Attribute::NotFramedOrEncircled, 54),
+    (Attribute::NotOverLined, 55),
+];
+
+impl From<ContentStyle> for SGRString {
+    fn from(value: ContentStyle) -> Self {
+        let attributes = value.attributes;
+        let kind = |place: &[Attribute], clean: Attribute| {
+            if place.iter().any(|&attribute| attributes.has(attribute)) {
+                StyleKind::Place
+            } else if attributes.has(clean) {
+                StyleKind::Clean
+            } else {
+                StyleKind::None
+            }
+        };
+        Self {
+            foreground: value
+                .foreground_color
+                .map_or(ColorKind::None, ColorKind::from),
+            background: value
+                .background_color
+                .map_or(ColorKind::None, ColorKind::from),
+            reset: attributes.has(Attribute::Reset),
+            bold: kind(&[Attribute::Bold], Attribute::NormalIntensity),
+            dim: kind(&[Attribute::Dim], Attribute::NormalIntensity),
+            italic: kind(&[Attribute::Italic], Attribute::NoItalic),
+            underline: kind(
+                &[
+                    Attribute::Underlined,
+                    Attribute::DoubleUnderlined,
+                    Attribute::Undercurled,
+                    Attribute::Underdotted,
+                    Attribute::Underdashed,
+                ],
+                Attribute::NoUnderline,
+            ),
+            blinking: kind(
+                &[Attribute::SlowBlink, Attribute::RapidBlink],
+                Attribute::NoBlink,
+            ),
+            inverse: kind(&[Attribute::Reverse], Attribute::NoReverse),
+            hidden: kind(&[Attribute::Hidden], Attribute::NoHidden),
+            strikethrough: kind(&[Attribute::CrossedOut], Attribute::NotCrossedOut),
+            custom_places: CUSTOM
+                .iter()
+                .filter(|(attribute, _)| attributes.has(*attribute))
+                .map(|&(_, code)| code)
+                .collect(),
+            ..Default::default()
+        }
+    }
+}
+impl From<&SGRString> for ContentStyle {
+    fn from(value: &SGRString) -> Self {
+        let mut attributes = Attributes::none();
+        if value.reset {
+            attributes.set(Attribute::Reset);
+        }
+        for (kind, place, clean) in [
+            (&value.bold, Attribute::Bold, Attribute::NormalIntensity),
+            (&value.dim, Attribute::Dim, Attribute::NormalIntensity),
+            (&value.italic, Attribute::Italic, Attribute::NoItalic),
+            (
+                &value.underline,
+                Attribute::Underlined,
+                Attribute::NoUnderline,
+            ),
+            (&value.blinking, Attribute::SlowBlink, Attribute::NoBlink),
+            (&value.inverse, Attribute::Reverse, Attribute::NoReverse),
+            (&value.hidden, Attribute::Hidden, Attribute::NoHidden),
+            (
+                &value.strikethrough,
+                Attribute::CrossedOut,
+                Attribute::NotCrossedOut,
+            ),
+        ] {
+            match kind {
+                StyleKind::None => (),
+                StyleKind::Place => attributes.set(place),
+                StyleKind::Clean => attributes.set(clean),
+            }
+        }
+        for (attribute, code) in CUSTOM {
+            if value.custom_places.contains(&code) {
+                attributes.set(attribute);
+            }
+        }
+        Self {
+            foreground_color: value.foreground.into(),
+            background_color: value.background.into(),
+            underline_color: None,
+            attributes,
+        }
+    }
+}
+impl From<SGRString> for ContentStyle {
+    fn from(value: SGRString) -> Self {
+        Self::from(&value)
+    }
+}
+impl From<Color> for ColorKind {
+    fn from(value: Color) -> Self {
+        match value {
+            Color::Reset => Self::Default,
+            Color::Black => Self::Black,
+            Color::DarkRed => Self::Red,
+            Color::DarkGreen => Self::Green,
+            Color::DarkYellow => Self::Yellow,
+            Color::DarkBlue => Self::Blue,
+            Color::DarkMagenta => Self::Magenta,
+            Color::DarkCyan => Self::Cyan,
+            Color::Grey => Self::White,
+            Color::DarkGrey => Self::Byte(8),
+            Color::Red => Self::Byte(9),
+            Color::Green => Self::Byte(10),
+            Color::Yellow => Self::Byte(11),
+            Color::Blue => Self::Byte(12),
+            Color::Magenta => Self::Byte(13),
+            Color::Cyan => Self::Byte(14),
+            Color::White => Self::Byte(15),
+            Color::AnsiValue(n) => Self::Byte(n),
+            Color::Rgb { r, g, b } => Self::Rgb(r, g, b),
+        }
+    }
+}
+impl From<ColorKind> for Option<Color> {
+    fn from(value: ColorKind) -> Self {
+        Some(match value {
+            ColorKind::None => return None,
+            ColorKind::Default => Color::Reset,
+            ColorKind::Black => Color::Black,
+            ColorKind::Red => Color::DarkRed,
+            ColorKind::Green => Color::DarkGreen,
+            ColorKind::Yellow => Color::DarkYellow,
+            ColorKind::Blue => Color::DarkBlue,
+            ColorKind::Magenta => Color::DarkMagenta,
+            ColorKind::Cyan => Color::DarkCyan,
+            ColorKind::White => Color::Grey,
+            ColorKind::Byte(8) => Color::DarkGrey,
+            ColorKind::Byte(9) => Color::Red,
+            ColorKind::Byte(10) => Color::Green,
+            ColorKind::Byte(11) => Color::Yellow,
+            ColorKind::Byte(12) => Color::Blue,
+            ColorKind::Byte(13) => Color::Magenta,
+            ColorKind::Byte(14) => Color::Cyan,
+            ColorKind::Byte(15) => Color::White,
+            ColorKind::Byte(n) => Color::AnsiValue(n),
+            ColorKind::Rgb(r, g, b) => Color::Rgb { r, g, b },
+        })
+    }
+}
+/// Writes the [`SGRString`] as it is displayed, codes & text alike
+impl Command for SGRString {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        write!(f, "{self}")
+    }
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> std::io::Result<()> {
+        panic!("tried to execute SGRString command using WinAPI, use ANSI instead");
+    }
+    #[cfg(windows)]
+    fn is_ansi_code_supported(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,14 @@
+#[cfg(feature = "ansi-term")]
+pub mod ansi_term;
+#[cfg(feature = "anstyle")]
+pub mod anstyle;
+#[cfg(feature = "clap")]
+pub mod clap;
+#[cfg(feature = "crossterm")]
+pub mod crossterm;
+#[cfg(feature = "owo-colors")]
+pub mod owo_colors;
+#[cfg(feature = "ratatui")]
+pub mod ratatui;
+#[cfg(feature = "termcolor")]
+pub mod termcolor;
@@ -0,0 +1,184 @@
+//! Conversions to & from [`owo-colors`](https://docs.rs/owo-colors) types
+//!
+//! - [`owo_colors::Style`] to & from [`SGRString`]
+//! - [`Styled`] to [`SGRString`], keeping the styled text
+//! - [`DynColors`], [`AnsiColors`], [`XtermColors`] & [`CssColors`] to [`ColorKind`]
+//! - [`ColorKind`] to `Option<DynColors>`
+//!
+//! `owo-colors` keeps the parts of a [`Style`](owo_colors::Style) private,
+//! so they are read from the codes of its prefix.
+//! Only those codes are parsed, styled text is never rendered & read back.
+//!
+//! Conversions are exact for what both sides share, the rest is dropped:
+//!
+//! - Bright [`AnsiColors`] become [`ColorKind::Byte`]s `8` to `15`,
+//!   so come back as [`XtermColors`]
+//! - [`CssColors`] become [`ColorKind::Rgb`]s
+//! - Fast blinking becomes blinking
+//! - The text, [`SGRString::clean`], [`SGRString::reset`], custom codes &
+//!   styles set to [`StyleKind::Clean`] of a [`SGRString`] are dropped
+//! - [`ColorKind::None`] & [`ColorKind::Default`] both become `None`
+//!
+//! # Examples
+//!
+//!```rust
+//!use easy_sgr::SGRString;
+//!use owo_colors::{OwoColorize, Style};
+//!
+//!let style = Style::new().red().bold();
+//!let sgr = SGRString::from(style);
+//!assert_eq!(sgr.to_string(), "\x1b[31;1m");
+//!assert_eq!(Style::from(&sgr), style);
+//!
+//!let styled = SGRString::from("owo".style(style));
+//!assert_eq!(styled.to_string(), "owo".style(style).to_string());
+//!```
+use std::fmt::Display;
+
+use ::owo_colors::{AnsiColors, CssColors, DynColors, Effect, Styled, XtermColors};
+
+use crate::{
+    escape::{EscapeScanner, Part},
+    CleanKind, ColorKind, SGRString, SgrState, StyleKind,
+};
+
+impl From<::owo_colors::Style> for SGRString {
+    fn from(value: ::owo_colors::Style) -> Self {
+        let state = state(value);
+        let kind = |applied: bool| {
+            if applied {
+                StyleKind::Place
+            } else {
+                StyleKind::None
+            }
+        };
+        Self {
+            foreground: state.foreground,
+            background: state.background,
+            bold: kind(state.bold),
+            dim: kind(state.dim),
+            italic: kind(state.italic),
+            underline: kind(state.underline),
+            blinking: kind(state.blinking || state.rapid_blinking),
+            inverse: kind(state.inverse),
+            hidden: kind(state.hidden),
+            strikethrough: kind(state.strikethrough),
+            ..Default::default()
+        }
+    }
+}
+/// Keeps the text of `value`, cleaned by a reset just as `owo-colors` does
+impl<T: Display> From<Styled<T>> for SGRString {
+    fn from(value: Styled<T>) -> Self {
+        Self {
+            text: value.inner().to_string(),
+            clean: if value.style.is_plain() {
+                CleanKind::None
+            } else {
+                CleanKind::Reset
+            },
+            ..value.style.into()
+        }
+    }
+}
+impl From<&SGRString> for ::owo_colors::Style {
+    fn from(value: &SGRString) -> Self {
+        let mut style = Self::new();
+        if let Some(color) = Option::<DynColors>::from(value.foreground) {
+            style = style.color(color);
+        }
+        if let Some(color) = Option::<DynColors>::from(value.background) {
+            style = style.on_color(color);
+        }
+        [
+            (&value.bold, Effect::Bold),
+            (&value.dim, Effect::Dimmed),
+            (&value.italic, Effect::Italic),
+            (&value.underline, Effect::Underline),
+            (&value.blinking, Effect::Blink),
+            (&value.inverse, Effect::Reversed),
+            (&value.hidden, Effect::Hidden),
+            (&value.strikethrough, Effect::Strikethrough),
+        ]
+        .into_iter()
+        .filter(|(kind, _)| **kind == StyleKind::Place)
+        .fold(style, |style, (_, effect)| style.effect(effect))
+    }
+}
+impl From<SGRString> for ::owo_colors::Style {
+    fn from(value: SGRString) -> Self {
+        Self::from(&value)
+    }
+}
+impl From<DynColors> for ColorKind {
+    fn from(value: DynColors) -> Self {
+        match value {
+            DynColors::Ansi(color) => color.into(),
+            DynColors::Css(color) => color.into(),
+            DynColors::Xterm(color) => color.into(),
+            DynColors::Rgb(r, g, b) => Self::Rgb(r, g, b),
+        }
+    }
+}
+impl From<AnsiColors> for ColorKind {
+    fn from(value: AnsiColors) -> Self {
+        match value {
+            AnsiColors::Black => Self::Black,
+            AnsiColors::Red => Self::Red,
+            AnsiColors::Green => Self::Green,
+            AnsiColors::Yellow => Self::Yellow,
+            AnsiColors::Blue => Self::Blue,
+            AnsiColors::Magenta => Self::Magenta,
+            AnsiColors::Cyan => Self::Cyan,
+            AnsiColors::White => Self::White,
+            AnsiColors::Default => Self::Default,
+            AnsiColors::BrightBlack => Self::Byte(8),
+            AnsiColors::BrightRed => Self::Byte(9),
+            AnsiColors::BrightGreen => Self::Byte(10),
+            AnsiColors::BrightYellow => Self::Byte(11),
+            AnsiColors::BrightBlue => Self::Byte(12),
+            AnsiColors::BrightMagenta => Self::Byte(13),
+            AnsiColors::BrightCyan => Self::Byte(14),
+            AnsiColors::BrightWhite => Self::Byte(15),
+        }
+    }
+}
+impl From<XtermColors> for ColorKind {
+    fn from(value: XtermColors) -> Self {
+        Self::Byte(value.into())
+    }
+}
+/// Read from the codes `owo-colors` writes, as the values of [`CssColors`] are private
+impl From<CssColors> for ColorKind {
+    fn from(value: CssColors) -> Self {
+        state(::owo_colors::Style::new().color(value)).foreground
+    }
+}
+impl From<ColorKind> for Option<DynColors> {
+    fn from(value: ColorKind) -> Self {
+        let ansi = |color: AnsiColors| Some(DynColors::Ansi(color));
+        match value {
+            ColorKind::None | ColorKind::Default => None,
+            ColorKind::Black => ansi(AnsiColors::Black),
+            ColorKind::Red => ansi(AnsiColors::Red),
+            ColorKind::Green => ansi(AnsiColors::Green),
+            ColorKind::Yellow => ansi(AnsiColors::Yellow),
+            ColorKind::Blue => ansi(AnsiColors::Blue),
+            ColorKind::Magenta => ansi(AnsiColors::Magenta),
+            ColorKind::Cyan => ansi(AnsiColors::Cyan),
+            ColorKind::White => ansi(AnsiColors::White),
+            ColorKind::Byte(n) => Some(DynColors::Xterm(n.into())),
+            ColorKind::Rgb(r, g, b) => Some(DynColors::Rgb(r, g, b)),
+        }
+    }
+}
+/// Returns the state the prefix of `style` leaves a terminal in
+fn state(style: ::owo_colors::Style) -> SgrState {
+    let mut state = SgrState::new();
+    EscapeScanner::default().scan_parts(&style.prefix_formatter().to_string(), |part| {
+        if let Part::Sgr(codes) = part {
+            state.apply(codes);
+        }
+    });
+    state
+}
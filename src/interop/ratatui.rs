@@ -0,0 +1,258 @@
+//! Conversions to & from [`ratatui`](https://docs.rs/ratatui) types
+//!
+//! - [`ratatui::style::Style`] to & from [`SGRString`]
+//! - [`SgrState`] to [`ratatui::style::Style`]
+//! - [`ratatui::style::Color`] to [`ColorKind`]
+//! - [`ColorKind`] to `Option<Color>`
+//! - Text containing SGR sequences to [`Text`], through [`text_from_ansi`]
+//!
+//! Colors map onto the ones `ratatui` draws the same way:
+//!
+//! - The named colors & [`Color::Gray`] become the named [`ColorKind`]s
+//! - The light named colors, [`Color::DarkGray`] & [`Color::White`]
+//!   become [`ColorKind::Byte`]s `8` to `15`, which come back as those named colors
+//! - [`Color::Reset`] becomes [`ColorKind::Default`]
+//!
+//! Added modifiers become styles set to [`StyleKind::Place`],
+//! removed modifiers styles set to [`StyleKind::Clean`].
+//! [`SGRString::reset`] becomes [`Style::reset`], patched with the rest of the [`SGRString`].
+//!
+//! The rest is dropped:
+//!
+//! - Rapid blinking becomes blinking
+//! - Underline colors are dropped
+//! - The text, [`SGRString::clean`] & custom codes of a [`SGRString`] are dropped
+//!
+//! # Examples
+//!
+//!```rust
+//!use easy_sgr::SGRString;
+//!use ratatui::style::{Color, Modifier, Style};
+//!
+//!let style = Style::new().fg(Color::Red).add_modifier(Modifier::BOLD);
+//!let sgr = SGRString::from(style);
+//!assert_eq!(sgr.to_string(), "\x1b[31;1m");
+//!assert_eq!(Style::from(&sgr), style);
+//!```
+use std::borrow::Cow;
+
+use ::ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+};
+
+use crate::{
+    escape::{EscapeScanner, Part},
+    ColorKind, SGRString, SgrState, StyleKind,
+};
+
+impl From<Style> for SGRString {
+    fn from(value: Style) -> Self {
+        let kind = |modifier: Modifier| {
+            if value.add_modifier.intersects(modifier) {
+                StyleKind::Place
+            } else if value.sub_modifier.intersects(modifier) {
+                StyleKind::Clean
+            } else {
+                StyleKind::None
+            }
+        };
+        Self {
+            foreground: value.fg.map_or(ColorKind::None, ColorKind::from),
+            background: value.bg.map_or(ColorKind::None, ColorKind::from),
+            bold: kind(Modifier::BOLD),
+            dim: kind(Modifier::DIM),
+            italic: kind(Modifier::ITALIC),
+            underline: kind(Modifier::UNDERLINED),
+            blinking: kind(Modifier::SLOW_BLINK | Modifier::RAPID_BLINK),
+            inverse: kind(Modifier::REVERSED),
+            hidden: kind(Modifier::HIDDEN),
+            strikethrough: kind(Modifier::CROSSED_OUT),
+            ..Default::default()
+        }
+    }
+}
+impl From<&SGRString> for Style {
+    fn from(value: &SGRString) -> Self {
+        let mut style = if value.reset {
+            Self::reset()
+        } else {
+            Self::new()
+        };
+        if let Some(color) = Option::<Color>::from(value.foreground) {
+            style = style.fg(color);
+        }
+        if let Some(color) = Option::<Color>::from(value.background) {
+            style = style.bg(color);
+        }
+        for (kind, modifier) in [
+            (&value.bold, Modifier::BOLD),
+            (&value.dim, Modifier::DIM),
+            (&value.italic, Modifier::ITALIC),
+            (&value.underline, Modifier::UNDERLINED),
+            (&value.blinking, Modifier::SLOW_BLINK),
+            (&value.inverse, Modifier::REVERSED),
+            (&value.hidden, Modifier::HIDDEN),
+            (&value.strikethrough, Modifier::CROSSED_OUT),
+        ] {
+            match kind {
+                StyleKind::None => (),
+                StyleKind::Place => style = style.add_modifier(modifier),
+                StyleKind::Clean => style = style.remove_modifier(modifier),
+            }
+        }
+        style
+    }
+}
+impl From<SGRString> for Style {
+    fn from(value: SGRString) -> Self {
+        Self::from(&value)
+    }
+}
+/// Only adds modifiers, default colors are left unset
+impl From<&SgrState> for Style {
+    fn from(value: &SgrState) -> Self {
+        let mut style = Self::new();
+        style.fg = value.foreground.into();
+        style.bg = value.background.into();
+        for (applied, modifier) in [
+            (value.bold, Modifier::BOLD),
+            (value.dim, Modifier::DIM),
+            (value.italic, Modifier::ITALIC),
+            (value.underline, Modifier::UNDERLINED),
+            (value.blinking, Modifier::SLOW_BLINK),
+            (value.rapid_blinking, Modifier::RAPID_BLINK),
+            (value.inverse, Modifier::REVERSED),
+            (value.hidden, Modifier::HIDDEN),
+            (value.strikethrough, Modifier::CROSSED_OUT),
+        ] {
+            if applied {
+                style.add_modifier |= modifier;
+            }
+        }
+        style
+    }
+}
+impl From<Color> for ColorKind {
+    fn from(value: Color) -> Self {
+        match value {
+            Color::Reset => Self::Default,
+            Color::Black => Self::Black,
+            Color::Red => Self::Red,
+            Color::Green => Self::Green,
+            Color::Yellow => Self::Yellow,
+            Color::Blue => Self::Blue,
+            Color::Magenta => Self::Magenta,
+            Color::Cyan => Self::Cyan,
+            Color::Gray => Self::White,
+            Color::DarkGray => Self::Byte(8),
+            Color::LightRed => Self::Byte(9),
+            Color::LightGreen => Self::Byte(10),
+            Color::LightYellow => Self::Byte(11),
+            Color::LightBlue => Self::Byte(12),
+            Color::LightMagenta => Self::Byte(13),
+            Color::LightCyan => Self::Byte(14),
+            Color::White => Self::Byte(15),
+            Color::Indexed(n) => Self::Byte(n),
+            Color::Rgb(r, g, b) => Self::Rgb(r, g, b),
+        }
+    }
+}
+impl From<ColorKind> for Option<Color> {
+    fn from(value: ColorKind) -> Self {
+        Some(match value {
+            ColorKind::None => return None,
+            ColorKind::Default => Color::Reset,
+            ColorKind::Black => Color::Black,
+            ColorKind::Red => Color::Red,
+            ColorKind::Green => Color::Green,
+            ColorKind::Yellow => Color::Yellow,
+            ColorKind::Blue => Color::Blue,
+            ColorKind::Magenta => Color::Magenta,
+            ColorKind::Cyan => Color::Cyan,
+            ColorKind::White => Color::Gray,
+            ColorKind::Byte(8) => Color::DarkGray,
+            ColorKind::Byte(9) => Color::LightRed,
+            ColorKind::Byte(10) => Color::LightGreen,
+            ColorKind::Byte(11) => Color::LightYellow,
+            ColorKind::Byte(12) => Color::LightBlue,
+            ColorKind::Byte(13) => Color::LightMagenta,
+            ColorKind::Byte(14) => Color::LightCyan,
+            ColorKind::Byte(15) => Color::White,
+            ColorKind::Byte(n) => Color::Indexed(n),
+            ColorKind::Rgb(r, g, b) => Color::Rgb(r, g, b),
+        })
+    }
+}
+/// Turns text containing SGR sequences, such as captured output, into [`Text`]
+///
+/// Each span is styled by the state the sequences before it leave a terminal in,
+/// see [`SgrState`]. Other escape sequences are dropped.
+///
+/// Lines are split like [`str::lines`], a trailing `\r` being removed from each
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::interop::ratatui::text_from_ansi;
+///use ratatui::{style::{Color, Style}, text::{Line, Span}};
+///
+///let text = text_from_ansi("plain \x1b[31mred\x1b[0m\nnext");
+///assert_eq!(
+///    text.lines,
+///    [
+///        Line::from(vec![
+///            Span::raw("plain "),
+///            Span::styled("red", Style::new().fg(Color::Red)),
+///        ]),
+///        Line::raw("next"),
+///    ]
+///);
+///```
+#[must_use]
+pub fn text_from_ansi(ansi: &str) -> Text<'_> {
+    let mut text = Text::default();
+    let mut line = Line::default();
+    let mut state = SgrState::new();
+    EscapeScanner::default().scan_parts(ansi, |part| match part {
+        Part::Text(range) => {
+            let mut pieces = ansi[range].split('\n');
+            if let Some(piece) = pieces.next() {
+                push_piece(&mut line, piece, &state);
+            }
+            for piece in pieces {
+                text.lines.push(end_line(std::mem::take(&mut line)));
+                push_piece(&mut line, piece, &state);
+            }
+        }
+        Part::Sgr(codes) => state.apply(codes),
+        Part::Escape(_) => (),
+    });
+    if !line.spans.is_empty() {
+        text.lines.push(end_line(line));
+    }
+    text
+}
+/// Adds `piece` to the end of `line`, joining the last span when styled the same
+fn push_piece<'a>(line: &mut Line<'a>, piece: &'a str, state: &SgrState) {
+    if piece.is_empty() {
+        return;
+    }
+    let style = Style::from(state);
+    match line.spans.last_mut() {
+        Some(span) if span.style == style => span.content.to_mut().push_str(piece),
+        _ => line.spans.push(Span::styled(Cow::Borrowed(piece), style)),
+    }
+}
+/// Removes the `\r` of a `\r\n` line ending from `line`
+fn end_line(mut line: Line<'_>) -> Line<'_> {
+    if let Some(span) = line.spans.last_mut() {
+        if let Some(content) = span.content.strip_suffix('\r') {
+            span.content = Cow::Owned(content.to_owned());
+            if span.content.is_empty() {
+                line.spans.pop();
+            }
+        }
+    }
+    line
+}
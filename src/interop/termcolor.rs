@@ -0,0 +1,263 @@
+//! Conversions to & from [`termcolor`](https://docs.rs/termcolor) types
+//!
+//! - [`&ColorSpec`](ColorSpec) to [`SGRString`], & back through [`SGRString::to_color_spec`]
+//! - [`termcolor::Color`] to [`ColorKind`]
+//! - [`TermcolorCompat`], writing through an [`SGRWriter`] as a [`WriteColor`]
+//!
+//! Intense named colors become [`ColorKind::Byte`]s `8` to `15`,
+//! rendering the same `38;5;n` & `48;5;n` codes `termcolor` writes for them.
+//! [`ColorSpec::reset`] maps to [`SGRString::reset`].
+//!
+//! Blinking, inverse & hidden text, [`StyleKind::Clean`] styles, custom codes
+//! & the text of a [`SGRString`] have no equivalent in a [`ColorSpec`] & are dropped
+//!
+//! # Examples
+//!
+//!```rust
+//!use easy_sgr::SGRString;
+//!use termcolor::{Color, ColorSpec};
+//!
+//!let mut spec = ColorSpec::new();
+//!spec.set_fg(Some(Color::Red)).set_bold(true).set_reset(false);
+//!
+//!let sgr = SGRString::from(&spec);
+//!assert_eq!(sgr.to_string(), "\x1b[31;1m");
+//!assert_eq!(sgr.to_color_spec(), spec);
+//!```
+use std::io;
+
+use ::termcolor::{ColorSpec, HyperlinkSpec, WriteColor};
+
+use crate::{CapableWriter, ColorKind, SGRString, SGRWriter, StyleKind};
+
+/// An [`SGRWriter`] usable as a [`WriteColor`], for libraries only writing to one
+///
+/// [`WriteColor::set_color`] places the [`SGRString`] converted from the [`ColorSpec`],
+/// [`WriteColor::reset`] writes `\x1b[0m` & hyperlinks are written as by
+/// [`SGRWriter::open_hyperlink`]. Color is supported while the writer
+/// [`is_enabled`](SGRWriter::is_enabled), so wrappers such as
+/// [`StripWriter`](crate::StripWriter) are written through as usual
+///
+/// Bytes given to [`io::Write`] must be UTF-8,
+/// a char split between two writes being an error
+///
+/// # Examples
+///
+///```rust
+///use std::io::Write;
+///
+///use easy_sgr::{interop::termcolor::TermcolorCompat, SGRWriter};
+///use termcolor::{Color, ColorSpec, WriteColor};
+///
+///let mut compat = TermcolorCompat::new(SGRWriter::from(String::new()));
+///compat.set_color(ColorSpec::new().set_fg(Some(Color::Red)).set_bold(true))?;
+///write!(compat, "error")?;
+///compat.reset()?;
+///// specs reset by default, as with `termcolor`'s own writers
+///assert_eq!(compat.into_inner().internal(), "\x1b[0;31;1merror\x1b[0m");
+///# Ok::<(), std::io::Error>(())
+///```
+#[derive(Debug, Clone)]
+pub struct TermcolorCompat<W: CapableWriter> {
+    writer: SGRWriter<W>,
+}
+impl<W: CapableWriter> TermcolorCompat<W> {
+    /// Creates a [`WriteColor`] writing through `writer`
+    #[must_use]
+    pub const fn new(writer: SGRWriter<W>) -> Self {
+        Self { writer }
+    }
+    /// Returns the writer being written through
+    pub fn into_inner(self) -> SGRWriter<W> {
+        self.writer
+    }
+    /// Returns a reference to the writer being written through
+    #[must_use]
+    pub const fn get_ref(&self) -> &SGRWriter<W> {
+        &self.writer
+    }
+    /// Returns a mutable reference to the writer being written through
+    pub const fn get_mut(&mut self) -> &mut SGRWriter<W> {
+        &mut self.writer
+    }
+}
+impl<W: CapableWriter> From<SGRWriter<W>> for TermcolorCompat<W> {
+    fn from(writer: SGRWriter<W>) -> Self {
+        Self::new(writer)
+    }
+}
+impl<W: CapableWriter> io::Write for TermcolorCompat<W>
+where
+    W::Error: Send + Sync + 'static,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let s = match std::str::from_utf8(buf) {
+            Ok(s) => s,
+            // the valid start is written, leaving the rest to the next write
+            Err(e) if e.valid_up_to() > 0 => {
+                std::str::from_utf8(&buf[..e.valid_up_to()]).expect("checked to be valid")
+            }
+            Err(e) => return Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+        };
+        self.writer.write_inner(s).map_err(io::Error::other)?;
+        Ok(s.len())
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush().map_err(io::Error::other)
+    }
+}
+impl<W: CapableWriter> WriteColor for TermcolorCompat<W>
+where
+    W::Error: Send + Sync + 'static,
+{
+    fn supports_color(&self) -> bool {
+        self.writer.is_enabled()
+    }
+    fn supports_hyperlinks(&self) -> bool {
+        self.writer.is_enabled()
+    }
+    fn set_color(&mut self, spec: &ColorSpec) -> io::Result<()> {
+        self.writer
+            .place_sgr(&SGRString::from(spec))
+            .map_err(io::Error::other)
+    }
+    fn set_hyperlink(&mut self, link: &HyperlinkSpec<'_>) -> io::Result<()> {
+        match link.uri() {
+            Some(uri) => {
+                let uri = std::str::from_utf8(uri)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                self.writer.open_hyperlink(uri, None)
+            }
+            None => self.writer.close_hyperlink(),
+        }
+        .map_err(io::Error::other)
+    }
+    fn reset(&mut self) -> io::Result<()> {
+        self.writer.reset().map_err(io::Error::other)
+    }
+}
+
+impl From<&ColorSpec> for SGRString {
+    fn from(value: &ColorSpec) -> Self {
+        let color = |color: Option<&::termcolor::Color>| match color {
+            Some(&color) if value.intense() => intense(color),
+            Some(&color) => color.into(),
+            None => ColorKind::None,
+        };
+        let kind = |applied: bool| {
+            if applied {
+                StyleKind::Place
+            } else {
+                StyleKind::None
+            }
+        };
+        Self {
+            foreground: color(value.fg()),
+            background: color(value.bg()),
+            reset: value.reset(),
+            bold: kind(value.bold()),
+            dim: kind(value.dimmed()),
+            italic: kind(value.italic()),
+            underline: kind(value.underline()),
+            strikethrough: kind(value.strikethrough()),
+            ..Default::default()
+        }
+    }
+}
+impl From<::termcolor::Color> for ColorKind {
+    fn from(value: ::termcolor::Color) -> Self {
+        use ::termcolor::Color;
+        match value {
+            Color::Black => Self::Black,
+            Color::Blue => Self::Blue,
+            Color::Green => Self::Green,
+            Color::Red => Self::Red,
+            Color::Cyan => Self::Cyan,
+            Color::Magenta => Self::Magenta,
+            Color::Yellow => Self::Yellow,
+            Color::White => Self::White,
+            Color::Ansi256(n) => Self::Byte(n),
+            Color::Rgb(r, g, b) => Self::Rgb(r, g, b),
+            _ => Self::None,
+        }
+    }
+}
+impl SGRString {
+    /// Returns the [`ColorSpec`] writing the same codes as this
+    ///
+    /// [`ColorKind::Byte`]s `8` to `15` become intense named colors,
+    /// unless a named color that isn't intense is also used
+    ///
+    /// See the [`termcolor`](crate::interop::termcolor) module for what is dropped
+    #[must_use]
+    pub fn to_color_spec(&self) -> ColorSpec {
+        let named = |color: &ColorKind| {
+            matches!(
+                color,
+                ColorKind::Black
+                    | ColorKind::Red
+                    | ColorKind::Green
+                    | ColorKind::Yellow
+                    | ColorKind::Blue
+                    | ColorKind::Magenta
+                    | ColorKind::Cyan
+                    | ColorKind::White
+            )
+        };
+        let bright = |color: &ColorKind| matches!(color, ColorKind::Byte(8..=15));
+        let colors = [&self.foreground, &self.background];
+        let intense = colors.iter().any(|c| bright(c)) && !colors.iter().any(|c| named(c));
+
+        let color = |color: &ColorKind| {
+            use ::termcolor::Color;
+            Some(match *color {
+                ColorKind::None | ColorKind::Default => return None,
+                ColorKind::Black => Color::Black,
+                ColorKind::Red => Color::Red,
+                ColorKind::Green => Color::Green,
+                ColorKind::Yellow => Color::Yellow,
+                ColorKind::Blue => Color::Blue,
+                ColorKind::Magenta => Color::Magenta,
+                ColorKind::Cyan => Color::Cyan,
+                ColorKind::White => Color::White,
+                ColorKind::Byte(n @ 8..=15) if intense => [
+                    Color::Black,
+                    Color::Red,
+                    Color::Green,
+                    Color::Yellow,
+                    Color::Blue,
+                    Color::Magenta,
+                    Color::Cyan,
+                    Color::White,
+                ][usize::from(n - 8)],
+                ColorKind::Byte(n) => Color::Ansi256(n),
+                ColorKind::Rgb(r, g, b) => Color::Rgb(r, g, b),
+            })
+        };
+        let mut spec = ColorSpec::new();
+        spec.set_fg(color(&self.foreground))
+            .set_bg(color(&self.background))
+            .set_intense(intense)
+            .set_reset(self.reset)
+            .set_bold(self.bold == StyleKind::Place)
+            .set_dimmed(self.dim == StyleKind::Place)
+            .set_italic(self.italic == StyleKind::Place)
+            .set_underline(self.underline == StyleKind::Place)
+            .set_strikethrough(self.strikethrough == StyleKind::Place);
+        spec
+    }
+}
+/// Returns the bright counterpart of a named color
+fn intense(color: ::termcolor::Color) -> ColorKind {
+    match ColorKind::from(color) {
+        ColorKind::Black => ColorKind::Byte(8),
+        ColorKind::Red => ColorKind::Byte(9),
+        ColorKind::Green => ColorKind::Byte(10),
+        ColorKind::Yellow => ColorKind::Byte(11),
+        ColorKind::Blue => ColorKind::Byte(12),
+        ColorKind::Magenta => ColorKind::Byte(13),
+        ColorKind::Cyan => ColorKind::Byte(14),
+        ColorKind::White => ColorKind::Byte(15),
+        other => other,
+    }
+}
@@ -0,0 +1,292 @@
+use std::fmt::Write;
+
+use crate::{
+    escape::{EscapeScanner, Part},
+    wrappers::char_width,
+    SGRBuilder, SGRString, SgrState,
+};
+
+/// How [`kv_block`] lays out its pairs
+#[derive(Debug, Clone)]
+pub struct KvOptions {
+    /// Written after every key, styled by its own codes
+    ///
+    /// By default an unstyled `:`, an empty text writes no separator
+    pub separator: SGRString,
+    /// The spaces between the widest key's separator & its value
+    ///
+    /// By default `1`
+    pub gap: usize,
+}
+impl Default for KvOptions {
+    fn default() -> Self {
+        Self {
+            separator: SGRString::from(":"),
+            gap: 1,
+        }
+    }
+}
+/// Returns `pairs` laid out as a block, one pair per line,
+/// the values aligned in a column after the widest key
+///
+/// Keys are measured by their visible width, so styled keys & keys
+/// containing escapes align the same as plain ones.
+/// Lines of a multi-line value after the first are indented to the value column,
+/// each styled separately so the indent is never styled.
+///
+/// With the `width` feature characters are measured by the
+/// columns they take up, otherwise each visible character is 1 column.
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{layout::{kv_block, KvOptions}, EasySGR, Style::*, CleanKind};
+///
+///let pairs = [
+///    ("Name".into(), "easy-sgr".into()),
+///    (Bold.text("Version").clean(CleanKind::Reverse), "0.1.1".into()),
+///];
+///assert_eq!(
+///    kv_block(&pairs, &KvOptions::default()),
+///    "Name:    easy-sgr\n\x1b[1mVersion\x1b[22m: 0.1.1\n"
+///);
+///```
+#[must_use]
+pub fn kv_block(pairs: &[(SGRString, SGRString)], opts: &KvOptions) -> String {
+    let widths: Vec<usize> = pairs
+        .iter()
+        .map(|(key, _)| measure_width(&key.text))
+        .collect();
+    let key_column = widths.iter().copied().max().unwrap_or_default();
+    let value_column = key_column + measure_width(&opts.separator.text) + opts.gap;
+
+    let mut block = String::new();
+    for ((key, value), width) in pairs.iter().zip(widths) {
+        // writing to a `String` never fails
+        let _ = write!(block, "{key}");
+        if !opts.separator.text.is_empty() {
+            let _ = write!(block, "{}", opts.separator);
+        }
+        push_spaces(&mut block, key_column - width + opts.gap);
+        for (i, line) in value.text.split('\n').enumerate() {
+            if i != 0 {
+                block.push('\n');
+                push_spaces(&mut block, value_column);
+            }
+            let _ = write!(
+                block,
+                "{}",
+                SGRString {
+                    text: line.to_string(),
+                    ..value.clone()
+                }
+            );
+        }
+        block.push('\n');
+    }
+    block
+}
+/// Returns the columns taken up by the text of `s`, skipping escape sequences
+///
+/// With the `width` feature characters are measured by the
+/// columns they take up, wide characters taking up 2 & zero-width ones none.
+/// Otherwise each character that isn't a control character is 1 column.
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::measure_width;
+///
+///assert_eq!(measure_width("\x1b[1mbold\x1b[0m & plain"), 12);
+///assert_eq!(measure_width("\x1b]8;;https://example.com\x07link\x1b]8;;\x07"), 4);
+///```
+#[must_use]
+pub fn measure_width(s: &str) -> usize {
+    let mut width = 0;
+    EscapeScanner::default().scan(s, |text| {
+        width += text.chars().map(char_width).sum::<usize>();
+    });
+    width
+}
+/// Returns the columns taken up by the text of `s` up to `max`,
+/// along with the byte index where text past `max` starts
+///
+/// The index is that of the first character not fitting within `max` columns,
+/// or the length of `s` when it all fits, so escapes right before the cut are kept
+/// & `&s[..index]` is `s` truncated. See [`measure_width`] for how text is measured
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::measure_width_upto;
+///
+///let s = "\x1b[31mtoo long\x1b[0m";
+///let (width, index) = measure_width_upto(s, 3);
+///assert_eq!((width, &s[..index]), (3, "\x1b[31mtoo"));
+///assert_eq!(measure_width_upto(s, 20), (8, s.len()));
+///```
+#[must_use]
+pub fn measure_width_upto(s: &str, max: usize) -> (usize, usize) {
+    let (mut width, mut cut) = (0, None);
+    EscapeScanner::default().scan_parts(s, |part| match part {
+        Part::Text(range) if cut.is_none() => {
+            for (i, c) in s[range.clone()].char_indices() {
+                if width + char_width(c) > max {
+                    cut = Some(range.start + i);
+                    return;
+                }
+                width += char_width(c);
+            }
+        }
+        _ => (),
+    });
+    (width, cut.unwrap_or(s.len()))
+}
+/// Returns `input` wrapped at spaces so no line is wider than `width`,
+/// keeping the styles of foreign text containing escape sequences
+///
+/// Each line ends with its styles reset, the next line placing them again,
+/// so every line renders the same on its own. Newlines already in `input`
+/// are kept, along with the styles carried over them.
+/// Spaces at a break are dropped, & a word wider than `width` is split
+/// between characters. Escape sequences are never split,
+/// though one left unfinished at the end of `input` is dropped.
+///
+/// Text is measured as by [`measure_width`]
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::layout::rewrap_ansi;
+///
+///let output = "\x1b[1;31merror\x1b[0m: \x1b[1mcould not compile\x1b[0m";
+///assert_eq!(
+///    rewrap_ansi(output, 12),
+///    "\x1b[1;31merror\x1b[0m: \x1b[1mcould\x1b[0m\n\x1b[1mnot compile\x1b[0m"
+///);
+///```
+#[must_use]
+pub fn rewrap_ansi(input: &str, width: usize) -> String {
+    let tokens = tokens(input);
+    let mut wrap = Rewrap {
+        wrapped: String::new(),
+        state: SgrState::new(),
+        column: 0,
+        width,
+    };
+    let mut i = 0;
+    while i < tokens.len() {
+        // the spaces & escapes before the next word
+        let spaces_end = i + tokens[i..]
+            .iter()
+            .take_while(|token| !matches!(token, Token::Char(c) if *c != ' '))
+            .count();
+        let word_end = spaces_end
+            + tokens[spaces_end..]
+                .iter()
+                .take_while(|token| !matches!(token, Token::Char(' ' | '\n')))
+                .count();
+        let spaces_width = tokens[i..spaces_end]
+            .iter()
+            .filter(|token| matches!(token, Token::Char(' ')))
+            .count();
+        let word_width: usize = tokens[spaces_end..word_end]
+            .iter()
+            .map(|token| match token {
+                Token::Char(c) => char_width(*c),
+                Token::Escape(..) => 0,
+            })
+            .sum();
+        let spaces = &tokens[i..spaces_end];
+        if wrap.column + spaces_width + word_width > width && (wrap.column > 0 || word_width == 0) {
+            // spaces are dropped at a break, though their escapes still apply
+            for token in spaces {
+                if let Token::Escape(..) = token {
+                    wrap.push(token);
+                }
+            }
+            if word_width > 0 {
+                wrap.newline();
+            }
+        } else {
+            for token in spaces {
+                wrap.push(token);
+            }
+        }
+        for token in &tokens[spaces_end..word_end] {
+            wrap.push(token);
+        }
+        i = word_end;
+        if matches!(tokens.get(i), Some(Token::Char('\n'))) {
+            wrap.newline();
+            i += 1;
+        }
+    }
+    wrap.wrapped
+}
+/// A part of the text given to [`rewrap_ansi`]
+enum Token<'a> {
+    /// A character outside of escape sequences
+    Char(char),
+    /// An escape sequence, along with its codes when SGR
+    Escape(&'a str, Option<Vec<u8>>),
+}
+/// Splits `input` into its characters & escape sequences
+fn tokens(input: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut codes = None;
+    EscapeScanner::default().scan_parts(input, |part| match part {
+        Part::Text(range) => tokens.extend(input[range].chars().map(Token::Char)),
+        Part::Sgr(sgr) => codes = Some(sgr.to_vec()),
+        Part::Escape(range) => tokens.push(Token::Escape(&input[range], codes.take())),
+    });
+    tokens
+}
+/// The text being written by [`rewrap_ansi`]
+struct Rewrap {
+    wrapped: String,
+    /// The styles applied by what has been written
+    state: SgrState,
+    column: usize,
+    width: usize,
+}
+impl Rewrap {
+    /// Writes `token`, breaking the line before a character past the width
+    fn push(&mut self, token: &Token<'_>) {
+        match token {
+            Token::Char(c) => {
+                let width = char_width(*c);
+                if self.column > 0 && self.column + width > self.width {
+                    self.newline();
+                }
+                self.wrapped.push(*c);
+                self.column += width;
+            }
+            Token::Escape(seq, codes) => {
+                if let Some(codes) = codes {
+                    self.state.apply(codes);
+                }
+                self.wrapped.push_str(seq);
+            }
+        }
+    }
+    /// Ends the line, resetting the styles before it & placing them again after
+    fn newline(&mut self) {
+        let styled = !self.state.is_default();
+        if styled {
+            self.wrapped.push_str("\x1b[0m");
+        }
+        self.wrapped.push('\n');
+        if styled {
+            let mut builder = SGRBuilder::default();
+            self.state.place(&mut builder);
+            // writing to a `String` never fails
+            let _ = builder.write_to(&mut self.wrapped);
+        }
+        self.column = 0;
+    }
+}
+/// Pushes `n` spaces onto `s`
+fn push_spaces(s: &mut String, n: usize) {
+    s.extend(std::iter::repeat_n(' ', n));
+}
@@ -32,9 +32,12 @@
 //! And its usage is very simple:
 //!
 //! ```rust
+//! # #[cfg(feature = "macros")]
+//! # {
 //! use easy_sgr::println;
 //!
 //! println!("{[italic red]}This should be italic & red!{[]}");
+//! # }
 //! ```
 //!
 //! `{[]}` is interpreted as a reset here.
@@ -70,7 +73,7 @@
 //! ### `EasySGR` trait
 //!
 //! This is similar to the method above but uses the `EasySGR` trait.
-//! This trait is implemented by anything that implements Into\<AnsiString\> including Style and Color.
+//! This trait is implemented by anything that implements `Into<SGRString>` including Style and Color.
 //! Its main purpose is to provide functions for chaining [`SGR`][SGR] codes.
 //!
 //! The example above can be achieved using it as such:
@@ -205,6 +208,65 @@
 //!
 //! [SGR]: https://en.wikipedia.org/wiki/ANSI_escape_code#SGR
 //!
+//! ## Breaking changes since `0.1.1`
+//!
+//! - `DiscreteSGR` no longer has `Display` & `EasySGR` as supertraits,
+//!   so that tuples of any `DiscreteSGR` implement it
+//!     - The crate's own `DiscreteSGR` types still implement both
+//!     - Generic code should add the bounds it needs,
+//!       or display through `DiscreteSGR::params`
+//! - `SGRWriter` no longer converts `From` any `io::Write` or `fmt::Write`
+//!     - `From` takes a `CapableWriter`, such as a `String` or `Vec<u8>`,
+//!       or one of the common `io::Write` types like `Stdout`, `File` & `BufWriter`
+//!     - Other writers are wrapped by `SGRWriter::io` or `SGRWriter::fmt`
+//! - `EasyWrite` is no longer implemented for every `DiscreteSGR`,
+//!   the blanket implementation kept a `DiscreteSGR` type from having an `EasyWrite` of its own
+//!     - This crate's `DiscreteSGR` types & tuples of them still implement it
+//!     - Other crates' `DiscreteSGR` types implement it by writing their codes,
+//!       `fn sgr(&self, builder: &mut SGRBuilder) { self.write(builder) }`
+//! - `Color` & `Style` are `#[non_exhaustive]`, so matching on them needs a wildcard arm
+//!     - Their codes are given by `Style::code` & `Color::codes`,
+//!       rather than matching each variant
+//! - `DiscreteSGR::write` takes a `&mut impl CodeSink` rather than a `&mut SGRBuilder`,
+//!   so it can also write to a fixed-capacity `ArrayBuilder`
+//!     - Implementations only need the new signature,
+//!       as `CodeSink` has the methods `SGRBuilder` had for writing codes
+//!
+//! An implementation of `DiscreteSGR` up to `0.1.1`:
+//!
+//! ```rust,compile_fail
+//! use easy_sgr::{DiscreteSGR, SGRBuilder};
+//!
+//! struct Emphasis;
+//! impl DiscreteSGR for Emphasis {
+//!     fn write(&self, builder: &mut SGRBuilder) {
+//!         builder.write_codes(&[1, 3]);
+//!     }
+//! }
+//! ```
+//!
+//! And the same implementation now:
+//!
+//! ```rust
+//! use easy_sgr::{CodeSink, DiscreteSGR};
+//!
+//! struct Emphasis;
+//! impl DiscreteSGR for Emphasis {
+//!     fn write(&self, builder: &mut impl CodeSink) {
+//!         builder.write_codes(&[1, 3]);
+//!     }
+//! }
+//! assert_eq!(Emphasis.params().to_string(), "1;3");
+//! ```
+//!
+//! ## Deprecated since `0.1.1`
+//!
+//! - `SGRWriter.writer`, in favour of `SGRWriter::get_ref`, `SGRWriter::get_mut`
+//!   & `SGRWriter::into_inner`
+//! - The public fields holding inner writers,
+//!   in favour of `get_ref`, `get_mut` & `into_inner`
+//!     - `IoWriter.0`, `FmtWriter.0`, `Tee.first` & `Tee.second`
+//!
 //! ## TODO for `1.0.0` release
 //!
 //! - [ ] Add examples to docs
@@ -216,7 +278,8 @@
 //!     - [ ] Add parsing from ansi codes
 //!     - [ ] Add parsing for `SGRString`
 //! - [ ] `EasySGR` implementation that doesn't allocate an `SGRString`
-#![forbid(unsafe_code)]
+#![cfg_attr(not(windows), forbid(unsafe_code))]
+#![cfg_attr(windows, deny(unsafe_code))]
 #![deny(
     clippy::all,
     clippy::pedantic,
@@ -228,23 +291,139 @@
 )]
 #![warn(missing_debug_implementations)]
 #![allow(clippy::enum_glob_use)]
+/// Appending values to strings without allocating, see [`append::AppendToString`]
+#[cfg(not(feature = "macro-only"))]
+pub mod append;
+/// Prebuilt SGR sequences, for hot paths & const contexts
+///
+/// Each constant is a complete sequence, the same as written for its keyword
+/// & its [`Style`] or [`Color`] variant, without any formatting at runtime.
+/// Indexed & RGB colors are built at compile time by [`consts::fg_256`],
+/// [`consts::bg_256`], [`consts::fg_rgb`] & [`consts::bg_rgb`].
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::consts::{fg_256, BOLD, RED_FG, RESET};
+///
+///const WARNING: &str = fg_256::<208>();
+///assert_eq!(format!("{BOLD}{RED_FG}error{RESET}"), "\x1b[1m\x1b[31merror\x1b[0m");
+///assert_eq!(WARNING, "\x1b[38;5;208m");
+///```
+#[cfg(not(feature = "macro-only"))]
+pub mod consts;
+/// A thread-local default style for rendering, see [`context::with_default_style`]
+#[cfg(not(feature = "macro-only"))]
+pub mod context;
+/// Exporting styles as CSS, for showing styled text on the web
+#[cfg(not(feature = "macro-only"))]
+pub mod css;
+/// Detection of what a terminal is able to display
+#[cfg(not(feature = "macro-only"))]
+pub mod detect;
+/// Highlighting the differences between two strings, see [`diff::inline_diff`]
+#[cfg(not(feature = "macro-only"))]
+pub mod diff;
 /// Implements SGR types that can be used standalone of a [`SGRString`]
 ///
 /// These types exist outside the context of a [`SGRString`], but
 /// can be used in conjunction of one through the use of [`EasySGR`]
 #[cfg(not(feature = "macro-only"))]
 pub mod discrete;
+/// An [`env_logger`](https://docs.rs/env_logger) format styling records with a [`Theme`]
+#[cfg(all(feature = "env-logger", not(feature = "macro-only")))]
+pub mod env_logger;
+/// A single error type for code mixing writers with differing errors
+#[cfg(not(feature = "macro-only"))]
+pub mod error;
 /// Contains the standard SGR implementations.
 ///
 /// Makes use of the [`writers`](writing) to write `SGR` codes to a writer
 #[cfg(not(feature = "macro-only"))]
 pub mod graphics;
+/// Highlighting parts of a string, see [`highlight::highlight_ranges`]
+#[cfg(not(feature = "macro-only"))]
+pub mod highlight;
+/// Converting text with embedded escape sequences to HTML, see [`html::ansi_to_html`]
+#[cfg(not(feature = "macro-only"))]
+pub mod html;
+/// Conversions to & from the types of other crates, each behind a feature
+#[cfg(not(feature = "macro-only"))]
+pub mod interop;
+/// Laying out styled text by its visible width, see [`layout::kv_block`] & [`measure_width`]
+#[cfg(not(feature = "macro-only"))]
+pub mod layout;
+/// A [`log`](https://docs.rs/log) implementation styling records by their level
+#[cfg(all(feature = "log", not(feature = "macro-only")))]
+pub mod log;
+/// A stable representation of styles, for sending across process boundaries
+#[cfg(not(feature = "macro-only"))]
+pub mod portable;
+/// Printing styled text without constructing a writer
+#[cfg(not(feature = "macro-only"))]
+pub mod print;
+/// Functions giving text a single color or style, for one-liners
+///
+/// Each function returns an [`SGRString`], so they nest & chain with [`EasySGR`]'s methods.
+/// Nesting merges the two: calling `bold(red("x"))` is the same as `"x".color(RedFg).style(Bold)`,
+/// so should both set the same field, the outer call wins.
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{quick::*, Color::*, EasySGR};
+///
+///assert_eq!(bold(red("error")).to_string(), "\x1b[31;1merror");
+///assert_eq!(red(green("red")).to_string(), "\x1b[31mred");
+///assert_eq!(underline("link").color(BlueBg).to_string(), "\x1b[44;4mlink");
+///```
+#[cfg(not(feature = "macro-only"))]
+pub mod quick;
+/// Restoring the terminal when panicking, see [`install_panic_restore`]
+#[cfg(not(feature = "macro-only"))]
+pub mod restore;
+/// Tracking the graphical state SGR codes leave a terminal in
+#[cfg(not(feature = "macro-only"))]
+pub mod state;
+/// Writers & assertions for what was written in tests
+#[cfg(all(feature = "test-util", not(feature = "macro-only")))]
+pub mod test;
+/// Named styles for configuring the styling of an application
+#[cfg(not(feature = "macro-only"))]
+pub mod theme;
+/// A [`tracing-subscriber`](https://docs.rs/tracing-subscriber) formatter styling events
+#[cfg(all(feature = "tracing", not(feature = "macro-only")))]
+pub mod tracing;
+/// [`ufmt`](https://docs.rs/ufmt) formatting & writers, for targets avoiding `core::fmt`
+#[cfg(all(feature = "ufmt", not(feature = "macro-only")))]
+pub mod ufmt;
+/// Helpers for getting Windows consoles to interpret `SGR` codes
+#[cfg(not(feature = "macro-only"))]
+pub mod windows;
+/// Writers wrapping other [`CapableWriter`]s to add to what they do
+#[cfg(not(feature = "macro-only"))]
+pub mod wrappers;
 /// Contains various structs and traits to help in writing `SGR` codes
 #[cfg(not(feature = "macro-only"))]
 pub mod writing;
 
 #[cfg(not(feature = "macro-only"))]
-pub use self::{discrete::*, graphics::*, writing::*};
+pub use self::{
+    detect::*,
+    discrete::*,
+    error::*,
+    graphics::*,
+    layout::{measure_width, measure_width_upto},
+    print::*,
+    restore::{install_panic_restore, install_panic_restore_with, PanicRestore, RestoreOptions},
+    state::*,
+    theme::*,
+    wrappers::*,
+    writing::*,
+};
 
 #[cfg(feature = "macros")]
 pub use easy_sgr_macros::*;
+
+#[cfg(not(feature = "macro-only"))]
+mod escape;
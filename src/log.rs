@@ -0,0 +1,230 @@
+use std::{
+    fmt::{self, Debug},
+    io,
+    sync::Mutex,
+};
+
+use ::log::{LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+use crate::{
+    theme::write_styled, CleanKind, Color::*, EasySGR, IoWriter, SGRString, SGRWriter, Style::*,
+    Theme,
+};
+
+/// Lays out the text of a record before it is styled
+pub type Format = dyn Fn(&mut dyn fmt::Write, &Record<'_>) -> fmt::Result + Send + Sync;
+
+/// A [`Log`] implementation that styles records by their level
+///
+/// The style of a record is looked up in its [`Theme`] under the lowercase
+/// name of its level, i.e. `"error"`, `"warn"`, `"info"`, `"debug"` & `"trace"`.
+/// Records without a matching entry are written plainly.
+///
+/// Each line of a record is styled separately, so multi-line
+/// messages don't leave the terminal styled between lines.
+/// A line is ended by the clean of its style, or a reset when it has none.
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{log::StyledLogger, SGRWriter};
+///use log::{Level, Log, Record};
+///
+///let logger = StyledLogger::new(SGRWriter::from(String::new()));
+///logger.log(
+///    &Record::builder()
+///        .level(Level::Warn)
+///        .target("app")
+///        .args(format_args!("careful"))
+///        .build(),
+///);
+///assert_eq!(
+///    logger.into_writer().internal(),
+///    "\x1b[33mWARN  app: careful\x1b[39m\n"
+///);
+///```
+pub struct StyledLogger<W: crate::CapableWriter = IoWriter<io::Stderr>> {
+    writer: Mutex<SGRWriter<W>>,
+    theme: Theme,
+    level: LevelFilter,
+    color: bool,
+    format: Box<Format>,
+}
+impl StyledLogger {
+    /// Creates a logger over [`SGRWriter::stderr`]
+    ///
+    /// Colors are used under the same conditions as [`SGRWriter::stderr`]
+    #[must_use]
+    pub fn stderr() -> Self {
+        Self::new(SGRWriter::stderr())
+    }
+}
+impl<W: crate::CapableWriter> StyledLogger<W> {
+    /// Creates a logger over `writer`
+    ///
+    /// Uses [`default_theme`], logs all levels & always colors
+    #[must_use]
+    pub fn new(writer: SGRWriter<W>) -> Self {
+        Self {
+            writer: Mutex::new(writer),
+            theme: default_theme(),
+            level: LevelFilter::Trace,
+            color: true,
+            format: Box::new(default_format),
+        }
+    }
+    /// Sets the theme records are styled with
+    #[must_use]
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+    /// Sets the most verbose level to be logged
+    #[must_use]
+    pub const fn level(mut self, level: LevelFilter) -> Self {
+        self.level = level;
+        self
+    }
+    /// Sets whether records are styled
+    #[must_use]
+    pub const fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+    /// Sets the layout of records
+    ///
+    /// By default records are written as `LEVEL target: message`
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{log::StyledLogger, SGRWriter};
+    ///
+    ///let logger = StyledLogger::new(SGRWriter::from(String::new()))
+    ///    .format(|f, record| write!(f, "[{}] {}", record.level(), record.args()));
+    ///```
+    #[must_use]
+    pub fn format(
+        mut self,
+        format: impl Fn(&mut dyn fmt::Write, &Record<'_>) -> fmt::Result + Send + Sync + 'static,
+    ) -> Self {
+        self.format = Box::new(format);
+        self
+    }
+    /// Returns the writer logged to
+    ///
+    /// # Panics
+    ///
+    /// Panics if the writer's lock was poisoned
+    #[must_use]
+    pub fn into_writer(self) -> SGRWriter<W> {
+        self.writer.into_inner().expect("Logger lock was poisoned")
+    }
+    /// Writes each line of `text` styled with `style`,
+    /// an empty `text` still being written as an empty line
+    fn write_lines(
+        &self,
+        writer: &mut SGRWriter<W>,
+        text: &str,
+        style: Option<&SGRString>,
+    ) -> Result<(), W::Error> {
+        if text.is_empty() {
+            return writer.write_inner("\n");
+        }
+        for line in text.lines() {
+            let style = style.filter(|_| self.color && !line.is_empty());
+            write_styled(writer, style, line)?;
+            writer.write_inner("\n")?;
+        }
+        Ok(())
+    }
+}
+impl<W: crate::CapableWriter + Send> Log for StyledLogger<W> {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.level() <= self.level
+    }
+    fn log(&self, record: &Record<'_>) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let mut text = String::new();
+        if (self.format)(&mut text, record).is_err() {
+            return;
+        }
+        let style = self.theme.get(level_name(record.level()));
+        if let Ok(mut writer) = self.writer.lock() {
+            // there is nowhere to report a failed write to
+            let _ = self.write_lines(&mut writer, &text, style);
+        }
+    }
+    fn flush(&self) {
+        if let Ok(mut writer) = self.writer.lock() {
+            // there is nowhere to report a failed flush to
+            let _ = writer.flush();
+        }
+    }
+}
+impl<W: crate::CapableWriter> Debug for StyledLogger<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StyledLogger")
+            .field("theme", &self.theme)
+            .field("level", &self.level)
+            .field("color", &self.color)
+            .finish_non_exhaustive()
+    }
+}
+/// Returns the theme used by default
+///
+/// Errors are bold red, warnings yellow, info green,
+/// debug blue & trace dimmed
+#[must_use]
+pub fn default_theme() -> Theme {
+    Theme::new()
+        .with("error", RedFg.style(Bold).clean(CleanKind::Reverse))
+        .with("warn", YellowFg.clean(CleanKind::Reverse))
+        .with("info", GreenFg.clean(CleanKind::Reverse))
+        .with("debug", BlueFg.clean(CleanKind::Reverse))
+        .with("trace", Dim.clean(CleanKind::Reverse))
+}
+/// Installs a [`StyledLogger::stderr`] using the [`default_theme`],
+/// logging records at [`LevelFilter::Info`] & above
+///
+/// # Errors
+///
+/// Returns an error if a logger has already been set
+pub fn init() -> Result<(), SetLoggerError> {
+    init_with(default_theme(), LevelFilter::Info)
+}
+/// Installs a [`StyledLogger::stderr`] using `theme`,
+/// logging records at `level_filter` & above
+///
+/// # Errors
+///
+/// Returns an error if a logger has already been set
+pub fn init_with(theme: Theme, level_filter: LevelFilter) -> Result<(), SetLoggerError> {
+    ::log::set_boxed_logger(Box::new(
+        StyledLogger::stderr().theme(theme).level(level_filter),
+    ))?;
+    ::log::set_max_level(level_filter);
+    Ok(())
+}
+/// The default layout of a record
+fn default_format(f: &mut dyn fmt::Write, record: &Record<'_>) -> fmt::Result {
+    write!(
+        f,
+        "{:<5} {}: {}",
+        record.level(),
+        record.target(),
+        record.args()
+    )
+}
+/// Returns the name a level's style is found under
+const fn level_name(level: ::log::Level) -> &'static str {
+    match level {
+        ::log::Level::Error => "error",
+        ::log::Level::Warn => "warn",
+        ::log::Level::Info => "info",
+        ::log::Level::Debug => "debug",
+        ::log::Level::Trace => "trace",
+    }
+}
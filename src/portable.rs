@@ -0,0 +1,368 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{state::extended_color, ColorKind, SGRString, SgrState, StyleKind};
+
+/// A style in a stable form, for sending across process boundaries
+///
+/// Unlike SGR sequences, this says what a style is rather than how it's written,
+/// so can be read without parsing escapes.
+///
+/// # Wire format
+///
+/// With the `serde` feature, this serializes to & from an object:
+///
+///```json
+///{
+///  "version": 1,
+///  "foreground": { "type": "red" },
+///  "background": { "type": "indexed", "index": 208 },
+///  "underline_color": { "type": "rgb", "r": 1, "g": 2, "b": 3 },
+///  "attributes": { "bold": true, "underline": true }
+///}
+///```
+///
+/// Colors are tagged by `type`, one of `default`, the eight named colors
+/// (`black` to `white`), `indexed` or `rgb`.
+/// Missing colors are unset, missing attributes are off.
+///
+/// # Compatibility
+///
+/// - Fields & color types are only ever added, never removed, renamed or changed in meaning
+/// - Readers ignore fields they don't know, so payloads from newer writers can be read,
+///   losing only what was added
+/// - New fields are optional, so payloads from older writers can be read
+/// - [`PortableStyle::VERSION`] is only raised by changes older readers can't
+///   read correctly, such as new color types, which `PortableStyle::from_json` refuses
+///
+/// # Conversions
+///
+/// Only placed styles of a [`SGRString`] are kept,
+/// its text, clean, reset & other custom codes being dropped.
+/// The underline color is kept as custom codes, being `58` or `59` followed by
+/// their arguments, named underline colors coming back as [`PortableColor::Indexed`].
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{portable::{PortableColor, PortableStyle}, Color::*, EasySGR, Style::*};
+///
+///let style = PortableStyle::from(&RedFg.style(Bold));
+///assert_eq!(style.foreground, Some(PortableColor::Red));
+///assert!(style.attributes.bold);
+///assert_eq!(easy_sgr::SGRString::from(style).to_string(), "\x1b[31;1m");
+///```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
+pub struct PortableStyle {
+    /// The version of the format this was written in, see [`PortableStyle::VERSION`]
+    pub version: u32,
+    /// The color of the foreground, unset when `None`
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub foreground: Option<PortableColor>,
+    /// The color of the background, unset when `None`
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub background: Option<PortableColor>,
+    /// The color of underlines, unset when `None`
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub underline_color: Option<PortableColor>,
+    /// The styles applied to text
+    pub attributes: PortableAttributes,
+}
+impl PortableStyle {
+    /// The version of the format written by this crate
+    pub const VERSION: u32 = 1;
+    /// Creates a style where nothing is set
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            version: Self::VERSION,
+            foreground: None,
+            background: None,
+            underline_color: None,
+            attributes: PortableAttributes::new(),
+        }
+    }
+    /// Serializes this as JSON
+    ///
+    /// # Errors
+    ///
+    /// Never, returning a result only as [`serde_json::to_string`] does
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+    /// Deserializes a style from JSON
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `json` isn't a style,
+    /// or was written in a version greater than [`PortableStyle::VERSION`]
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::portable::{PortableColor, PortableStyle};
+    ///
+    ///let style = PortableStyle::from_json(r#"{"version":1,"foreground":{"type":"cyan"}}"#).unwrap();
+    ///assert_eq!(style.foreground, Some(PortableColor::Cyan));
+    ///assert!(PortableStyle::from_json(r#"{"version":2}"#).is_err());
+    ///```
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        let style: Self = serde_json::from_str(json)?;
+        if style.version > Self::VERSION {
+            return Err(serde::de::Error::custom(format_args!(
+                "unsupported version {}, expected at most {}",
+                style.version,
+                Self::VERSION
+            )));
+        }
+        Ok(style)
+    }
+}
+impl Default for PortableStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+/// The styles of a [`PortableStyle`], each being applied when `true`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
+#[allow(clippy::struct_excessive_bools)]
+pub struct PortableAttributes {
+    /// Whether text is bold
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
+    pub bold: bool,
+    /// Whether text is dimmed
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
+    pub dim: bool,
+    /// Whether text is italic
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
+    pub italic: bool,
+    /// Whether text is underlined
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
+    pub underline: bool,
+    /// Whether text is blinking
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
+    pub blinking: bool,
+    /// Whether the foreground & background are swapped
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
+    pub inverse: bool,
+    /// Whether text is hidden
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
+    pub hidden: bool,
+    /// Whether text is struck through
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "is_false"))]
+    pub strikethrough: bool,
+}
+impl PortableAttributes {
+    /// Creates attributes where nothing is applied
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            bold: false,
+            dim: false,
+            italic: false,
+            underline: false,
+            blinking: false,
+            inverse: false,
+            hidden: false,
+            strikethrough: false,
+        }
+    }
+}
+/// A color of a [`PortableStyle`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(tag = "type", rename_all = "lowercase")
+)]
+pub enum PortableColor {
+    /// The terminal's default color
+    Default,
+    /// Black
+    Black,
+    /// Red
+    Red,
+    /// Green
+    Green,
+    /// Yellow
+    Yellow,
+    /// Blue
+    Blue,
+    /// Magenta
+    Magenta,
+    /// Cyan
+    Cyan,
+    /// White
+    White,
+    /// One of the 256 indexed colors
+    Indexed {
+        /// The index of the color
+        index: u8,
+    },
+    /// A 24-bit color
+    Rgb {
+        /// The red channel
+        r: u8,
+        /// The green channel
+        g: u8,
+        /// The blue channel
+        b: u8,
+    },
+}
+impl From<&SGRString> for PortableStyle {
+    fn from(value: &SGRString) -> Self {
+        let placed = |kind: &StyleKind| *kind == StyleKind::Place;
+        Self {
+            foreground: value.foreground.into(),
+            background: value.background.into(),
+            underline_color: underline_color(&value.custom_places),
+            attributes: PortableAttributes {
+                bold: placed(&value.bold),
+                dim: placed(&value.dim),
+                italic: placed(&value.italic),
+                underline: placed(&value.underline),
+                blinking: placed(&value.blinking),
+                inverse: placed(&value.inverse),
+                hidden: placed(&value.hidden),
+                strikethrough: placed(&value.strikethrough),
+            },
+            ..Self::new()
+        }
+    }
+}
+impl From<SGRString> for PortableStyle {
+    fn from(value: SGRString) -> Self {
+        Self::from(&value)
+    }
+}
+impl From<&SgrState> for PortableStyle {
+    fn from(value: &SgrState) -> Self {
+        Self {
+            foreground: value.foreground.into(),
+            background: value.background.into(),
+            attributes: PortableAttributes {
+                bold: value.bold,
+                dim: value.dim,
+                italic: value.italic,
+                underline: value.underline,
+                blinking: value.blinking || value.rapid_blinking,
+                inverse: value.inverse,
+                hidden: value.hidden,
+                strikethrough: value.strikethrough,
+            },
+            ..Self::new()
+        }
+    }
+}
+impl From<&PortableStyle> for SGRString {
+    fn from(value: &PortableStyle) -> Self {
+        let kind = |applied: bool| {
+            if applied {
+                StyleKind::Place
+            } else {
+                StyleKind::None
+            }
+        };
+        let attributes = &value.attributes;
+        let custom_places = match value.underline_color {
+            None => Vec::new(),
+            Some(PortableColor::Default) => vec![59],
+            Some(PortableColor::Rgb { r, g, b }) => vec![58, 2, r, g, b],
+            Some(color) => match ColorKind::from(color) {
+                ColorKind::Byte(n) => vec![58, 5, n],
+                named => vec![58, 5, named_index(named)],
+            },
+        };
+        Self {
+            foreground: value.foreground.map_or(ColorKind::None, ColorKind::from),
+            background: value.background.map_or(ColorKind::None, ColorKind::from),
+            bold: kind(attributes.bold),
+            dim: kind(attributes.dim),
+            italic: kind(attributes.italic),
+            underline: kind(attributes.underline),
+            blinking: kind(attributes.blinking),
+            inverse: kind(attributes.inverse),
+            hidden: kind(attributes.hidden),
+            strikethrough: kind(attributes.strikethrough),
+            custom_places,
+            ..Default::default()
+        }
+    }
+}
+impl From<PortableStyle> for SGRString {
+    fn from(value: PortableStyle) -> Self {
+        Self::from(&value)
+    }
+}
+impl From<PortableColor> for ColorKind {
+    fn from(value: PortableColor) -> Self {
+        match value {
+            PortableColor::Default => Self::Default,
+            PortableColor::Black => Self::Black,
+            PortableColor::Red => Self::Red,
+            PortableColor::Green => Self::Green,
+            PortableColor::Yellow => Self::Yellow,
+            PortableColor::Blue => Self::Blue,
+            PortableColor::Magenta => Self::Magenta,
+            PortableColor::Cyan => Self::Cyan,
+            PortableColor::White => Self::White,
+            PortableColor::Indexed { index } => Self::Byte(index),
+            PortableColor::Rgb { r, g, b } => Self::Rgb(r, g, b),
+        }
+    }
+}
+impl From<ColorKind> for Option<PortableColor> {
+    fn from(value: ColorKind) -> Self {
+        Some(match value {
+            ColorKind::None => return None,
+            ColorKind::Default => PortableColor::Default,
+            ColorKind::Black => PortableColor::Black,
+            ColorKind::Red => PortableColor::Red,
+            ColorKind::Green => PortableColor::Green,
+            ColorKind::Yellow => PortableColor::Yellow,
+            ColorKind::Blue => PortableColor::Blue,
+            ColorKind::Magenta => PortableColor::Magenta,
+            ColorKind::Cyan => PortableColor::Cyan,
+            ColorKind::White => PortableColor::White,
+            ColorKind::Byte(index) => PortableColor::Indexed { index },
+            ColorKind::Rgb(r, g, b) => PortableColor::Rgb { r, g, b },
+        })
+    }
+}
+/// Reads the last underline color set by `codes`
+fn underline_color(codes: &[u8]) -> Option<PortableColor> {
+    let mut color = None;
+    let mut codes = codes.iter().copied();
+    while let Some(code) = codes.next() {
+        match code {
+            38 | 48 => drop(extended_color(&mut codes)),
+            58 => color = extended_color(&mut codes).into(),
+            59 => color = Some(PortableColor::Default),
+            _ => (),
+        }
+    }
+    color
+}
+/// Returns the index of one of the 8 named colors
+const fn named_index(color: ColorKind) -> u8 {
+    match color {
+        ColorKind::Red => 1,
+        ColorKind::Green => 2,
+        ColorKind::Yellow => 3,
+        ColorKind::Blue => 4,
+        ColorKind::Magenta => 5,
+        ColorKind::Cyan => 6,
+        ColorKind::White => 7,
+        _ => 0,
+    }
+}
+/// Used to leave attributes that are off out of serialized styles
+#[cfg(feature = "serde")]
+#[allow(clippy::trivially_copy_pass_by_ref)]
+const fn is_false(value: &bool) -> bool {
+    !*value
+}
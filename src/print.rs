@@ -0,0 +1,172 @@
+use std::io::{self, IsTerminal, Write};
+
+use crate::{EasyWrite, IoWriter, SGRString, SGRWriter};
+
+/// Prints `text` styled by `sgr` to stdout, followed by a reset
+///
+/// Styled like [`SGRWriter::stdout`], so nothing but `text` is
+/// printed when stdout isn't a terminal or colors are disabled
+///
+/// # Errors
+///
+/// Unlike [`print!`] this never panics,
+/// failed writes such as to a closed pipe are returned instead
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{print_styled, Color::*};
+///
+///print_styled(&RedFg, "This should be red!").unwrap();
+///```
+pub fn print_styled(sgr: &impl EasyWrite, text: &str) -> io::Result<()> {
+    with_stdout(|writer| writer.styled(sgr, text))
+}
+/// Prints `text` styled by `sgr` to stdout, followed by a reset & a newline
+///
+/// See [`print_styled`]
+///
+/// # Errors
+///
+/// Returns an error if writing fails
+pub fn println_styled(sgr: &impl EasyWrite, text: &str) -> io::Result<()> {
+    with_stdout(|writer| {
+        writer.styled(sgr, text)?;
+        writer.write_inner("\n")
+    })
+}
+/// Prints `text` styled by `sgr` to stderr, followed by a reset
+///
+/// See [`print_styled`]
+///
+/// # Errors
+///
+/// Returns an error if writing fails
+pub fn eprint_styled(sgr: &impl EasyWrite, text: &str) -> io::Result<()> {
+    with_stderr(|writer| writer.styled(sgr, text))
+}
+/// Prints `text` styled by `sgr` to stderr, followed by a reset & a newline
+///
+/// See [`print_styled`]
+///
+/// # Errors
+///
+/// Returns an error if writing fails
+pub fn eprintln_styled(sgr: &impl EasyWrite, text: &str) -> io::Result<()> {
+    with_stderr(|writer| {
+        writer.styled(sgr, text)?;
+        writer.write_inner("\n")
+    })
+}
+/// Prints the text of `sgr` to stdout, between its place & clean
+///
+/// See [`print_styled`]
+///
+/// # Errors
+///
+/// Returns an error if writing fails
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{print_sgr_string, Color::*, EasySGR};
+///
+///print_sgr_string(&"This should be red!".color(RedFg)).unwrap();
+///```
+pub fn print_sgr_string(sgr: &SGRString) -> io::Result<()> {
+    with_stdout(|writer| writer.sgr_string(sgr))
+}
+/// Prints the text of `sgr` to stdout, between its place & clean, followed by a newline
+///
+/// See [`print_styled`]
+///
+/// # Errors
+///
+/// Returns an error if writing fails
+pub fn println_sgr_string(sgr: &SGRString) -> io::Result<()> {
+    with_stdout(|writer| {
+        writer.sgr_string(sgr)?;
+        writer.write_inner("\n")
+    })
+}
+/// Prints the text of `sgr` to stderr, between its place & clean
+///
+/// See [`print_styled`]
+///
+/// # Errors
+///
+/// Returns an error if writing fails
+pub fn eprint_sgr_string(sgr: &SGRString) -> io::Result<()> {
+    with_stderr(|writer| writer.sgr_string(sgr))
+}
+/// Prints the text of `sgr` to stderr, between its place & clean, followed by a newline
+///
+/// See [`print_styled`]
+///
+/// # Errors
+///
+/// Returns an error if writing fails
+pub fn eprintln_sgr_string(sgr: &SGRString) -> io::Result<()> {
+    with_stderr(|writer| {
+        writer.sgr_string(sgr)?;
+        writer.write_inner("\n")
+    })
+}
+/// Calls `f` with a writer over locked stdout
+fn with_stdout(
+    f: impl FnOnce(&mut SGRWriter<IoWriter<io::StdoutLock<'static>>>) -> io::Result<()>,
+) -> io::Result<()> {
+    let stdout = io::stdout();
+    let is_terminal = stdout.is_terminal();
+    with_output(stdout.lock(), is_terminal, f)
+}
+/// Calls `f` with a writer over locked stderr
+fn with_stderr(
+    f: impl FnOnce(&mut SGRWriter<IoWriter<io::StderrLock<'static>>>) -> io::Result<()>,
+) -> io::Result<()> {
+    let stderr = io::stderr();
+    let is_terminal = stderr.is_terminal();
+    with_output(stderr.lock(), is_terminal, f)
+}
+/// Calls `f` with a writer over `out`, enabled as [`SGRWriter::stdout`] would be
+/// for an output that is or isn't a terminal, then flushes `out`
+pub(crate) fn with_output<W: Write>(
+    out: W,
+    is_terminal: bool,
+    f: impl FnOnce(&mut SGRWriter<IoWriter<W>>) -> io::Result<()>,
+) -> io::Result<()> {
+    let mut writer = SGRWriter::auto(IoWriter(out), is_terminal);
+    f(&mut writer)?;
+    writer.get_mut().get_mut().flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::with_output;
+    use crate::{set_color_choice, Color::*, ColorChoice, EasySGR};
+
+    fn output(is_terminal: bool) -> io::Result<Vec<u8>> {
+        let mut out = Vec::new();
+        with_output(&mut out, is_terminal, |writer| {
+            writer.styled(&RedFg, "red")?;
+            writer.write_inner("\n")?;
+            writer.sgr_string(&"blue".color(BlueFg))
+        })?;
+        Ok(out)
+    }
+
+    // a single test, as the color choice is global
+    #[test]
+    fn with_output_enabled() -> io::Result<()> {
+        set_color_choice(ColorChoice::Always);
+        assert_eq!(output(false)?, b"\x1b[31mred\x1b[0m\n\x1b[34mblue");
+        set_color_choice(ColorChoice::Never);
+        assert_eq!(output(true)?, b"red\nblue");
+        // not a terminal, so detected as disabled
+        set_color_choice(ColorChoice::Auto);
+        assert_eq!(output(false)?, b"red\nblue");
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{Color, EasySGR, SGRString, Style};
+
+/// Defines functions giving text a [`Color`] or [`Style`] through an [`EasySGR`] method
+macro_rules! quick {
+    ($method:ident: $($name:ident => $value:expr, $doc:literal;)*) => {$(
+        #[doc = concat!("Gives `text` ", $doc)]
+        ///
+        #[doc = concat!(
+            "The same as calling [`EasySGR::", stringify!($method), "`] with [`",
+            stringify!($value), "`]",
+        )]
+        #[must_use]
+        pub fn $name(text: impl Into<SGRString>) -> SGRString {
+            text.into().$method($value)
+        }
+    )*};
+}
+quick! {style:
+    bold => Style::Bold, "bold";
+    dim => Style::Dim, "dimmed";
+    italic => Style::Italic, "italic";
+    underline => Style::Underline, "an underline";
+    blinking => Style::Blinking, "blinking";
+    inverse => Style::Inverse, "its foreground & background swapped";
+    hidden => Style::Hidden, "hidden";
+    strikethrough => Style::Strikethrough, "struck through";
+}
+quick! {color:
+    black => Color::BLACK, "a black foreground";
+    red => Color::RED, "a red foreground";
+    green => Color::GREEN, "a green foreground";
+    yellow => Color::YELLOW, "a yellow foreground";
+    blue => Color::BLUE, "a blue foreground";
+    magenta => Color::MAGENTA, "a magenta foreground";
+    cyan => Color::CYAN, "a cyan foreground";
+    white => Color::WHITE, "a white foreground";
+    bright_black => Color::BRIGHT_BLACK, "a bright black foreground";
+    bright_red => Color::BRIGHT_RED, "a bright red foreground";
+    bright_green => Color::BRIGHT_GREEN, "a bright green foreground";
+    bright_yellow => Color::BRIGHT_YELLOW, "a bright yellow foreground";
+    bright_blue => Color::BRIGHT_BLUE, "a bright blue foreground";
+    bright_magenta => Color::BRIGHT_MAGENTA, "a bright magenta foreground";
+    bright_cyan => Color::BRIGHT_CYAN, "a bright cyan foreground";
+    bright_white => Color::BRIGHT_WHITE, "a bright white foreground";
+    on_black => Color::ON_BLACK, "a black background";
+    on_red => Color::ON_RED, "a red background";
+    on_green => Color::ON_GREEN, "a green background";
+    on_yellow => Color::ON_YELLOW, "a yellow background";
+    on_blue => Color::ON_BLUE, "a blue background";
+    on_magenta => Color::ON_MAGENTA, "a magenta background";
+    on_cyan => Color::ON_CYAN, "a cyan background";
+    on_white => Color::ON_WHITE, "a white background";
+    on_bright_black => Color::ON_BRIGHT_BLACK, "a bright black background";
+    on_bright_red => Color::ON_BRIGHT_RED, "a bright red background";
+    on_bright_green => Color::ON_BRIGHT_GREEN, "a bright green background";
+    on_bright_yellow => Color::ON_BRIGHT_YELLOW, "a bright yellow background";
+    on_bright_blue => Color::ON_BRIGHT_BLUE, "a bright blue background";
+    on_bright_magenta => Color::ON_BRIGHT_MAGENTA, "a bright magenta background";
+    on_bright_cyan => Color::ON_BRIGHT_CYAN, "a bright cyan background";
+    on_bright_white => Color::ON_BRIGHT_WHITE, "a bright white background";
+}
@@ -0,0 +1,151 @@
+use std::{
+    fmt,
+    io::{self, IsTerminal, Write},
+    panic,
+    sync::{Arc, Mutex, MutexGuard, Once, PoisonError, TryLockError},
+};
+
+use crate::{color_choice, ColorChoice, SequenceBuilder};
+
+/// What is written by the hook of [`install_panic_restore_with`]
+///
+/// By default only the styling is reset,
+/// written to stdout & stderr
+#[derive(Clone, Default)]
+pub struct RestoreOptions {
+    /// Whether the cursor is shown, having been hidden through
+    /// [`SGRWriter::hide_cursor`](crate::SGRWriter::hide_cursor)
+    pub show_cursor: bool,
+    /// Whether an open hyperlink is closed
+    pub close_hyperlink: bool,
+    /// Written to rather than stdout & stderr, e.g. to capture what is written in tests
+    pub output: Option<Arc<Mutex<dyn Write + Send>>>,
+}
+impl RestoreOptions {
+    /// Returns the sequences written when panicking
+    fn sequence(&self) -> SequenceBuilder {
+        let mut seq = SequenceBuilder::default();
+        seq.sgr(&crate::Style::Reset);
+        if self.show_cursor {
+            seq.show_cursor();
+        }
+        if self.close_hyperlink {
+            seq.close_hyperlink();
+        }
+        seq
+    }
+}
+impl fmt::Debug for RestoreOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RestoreOptions")
+            .field("show_cursor", &self.show_cursor)
+            .field("close_hyperlink", &self.close_hyperlink)
+            .field("output", &self.output.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+/// The options of the installed hook, `None` while uninstalled
+static OPTIONS: Mutex<Option<RestoreOptions>> = Mutex::new(None);
+/// Installs the hook, wrapping the previous one, only once
+static HOOK: Once = Once::new();
+
+/// A handle to the hook installed by [`install_panic_restore`]
+///
+/// Dropping it leaves the hook installed
+#[derive(Debug)]
+pub struct PanicRestore(());
+impl PanicRestore {
+    /// Stops the terminal from being restored when panicking
+    ///
+    /// Hooks installed by other crates meanwhile may have wrapped this one,
+    /// so it is left in place, only calling the hook it wrapped
+    pub fn uninstall(self) {
+        *options() = None;
+    }
+}
+/// Installs a panic hook resetting the terminal's styling before the panic message is written
+///
+/// See [`install_panic_restore_with`]
+///
+/// # Examples
+///
+///```rust
+///let restore = easy_sgr::install_panic_restore();
+///// a panic now resets the styling of stdout & stderr
+///restore.uninstall();
+///```
+// the handle is only needed to uninstall the hook
+#[allow(clippy::must_use_candidate)]
+pub fn install_panic_restore() -> PanicRestore {
+    install_panic_restore_with(RestoreOptions::default())
+}
+/// Installs a panic hook writing what `options` asks for, before the panic message is written
+///
+/// The hook that was installed before, e.g. by another crate, is called afterwards.
+/// Installing again only replaces the options, the hook never being installed twice.
+///
+/// Writing is best-effort, errors being ignored.
+/// Stdout & stderr are each written to only when they're a terminal.
+/// Nothing is written while the [`ColorChoice`] is never
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{install_panic_restore_with, RestoreOptions};
+///
+///let restore = install_panic_restore_with(RestoreOptions {
+///    show_cursor: true,
+///    close_hyperlink: true,
+///    ..Default::default()
+///});
+///# restore.uninstall();
+///```
+#[allow(clippy::must_use_candidate)]
+pub fn install_panic_restore_with(options: RestoreOptions) -> PanicRestore {
+    *self::options() = Some(options);
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            restore();
+            previous(info);
+        }));
+    });
+    PanicRestore(())
+}
+/// Locks the options, whether or not a thread panicked while holding them
+fn options() -> MutexGuard<'static, Option<RestoreOptions>> {
+    OPTIONS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+/// Writes the sequences of the installed options
+fn restore() {
+    if color_choice() == ColorChoice::Never {
+        return;
+    }
+    let Some(options) = installed() else { return };
+    let seq = options.sequence();
+    let seq = seq.as_str().as_bytes();
+    if let Some(output) = &options.output {
+        if let Ok(mut output) = output.try_lock() {
+            let _ = output.write_all(seq).and_then(|()| output.flush());
+        }
+    } else {
+        let mut stdout = io::stdout().lock();
+        if stdout.is_terminal() {
+            let _ = stdout.write_all(seq).and_then(|()| stdout.flush());
+        }
+        let mut stderr = io::stderr().lock();
+        if stderr.is_terminal() {
+            let _ = stderr.write_all(seq);
+        }
+    }
+}
+/// Returns the installed options, without waiting on the lock
+///
+/// The panic may have happened while the options were locked
+fn installed() -> Option<RestoreOptions> {
+    match OPTIONS.try_lock() {
+        Ok(options) => options.clone(),
+        Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner().clone(),
+        Err(TryLockError::WouldBlock) => None,
+    }
+}
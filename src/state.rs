@@ -0,0 +1,271 @@
+use crate::{ColorKind, SGRBuilder, SGRString};
+
+/// The graphical state SGR codes leave a terminal in
+///
+/// Built up by [`SgrState::apply`]ing codes in the order they were written,
+/// and recreated from the default state by [`SgrState::place`]
+///
+/// Bright colors, i.e. codes `90`-`97` & `100`-`107`,
+/// are kept as the equivalent [`ColorKind::Byte`]
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{ColorKind, SGRBuilder, SgrState};
+///
+///let mut state = SgrState::new();
+///state.apply(&[1, 31]);
+///state.apply(&[22]);
+///assert_eq!(state.foreground, ColorKind::Red);
+///assert!(!state.bold);
+///
+///let mut builder = SGRBuilder::default();
+///state.place(&mut builder);
+///assert_eq!(builder.codes(), [31]);
+///```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct SgrState {
+    /// The color of the foreground, [`ColorKind::None`] when default
+    pub foreground: ColorKind,
+    /// The color of the background, [`ColorKind::None`] when default
+    pub background: ColorKind,
+    /// Whether text is bold
+    pub bold: bool,
+    /// Whether text is dimmed
+    pub dim: bool,
+    /// Whether text is italic
+    pub italic: bool,
+    /// Whether text is underlined
+    pub underline: bool,
+    /// Whether text is blinking slowly
+    pub blinking: bool,
+    /// Whether text is blinking rapidly
+    ///
+    /// Never applied along with [`SgrState::blinking`], the last applied of the two winning
+    pub rapid_blinking: bool,
+    /// Whether the foreground & background are swapped
+    pub inverse: bool,
+    /// Whether text is hidden
+    pub hidden: bool,
+    /// Whether text is struck through
+    pub strikethrough: bool,
+}
+impl SgrState {
+    /// Creates the default state, where nothing is applied
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            foreground: ColorKind::None,
+            background: ColorKind::None,
+            bold: false,
+            dim: false,
+            italic: false,
+            underline: false,
+            blinking: false,
+            rapid_blinking: false,
+            inverse: false,
+            hidden: false,
+            strikethrough: false,
+        }
+    }
+    /// Returns whether nothing is applied
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        *self == Self::new()
+    }
+    /// Updates the state with the codes of a single SGR sequence
+    ///
+    /// Unknown codes are ignored
+    pub fn apply(&mut self, codes: &[u8]) {
+        let mut codes = codes.iter().copied();
+        while let Some(code) = codes.next() {
+            match code {
+                0 => *self = Self::new(),
+                1 => self.bold = true,
+                2 => self.dim = true,
+                3 => self.italic = true,
+                4 => self.underline = true,
+                5 => (self.blinking, self.rapid_blinking) = (true, false),
+                6 => (self.blinking, self.rapid_blinking) = (false, true),
+                7 => self.inverse = true,
+                8 => self.hidden = true,
+                9 => self.strikethrough = true,
+                22 => (self.bold, self.dim) = (false, false),
+                23 => self.italic = false,
+                24 => self.underline = false,
+                25 => (self.blinking, self.rapid_blinking) = (false, false),
+                27 => self.inverse = false,
+                28 => self.hidden = false,
+                29 => self.strikethrough = false,
+                30..=37 => self.foreground = basic_color(code - 30),
+                38 => self.foreground = extended_color(&mut codes),
+                39 => self.foreground = ColorKind::None,
+                40..=47 => self.background = basic_color(code - 40),
+                48 => self.background = extended_color(&mut codes),
+                49 => self.background = ColorKind::None,
+                // underline colors aren't kept, but their arguments aren't codes
+                58 => drop(extended_color(&mut codes)),
+                90..=97 => self.foreground = ColorKind::Byte(code - 82),
+                100..=107 => self.background = ColorKind::Byte(code - 92),
+                _ => (),
+            }
+        }
+    }
+    /// Writes the codes recreating this state from the default state
+    ///
+    /// Does not perform any IO operations
+    pub fn place(&self, builder: &mut SGRBuilder) {
+        place_color(self.foreground, 30, builder);
+        place_color(self.background, 40, builder);
+        builder.write_codes_iter(
+            self.styles()
+                .into_iter()
+                .filter_map(|(applied, code)| applied.then_some(code)),
+        );
+    }
+    /// Writes the fewest codes changing this state into `to`,
+    /// without resetting what both have in common
+    ///
+    /// Does not perform any IO operations
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{SGRBuilder, SgrState};
+    ///
+    ///let mut from = SgrState::new();
+    ///from.apply(&[1, 31, 44]);
+    ///let mut to = SgrState::new();
+    ///to.apply(&[1, 32]);
+    ///
+    ///let mut builder = SGRBuilder::default();
+    ///from.transition(&to, &mut builder);
+    ///assert_eq!(builder.codes(), [32, 49]);
+    ///```
+    pub fn transition(&self, to: &Self, builder: &mut SGRBuilder) {
+        for (from, to, base) in [
+            (self.foreground, to.foreground, 30),
+            (self.background, to.background, 40),
+        ] {
+            match to {
+                _ if from == to => (),
+                ColorKind::None | ColorKind::Default => builder.write_code(base + 9),
+                to => place_color(to, base, builder),
+            }
+        }
+        // `22` ends both bold & dim, so whichever remains is placed again
+        let intensity = self.bold && !to.bold || self.dim && !to.dim;
+        if intensity {
+            builder.write_code(22);
+        }
+        let blink = (self.blink(), to.blink());
+        for ((from, code), (to, _)) in self.styles().into_iter().zip(to.styles()) {
+            match code {
+                1 | 2 if intensity => {
+                    if to {
+                        builder.write_code(code);
+                    }
+                }
+                // switching between slow & rapid blinking only places the other
+                5 => match blink {
+                    (from, to) if from == to => (),
+                    (_, Some(code)) => builder.write_code(code),
+                    (_, None) => builder.write_code(25),
+                },
+                6 => (),
+                _ if from == to => (),
+                1 | 2 => builder.write_code(code),
+                _ if to => builder.write_code(code),
+                _ => builder.write_code(code + 20),
+            }
+        }
+    }
+    /// Returns the code placing the blinking applied, if any
+    const fn blink(&self) -> Option<u8> {
+        if self.rapid_blinking {
+            Some(6)
+        } else if self.blinking {
+            Some(5)
+        } else {
+            None
+        }
+    }
+    /// Returns whether each style is applied, along with the code placing it
+    const fn styles(&self) -> [(bool, u8); 9] {
+        [
+            (self.bold, 1),
+            (self.dim, 2),
+            (self.italic, 3),
+            (self.underline, 4),
+            (self.blinking, 5),
+            (self.rapid_blinking, 6),
+            (self.inverse, 7),
+            (self.hidden, 8),
+            (self.strikethrough, 9),
+        ]
+    }
+}
+/// The state placing `sgr` leaves the default state in
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{Color::*, ColorKind, EasySGR, SgrState, Style::*};
+///
+///let state = SgrState::from(&RedFg.style(Bold).text("error"));
+///assert_eq!(state.foreground, ColorKind::Red);
+///assert!(state.bold);
+///```
+impl From<&SGRString> for SgrState {
+    fn from(sgr: &SGRString) -> Self {
+        let mut placed = SGRBuilder::default();
+        sgr.place_all(&mut placed);
+        let mut state = Self::new();
+        state.apply(placed.codes());
+        state
+    }
+}
+/// Writes the codes placing `color`, `base` being `30` for the foreground
+/// & `40` for the background
+fn place_color(color: ColorKind, base: u8, builder: &mut SGRBuilder) {
+    match color {
+        ColorKind::None | ColorKind::Default => (),
+        ColorKind::Black => builder.write_code(base),
+        ColorKind::Red => builder.write_code(base + 1),
+        ColorKind::Green => builder.write_code(base + 2),
+        ColorKind::Yellow => builder.write_code(base + 3),
+        ColorKind::Blue => builder.write_code(base + 4),
+        ColorKind::Magenta => builder.write_code(base + 5),
+        ColorKind::Cyan => builder.write_code(base + 6),
+        ColorKind::White => builder.write_code(base + 7),
+        ColorKind::Byte(n) => builder.write_codes(&[base + 8, 5, n]),
+        ColorKind::Rgb(r, g, b) => builder.write_codes(&[base + 8, 2, r, g, b]),
+    }
+}
+/// Returns one of the 8 basic colors by its index
+const fn basic_color(index: u8) -> ColorKind {
+    match index {
+        0 => ColorKind::Black,
+        1 => ColorKind::Red,
+        2 => ColorKind::Green,
+        3 => ColorKind::Yellow,
+        4 => ColorKind::Blue,
+        5 => ColorKind::Magenta,
+        6 => ColorKind::Cyan,
+        _ => ColorKind::White,
+    }
+}
+/// Reads the color following a `38` or `48` code
+///
+/// Incomplete colors are read as [`ColorKind::None`]
+pub(crate) fn extended_color(codes: &mut impl Iterator<Item = u8>) -> ColorKind {
+    match codes.next() {
+        Some(5) => codes.next().map_or(ColorKind::None, ColorKind::Byte),
+        Some(2) => match (codes.next(), codes.next(), codes.next()) {
+            (Some(r), Some(g), Some(b)) => ColorKind::Rgb(r, g, b),
+            _ => ColorKind::None,
+        },
+        _ => ColorKind::None,
+    }
+}
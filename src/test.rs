@@ -0,0 +1,528 @@
+use std::{
+    convert::Infallible,
+    error::Error,
+    fmt::{self, Display},
+};
+
+use crate::{
+    append::AppendToString,
+    escape::{EscapeScanner, Part},
+    CapableWriter, SGRBuilder,
+};
+
+/// Something written to a [`TestWriter`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// Codes written as a complete sequence, through [`CapableWriter::write_sgr`]
+    Codes(Vec<u8>),
+    /// Codes written without the escape or end, through [`CapableWriter::write_partial_sgr`]
+    PartialCodes(Vec<u8>),
+    /// Text written through [`CapableWriter::write`]
+    ///
+    /// Consecutive writes are merged into a single event
+    Text(String),
+    /// An escape sequence written through [`CapableWriter::write_escape`]
+    Escape(String),
+}
+/// A writer recording SGR codes & text separately
+///
+/// Lets tests assert on what was written without comparing raw escape sequences
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{test::{Event, TestWriter}, Color::*, EasySGR, SGRWriter, Style::*};
+///
+///let mut writer = SGRWriter::from(TestWriter::new());
+///writer.sgr(&RedFg.style(Bold)).unwrap();
+///writer.write_inner("Error").unwrap();
+///writer.inline_sgr(&Reset).unwrap();
+///
+///writer.get_ref().assert_sequence(&[
+///    Event::Codes(vec![31, 1]),
+///    Event::Text("Error".into()),
+///    Event::Codes(vec![0]),
+///]);
+///```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TestWriter {
+    /// Everything written, in order
+    pub events: Vec<Event>,
+}
+impl TestWriter {
+    /// Creates a writer without any events
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { events: Vec::new() }
+    }
+    /// Returns every code written, partial or not, in order
+    #[must_use]
+    pub fn codes_flat(&self) -> Vec<u8> {
+        self.events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Codes(codes) | Event::PartialCodes(codes) => Some(codes.as_slice()),
+                Event::Text(_) | Event::Escape(_) => None,
+            })
+            .flatten()
+            .copied()
+            .collect()
+    }
+    /// Returns all text written, without any codes
+    #[must_use]
+    pub fn text_concat(&self) -> String {
+        self.events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Text(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+    /// Asserts that exactly `expected` was written
+    ///
+    /// # Panics
+    ///
+    /// Panics if the events written differ from `expected`
+    #[track_caller]
+    pub fn assert_sequence(&self, expected: &[Event]) {
+        assert_eq!(self.events, expected, "written events differ");
+    }
+}
+impl CapableWriter for TestWriter {
+    type Writer = Vec<Event>;
+    type Error = Infallible;
+    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+        if s.is_empty() {
+            return Ok(());
+        }
+        match self.events.last_mut() {
+            Some(Event::Text(text)) => text.push_str(s),
+            _ => self.events.push(Event::Text(s.to_string())),
+        }
+        Ok(())
+    }
+    fn write_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        self.events.push(Event::Codes(codes.to_vec()));
+        Ok(())
+    }
+    fn write_partial_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        self.events.push(Event::PartialCodes(codes.to_vec()));
+        Ok(())
+    }
+    fn write_escape(&mut self, seq: &str) -> Result<(), Self::Error> {
+        self.events.push(Event::Escape(seq.to_string()));
+        Ok(())
+    }
+    fn get_writer(self) -> Self::Writer {
+        self.events
+    }
+}
+/// A writer recording what is written as a transcript, for golden tests
+///
+/// Each event is written as one entry of the transcript:
+///
+/// - `SGR[1;31]` for codes written as a complete sequence
+/// - `PARTIAL[1;31]` for codes written without the escape or end
+/// - `TEXT"error"` for text, consecutive writes being merged
+/// - `ESC"\e[?25l"` for escape sequences written through [`CapableWriter::write_escape`]
+///
+/// Entries are separated by a space, or a newline after text ending in one.
+/// Within quotes, `\\`, `\"`, `\n`, `\r`, `\t` & `\e` are escaped,
+/// as are other control characters in the form `\u{1f}`.
+///
+/// What a transcript records is written again by [`replay`]
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{test::RecordingWriter, Color::*, EasySGR, SGRWriter, Style::*};
+///
+///let mut writer = SGRWriter::from(RecordingWriter::new());
+///writer.sgr(&Bold.color(RedFg)).unwrap();
+///writer.write_inner("error").unwrap();
+///writer.inline_sgr(&Reset).unwrap();
+///
+///assert_eq!(writer.get_ref().transcript(), "SGR[31;1] TEXT\"error\" SGR[0]");
+///```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RecordingWriter {
+    recorder: TestWriter,
+}
+impl RecordingWriter {
+    /// Creates a writer without anything recorded
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            recorder: TestWriter::new(),
+        }
+    }
+    /// Returns everything written, in order
+    #[must_use]
+    pub fn events(&self) -> &[Event] {
+        &self.recorder.events
+    }
+    /// Returns the transcript of everything written
+    #[must_use]
+    pub fn transcript(&self) -> String {
+        let mut transcript = String::new();
+        let mut separator = None;
+        for event in &self.recorder.events {
+            if let Some(separator) = separator {
+                transcript.push(separator);
+            }
+            separator = match event {
+                Event::Text(text) if text.ends_with('\n') => Some('\n'),
+                _ => Some(' '),
+            };
+            match event {
+                Event::Codes(codes) => write_codes(&mut transcript, "SGR", codes),
+                Event::PartialCodes(codes) => write_codes(&mut transcript, "PARTIAL", codes),
+                Event::Text(text) => write_quoted(&mut transcript, "TEXT", text),
+                Event::Escape(seq) => write_quoted(&mut transcript, "ESC", seq),
+            }
+        }
+        transcript
+    }
+}
+impl CapableWriter for RecordingWriter {
+    type Writer = String;
+    type Error = Infallible;
+    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.recorder.write(s)
+    }
+    fn write_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        self.recorder.write_sgr(codes)
+    }
+    fn write_partial_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        self.recorder.write_partial_sgr(codes)
+    }
+    fn write_escape(&mut self, seq: &str) -> Result<(), Self::Error> {
+        self.recorder.write_escape(seq)
+    }
+    fn get_writer(self) -> Self::Writer {
+        self.transcript()
+    }
+}
+/// Writes what a transcript of a [`RecordingWriter`] records to `writer`
+///
+/// # Errors
+///
+/// Returns [`ReplayError::Transcript`] if the transcript is malformed, nothing is written then,
+/// or [`ReplayError::Write`] if writing fails
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::test::replay;
+///
+///let mut written = String::new();
+///replay("SGR[1] TEXT\"bold\\n\"\nSGR[0]", &mut written).unwrap();
+///
+///assert_eq!(written, "\x1b[1mbold\n\x1b[0m");
+///```
+pub fn replay<W: CapableWriter>(
+    transcript: &str,
+    writer: &mut W,
+) -> Result<(), ReplayError<W::Error>> {
+    for event in parse_transcript(transcript).map_err(ReplayError::Transcript)? {
+        match event {
+            Event::Codes(codes) => writer.write_sgr(&codes),
+            Event::PartialCodes(codes) => writer.write_partial_sgr(&codes),
+            Event::Text(text) => writer.write(&text),
+            Event::Escape(seq) => writer.write_escape(&seq),
+        }
+        .map_err(ReplayError::Write)?;
+    }
+    Ok(())
+}
+/// An error returned by [`replay`]
+#[derive(Debug, PartialEq, Eq)]
+pub enum ReplayError<E> {
+    /// The transcript was malformed at this byte index
+    Transcript(usize),
+    /// Writing failed
+    Write(E),
+}
+impl<E: Display> Display for ReplayError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Transcript(i) => write!(f, "Malformed transcript at index {i}"),
+            Self::Write(e) => write!(f, "Writing failed: {e}"),
+        }
+    }
+}
+impl<E: Error + 'static> Error for ReplayError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Transcript(_) => None,
+            Self::Write(e) => Some(e),
+        }
+    }
+}
+/// Asserts that two strings are equal once their escape sequences are removed
+///
+/// Both sides may be anything implementing [`Display`](std::fmt::Display),
+/// see [`assert_sgr_eq`](crate::test::assert_sgr_eq) for what is printed on failure.
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{sgr_assert_eq, Color::*, EasySGR};
+///
+///sgr_assert_eq!(RedFg.text("error"), "\x1b[1merror\x1b[0m");
+///```
+#[macro_export]
+macro_rules! sgr_assert_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::test::assert_sgr_eq(
+            &::std::string::ToString::to_string(&$left),
+            &::std::string::ToString::to_string(&$right),
+        )
+    };
+}
+/// Asserts that two strings are equal once their SGR sequences are normalized
+///
+/// Both sides may be anything implementing [`Display`](std::fmt::Display),
+/// see [`assert_styled_eq`](crate::test::assert_styled_eq) for what is printed on failure.
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{sgr_assert_styled_eq, Color::*, EasySGR, Style::*};
+///
+///sgr_assert_styled_eq!(RedFg.style(Bold).text("error"), "\x1b[31m\x1b[1merror");
+///```
+#[macro_export]
+macro_rules! sgr_assert_styled_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::test::assert_styled_eq(
+            &::std::string::ToString::to_string(&$left),
+            &::std::string::ToString::to_string(&$right),
+        )
+    };
+}
+/// Returns `s` without its escape sequences
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::test::strip_escapes;
+///
+///assert_eq!(strip_escapes("\x1b[1mbold\x1b[0m \x1b[2Kplain"), "bold plain");
+///```
+#[must_use]
+pub fn strip_escapes(s: &str) -> String {
+    let mut stripped = String::with_capacity(s.len());
+    EscapeScanner::default().scan(s, |text| stripped.push_str(text));
+    stripped
+}
+/// Returns `s` with its SGR sequences written in a canonical form
+///
+/// Adjacent SGR sequences are joined into one, empty parameters are written as `0`
+/// & escape sequences other than SGR sequences are removed
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::test::normalize_sgr;
+///
+///assert_eq!(normalize_sgr("\x1b[1m\x1b[;31mbold\x1b[m"), "\x1b[1;0;31mbold\x1b[0m");
+///```
+#[must_use]
+pub fn normalize_sgr(s: &str) -> String {
+    let mut normalized = String::with_capacity(s.len());
+    let mut codes = Vec::new();
+    EscapeScanner::default().scan_parts(s, |part| match part {
+        Part::Text(range) => {
+            normalized.push_str(&SGRBuilder::from(&codes[..]).to_sequence_string());
+            normalized.push_str(&s[range]);
+            codes.clear();
+        }
+        Part::Sgr(sgr) => codes.extend_from_slice(sgr),
+        Part::Escape(_) => (),
+    });
+    normalized.push_str(&SGRBuilder::from(codes).to_sequence_string());
+    normalized
+}
+/// Returns `s` quoted with its escapes made visible, e.g. `"\e[1mbold"`
+///
+/// Escapes are written as they are in the transcript of a [`RecordingWriter`]
+#[must_use]
+pub fn visible_escapes(s: &str) -> String {
+    let mut visible = String::with_capacity(s.len() + 2);
+    write_quoted(&mut visible, "", s);
+    visible
+}
+/// Asserts that `left` & `right` are equal once their escape sequences are removed
+///
+/// # Panics
+///
+/// Panics if the text of `left` & `right` differs, printing
+/// the text of both & both with their escapes made visible, e.g.
+///
+///```text
+///assertion `left == right` failed: text differs at char 3
+///  left text: "bold"
+/// right text: "bolt"
+///       left: "\e[1mbold\e[0m"
+///      right: "\e[1mbolt\e[0m"
+///```
+#[track_caller]
+pub fn assert_sgr_eq(left: &str, right: &str) {
+    let (left_text, right_text) = (strip_escapes(left), strip_escapes(right));
+    assert!(
+        left_text == right_text,
+        "assertion `left == right` failed: text differs at char {}\n  \
+         left text: {:?}\n right text: {:?}\n       left: {}\n      right: {}",
+        first_difference(&left_text, &right_text),
+        left_text,
+        right_text,
+        visible_escapes(left),
+        visible_escapes(right),
+    );
+}
+/// Asserts that `left` & `right` are equal once their SGR sequences are normalized
+///
+/// The text is compared first, as by [`assert_sgr_eq`],
+/// then the SGR sequences as normalized by [`normalize_sgr`].
+///
+/// # Panics
+///
+/// Panics if the text of `left` & `right` differs, as [`assert_sgr_eq`] does,
+/// or if their styling differs, printing both normalized & as written
+/// with their escapes made visible, e.g.
+///
+///```text
+///assertion `left == right` failed: styling differs
+/// left normalized: "\e[1mbold"
+///right normalized: "\e[31mbold"
+///            left: "\e[1mbold"
+///           right: "\e[31mbold"
+///```
+#[track_caller]
+pub fn assert_styled_eq(left: &str, right: &str) {
+    assert_sgr_eq(left, right);
+    let (left_normalized, right_normalized) = (normalize_sgr(left), normalize_sgr(right));
+    assert!(
+        left_normalized == right_normalized,
+        "assertion `left == right` failed: styling differs\n \
+             left normalized: {}\nright normalized: {}\n            left: {}\n           right: {}",
+        visible_escapes(&left_normalized),
+        visible_escapes(&right_normalized),
+        visible_escapes(left),
+        visible_escapes(right),
+    );
+}
+/// Returns the index of the first char differing between `left` & `right`
+fn first_difference(left: &str, right: &str) -> usize {
+    left.chars()
+        .zip(right.chars())
+        .position(|(l, r)| l != r)
+        .unwrap_or_else(|| left.chars().count().min(right.chars().count()))
+}
+/// Writes a `tag[codes]` entry
+fn write_codes(transcript: &mut String, tag: &str, codes: &[u8]) {
+    transcript.push_str(tag);
+    transcript.push('[');
+    for (i, code) in codes.iter().enumerate() {
+        if i != 0 {
+            transcript.push(';');
+        }
+        code.append_to(transcript);
+    }
+    transcript.push(']');
+}
+/// Writes a `tag"text"` entry
+fn write_quoted(transcript: &mut String, tag: &str, text: &str) {
+    transcript.push_str(tag);
+    transcript.push('"');
+    for c in text.chars() {
+        match c {
+            '\\' => transcript.push_str("\\\\"),
+            '"' => transcript.push_str("\\\""),
+            '\n' => transcript.push_str("\\n"),
+            '\r' => transcript.push_str("\\r"),
+            '\t' => transcript.push_str("\\t"),
+            '\x1b' => transcript.push_str("\\e"),
+            c if c.is_control() => {
+                let _ =
+                    fmt::Write::write_fmt(transcript, format_args!("\\u{{{:x}}}", u32::from(c)));
+            }
+            c => transcript.push(c),
+        }
+    }
+    transcript.push('"');
+}
+/// Reads the events recorded by a transcript,
+/// returning the byte index it is malformed at otherwise
+fn parse_transcript(transcript: &str) -> Result<Vec<Event>, usize> {
+    let mut events = Vec::new();
+    let mut rest = transcript.trim_start();
+    while !rest.is_empty() {
+        let at = transcript.len() - rest.len();
+        let (event, after) = parse_entry(rest).ok_or(at)?;
+        if !(after.is_empty() || after.starts_with(char::is_whitespace)) {
+            return Err(transcript.len() - after.len());
+        }
+        events.push(event);
+        rest = after.trim_start();
+    }
+    Ok(events)
+}
+/// Reads a single entry, returning it & what follows
+fn parse_entry(s: &str) -> Option<(Event, &str)> {
+    if let Some(codes) = s.strip_prefix("SGR[") {
+        let (codes, after) = parse_codes(codes)?;
+        return Some((Event::Codes(codes), after));
+    }
+    if let Some(codes) = s.strip_prefix("PARTIAL[") {
+        let (codes, after) = parse_codes(codes)?;
+        return Some((Event::PartialCodes(codes), after));
+    }
+    if let Some(text) = s.strip_prefix("TEXT\"") {
+        let (text, after) = parse_quoted(text)?;
+        return Some((Event::Text(text), after));
+    }
+    let (seq, after) = parse_quoted(s.strip_prefix("ESC\"")?)?;
+    Some((Event::Escape(seq), after))
+}
+/// Reads codes up to a `]`, returning them & what follows
+fn parse_codes(s: &str) -> Option<(Vec<u8>, &str)> {
+    let (codes, after) = s.split_once(']')?;
+    if codes.is_empty() {
+        return Some((Vec::new(), after));
+    }
+    let codes = codes
+        .split(';')
+        .map(str::parse)
+        .collect::<Result<_, _>>()
+        .ok()?;
+    Some((codes, after))
+}
+/// Reads quoted text up to its closing `"`, returning it & what follows
+fn parse_quoted(mut s: &str) -> Option<(String, &str)> {
+    let mut text = String::new();
+    loop {
+        let mut chars = s.chars();
+        match chars.next()? {
+            '"' => return Some((text, chars.as_str())),
+            '\\' => match chars.next()? {
+                'n' => text.push('\n'),
+                'r' => text.push('\r'),
+                't' => text.push('\t'),
+                'e' => text.push('\x1b'),
+                'u' => {
+                    let (code, after) = chars.as_str().strip_prefix('{')?.split_once('}')?;
+                    text.push(char::from_u32(u32::from_str_radix(code, 16).ok()?)?);
+                    s = after;
+                    continue;
+                }
+                c @ ('\\' | '"') => text.push(c),
+                _ => return None,
+            },
+            c => text.push(c),
+        }
+        s = chars.as_str();
+    }
+}
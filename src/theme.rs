@@ -0,0 +1,286 @@
+use std::collections::BTreeMap;
+
+use crate::SGRString;
+#[cfg(any(
+    feature = "log",
+    feature = "tracing",
+    feature = "env-logger",
+    feature = "from-str"
+))]
+use crate::Style;
+#[cfg(any(feature = "log", feature = "tracing", feature = "env-logger"))]
+use crate::{CapableWriter, CleanKind, SGRWriter};
+#[cfg(feature = "from-str")]
+use crate::{Color, EasySGR};
+
+/// A set of named styles
+///
+/// Lets the styling of an application be configured in one place,
+/// integrations such as the `log` adapter look their styles up by name
+///
+/// Only the SGR codes of each entry are used, any text they contain is ignored
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{Color::*, EasySGR, Style::*, Theme};
+///
+///let theme = Theme::new()
+///    .with("error", RedFg.style(Bold))
+///    .with("note", BlueFg);
+///
+///assert!(theme.get("error").is_some());
+///assert!(theme.get("warn").is_none());
+///```
+#[derive(Debug, Clone, Default)]
+pub struct Theme {
+    entries: BTreeMap<String, SGRString>,
+}
+impl Theme {
+    /// Creates a theme without any entries
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+    /// Returns the style named `name`
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&SGRString> {
+        self.entries.get(name)
+    }
+    /// Sets the style named `name`, returning the previous style
+    pub fn set(
+        &mut self,
+        name: impl Into<String>,
+        style: impl Into<SGRString>,
+    ) -> Option<SGRString> {
+        self.entries.insert(name.into(), style.into())
+    }
+    /// Removes the style named `name`, returning it
+    pub fn remove(&mut self, name: &str) -> Option<SGRString> {
+        self.entries.remove(name)
+    }
+    /// Sets the style named `name`
+    ///
+    /// Used for chaining
+    #[must_use]
+    pub fn with(mut self, name: impl Into<String>, style: impl Into<SGRString>) -> Self {
+        self.set(name, style);
+        self
+    }
+    /// Returns an iterator over the entries, ordered by name
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &SGRString)> {
+        self.entries
+            .iter()
+            .map(|(name, style)| (name.as_str(), style))
+    }
+    /// Returns this with every entry of `overrides` set,
+    /// replacing any entries of the same name
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color::*, Theme};
+    ///
+    ///let defaults = Theme::new().with("error", RedFg).with("note", BlueFg);
+    ///let theme = defaults.merge(Theme::new().with("note", CyanFg));
+    ///assert_eq!(theme.get("error").unwrap().foreground, easy_sgr::ColorKind::Red);
+    ///assert_eq!(theme.get("note").unwrap().foreground, easy_sgr::ColorKind::Cyan);
+    ///```
+    #[must_use]
+    pub fn merge(mut self, overrides: Self) -> Self {
+        self.entries.extend(overrides.entries);
+        self
+    }
+    /// Loads a theme from the environment variables named `{prefix}_COLOR_{NAME}`,
+    /// see [`Theme::from_vars`]
+    #[cfg(feature = "from-str")]
+    #[must_use]
+    pub fn from_env(prefix: &str) -> (Self, Vec<ThemeVarError>) {
+        Self::from_vars(prefix, std::env::vars())
+    }
+    /// Loads a theme from the variables named `{prefix}_COLOR_{NAME}` within `vars`
+    ///
+    /// Each becomes the entry named by `NAME` in lowercase, styled by its value:
+    /// words separated by whitespace, each a [`Color`] or [`Style`] as parsed by
+    /// [`FromStr`](std::str::FromStr), or a keyword of the macros, e.g. `bold`,
+    /// `on-red`, `#ff5555` or `255,85,85`
+    ///
+    /// Variables with invalid words are skipped, each returned as an error
+    /// without failing the rest of the load
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{ColorKind, StyleKind, Theme};
+    ///
+    ///let vars = [
+    ///    ("MYAPP_COLOR_ERROR", "#ff5555 bold"),
+    ///    ("MYAPP_COLOR_NOTE", "BlueFg"),
+    ///    ("MYAPP_COLOR_WARN", "yellow sparkly"),
+    ///    ("OTHER_COLOR_ERROR", "green"),
+    ///];
+    ///let (theme, errors) = Theme::from_vars("MYAPP", vars);
+    ///
+    ///let error = theme.get("error").unwrap();
+    ///assert_eq!(error.foreground, ColorKind::Rgb(255, 85, 85));
+    ///assert_eq!(error.bold, StyleKind::Place);
+    ///assert_eq!(theme.get("note").unwrap().foreground, ColorKind::Blue);
+    ///assert!(theme.get("warn").is_none());
+    ///assert_eq!(errors[0].word, "sparkly");
+    ///```
+    #[cfg(feature = "from-str")]
+    pub fn from_vars(
+        prefix: &str,
+        vars: impl IntoIterator<Item = (impl AsRef<str>, impl AsRef<str>)>,
+    ) -> (Self, Vec<ThemeVarError>) {
+        let prefix = format!("{prefix}_COLOR_");
+        let mut theme = Self::new();
+        let mut errors = Vec::new();
+        for (var, value) in vars {
+            let (var, value) = (var.as_ref(), value.as_ref());
+            let Some(name) = var.strip_prefix(&prefix).filter(|name| !name.is_empty()) else {
+                continue;
+            };
+            let mut style = SGRString::default();
+            let invalid = value
+                .split_whitespace()
+                .find(|word| match parse_word(word) {
+                    Some(Word::Color(color)) => {
+                        style = std::mem::take(&mut style).color(color);
+                        false
+                    }
+                    Some(Word::Style(s)) => {
+                        style = std::mem::take(&mut style).style(s);
+                        false
+                    }
+                    None => true,
+                });
+            match invalid {
+                Some(word) => errors.push(ThemeVarError {
+                    var: var.to_string(),
+                    word: word.to_string(),
+                }),
+                None => {
+                    theme.set(name.to_lowercase(), style);
+                }
+            }
+        }
+        (theme, errors)
+    }
+}
+/// A variable given to [`Theme::from_vars`] containing an invalid word
+#[cfg(feature = "from-str")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThemeVarError {
+    /// The name of the variable
+    pub var: String,
+    /// The first word that is neither a color nor a style
+    pub word: String,
+}
+#[cfg(feature = "from-str")]
+impl std::fmt::Display for ThemeVarError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid style `{}` in `{}`", self.word, self.var)
+    }
+}
+#[cfg(feature = "from-str")]
+impl std::error::Error for ThemeVarError {}
+/// A word of a variable given to [`Theme::from_vars`]
+#[cfg(feature = "from-str")]
+enum Word {
+    Color(Color),
+    Style(Style),
+}
+/// Parses a word of a variable given to [`Theme::from_vars`]
+#[cfg(feature = "from-str")]
+fn parse_word(word: &str) -> Option<Word> {
+    use crate::Style::*;
+    if let Ok(color) = word.parse() {
+        return Some(Word::Color(color));
+    }
+    if let Ok(style) = word.parse() {
+        return Some(Word::Style(style));
+    }
+    let style = match word {
+        "reset" => Reset,
+        "bold" => Bold,
+        "dim" => Dim,
+        "italic" => Italic,
+        "underline" => Underline,
+        "blink" => Blinking,
+        "inverse" => Inverse,
+        "hide" => Hidden,
+        "strike" => Strikethrough,
+        "!bold" => NotBold,
+        "!dim" => NotDim,
+        "!italic" => NotItalic,
+        "!underline" => NotUnderline,
+        "!blink" => NotBlinking,
+        "!inverse" => NotInverse,
+        "!hide" => NotHidden,
+        "!strike" => NotStrikethrough,
+        _ => return parse_color_word(word).map(Word::Color),
+    };
+    Some(Word::Style(style))
+}
+/// Parses a color keyword of the macros, e.g. `red`, `on-#ff5555` or `15`
+#[cfg(feature = "from-str")]
+fn parse_color_word(word: &str) -> Option<Color> {
+    use Color::*;
+    const FOREGROUNDS: [Color; 8] = [
+        BlackFg, RedFg, GreenFg, YellowFg, BlueFg, MagentaFg, CyanFg, WhiteFg,
+    ];
+    const BACKGROUNDS: [Color; 8] = [
+        BlackBg, RedBg, GreenBg, YellowBg, BlueBg, MagentaBg, CyanBg, WhiteBg,
+    ];
+    const NAMES: [&str; 8] = [
+        "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+    ];
+    let (background, word) = word
+        .strip_prefix("on-")
+        .map_or((false, word), |word| (true, word));
+    if let Some(i) = NAMES.iter().position(|&name| name == word) {
+        return Some(if background { BACKGROUNDS } else { FOREGROUNDS }[i].clone());
+    }
+    let hex = |s: &str| u8::from_str_radix(s, 16).ok();
+    let codes = match word.strip_prefix('#') {
+        _ if word == "default" => return Some(if background { DefaultBg } else { DefaultFg }),
+        Some(s) if s.len() == 2 => vec![hex(s)?],
+        Some(s) if s.len() == 6 => vec![hex(s.get(0..2)?)?, hex(s.get(2..4)?)?, hex(s.get(4..6)?)?],
+        Some(_) => return None,
+        None => word
+            .split(',')
+            .map(|n| n.parse().ok())
+            .collect::<Option<Vec<u8>>>()?,
+    };
+    match (background, codes.as_slice()) {
+        (false, &[n]) => Some(ByteFg(n)),
+        (true, &[n]) => Some(ByteBg(n)),
+        (false, &[r, g, b]) => Some(RgbFg(r, g, b)),
+        (true, &[r, g, b]) => Some(RgbBg(r, g, b)),
+        _ => None,
+    }
+}
+/// Writes `text` styled by `style`, plainly when there is no style
+///
+/// Ended by the clean of `style`, or a reset when it has none,
+/// so nothing is left styled after `text`
+#[cfg(any(feature = "log", feature = "tracing", feature = "env-logger"))]
+pub(crate) fn write_styled<W: CapableWriter>(
+    writer: &mut SGRWriter<W>,
+    style: Option<&SGRString>,
+    text: &str,
+) -> Result<(), W::Error> {
+    let Some(style) = style else {
+        return writer.write_inner(text);
+    };
+    writer.place_sgr(style)?;
+    writer.write_inner(text)?;
+    if style.clean == CleanKind::None {
+        writer.inline_sgr(&Style::Reset)
+    } else {
+        writer.clean_sgr(style)
+    }
+}
@@ -0,0 +1,249 @@
+use std::{
+    fmt::{self, Debug},
+    io::{self, IsTerminal},
+    sync::OnceLock,
+};
+
+use tracing_core::{
+    field::{Field, Visit},
+    Event, Level, Subscriber,
+};
+use tracing_subscriber::{
+    field::RecordFields,
+    fmt::{format::Writer, FmtContext, FormatEvent, FormatFields, FormattedFields},
+    registry::{LookupSpan, Scope},
+};
+
+use crate::{
+    theme::write_styled, CleanKind, Color::*, EasySGR, FmtWriter, SGRString, SGRWriter, Style::*,
+    Theme,
+};
+
+/// A [`tracing_subscriber`] formatter styling events with a [`Theme`]
+///
+/// Events are written as `LEVEL span{fields}: target: message key=value`,
+/// using the entries:
+///
+/// - `"error"`, `"warn"`, `"info"`, `"debug"` & `"trace"` for the level badge
+/// - `"target"` for the target
+/// - `"span"` for span names, when spans are styled
+/// - `"field"` for field keys
+///
+/// Missing entries are written plainly.
+///
+/// Colors follow the [`ColorChoice`](crate::ColorChoice) rather than
+/// `tracing_subscriber`'s own ANSI setting, detecting for stdout as
+/// [`SGRWriter::stdout`] does while none is set, keeping them consistent
+/// with the rest of this crate.
+///
+/// Span fields are styled when this is also used for
+/// [`fmt_fields`](tracing_subscriber::fmt::SubscriberBuilder::fmt_fields)
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{tracing::StyledFormat, Color::*};
+///use tracing::Level;
+///
+///let format = StyledFormat::new().level_style(Level::INFO, CyanFg);
+///tracing_subscriber::fmt()
+///    .event_format(format.clone())
+///    .fmt_fields(format)
+///    .finish();
+///```
+#[derive(Debug, Clone)]
+pub struct StyledFormat {
+    theme: Theme,
+    /// Whether styles are used, `None` following the [`ColorChoice`](crate::ColorChoice)
+    color: Option<bool>,
+    style_spans: bool,
+}
+impl StyledFormat {
+    /// Creates a formatter using [`default_theme`]
+    ///
+    /// Spans are styled, colors following the [`ColorChoice`](crate::ColorChoice)
+    /// unless set through [`StyledFormat::color`]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            theme: default_theme(),
+            color: None,
+            style_spans: true,
+        }
+    }
+    /// Sets the theme events are styled with
+    #[must_use]
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+    /// Sets the style of the badge of `level`
+    #[must_use]
+    pub fn level_style(mut self, level: Level, style: impl Into<SGRString>) -> Self {
+        self.theme.set(level_name(level), style);
+        self
+    }
+    /// Sets whether span names are styled
+    #[must_use]
+    pub const fn style_spans(mut self, style_spans: bool) -> Self {
+        self.style_spans = style_spans;
+        self
+    }
+    /// Sets whether events are styled at all
+    #[must_use]
+    pub const fn color(mut self, color: bool) -> Self {
+        self.color = Some(color);
+        self
+    }
+    /// Returns the style named `name`, `None` when not coloring
+    fn style(&self, name: &str) -> Option<&SGRString> {
+        self.theme.get(name).filter(|_| self.colored())
+    }
+    /// Returns whether events are styled, detecting for stdout unless set
+    fn colored(&self) -> bool {
+        static DETECTED: OnceLock<bool> = OnceLock::new();
+        self.color.unwrap_or_else(|| {
+            crate::detect::output_enabled(&DETECTED, || io::stdout().is_terminal())
+        })
+    }
+}
+impl Default for StyledFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<S, N> FormatEvent<S, N> for StyledFormat
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        let mut sgr_writer = SGRWriter::from(FmtWriter(writer.by_ref()));
+        let level = *event.metadata().level();
+        // padding is kept outside of the badge's escapes
+        sgr_writer.write_inner(&"     "[level.as_str().len()..])?;
+        write_styled(
+            &mut sgr_writer,
+            self.style(level_name(level)),
+            level.as_str(),
+        )?;
+        sgr_writer.write_inner(" ")?;
+
+        for span in ctx.event_scope().into_iter().flat_map(Scope::from_root) {
+            let span_style = self.style("span").filter(|_| self.style_spans);
+            write_styled(&mut sgr_writer, span_style, span.name())?;
+            if let Some(fields) = span.extensions().get::<FormattedFields<N>>() {
+                if !fields.is_empty() {
+                    sgr_writer.write_inner("{")?;
+                    sgr_writer.write_inner(fields)?;
+                    sgr_writer.write_inner("}")?;
+                }
+            }
+            sgr_writer.write_inner(": ")?;
+        }
+
+        write_styled(
+            &mut sgr_writer,
+            self.style("target"),
+            event.metadata().target(),
+        )?;
+        sgr_writer.write_inner(": ")?;
+
+        let mut visitor = FieldVisitor::new(sgr_writer, self.style("field"));
+        event.record(&mut visitor);
+        visitor.result?;
+        writeln!(writer)
+    }
+}
+impl<'writer> FormatFields<'writer> for StyledFormat {
+    fn format_fields<R: RecordFields>(&self, writer: Writer<'writer>, fields: R) -> fmt::Result {
+        let mut visitor =
+            FieldVisitor::new(SGRWriter::from(FmtWriter(writer)), self.style("field"));
+        fields.record(&mut visitor);
+        visitor.result
+    }
+}
+/// Returns the theme used by default
+///
+/// Levels are colored like `tracing_subscriber`'s own formatter,
+/// targets are dimmed, span names bold & field keys italic
+#[must_use]
+pub fn default_theme() -> Theme {
+    Theme::new()
+        .with("error", RedFg.clean(CleanKind::Reverse))
+        .with("warn", YellowFg.clean(CleanKind::Reverse))
+        .with("info", GreenFg.clean(CleanKind::Reverse))
+        .with("debug", BlueFg.clean(CleanKind::Reverse))
+        .with("trace", MagentaFg.clean(CleanKind::Reverse))
+        .with("target", Dim.clean(CleanKind::Reverse))
+        .with("span", Bold.clean(CleanKind::Reverse))
+        .with("field", Italic.clean(CleanKind::Reverse))
+}
+/// Writes the fields of an event or span
+///
+/// The `message` field is written without its key
+struct FieldVisitor<'a, 'writer> {
+    writer: SGRWriter<FmtWriter<Writer<'writer>>>,
+    key_style: Option<&'a SGRString>,
+    first: bool,
+    result: fmt::Result,
+}
+impl<'a, 'writer> FieldVisitor<'a, 'writer> {
+    const fn new(
+        writer: SGRWriter<FmtWriter<Writer<'writer>>>,
+        key_style: Option<&'a SGRString>,
+    ) -> Self {
+        Self {
+            writer,
+            key_style,
+            first: true,
+            result: Ok(()),
+        }
+    }
+    /// Writes a single field, `value` being formatted by `write_value`
+    fn field(
+        &mut self,
+        field: &Field,
+        write_value: impl FnOnce(&mut Writer<'writer>) -> fmt::Result,
+    ) -> fmt::Result {
+        if !std::mem::take(&mut self.first) {
+            self.writer.write_inner(" ")?;
+        }
+        if field.name() != "message" {
+            write_styled(&mut self.writer, self.key_style, field.name())?;
+            self.writer.write_inner("=")?;
+        }
+        write_value(self.writer.get_mut().get_mut())
+    }
+}
+impl Visit for FieldVisitor<'_, '_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if self.result.is_ok() {
+            self.result = if field.name() == "message" {
+                self.field(field, |writer| writer.write_str(value))
+            } else {
+                self.field(field, |writer| write!(writer, "{value:?}"))
+            };
+        }
+    }
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if self.result.is_ok() {
+            self.result = self.field(field, |writer| write!(writer, "{value:?}"));
+        }
+    }
+}
+/// Returns the name a level's style is found under
+const fn level_name(level: Level) -> &'static str {
+    match level {
+        Level::ERROR => "error",
+        Level::WARN => "warn",
+        Level::INFO => "info",
+        Level::DEBUG => "debug",
+        _ => "trace",
+    }
+}
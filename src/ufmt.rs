@@ -0,0 +1,136 @@
+use ::ufmt::{uDisplay, uWrite, Formatter};
+
+use crate::{
+    color_choice, writing::push_codes, CapableWriter, Color, ColorChoice, DiscreteSGR, SGRBuilder,
+    SGRString, Style,
+};
+
+/// Used to implement [`CapableWriter`] for [`uWrite`]
+///
+/// Text & SGR sequences are written through [`uWrite::write_str`],
+/// without using `core::fmt`.
+/// Only [`CapableWriter::write_fmt`] formats through `core::fmt`,
+/// so is best avoided where code size matters
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{ufmt::UfmtWriter, Color::*, SGRWriter};
+///
+///let mut writer = SGRWriter::from(UfmtWriter(String::new()));
+///writer.styled(&RedFg, "error").unwrap();
+///assert_eq!(writer.internal(), "\x1b[31merror\x1b[0m");
+///```
+#[derive(Debug, Clone)]
+pub struct UfmtWriter<W: uWrite>(pub W);
+impl<W: uWrite> UfmtWriter<W> {
+    /// Returns the writer being written to
+    pub fn into_inner(self) -> W {
+        self.0
+    }
+    /// Returns a reference to the writer being written to
+    #[must_use]
+    pub const fn get_ref(&self) -> &W {
+        &self.0
+    }
+    /// Returns a mutable reference to the writer being written to
+    pub const fn get_mut(&mut self) -> &mut W {
+        &mut self.0
+    }
+}
+impl<W: uWrite> CapableWriter for UfmtWriter<W>
+where
+    W::Error: std::error::Error,
+{
+    type Writer = W;
+    type Error = W::Error;
+    #[inline]
+    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.0.write_str(s)
+    }
+    fn get_writer(self) -> Self::Writer {
+        self.0
+    }
+}
+/// Writes the same text as [`Display`](std::fmt::Display), without using `core::fmt`
+///
+/// Follows the [`ColorChoice`] & any default style of the
+/// current thread, see [`with_default_style`](crate::context::with_default_style)
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{Color::*, EasySGR};
+///
+///let mut s = String::new();
+///ufmt::uwrite!(s, "{}", "error".to_sgr().color(RedFg)).unwrap();
+///assert_eq!(s, "\x1b[31merror");
+///```
+impl uDisplay for SGRString {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        crate::context::with_applied(self, |sgr| {
+            if color_choice() == ColorChoice::Never {
+                return f.write_str(&sgr.text);
+            }
+            let mut builder = SGRBuilder::default();
+            for seq in &sgr.raw_places {
+                f.write_str(seq)?;
+            }
+            sgr.place_all(&mut builder);
+            write_sgr(f, builder.codes())?;
+            f.write_str(&sgr.text)?;
+            builder.clear();
+            sgr.clean_all(&mut builder);
+            write_sgr(f, builder.codes())?;
+            for seq in &sgr.raw_cleans {
+                f.write_str(seq)?;
+            }
+            Ok(())
+        })
+    }
+}
+/// Writes the same text as [`Display`](std::fmt::Display), without using `core::fmt`
+///
+/// Nothing is written while the [`ColorChoice`] is never
+impl uDisplay for Color {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        discrete_display(self, f)
+    }
+}
+/// Writes the same text as [`Display`](std::fmt::Display), without using `core::fmt`
+///
+/// Nothing is written while the [`ColorChoice`] is never
+impl uDisplay for Style {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        discrete_display(self, f)
+    }
+}
+/// Writes a [`DiscreteSGR`] as [`DiscreteSGR::standard_display`] does
+fn discrete_display<W: uWrite + ?Sized>(
+    sgr: &impl DiscreteSGR,
+    f: &mut Formatter<'_, W>,
+) -> Result<(), W::Error> {
+    if color_choice() == ColorChoice::Never {
+        return Ok(());
+    }
+    let mut builder = SGRBuilder::default();
+    sgr.write(&mut builder);
+    if cfg!(feature = "partial") {
+        let mut seq = String::new();
+        push_codes(&mut seq, builder.codes());
+        f.write_str(&seq)
+    } else {
+        write_sgr(f, builder.codes())
+    }
+}
+/// Writes a complete SGR sequence made up of `codes`, nothing when there are none
+fn write_sgr<W: uWrite + ?Sized>(f: &mut Formatter<'_, W>, codes: &[u8]) -> Result<(), W::Error> {
+    if codes.is_empty() {
+        return Ok(());
+    }
+    let mut seq = String::with_capacity(codes.len() * 4 + 2);
+    seq.push_str("\x1b[");
+    push_codes(&mut seq, codes);
+    seq.push('m');
+    f.write_str(&seq)
+}
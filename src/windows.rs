@@ -0,0 +1,66 @@
+use std::io;
+
+/// Enables virtual terminal processing on the stdout & stderr console handles
+///
+/// Windows 10+ consoles only interpret SGR sequences once
+/// `ENABLE_VIRTUAL_TERMINAL_PROCESSING` is set, without it codes show up
+/// as plain text (`←[31m`). Handles that aren't consoles, i.e. when output
+/// is redirected to a file or pipe, are left alone.
+///
+/// Off of Windows this is a no-op that always returns `Ok(true)`
+///
+/// # Returns
+///
+/// - `Ok(true)` when virtual terminal processing is enabled on every console handle
+/// - `Ok(false)` when neither stdout nor stderr is a console, so nothing was enabled
+///
+/// # Errors
+///
+/// Returns the OS error when a console refuses the mode,
+/// as happens on versions of Windows older than 10
+#[allow(clippy::unnecessary_wraps, clippy::missing_const_for_fn)]
+pub fn enable_vt() -> io::Result<bool> {
+    #[cfg(windows)]
+    {
+        use windows_sys::Win32::System::Console::{STD_ERROR_HANDLE, STD_OUTPUT_HANDLE};
+
+        let stdout = enable_handle(STD_OUTPUT_HANDLE)?;
+        let stderr = enable_handle(STD_ERROR_HANDLE)?;
+        Ok(stdout || stderr)
+    }
+    #[cfg(not(windows))]
+    Ok(true)
+}
+/// Sets `ENABLE_VIRTUAL_TERMINAL_PROCESSING` on the given standard handle
+///
+/// Returns `Ok(false)` when the handle isn't a console
+#[cfg(windows)]
+#[allow(unsafe_code)]
+fn enable_handle(std_handle: windows_sys::Win32::System::Console::STD_HANDLE) -> io::Result<bool> {
+    use windows_sys::Win32::{
+        Foundation::INVALID_HANDLE_VALUE,
+        System::Console::{
+            GetConsoleMode, GetStdHandle, SetConsoleMode, ENABLE_VIRTUAL_TERMINAL_PROCESSING,
+        },
+    };
+    // SAFETY: the standard handles are owned by the process and stay valid,
+    // `mode` outlives the calls it's borrowed by
+    unsafe {
+        let handle = GetStdHandle(std_handle);
+        if handle.is_null() || handle == INVALID_HANDLE_VALUE {
+            return Ok(false);
+        }
+        let mut mode = 0;
+        if GetConsoleMode(handle, &raw mut mode) == 0 {
+            // not a console, e.g. redirected to a file
+            return Ok(false);
+        }
+        if mode & ENABLE_VIRTUAL_TERMINAL_PROCESSING != 0 {
+            return Ok(true);
+        }
+        if SetConsoleMode(handle, mode | ENABLE_VIRTUAL_TERMINAL_PROCESSING) == 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(true)
+}
@@ -0,0 +1,116 @@
+use crate::{escape::EscapeScanner, CapableWriter};
+
+/// A writer keeping count of the visible text written through it
+///
+/// Escape sequences, whether written by [`SGRWriter`](crate::SGRWriter)'s methods
+/// or embedded within plain text, are not counted, nor are control characters.
+///
+/// With the `width` feature characters are counted by the
+/// columns they take up, so wide characters count as 2
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{Color::*, CountingWriter, SGRWriter};
+///
+///let mut writer = SGRWriter::from(CountingWriter::new(String::new()));
+///writer.inline_sgr(&RedFg).unwrap();
+///writer.write_inner("Done").unwrap();
+///writer.inline_sgr(&DefaultFg).unwrap();
+///
+///assert_eq!(writer.get_ref().current_column(), 4);
+///```
+#[derive(Debug, Clone)]
+pub struct CountingWriter<W: CapableWriter> {
+    writer: W,
+    scanner: EscapeScanner,
+    column: usize,
+    total: usize,
+}
+impl<W: CapableWriter> CountingWriter<W> {
+    /// Creates a writer with both counts starting at `0`
+    #[must_use]
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            scanner: EscapeScanner::default(),
+            column: 0,
+            total: 0,
+        }
+    }
+    /// Returns the column the next character will be written to
+    ///
+    /// Starts from `0`, and is reset by `\n` & `\r`
+    #[must_use]
+    pub const fn current_column(&self) -> usize {
+        self.column
+    }
+    /// Returns the visible characters written in total
+    #[must_use]
+    pub const fn total_visible_chars(&self) -> usize {
+        self.total
+    }
+    /// Returns the writer being counted
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+    /// Returns a reference to the writer being counted
+    #[must_use]
+    pub const fn get_ref(&self) -> &W {
+        &self.writer
+    }
+    /// Returns a mutable reference to the writer being counted
+    ///
+    /// Text written to it directly isn't counted
+    pub const fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+    /// Counts the visible characters of `text`
+    fn count(&mut self, text: &str) {
+        for c in text.chars() {
+            match c {
+                '\n' | '\r' => self.column = 0,
+                c => {
+                    let width = char_width(c);
+                    self.column += width;
+                    self.total += width;
+                }
+            }
+        }
+    }
+}
+impl<W: CapableWriter> CapableWriter for CountingWriter<W> {
+    type Writer = W::Writer;
+    type Error = W::Error;
+    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+        let mut scanner = std::mem::take(&mut self.scanner);
+        scanner.scan(s, |text| self.count(text));
+        self.scanner = scanner;
+        self.writer.write(s)
+    }
+    fn write_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        self.writer.write_sgr(codes)
+    }
+    fn write_partial_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        self.writer.write_partial_sgr(codes)
+    }
+    fn write_escape(&mut self, seq: &str) -> Result<(), Self::Error> {
+        self.writer.write_escape(seq)
+    }
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.writer.flush()
+    }
+    fn get_writer(self) -> Self::Writer {
+        self.writer.get_writer()
+    }
+}
+/// Returns the columns taken up by `c`
+#[cfg(feature = "width")]
+pub fn char_width(c: char) -> usize {
+    unicode_width::UnicodeWidthChar::width(c).unwrap_or(0)
+}
+/// Returns the columns taken up by `c`
+#[cfg(not(feature = "width"))]
+pub fn char_width(c: char) -> usize {
+    usize::from(!c.is_control())
+}
@@ -0,0 +1,123 @@
+use std::convert::Infallible;
+
+use crate::{
+    escape::{EscapeScanner, Part},
+    CapableWriter, SgrState,
+};
+
+/// What is written to an [`EventWriter`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StyleEvent<'a> {
+    /// Plain text, without any escape sequences
+    Text(&'a str),
+    /// An SGR sequence was written, leaving the [`SgrState`] given
+    SgrChanged(SgrState),
+    /// An escape sequence that isn't SGR, such as a hyperlink
+    ///
+    /// Only the end of a sequence split over several writes is given
+    Escape(&'a str),
+}
+/// A writer calling back with what is written, rather than writing bytes
+///
+/// SGR codes, whether written by [`SGRWriter`](crate::SGRWriter)'s methods
+/// or embedded within plain text, are applied to an [`SgrState`]
+/// given through [`StyleEvent::SgrChanged`],
+/// so text may be rendered elsewhere, e.g. into a GUI widget
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{Color::*, ColorKind, EventWriter, SGRWriter, StyleEvent};
+///
+///let mut events = Vec::new();
+///let mut writer = SGRWriter::from(EventWriter::new(|event| match event {
+///    StyleEvent::Text(text) => events.push(format!("{text:?}")),
+///    StyleEvent::SgrChanged(state) => events.push(format!("{:?}", state.foreground)),
+///    StyleEvent::Escape(_) => (),
+///}));
+///writer.inline_sgr(&RedFg)?;
+///writer.write_inner("error\x1b[39m!")?;
+///drop(writer);
+///
+///assert_eq!(events, ["Red", "\"error\"", "None", "\"!\""]);
+///# Ok::<(), std::convert::Infallible>(())
+///```
+#[derive(Debug, Clone)]
+pub struct EventWriter<F: FnMut(StyleEvent<'_>)> {
+    on_event: F,
+    state: SgrState,
+    scanner: EscapeScanner,
+}
+impl<F: FnMut(StyleEvent<'_>)> EventWriter<F> {
+    /// Creates a writer calling `on_event` with what is written to it
+    #[must_use]
+    pub fn new(on_event: F) -> Self {
+        Self {
+            on_event,
+            state: SgrState::new(),
+            scanner: EscapeScanner::default(),
+        }
+    }
+    /// Returns the state left by the SGR codes written so far
+    #[must_use]
+    pub const fn state(&self) -> SgrState {
+        self.state
+    }
+    /// Returns the callback
+    pub fn into_inner(self) -> F {
+        self.on_event
+    }
+    /// Returns a reference to the callback
+    #[must_use]
+    pub const fn get_ref(&self) -> &F {
+        &self.on_event
+    }
+    /// Returns a mutable reference to the callback
+    pub const fn get_mut(&mut self) -> &mut F {
+        &mut self.on_event
+    }
+    /// Applies `codes`, calling back with the new state
+    fn apply(&mut self, codes: &[u8]) {
+        self.state.apply(codes);
+        (self.on_event)(StyleEvent::SgrChanged(self.state));
+    }
+}
+/// Returns the callback as its [`CapableWriter::Writer`]
+impl<F: FnMut(StyleEvent<'_>)> CapableWriter for EventWriter<F> {
+    type Writer = F;
+    type Error = Infallible;
+    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+        let (on_event, state) = (&mut self.on_event, &mut self.state);
+        // the escape following the codes of an SGR sequence is the same sequence
+        let mut after_sgr = false;
+        self.scanner.scan_parts(s, |part| match part {
+            Part::Text(range) => on_event(StyleEvent::Text(&s[range])),
+            Part::Sgr(codes) => {
+                state.apply(codes);
+                on_event(StyleEvent::SgrChanged(*state));
+                after_sgr = true;
+            }
+            Part::Escape(_) if after_sgr => after_sgr = false,
+            Part::Escape(range) => on_event(StyleEvent::Escape(&s[range])),
+        });
+        Ok(())
+    }
+    fn write_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        self.apply(codes);
+        Ok(())
+    }
+    fn write_partial_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        self.apply(codes);
+        Ok(())
+    }
+    fn write_escape(&mut self, seq: &str) -> Result<(), Self::Error> {
+        (self.on_event)(StyleEvent::Escape(seq));
+        Ok(())
+    }
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+    fn get_writer(self) -> Self::Writer {
+        self.on_event
+    }
+}
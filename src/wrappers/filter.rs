@@ -0,0 +1,144 @@
+use crate::{
+    escape::{EscapeScanner, Part},
+    CapableWriter, SGRBuilder,
+};
+
+/// A writer letting through only the SGR codes matching a predicate
+///
+/// Codes written by [`SGRWriter`](crate::SGRWriter)'s methods are filtered,
+/// as are the SGR sequences embedded within plain text.
+/// The extended colors `38`, `48` & `58` are kept or removed along with their parameters,
+/// by whether their first code matches.
+/// A sequence left without any codes isn't written at all.
+///
+/// Sequences written through [`SGRWriter::write_escape`](crate::SGRWriter::write_escape)
+/// & embedded sequences that aren't SGR are passed through as is.
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{Color::*, EasySGR, FilterWriter, SGRWriter, Style::*};
+///
+///let mut writer = SGRWriter::from(FilterWriter::monochrome(String::new()));
+///writer.sgr(&RedFg.style(Bold)).unwrap();
+///writer.write_inner("bold \x1b[38;5;208;4munderlined").unwrap();
+///writer.inline_sgr(&BlueBg).unwrap();
+///
+///assert_eq!(writer.internal(), "\x1b[1mbold \x1b[4munderlined");
+///```
+#[derive(Debug, Clone)]
+pub struct FilterWriter<W: CapableWriter> {
+    writer: W,
+    keep: fn(u8) -> bool,
+    scanner: EscapeScanner,
+    /// The start of a sequence yet to be completed by a later write
+    pending: String,
+}
+impl<W: CapableWriter> FilterWriter<W> {
+    /// Creates a writer letting through the codes for which `keep` returns `true`
+    #[must_use]
+    pub fn new(writer: W, keep: fn(u8) -> bool) -> Self {
+        Self {
+            writer,
+            keep,
+            scanner: EscapeScanner::default(),
+            pending: String::new(),
+        }
+    }
+    /// Creates a writer removing every color, keeping styles such as bold & underline
+    ///
+    /// Removes the foreground & background colors `30`-`49`, `90`-`97` & `100`-`107`,
+    /// along with the underline colors `58` & `59`
+    #[must_use]
+    pub fn monochrome(writer: W) -> Self {
+        Self::new(
+            writer,
+            |code| !matches!(code, 30..=49 | 58 | 59 | 90..=97 | 100..=107),
+        )
+    }
+    /// Returns the writer being filtered for
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+    /// Returns a reference to the writer being filtered for
+    #[must_use]
+    pub const fn get_ref(&self) -> &W {
+        &self.writer
+    }
+    /// Returns a mutable reference to the writer being filtered for
+    pub const fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+    /// Returns the codes matching the predicate
+    fn filter(&self, codes: &[u8]) -> SGRBuilder {
+        let mut builder = SGRBuilder::default();
+        let mut rest = codes;
+        while let Some(&code) = rest.first() {
+            let len = match (code, rest.get(1)) {
+                (38 | 48 | 58, Some(5)) => 3,
+                (38 | 48 | 58, Some(2)) => 5,
+                _ => 1,
+            }
+            .min(rest.len());
+            if (self.keep)(code) {
+                builder.write_codes(&rest[..len]);
+            }
+            rest = &rest[len..];
+        }
+        builder
+    }
+}
+impl<W: CapableWriter> CapableWriter for FilterWriter<W> {
+    type Writer = W::Writer;
+    type Error = W::Error;
+    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+        let mut scanner = std::mem::take(&mut self.scanner);
+        let mut result = Ok(());
+        let mut end = 0;
+        let mut sgr = false;
+        scanner.scan_parts(s, |part| {
+            if result.is_err() {
+                return;
+            }
+            match part {
+                Part::Text(range) => {
+                    end = range.end;
+                    result = self.writer.write(&s[range]);
+                }
+                Part::Sgr(codes) => {
+                    sgr = true;
+                    result = self.filter(codes).write_to(&mut self.writer);
+                }
+                Part::Escape(range) => {
+                    end = range.end;
+                    let start = std::mem::take(&mut self.pending);
+                    if !std::mem::take(&mut sgr) {
+                        result = self
+                            .writer
+                            .write(&start)
+                            .and_then(|()| self.writer.write(&s[range]));
+                    }
+                }
+            }
+        });
+        self.scanner = scanner;
+        result?;
+        self.pending.push_str(&s[end..]);
+        Ok(())
+    }
+    fn write_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        self.filter(codes).write_to(&mut self.writer)
+    }
+    fn write_partial_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        self.filter(codes).write_partial(&mut self.writer)
+    }
+    fn write_escape(&mut self, seq: &str) -> Result<(), Self::Error> {
+        self.writer.write_escape(seq)
+    }
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.writer.flush()
+    }
+    fn get_writer(self) -> Self::Writer {
+        self.writer.get_writer()
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{
+    escape::{EscapeScanner, Part},
+    writing::push_codes,
+    CapableWriter, SGRBuilder, SgrState,
+};
+
+/// A writer making each line it writes self-contained
+///
+/// Keeps track of the active [`SgrState`], from both the SGR codes written by
+/// [`SGRWriter`](crate::SGRWriter)'s methods & the sequences embedded within plain text.
+/// A reset is written before every `\n` while anything is applied,
+/// and the state is written again before the text of the next line.
+///
+/// Sequences written through [`SGRWriter::write_escape`](crate::SGRWriter::write_escape)
+/// are passed through as is, without updating the state.
+///
+/// Useful when output goes through tools resetting styles at line boundaries,
+/// such as pagers & log viewers
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{Color::*, LineResetWriter, SGRWriter};
+///
+///let mut writer = SGRWriter::from(LineResetWriter::new(String::new()));
+///writer.inline_sgr(&RedFg).unwrap();
+///writer.write_inner("first\nsecond").unwrap();
+///writer.inline_sgr(&DefaultFg).unwrap();
+///
+///assert_eq!(
+///    writer.internal(),
+///    "\x1b[31mfirst\x1b[0m\n\x1b[31msecond\x1b[39m"
+///);
+///```
+#[derive(Debug, Clone)]
+pub struct LineResetWriter<W: CapableWriter> {
+    writer: W,
+    scanner: EscapeScanner,
+    state: SgrState,
+    /// Whether a line was ended, the state not having been written since
+    restore: bool,
+}
+impl<W: CapableWriter> LineResetWriter<W> {
+    /// Creates a writer making lines written to `writer` self-contained
+    #[must_use]
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            scanner: EscapeScanner::default(),
+            state: SgrState::new(),
+            restore: false,
+        }
+    }
+    /// Returns the state active at the end of what has been written
+    #[must_use]
+    pub const fn state(&self) -> &SgrState {
+        &self.state
+    }
+    /// Returns the writer lines are written to
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+    /// Returns a reference to the writer lines are written to
+    #[must_use]
+    pub const fn get_ref(&self) -> &W {
+        &self.writer
+    }
+    /// Returns a mutable reference to the writer lines are written to
+    ///
+    /// SGR codes written to it directly aren't tracked
+    pub const fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+    /// Writes the state again if a line was ended
+    fn restore(&mut self) -> Result<(), W::Error> {
+        if std::mem::take(&mut self.restore) {
+            let mut builder = SGRBuilder::default();
+            self.state.place(&mut builder);
+            builder.write_to(&mut self.writer)?;
+        }
+        Ok(())
+    }
+    /// Writes `line`, then ends it
+    fn end_line(&mut self, line: &str) -> Result<(), W::Error> {
+        if !line.is_empty() {
+            self.restore()?;
+            self.writer.write(line)?;
+        }
+        if !self.restore && !self.state.is_default() {
+            self.writer.write_sgr(&[0])?;
+        }
+        self.restore = true;
+        self.writer.write("\n")
+    }
+}
+impl<W: CapableWriter> CapableWriter for LineResetWriter<W> {
+    type Writer = W::Writer;
+    type Error = W::Error;
+    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+        let mut scanner = std::mem::take(&mut self.scanner);
+        let mut result = Ok(());
+        let mut start = 0;
+        scanner.scan_parts(s, |part| match part {
+            Part::Sgr(codes) => {
+                // the state before the sequence, as the line is yet to be written
+                if result.is_ok() {
+                    result = self.restore();
+                }
+                self.state.apply(codes);
+            }
+            Part::Text(range) => {
+                for (i, _) in s[range.clone()].match_indices('\n') {
+                    let end = range.start + i;
+                    if result.is_ok() {
+                        result = self.end_line(&s[start..end]);
+                    }
+                    start = end + 1;
+                }
+            }
+            Part::Escape(_) => (),
+        });
+        self.scanner = scanner;
+        result?;
+        if start < s.len() {
+            self.restore()?;
+            self.writer.write(&s[start..])?;
+        }
+        Ok(())
+    }
+    fn write_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        self.state.apply(codes);
+        // written along with the rest of the state once the next line starts
+        if self.restore {
+            Ok(())
+        } else {
+            self.writer.write_sgr(codes)
+        }
+    }
+    fn write_partial_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        let mut params = String::new();
+        push_codes(&mut params, codes);
+        self.scanner.scan(&params, |_| ());
+        self.writer.write_partial_sgr(codes)
+    }
+    fn write_escape(&mut self, seq: &str) -> Result<(), Self::Error> {
+        self.writer.write_escape(seq)
+    }
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.writer.flush()
+    }
+    fn get_writer(self) -> Self::Writer {
+        self.writer.get_writer()
+    }
+}
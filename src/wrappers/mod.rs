@@ -0,0 +1,20 @@
+mod counting;
+mod event;
+mod filter;
+mod line_reset;
+mod prefix;
+mod shared;
+mod strip;
+mod tee;
+
+pub(crate) use self::counting::char_width;
+pub use self::{
+    counting::CountingWriter,
+    event::{EventWriter, StyleEvent},
+    filter::FilterWriter,
+    line_reset::LineResetWriter,
+    prefix::PrefixWriter,
+    shared::{SharedError, SharedGuard, SharedWriter},
+    strip::{StripReader, StripWriter},
+    tee::{Tee, TeeError, TeePolicy},
+};
@@ -0,0 +1,190 @@
+use std::fmt::{self, Debug};
+
+use crate::{
+    escape::{EscapeScanner, Part},
+    writing::push_codes,
+    CapableWriter, CleanKind, SGRBuilder, SGRString, SgrState,
+};
+
+/// Returns the prefix of a line by its number
+type PrefixFn = dyn FnMut(usize) -> SGRString + Send;
+
+/// A writer starting every line with a styled prefix
+///
+/// The prefix is written before the first character of each line,
+/// so text ending in `\n` isn't followed by a dangling prefix.
+///
+/// The style of the body, tracked as an [`SgrState`], doesn't leak into the prefix:
+/// it is reset before the prefix and written again after the prefix's clean.
+/// A prefix without a clean is ended by a reset.
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{CleanKind, Color::*, EasySGR, PrefixWriter, SGRWriter};
+///
+///let prefix = "| ".color(BlueFg).clean(CleanKind::Reverse);
+///let mut writer = SGRWriter::from(PrefixWriter::new(String::new(), prefix));
+///writer.write_inner("first\nsecond\n").unwrap();
+///
+///assert_eq!(
+///    writer.internal(),
+///    "\x1b[34m| \x1b[39mfirst\n\x1b[34m| \x1b[39msecond\n"
+///);
+///```
+pub struct PrefixWriter<W: CapableWriter> {
+    writer: W,
+    prefix: Box<PrefixFn>,
+    scanner: EscapeScanner,
+    state: SgrState,
+    /// The number of the last line a prefix was written for
+    line: usize,
+    /// Whether nothing has been written of the current line
+    line_start: bool,
+}
+impl<W: CapableWriter> PrefixWriter<W> {
+    /// Creates a writer starting every line written to `writer` with `prefix`
+    #[must_use]
+    pub fn new(writer: W, prefix: impl Into<SGRString>) -> Self {
+        let prefix = prefix.into();
+        Self::with_prefix_fn(writer, move |_| prefix.clone())
+    }
+    /// Creates a writer starting every line written to `writer`
+    /// with the prefix returned by `prefix`
+    ///
+    /// `prefix` is given the number of the line, starting from `1`
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{PrefixWriter, SGRWriter};
+    ///
+    ///let mut writer = SGRWriter::from(PrefixWriter::with_prefix_fn(String::new(), |line| {
+    ///    format!("{line}: ").into()
+    ///}));
+    ///writer.write_inner("first\nsecond").unwrap();
+    ///
+    ///assert_eq!(writer.internal(), "1: first\n2: second");
+    ///```
+    #[must_use]
+    pub fn with_prefix_fn(
+        writer: W,
+        prefix: impl FnMut(usize) -> SGRString + Send + 'static,
+    ) -> Self {
+        Self {
+            writer,
+            prefix: Box::new(prefix),
+            scanner: EscapeScanner::default(),
+            state: SgrState::new(),
+            line: 0,
+            line_start: true,
+        }
+    }
+    /// Returns the number of lines a prefix has been written for
+    #[must_use]
+    pub const fn lines(&self) -> usize {
+        self.line
+    }
+    /// Returns the writer lines are written to
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+    /// Returns a reference to the writer lines are written to
+    #[must_use]
+    pub const fn get_ref(&self) -> &W {
+        &self.writer
+    }
+    /// Returns a mutable reference to the writer lines are written to
+    ///
+    /// Lines written to it directly aren't given a prefix
+    pub const fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+    /// Writes the prefix of the next line
+    fn write_prefix(&mut self) -> Result<(), W::Error> {
+        self.line += 1;
+        let prefix = (self.prefix)(self.line);
+
+        let mut place = SGRBuilder::default();
+        prefix.place_all(&mut place);
+        let mut builder = SGRBuilder::default();
+        if !self.state.is_default() {
+            builder.write_code(0);
+        }
+        builder.write_codes(place.codes());
+        builder.write_to(&mut self.writer)?;
+        self.writer.write(&prefix.text)?;
+
+        let mut builder = SGRBuilder::default();
+        if prefix.clean == CleanKind::None && !place.is_empty() {
+            builder.write_code(0);
+        }
+        prefix.clean_all(&mut builder);
+        self.state.place(&mut builder);
+        builder.write_to(&mut self.writer)
+    }
+}
+impl<W: CapableWriter> CapableWriter for PrefixWriter<W> {
+    type Writer = W::Writer;
+    type Error = W::Error;
+    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+        let mut scanner = std::mem::take(&mut self.scanner);
+        let mut result = Ok(());
+        let mut start = 0;
+        scanner.scan_parts(s, |part| match part {
+            Part::Sgr(codes) => self.state.apply(codes),
+            Part::Text(range) => {
+                let mut pos = range.start;
+                while pos < range.end && result.is_ok() {
+                    if std::mem::take(&mut self.line_start) {
+                        result = self
+                            .writer
+                            .write(&s[start..pos])
+                            .and_then(|()| self.write_prefix());
+                        start = pos;
+                    }
+                    let Some(i) = s[pos..range.end].find('\n') else {
+                        break;
+                    };
+                    pos += i + 1;
+                    self.line_start = true;
+                }
+            }
+            Part::Escape(_) => (),
+        });
+        self.scanner = scanner;
+        result?;
+        if start < s.len() {
+            self.writer.write(&s[start..])?;
+        }
+        Ok(())
+    }
+    fn write_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        self.state.apply(codes);
+        self.writer.write_sgr(codes)
+    }
+    fn write_partial_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        let mut params = String::new();
+        push_codes(&mut params, codes);
+        self.scanner.scan(&params, |_| ());
+        self.writer.write_partial_sgr(codes)
+    }
+    fn write_escape(&mut self, seq: &str) -> Result<(), Self::Error> {
+        self.writer.write_escape(seq)
+    }
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.writer.flush()
+    }
+    fn get_writer(self) -> Self::Writer {
+        self.writer.get_writer()
+    }
+}
+impl<W: CapableWriter + Debug> Debug for PrefixWriter<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PrefixWriter")
+            .field("writer", &self.writer)
+            .field("state", &self.state)
+            .field("line", &self.line)
+            .finish_non_exhaustive()
+    }
+}
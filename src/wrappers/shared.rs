@@ -0,0 +1,191 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use crate::{CapableWriter, SGRWriter};
+
+/// A writer shared between threads, locked for each write
+///
+/// Each call made through it, such as a single [`SGRWriter`] method,
+/// is written under its own lock.
+/// Use [`SharedWriter::lock`] to write several parts, such as a whole styled line,
+/// without those of other threads being written in between
+///
+/// A writer poisoned by a thread panicking while holding the lock
+/// is reported through [`SharedError::Poisoned`]
+///
+/// # Examples
+///
+///```rust
+///use std::thread;
+///
+///use easy_sgr::{Color::*, SGRWriter, SharedWriter};
+///
+///let shared = SharedWriter::new(String::new());
+///let handles: Vec<_> = [RedFg, BlueFg]
+///    .into_iter()
+///    .map(|color| {
+///        let shared = shared.clone();
+///        thread::spawn(move || {
+///            let mut writer = shared.lock().unwrap();
+///            writer.styled(&color, "line").unwrap();
+///            writer.write_inner("\n").unwrap();
+///        })
+///    })
+///    .collect();
+///for handle in handles {
+///    handle.join().unwrap();
+///}
+///
+///let written = shared.lock().unwrap().internal().clone();
+///assert!(written.lines().all(|line| line.ends_with("line\x1b[0m")));
+///```
+#[derive(Debug)]
+pub struct SharedWriter<W: CapableWriter> {
+    writer: Arc<Mutex<W>>,
+}
+/// The writer locked by [`SharedWriter::lock`]
+///
+/// The lock is held until it is dropped
+#[derive(Debug)]
+pub struct SharedGuard<'a, W: CapableWriter>(MutexGuard<'a, W>);
+/// The error of a [`SharedWriter`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SharedError<E> {
+    /// A thread panicked while holding the lock, nothing was written
+    Poisoned,
+    /// Writing to the shared writer failed
+    Write(E),
+}
+impl<W: CapableWriter> SharedWriter<W> {
+    /// Creates a writer shared by each of its clones
+    #[must_use]
+    pub fn new(writer: W) -> Self {
+        Self::from_arc(Arc::new(Mutex::new(writer)))
+    }
+    /// Creates a writer sharing an existing mutex
+    #[must_use]
+    pub const fn from_arc(writer: Arc<Mutex<W>>) -> Self {
+        Self { writer }
+    }
+    /// Returns the mutex shared by each clone
+    #[must_use]
+    pub fn into_inner(self) -> Arc<Mutex<W>> {
+        self.writer
+    }
+    /// Returns a reference to the mutex shared by each clone
+    #[must_use]
+    pub const fn get_ref(&self) -> &Arc<Mutex<W>> {
+        &self.writer
+    }
+    /// Returns a mutable reference to the mutex shared by each clone,
+    /// e.g. for [`Arc::get_mut`] when there are no other clones
+    pub const fn get_mut(&mut self) -> &mut Arc<Mutex<W>> {
+        &mut self.writer
+    }
+    /// Locks the writer, returning an [`SGRWriter`] over it
+    ///
+    /// Other threads wait for the writer to be dropped before writing
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SharedError::Poisoned`] if a thread panicked while holding the lock
+    pub fn lock(&self) -> Result<SGRWriter<SharedGuard<'_, W>>, SharedError<W::Error>> {
+        self.writer
+            .lock()
+            .map(|guard| SGRWriter::from(SharedGuard(guard)))
+            .map_err(|_| SharedError::Poisoned)
+    }
+    /// Calls `f` with the writer locked
+    fn locked(
+        &self,
+        f: impl FnOnce(&mut W) -> Result<(), W::Error>,
+    ) -> Result<(), SharedError<W::Error>> {
+        let mut writer = self.writer.lock().map_err(|_| SharedError::Poisoned)?;
+        f(&mut writer).map_err(SharedError::Write)
+    }
+}
+impl<W: CapableWriter> Clone for SharedWriter<W> {
+    fn clone(&self) -> Self {
+        Self::from_arc(Arc::clone(&self.writer))
+    }
+}
+/// Returns the shared mutex as its [`CapableWriter::Writer`]
+impl<W: CapableWriter> CapableWriter for SharedWriter<W>
+where
+    W::Error: 'static,
+{
+    type Writer = Arc<Mutex<W>>;
+    type Error = SharedError<W::Error>;
+    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.locked(|w| w.write(s))
+    }
+    fn write_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        self.locked(|w| w.write_sgr(codes))
+    }
+    fn write_partial_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        self.locked(|w| w.write_partial_sgr(codes))
+    }
+    fn write_escape(&mut self, seq: &str) -> Result<(), Self::Error> {
+        self.locked(|w| w.write_escape(seq))
+    }
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> Result<(), Self::Error> {
+        self.locked(|w| w.write_fmt(args))
+    }
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.locked(CapableWriter::flush)
+    }
+    fn get_writer(self) -> Self::Writer {
+        self.into_inner()
+    }
+}
+/// Returns the lock itself as its [`CapableWriter::Writer`]
+impl<'a, W: CapableWriter> CapableWriter for SharedGuard<'a, W> {
+    type Writer = MutexGuard<'a, W>;
+    type Error = W::Error;
+    #[inline]
+    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.0.write(s)
+    }
+    #[inline]
+    fn write_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        self.0.write_sgr(codes)
+    }
+    #[inline]
+    fn write_partial_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        self.0.write_partial_sgr(codes)
+    }
+    #[inline]
+    fn write_escape(&mut self, seq: &str) -> Result<(), Self::Error> {
+        self.0.write_escape(seq)
+    }
+    #[inline]
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> Result<(), Self::Error> {
+        self.0.write_fmt(args)
+    }
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.0.flush()
+    }
+    fn get_writer(self) -> Self::Writer {
+        self.0
+    }
+}
+impl<E: Display> Display for SharedError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Poisoned => f.write_str("The shared writer was poisoned"),
+            Self::Write(e) => write!(f, "Writing to the shared writer failed: {e}"),
+        }
+    }
+}
+impl<E: Error + 'static> Error for SharedError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Poisoned => None,
+            Self::Write(e) => Some(e),
+        }
+    }
+}
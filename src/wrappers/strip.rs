@@ -0,0 +1,209 @@
+use std::io::{self, BufRead, Read};
+
+use crate::{
+    escape::{EscapeScanner, Part},
+    CapableWriter,
+};
+
+/// A writer only letting plain text through
+///
+/// SGR codes & escape sequences written by [`SGRWriter`](crate::SGRWriter)'s methods
+/// are dropped, as are escape sequences embedded within plain text
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{Color::*, SGRWriter, StripWriter};
+///
+///let mut writer = SGRWriter::from(StripWriter::new(String::new()));
+///writer.inline_sgr(&RedFg).unwrap();
+///writer.write_inner("plain \x1b[1mtext").unwrap();
+///
+///assert_eq!(writer.internal(), "plain text");
+///```
+#[derive(Debug, Clone)]
+pub struct StripWriter<W: CapableWriter> {
+    writer: W,
+    scanner: EscapeScanner,
+}
+impl<W: CapableWriter> StripWriter<W> {
+    /// Creates a writer stripping what is written to `writer`
+    #[must_use]
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            scanner: EscapeScanner::default(),
+        }
+    }
+    /// Returns the writer being stripped for
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+    /// Returns a reference to the writer being stripped for
+    #[must_use]
+    pub const fn get_ref(&self) -> &W {
+        &self.writer
+    }
+    /// Returns a mutable reference to the writer being stripped for
+    pub const fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+}
+impl<W: CapableWriter> CapableWriter for StripWriter<W> {
+    type Writer = W::Writer;
+    type Error = W::Error;
+    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+        let mut result = Ok(());
+        self.scanner.scan(s, |text| {
+            if result.is_ok() {
+                result = self.writer.write(text);
+            }
+        });
+        result
+    }
+    fn write_sgr(&mut self, _: &[u8]) -> Result<(), Self::Error> {
+        Ok(())
+    }
+    fn write_partial_sgr(&mut self, _: &[u8]) -> Result<(), Self::Error> {
+        Ok(())
+    }
+    fn write_escape(&mut self, _: &str) -> Result<(), Self::Error> {
+        Ok(())
+    }
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.writer.flush()
+    }
+    fn get_writer(self) -> Self::Writer {
+        self.writer.get_writer()
+    }
+}
+/// A reader removing SGR sequences from what it reads, such as a subprocess' output
+///
+/// Sequences split between reads are still removed, only an unfinished sequence
+/// being kept between them. One unfinished at the end of the stream is dropped.
+/// Bytes outside of sequences are passed through as they are, UTF-8 or not
+///
+/// Other escape sequences, such as cursor movements or hyperlinks,
+/// are passed through unless [`StripReader::strip_all`] is set
+///
+/// Also implements [`BufRead`], e.g. for reading [`BufRead::lines`]
+///
+/// # Examples
+///
+///```rust
+///use std::io::{BufRead, Read};
+///
+///use easy_sgr::StripReader;
+///
+///let output = b"\x1b[1;31merror\x1b[0m: \x1b[2Kbad\nok";
+///let mut stripped = String::new();
+///StripReader::new(&output[..]).read_to_string(&mut stripped)?;
+///assert_eq!(stripped, "error: \x1b[2Kbad\nok");
+///
+///let lines = StripReader::new(&output[..]).strip_all(true).lines();
+///assert_eq!(lines.collect::<Result<Vec<_>, _>>()?, ["error: bad", "ok"]);
+///# Ok::<(), std::io::Error>(())
+///```
+#[derive(Debug, Clone)]
+pub struct StripReader<R: Read> {
+    reader: R,
+    scanner: EscapeScanner,
+    all: bool,
+    /// The start of a sequence continuing into the next read
+    pending: Vec<u8>,
+    /// What has been read & stripped
+    stripped: Vec<u8>,
+    /// How much of `stripped` has been consumed
+    pos: usize,
+}
+impl<R: Read> StripReader<R> {
+    /// Creates a reader removing the SGR sequences read from `reader`
+    #[must_use]
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            scanner: EscapeScanner::default(),
+            all: false,
+            pending: Vec::new(),
+            stripped: Vec::new(),
+            pos: 0,
+        }
+    }
+    /// Sets whether every escape sequence is removed, rather than only SGR sequences
+    #[must_use]
+    pub const fn strip_all(mut self, all: bool) -> Self {
+        self.all = all;
+        self
+    }
+    /// Returns the reader being stripped
+    ///
+    /// Anything read but not yet consumed is lost
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+    /// Returns a reference to the reader being stripped
+    #[must_use]
+    pub const fn get_ref(&self) -> &R {
+        &self.reader
+    }
+    /// Returns a mutable reference to the reader being stripped
+    ///
+    /// Reading from it directly skips what has been read but not yet consumed
+    pub const fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+    /// Strips `chunk` into [`StripReader::stripped`]
+    fn strip(&mut self, chunk: &[u8]) {
+        let (all, pending, stripped) = (self.all, &mut self.pending, &mut self.stripped);
+        // the end of the last run of text or sequence
+        let mut end = 0;
+        self.scanner.scan_bytes(chunk, |part| match part {
+            Part::Text(range) => {
+                stripped.extend_from_slice(&chunk[range.clone()]);
+                end = range.end;
+            }
+            Part::Sgr(_) => (),
+            Part::Escape(range) => {
+                pending.extend_from_slice(&chunk[range.clone()]);
+                let sgr = pending.starts_with(b"\x1b[") && pending.ends_with(b"m");
+                if !(all || sgr) {
+                    stripped.extend_from_slice(pending);
+                }
+                pending.clear();
+                end = range.end;
+            }
+        });
+        if !all {
+            pending.extend_from_slice(&chunk[end..]);
+        }
+    }
+}
+impl<R: Read> Read for StripReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let len = available.len().min(buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        self.consume(len);
+        Ok(len)
+    }
+}
+impl<R: Read> BufRead for StripReader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        let mut chunk = [0; 4096];
+        // chunks made up only of sequences are skipped rather than ending the stream
+        while self.pos == self.stripped.len() {
+            let len = self.reader.read(&mut chunk)?;
+            if len == 0 {
+                self.pending.clear();
+                break;
+            }
+            self.stripped.clear();
+            self.pos = 0;
+            self.strip(&chunk[..len]);
+        }
+        Ok(&self.stripped[self.pos..])
+    }
+    fn consume(&mut self, amt: usize) {
+        self.pos = (self.pos + amt).min(self.stripped.len());
+    }
+}
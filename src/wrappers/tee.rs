@@ -0,0 +1,156 @@
+use std::{error::Error, fmt::Display};
+
+use crate::CapableWriter;
+
+/// A writer writing everything to two other writers
+///
+/// Each leg can be a different type of writer, e.g. a [`StripWriter`](crate::StripWriter)
+/// over a log file next to a terminal
+///
+/// Legs are written to in order, what happens when one fails
+/// is decided by its [`TeePolicy`]
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{Color::*, SGRWriter, StripWriter, Tee};
+///
+///let tee = Tee::new(
+///    String::new(),
+///    StripWriter::new(String::new()),
+///);
+///let mut writer = SGRWriter::from(tee);
+///writer.inline_sgr(&RedFg).unwrap();
+///writer.write_inner("red").unwrap();
+///
+///let (colored, plain) = writer.internal();
+///assert_eq!(colored, "\x1b[31mred");
+///assert_eq!(plain, "red");
+///```
+#[derive(Debug, Clone)]
+pub struct Tee<A: CapableWriter, B: CapableWriter> {
+    /// The leg written to first
+    #[deprecated(note = "use `get_ref`, `get_mut` or `into_inner` instead")]
+    pub first: A,
+    /// The leg written to second
+    #[deprecated(note = "use `get_ref`, `get_mut` or `into_inner` instead")]
+    pub second: B,
+    policy: TeePolicy,
+}
+/// What a [`Tee`] does when writing to one of its legs fails
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TeePolicy {
+    /// Return the error immediately, without writing to the following leg
+    #[default]
+    FailFast,
+    /// Still write to the following leg, then return the error
+    Continue,
+}
+/// The error of a [`Tee`], telling which leg failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TeeError<A, B> {
+    /// Writing to the first leg failed
+    First(A),
+    /// Writing to the second leg failed
+    Second(B),
+    /// Writing to both legs failed, only with [`TeePolicy::Continue`]
+    Both(A, B),
+}
+impl<A: CapableWriter, B: CapableWriter> Tee<A, B> {
+    /// Creates a tee over both legs using [`TeePolicy::FailFast`]
+    #[must_use]
+    #[allow(deprecated)]
+    pub const fn new(first: A, second: B) -> Self {
+        Self {
+            first,
+            second,
+            policy: TeePolicy::FailFast,
+        }
+    }
+    /// Sets what happens when writing to a leg fails
+    #[must_use]
+    pub const fn policy(mut self, policy: TeePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+    /// Returns both legs
+    #[allow(deprecated)]
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+    /// Returns a reference to both legs
+    #[must_use]
+    #[allow(deprecated)]
+    pub const fn get_ref(&self) -> (&A, &B) {
+        (&self.first, &self.second)
+    }
+    /// Returns a mutable reference to both legs
+    #[allow(deprecated)]
+    pub const fn get_mut(&mut self) -> (&mut A, &mut B) {
+        (&mut self.first, &mut self.second)
+    }
+    /// Calls `first` then `second` according to the policy
+    fn both(
+        &mut self,
+        first: impl FnOnce(&mut A) -> Result<(), A::Error>,
+        second: impl FnOnce(&mut B) -> Result<(), B::Error>,
+    ) -> Result<(), TeeError<A::Error, B::Error>> {
+        let policy = self.policy;
+        let (first_leg, second_leg) = self.get_mut();
+        match (first(first_leg), policy) {
+            (Ok(()), _) => second(second_leg).map_err(TeeError::Second),
+            (Err(a), TeePolicy::FailFast) => Err(TeeError::First(a)),
+            (Err(a), TeePolicy::Continue) => match second(second_leg) {
+                Ok(()) => Err(TeeError::First(a)),
+                Err(b) => Err(TeeError::Both(a, b)),
+            },
+        }
+    }
+}
+impl<A: CapableWriter, B: CapableWriter> CapableWriter for Tee<A, B>
+where
+    A::Error: 'static,
+    B::Error: 'static,
+{
+    type Writer = (A::Writer, B::Writer);
+    type Error = TeeError<A::Error, B::Error>;
+    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.both(|a| a.write(s), |b| b.write(s))
+    }
+    fn write_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        self.both(|a| a.write_sgr(codes), |b| b.write_sgr(codes))
+    }
+    fn write_partial_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        self.both(
+            |a| a.write_partial_sgr(codes),
+            |b| b.write_partial_sgr(codes),
+        )
+    }
+    fn write_escape(&mut self, seq: &str) -> Result<(), Self::Error> {
+        self.both(|a| a.write_escape(seq), |b| b.write_escape(seq))
+    }
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.both(CapableWriter::flush, CapableWriter::flush)
+    }
+    fn get_writer(self) -> Self::Writer {
+        let (first, second) = self.into_inner();
+        (first.get_writer(), second.get_writer())
+    }
+}
+impl<A: Display, B: Display> Display for TeeError<A, B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::First(a) => write!(f, "Writing to the first leg failed: {a}"),
+            Self::Second(b) => write!(f, "Writing to the second leg failed: {b}"),
+            Self::Both(a, b) => write!(f, "Writing to both legs failed: {a}; {b}"),
+        }
+    }
+}
+impl<A: Error + 'static, B: Error + 'static> Error for TeeError<A, B> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::First(a) | Self::Both(a, _) => Some(a),
+            Self::Second(b) => Some(b),
+        }
+    }
+}
@@ -1,6 +1,14 @@
-use std::{fmt, io};
+use std::{
+    fmt,
+    io::{self, IsTerminal},
+};
 
-use crate::{DiscreteSGR, SGRString};
+use crate::{
+    append::AppendToString,
+    escape::{EscapeScanner, Part},
+    CapacityError, Color, DiscreteSGR, EscapeError, Plane, SGRString, SgrParseError, SgrState,
+    Style,
+};
 
 /// An interface for an [`SGRWriter`] to work with
 ///
@@ -21,6 +29,86 @@ pub trait CapableWriter: Sized {
     /// Returns an error if writing fails.
     /// Error type specified by [`CapableWriter::Error`]
     fn write(&mut self, s: &str) -> Result<(), Self::Error>;
+    /// Writes a complete SGR sequence made up of `codes`
+    ///
+    /// Called by [`SGRBuilder::write_to`], by default writes
+    /// the escape, the codes separated by `;` & the end
+    /// through a single call to [`CapableWriter::write`]
+    ///
+    /// Writers may override this to tell SGR codes apart from text
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    fn write_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        with_sequence(codes, true, |seq| self.write(seq))
+    }
+    /// Writes SGR codes without the escape or end
+    ///
+    /// Called by [`SGRBuilder::write_partial`], by default writes
+    /// the codes separated by `;` through a single call to [`CapableWriter::write`]
+    ///
+    /// Writers may override this to tell SGR codes apart from text
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    fn write_partial_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        with_sequence(codes, false, |seq| self.write(seq))
+    }
+    /// Writes a single escape sequence that isn't SGR
+    ///
+    /// Called by [`SGRWriter::write_escape`], by default writes
+    /// the sequence through [`CapableWriter::write`]
+    ///
+    /// Writers may override this to tell such sequences apart from text
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    fn write_escape(&mut self, seq: &str) -> Result<(), Self::Error> {
+        self.write(seq)
+    }
+    /// Writes formatted text to the inner writer
+    ///
+    /// By default streams each piece of the text through [`CapableWriter::write`],
+    /// without allocating
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if a formatting trait implementation returns an error
+    /// when writing did not fail, as [`io::Write::write_fmt`] does
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> Result<(), Self::Error> {
+        let mut adapter = FmtAdapter {
+            writer: self,
+            error: None,
+        };
+        match fmt::write(&mut adapter, args) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(adapter.error.expect(
+                "a formatting trait implementation returned an error when writing did not",
+            )),
+        }
+    }
+    /// Flushes anything buffered by the inner writer
+    ///
+    /// By default does nothing, for writers that don't buffer
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if flushing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
     /// Returns the type specified by [`CapableWriter::Writer`]
     fn get_writer(self) -> Self::Writer;
 }
@@ -28,9 +116,233 @@ pub trait CapableWriter: Sized {
 #[derive(Debug, Clone)]
 pub struct SGRWriter<W: CapableWriter> {
     /// A writer capable of writing a [`str`]
+    #[deprecated(note = "use `get_ref`, `get_mut` or `into_inner` instead")]
     pub writer: W,
+    /// Whether SGR codes are written
+    ///
+    /// When `false` only plain text makes it to the writer
+    enabled: bool,
+    /// How styles are cleaned, see [`SGRWriter::set_reset_strategy`]
+    reset_strategy: Option<ResetStrategy>,
+}
+impl SGRWriter<IoWriter<io::Stdout>> {
+    /// Creates a writer over [`io::stdout`]
+    ///
+    /// SGR codes are only written when stdout is a terminal,
+    /// [`no_color`](crate::no_color) is `false`, `TERM` isn't `dumb` &
+    /// [`enable_vt`](crate::windows::enable_vt) succeeds,
+    /// otherwise they are stripped so only plain text is written.
+    /// A [`ColorChoice`](crate::ColorChoice) of always or never set through
+    /// [`set_color_choice`](crate::set_color_choice) is followed instead
+    #[must_use]
+    pub fn stdout() -> Self {
+        let stdout = io::stdout();
+        let is_terminal = stdout.is_terminal();
+        Self::auto(IoWriter(stdout), is_terminal)
+    }
+}
+impl SGRWriter<IoWriter<io::Stderr>> {
+    /// Creates a writer over [`io::stderr`]
+    ///
+    /// SGR codes are only written when stderr is a terminal,
+    /// [`no_color`](crate::no_color) is `false`, `TERM` isn't `dumb` &
+    /// [`enable_vt`](crate::windows::enable_vt) succeeds,
+    /// otherwise they are stripped so only plain text is written.
+    /// A [`ColorChoice`](crate::ColorChoice) of always or never set through
+    /// [`set_color_choice`](crate::set_color_choice) is followed instead
+    #[must_use]
+    pub fn stderr() -> Self {
+        let stderr = io::stderr();
+        let is_terminal = stderr.is_terminal();
+        Self::auto(IoWriter(stderr), is_terminal)
+    }
+}
+impl<'a> SGRWriter<IoWriter<Box<dyn io::Write + Send + 'a>>> {
+    /// Creates a writer over a boxed [`io::Write`] trait object,
+    /// for when the writer's concrete type can't be named
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use std::io::Write;
+    ///
+    ///use easy_sgr::{Color::*, SGRWriter};
+    ///
+    ///let sink: Box<dyn Write + Send> = Box::new(Vec::new());
+    ///let mut writer = SGRWriter::boxed_io(sink);
+    ///writer.styled(&RedFg, "error").unwrap();
+    ///```
+    #[must_use]
+    pub fn boxed_io(writer: Box<dyn io::Write + Send + 'a>) -> Self {
+        Self::from(writer)
+    }
+}
+impl<W: io::Write> SGRWriter<IoWriter<W>> {
+    /// Creates a writer over any [`io::Write`], wrapping it in an [`IoWriter`]
+    ///
+    /// `From` is only implemented for common [`io::Write`] types,
+    /// as a blanket impl would overlap with the one for [`CapableWriter`]s
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color::*, SGRWriter};
+    ///
+    ///let mut writer = SGRWriter::io(std::io::Cursor::new(Vec::new()));
+    ///writer.styled(&RedFg, "error").unwrap();
+    ///assert_eq!(writer.internal().into_inner(), b"\x1b[31merror\x1b[0m");
+    ///```
+    ///
+    /// Other [`io::Write`] types don't implement [`CapableWriter`],
+    /// so `From` can't be used
+    ///
+    ///```rust,compile_fail
+    ///use easy_sgr::SGRWriter;
+    ///
+    ///let writer = SGRWriter::from(std::io::Cursor::new(Vec::new()));
+    ///```
+    ///
+    /// Only [`io::Write`] types are taken, e.g. not a [`String`]
+    ///
+    ///```rust,compile_fail
+    ///use easy_sgr::SGRWriter;
+    ///
+    ///let writer = SGRWriter::io(String::new());
+    ///```
+    #[must_use]
+    pub const fn io(writer: W) -> Self {
+        Self::capable(IoWriter(writer))
+    }
+}
+impl<W: fmt::Write> SGRWriter<FmtWriter<W>> {
+    /// Creates a writer over any [`fmt::Write`], wrapping it in a [`FmtWriter`]
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use std::fmt::Write;
+    ///
+    ///use easy_sgr::{Style::*, SGRWriter};
+    ///
+    ///let mut s = String::new();
+    ///let sink: &mut dyn Write = &mut s;
+    ///SGRWriter::fmt(sink).styled(&Bold, "bold").unwrap();
+    ///assert_eq!(s, "\x1b[1mbold\x1b[0m");
+    ///```
+    ///
+    /// Only [`fmt::Write`] types are taken, e.g. not a [`Vec<u8>`]
+    ///
+    ///```rust,compile_fail
+    ///use easy_sgr::SGRWriter;
+    ///
+    ///let writer = SGRWriter::fmt(Vec::<u8>::new());
+    ///```
+    #[must_use]
+    pub const fn fmt(writer: W) -> Self {
+        Self::capable(FmtWriter(writer))
+    }
 }
 impl<W: CapableWriter> SGRWriter<W> {
+    /// Creates a writer over a [`CapableWriter`], always writing SGR codes
+    ///
+    /// The same as `SGRWriter::from`, without the target type being inferred
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color::*, SGRWriter};
+    ///
+    ///let mut writer = SGRWriter::capable(String::new());
+    ///writer.styled(&BlueFg, "info").unwrap();
+    ///assert_eq!(writer.internal(), "\x1b[34minfo\x1b[0m");
+    ///```
+    ///
+    /// Other [`io::Write`] & [`fmt::Write`] types are taken by
+    /// [`SGRWriter::io`] & [`SGRWriter::fmt`] instead
+    ///
+    ///```rust,compile_fail
+    ///use easy_sgr::SGRWriter;
+    ///
+    ///let writer = SGRWriter::capable(std::io::Cursor::new(Vec::new()));
+    ///```
+    #[must_use]
+    #[allow(deprecated)]
+    pub const fn capable(writer: W) -> Self {
+        Self {
+            writer,
+            enabled: true,
+            reset_strategy: None,
+        }
+    }
+    /// Creates a writer that only writes SGR codes when writing to a terminal,
+    /// [`no_color`](crate::no_color) is `false`, the terminal isn't
+    /// [`ColorLevel::None`](crate::ColorLevel::None) &
+    /// [`enable_vt`](crate::windows::enable_vt) succeeds,
+    /// unless a [`ColorChoice`](crate::ColorChoice) of always or never was set
+    pub(crate) fn auto(writer: W, is_terminal: bool) -> Self {
+        Self {
+            enabled: crate::detect::writer_enabled(is_terminal),
+            ..Self::capable(writer)
+        }
+    }
+    /// Sets whether SGR codes are written
+    ///
+    /// When disabled all SGR, hyperlink & control methods write nothing,
+    /// while plain text is still written
+    ///
+    /// The initial value comes from how the writer was created,
+    /// e.g. [`SGRWriter::stdout`] detects it from the environment
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color::*, SGRWriter};
+    ///
+    ///let mut writer = SGRWriter::from(String::new());
+    ///writer.set_enabled(false);
+    ///writer.styled(&RedFg, "plain").unwrap();
+    ///assert_eq!(writer.internal(), "plain");
+    ///```
+    #[inline]
+    pub const fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+    /// Returns whether SGR codes are written
+    ///
+    /// See [`SGRWriter::set_enabled`]
+    #[inline]
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+    /// Sets how the styles placed by an [`SGRString`] are cleaned,
+    /// overriding its [`SGRString::clean`]
+    ///
+    /// Followed by [`SGRWriter::clean_sgr`], [`SGRWriter::with_sgr`] & [`SGRWriter::sgr_guard`].
+    /// By default `None`, meaning each [`SGRString`] is cleaned as it specifies
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color::*, EasySGR, ResetStrategy, SGRWriter, Style::*};
+    ///
+    ///let mut writer = SGRWriter::from(String::new());
+    ///writer.set_reset_strategy(Some(ResetStrategy::Targeted));
+    ///writer.sgr_string(&RedFg.style(Bold).text("error")).unwrap();
+    ///assert_eq!(writer.internal(), "\x1b[31;1merror\x1b[39;22m");
+    ///```
+    #[inline]
+    pub const fn set_reset_strategy(&mut self, strategy: Option<ResetStrategy>) {
+        self.reset_strategy = strategy;
+    }
+    /// Returns how the styles placed by an [`SGRString`] are cleaned
+    ///
+    /// See [`SGRWriter::set_reset_strategy`]
+    #[inline]
+    #[must_use]
+    pub const fn reset_strategy(&self) -> Option<ResetStrategy> {
+        self.reset_strategy
+    }
     /// Writes a [`str`] to the inner writer
     ///
     /// A shortcut to [`CapableWriter::write`] without having to import it
@@ -43,6 +355,42 @@ impl<W: CapableWriter> SGRWriter<W> {
     pub fn write_inner(&mut self, s: &str) -> Result<(), W::Error> {
         self.write(s)
     }
+    /// Writes formatted text to the inner writer
+    ///
+    /// Lets [`write!`] be used directly on the writer
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color::*, SGRWriter};
+    ///
+    ///let mut writer = SGRWriter::from(String::new());
+    ///writer.inline_sgr(&RedFg).unwrap();
+    ///write!(writer, "{} errors", 3).unwrap();
+    ///
+    ///assert_eq!(writer.internal(), "\x1b[31m3 errors");
+    ///```
+    #[inline]
+    pub fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> Result<(), W::Error> {
+        self.get_mut().write_fmt(args)
+    }
+    /// Flushes the inner writer
+    ///
+    /// A shortcut to [`CapableWriter::flush`] without having to import it
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if flushing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    #[inline]
+    pub fn flush(&mut self) -> Result<(), W::Error> {
+        self.get_mut().flush()
+    }
     /// Returns the internal writer
     ///
     /// Returns the type specified by [`CapableWriter::get_writer`]
@@ -51,6 +399,38 @@ impl<W: CapableWriter> SGRWriter<W> {
     pub fn internal(self) -> W::Writer {
         self.get_writer().get_writer()
     }
+    /// Returns the writer being written to
+    ///
+    /// Unlike [`SGRWriter::internal`] a wrapper such as an [`IoWriter`] is kept
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.get_writer()
+    }
+    /// Returns a reference to the writer being written to
+    #[inline]
+    #[allow(deprecated)]
+    pub const fn get_ref(&self) -> &W {
+        &self.writer
+    }
+    /// Returns a mutable reference to the writer being written to
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color::*, SGRWriter};
+    ///
+    ///let mut writer = SGRWriter::from(String::new());
+    ///writer.styled(&RedFg, "error")?;
+    ///writer.get_mut().push_str(": ");
+    ///writer.styled(&BlueFg, "info")?;
+    ///assert_eq!(writer.internal(), "\x1b[31merror\x1b[0m: \x1b[34minfo\x1b[0m");
+    ///# Ok::<(), std::fmt::Error>(())
+    ///```
+    #[inline]
+    #[allow(deprecated)]
+    pub const fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
     /// Returns a new, empty [`SGRBuilder`]
     ///
     /// Used for convenience
@@ -60,19 +440,28 @@ impl<W: CapableWriter> SGRWriter<W> {
     }
     /// Writes the contained SGR codes to the writer through calling [`SGRString::place_all`]
     ///
+    /// Any [`SGRString::raw_places`] are written as escapes before the codes
+    ///
     /// # Errors
     ///
     /// Returns an error if writing fails.
     /// Error type specified by [`CapableWriter::Error`]
     #[inline]
     pub fn place_sgr(&mut self, sgr: &SGRString) -> Result<(), W::Error> {
+        if !self.enabled {
+            return Ok(());
+        }
+        for seq in &sgr.raw_places {
+            self.get_mut().write_escape(seq)?;
+        }
         let mut builder = SGRBuilder::default();
         sgr.place_all(&mut builder);
         builder.write_to(self)
     }
     /// Writes the contained SGR codes to the writer through calling [`SGRString::clean_all`]
     ///
-    /// Supposed to reverse the effects made by [`SGRString::place_all`]
+    /// Supposed to reverse the effects made by [`SGRString::place_all`],
+    /// any [`SGRString::raw_cleans`] being written as escapes after the codes
     ///
     /// # Errors
     ///
@@ -80,9 +469,15 @@ impl<W: CapableWriter> SGRWriter<W> {
     /// Error type specified by [`CapableWriter::Error`]
     #[inline]
     pub fn clean_sgr(&mut self, sgr: &SGRString) -> Result<(), W::Error> {
+        if !self.enabled {
+            return Ok(());
+        }
         let mut builder = SGRBuilder::default();
-        sgr.clean_all(&mut builder);
-        builder.write_to(self)
+        self.clean_codes(sgr, &mut builder);
+        builder.write_to(self)?;
+        sgr.raw_cleans
+            .iter()
+            .try_for_each(|seq| self.get_mut().write_escape(seq))
     }
     /// Writes the contained SGR codes to the writer through calling [`DiscreteSGR::write`]
     ///
@@ -92,10 +487,74 @@ impl<W: CapableWriter> SGRWriter<W> {
     /// Error type specified by [`CapableWriter::Error`]
     #[inline]
     pub fn inline_sgr(&mut self, sgr: &impl DiscreteSGR) -> Result<(), W::Error> {
+        if !self.enabled {
+            return Ok(());
+        }
         let mut builder = SGRBuilder::default();
         sgr.write(&mut builder);
         builder.write_to(self)
     }
+    /// Writes the SGR codes of every item of `iter` as a single sequence
+    ///
+    /// Nothing is written when `iter` is empty
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{AnySgr, Color::*, SGRWriter, Style::*};
+    ///
+    ///let mut writer = SGRWriter::from(String::new());
+    ///let styles = [Bold, Italic, Underline];
+    ///writer.inline_sgr_all(styles.into_iter().filter(|s| *s != Italic)).unwrap();
+    ///writer.inline_sgr_all([AnySgr::from(RedFg), AnySgr::from(Bold)]).unwrap();
+    ///writer.inline_sgr_all(Vec::<AnySgr>::new()).unwrap();
+    ///assert_eq!(writer.internal(), "\x1b[1;4m\x1b[31;1m");
+    ///```
+    pub fn inline_sgr_all<I>(&mut self, iter: I) -> Result<(), W::Error>
+    where
+        I: IntoIterator,
+        I::Item: DiscreteSGR,
+    {
+        if !self.enabled {
+            return Ok(());
+        }
+        let mut builder = SGRBuilder::default();
+        for sgr in iter {
+            sgr.write(&mut builder);
+        }
+        builder.write_to(self)
+    }
+    /// Resets all styles & colors, writing `\x1b[0m`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    #[inline]
+    pub fn reset(&mut self) -> Result<(), W::Error> {
+        if !self.enabled {
+            return Ok(());
+        }
+        self.builder().chain_code(0).write_to(self)
+    }
+    /// Resets the foreground & background colors, writing `\x1b[39;49m`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    #[inline]
+    pub fn reset_colors(&mut self) -> Result<(), W::Error> {
+        if !self.enabled {
+            return Ok(());
+        }
+        self.builder().chain_codes(&[39, 49]).write_to(self)
+    }
     /// Writes the contained SGR codes to the writer
     ///
     /// Uses [`EasyWrite`] so the it can be used for both
@@ -106,6 +565,9 @@ impl<W: CapableWriter> SGRWriter<W> {
     /// Returns an error if writing fails.
     /// Error type specified by [`CapableWriter::Error`]
     pub fn sgr(&mut self, sgr: &impl EasyWrite) -> Result<(), W::Error> {
+        if !self.enabled {
+            return Ok(());
+        }
         let mut builder = SGRBuilder::default();
         sgr.sgr(&mut builder);
         builder.write_to(self)
@@ -123,103 +585,1532 @@ impl<W: CapableWriter> SGRWriter<W> {
     /// Error type specified by [`CapableWriter::Error`]
     #[inline]
     pub fn partial_sgr(&mut self, sgr: &impl EasyWrite) -> Result<(), W::Error> {
+        if !self.enabled {
+            return Ok(());
+        }
         let mut builder = SGRBuilder::default();
         sgr.sgr(&mut builder);
         builder.write_partial(self)
     }
-}
-impl<W: CapableWriter> From<W> for SGRWriter<W> {
-    fn from(value: W) -> Self {
-        Self { writer: value }
+    /// Places `sgr`, calls `f` & then cleans `sgr`
+    ///
+    /// The clean is written even when `f` returns an error,
+    /// so early returns can't leave the terminal styled
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `f` or writing fails.
+    /// When both `f` & the clean fail, the error of `f` is returned
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{CleanKind, Color::*, EasySGR, SGRWriter};
+    ///
+    ///let mut writer = SGRWriter::from(String::new());
+    ///writer
+    ///    .with_sgr(&RedFg.clean(CleanKind::Reverse), |w| w.write_inner("red"))
+    ///    .unwrap();
+    ///assert_eq!(writer.internal(), "\x1b[31mred\x1b[39m");
+    ///```
+    pub fn with_sgr<R>(
+        &mut self,
+        sgr: &SGRString,
+        f: impl FnOnce(&mut Self) -> Result<R, W::Error>,
+    ) -> Result<R, W::Error> {
+        self.place_sgr(sgr)?;
+        let result = f(self);
+        let clean = self.clean_sgr(sgr);
+        let value = result?;
+        clean.map(|()| value)
     }
-}
-impl<W: std::fmt::Write> From<W> for SGRWriter<FmtWriter<W>> {
-    fn from(value: W) -> Self {
-        Self {
-            writer: FmtWriter(value),
+    /// Writes `place`, calls `f` & then writes `clean`
+    ///
+    /// Like [`SGRWriter::with_sgr`], but for any [`EasyWrite`] with an explicit clean
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `f` or writing fails.
+    /// When both `f` & the clean fail, the error of `f` is returned
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{SGRWriter, Style::*};
+    ///
+    ///let mut writer = SGRWriter::from(String::new());
+    ///writer
+    ///    .with_sgr_clean(&Bold, &NotBold, |w| w.write_inner("bold"))
+    ///    .unwrap();
+    ///assert_eq!(writer.internal(), "\x1b[1mbold\x1b[22m");
+    ///```
+    pub fn with_sgr_clean<R>(
+        &mut self,
+        place: &impl EasyWrite,
+        clean: &impl EasyWrite,
+        f: impl FnOnce(&mut Self) -> Result<R, W::Error>,
+    ) -> Result<R, W::Error> {
+        self.sgr(place)?;
+        let result = f(self);
+        let clean = self.sgr(clean);
+        let value = result?;
+        clean.map(|()| value)
+    }
+    /// Writes `text` styled by `sgr`, followed by a reset
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color::*, SGRWriter};
+    ///
+    ///let mut writer = SGRWriter::from(String::new());
+    ///writer.styled(&RedFg, "red").unwrap();
+    ///assert_eq!(writer.internal(), "\x1b[31mred\x1b[0m");
+    ///```
+    pub fn styled(&mut self, sgr: &impl EasyWrite, text: &str) -> Result<(), W::Error> {
+        self.sgr(sgr)?;
+        self.write(text)?;
+        self.inline_sgr(&crate::Style::Reset)
+    }
+    /// Writes `ch` `count` times, styled by `style` followed by a reset if given
+    ///
+    /// The character is encoded once & written in chunks from a buffer on the stack,
+    /// so no string as wide as the run is allocated.
+    /// Nothing is written when `count` is `0`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color::*, SGRWriter, Style};
+    ///
+    ///let mut writer = SGRWriter::from(String::new());
+    ///writer.write_repeated('─', 3, Some(&BlueFg)).unwrap();
+    ///writer.write_repeated('=', 2, None::<&Style>).unwrap();
+    ///assert_eq!(writer.internal(), "\x1b[34m───\x1b[0m==");
+    ///```
+    pub fn write_repeated(
+        &mut self,
+        ch: char,
+        count: usize,
+        style: Option<&impl EasyWrite>,
+    ) -> Result<(), W::Error> {
+        /// The size of the buffer the character is repeated in
+        const CHUNK: usize = 256;
+        if count == 0 {
+            return Ok(());
+        }
+        let mut buf = [0; CHUNK];
+        let len = ch.encode_utf8(&mut buf).len();
+        let per_chunk = CHUNK / len;
+        for i in 1..per_chunk.min(count) {
+            buf.copy_within(..len, i * len);
+        }
+        if let Some(style) = style {
+            self.sgr(style)?;
+        }
+        let mut left = count;
+        while left > 0 {
+            let chars = left.min(per_chunk);
+            // whole characters are copied, so the chunk is always valid
+            if let Ok(chunk) = std::str::from_utf8(&buf[..chars * len]) {
+                self.write(chunk)?;
+            }
+            left -= chars;
         }
+        if style.is_some() {
+            self.inline_sgr(&crate::Style::Reset)?;
+        }
+        Ok(())
     }
-}
-impl<W: std::io::Write> From<W> for SGRWriter<IoWriter<W>> {
-    fn from(value: W) -> Self {
-        Self {
-            writer: IoWriter(value),
+    /// Writes the text of `sgr` between its place & clean
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{CleanKind, Color::*, EasySGR, SGRWriter};
+    ///
+    ///let mut writer = SGRWriter::from(String::new());
+    ///writer
+    ///    .sgr_string(&"red".color(RedFg).clean(CleanKind::Reverse))
+    ///    .unwrap();
+    ///assert_eq!(writer.internal(), "\x1b[31mred\x1b[39m");
+    ///```
+    pub fn sgr_string(&mut self, sgr: &SGRString) -> Result<(), W::Error> {
+        self.place_sgr(sgr)?;
+        self.write(&sgr.text)?;
+        self.clean_sgr(sgr)
+    }
+    /// Writes the text of each of `items` styled by it,
+    /// `separator` being written unstyled between them
+    ///
+    /// Rather than placing & cleaning every item, only the codes changing
+    /// the style of one item into the next are written, tracked by [`SgrState`],
+    /// & the last item is cleaned once at the end.
+    /// Each item is styled as it would be written alone after a reset,
+    /// so the style of one never leaks into the next.
+    ///
+    /// Items with custom codes or raw sequences, which aren't tracked,
+    /// are instead placed & cleaned as by [`SGRWriter::sgr_string`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color::*, EasySGR, SGRWriter, Style::*};
+    ///
+    ///let items = [RedFg.text("a"), RedFg.style(Bold).text("b"), "c".into()];
+    ///let mut writer = SGRWriter::from(String::new());
+    ///writer.write_all_styled(&items, None).unwrap();
+    ///assert_eq!(writer.internal(), "\x1b[31ma\x1b[1mb\x1b[39;22mc");
+    ///```
+    pub fn write_all_styled<'a>(
+        &mut self,
+        items: impl IntoIterator<Item = &'a SGRString>,
+        separator: Option<&str>,
+    ) -> Result<(), W::Error> {
+        let mut builder = SGRBuilder::default();
+        let mut current = SgrState::new();
+        for (i, item) in items.into_iter().enumerate() {
+            if let Some(separator) = separator.filter(|_| i != 0) {
+                self.transition(&mut current, SgrState::new(), &mut builder)?;
+                self.write(separator)?;
+            }
+            builder.clear();
+            item.place_all(&mut builder);
+            let mut target = SgrState::new();
+            target.apply(builder.codes());
+            if item.custom_places.is_empty()
+                && item.custom_cleans.is_empty()
+                && item.raw_places.is_empty()
+                && item.raw_cleans.is_empty()
+            {
+                self.transition(&mut current, target, &mut builder)?;
+                self.write(&item.text)?;
+            } else {
+                self.transition(&mut current, SgrState::new(), &mut builder)?;
+                self.sgr_string(item)?;
+                builder.clear();
+                item.clean_all(&mut builder);
+                target.apply(builder.codes());
+                current = target;
+            }
         }
+        self.transition(&mut current, SgrState::new(), &mut builder)
     }
-}
-impl<W: CapableWriter> CapableWriter for SGRWriter<W> {
-    type Writer = W;
-    type Error = W::Error;
-    #[inline]
-    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
-        self.writer.write(s)
+    /// Writes the codes cleaning `sgr`, following the reset strategy if set
+    fn clean_codes(&self, sgr: &SGRString, builder: &mut SGRBuilder) {
+        match self.reset_strategy {
+            None => sgr.clean_all(builder),
+            Some(ResetStrategy::Full) => {
+                builder.write_code(0);
+                sgr.clean_custom(builder);
+            }
+            Some(ResetStrategy::Targeted) => {
+                sgr.clean_colors(builder);
+                sgr.clean_styles(builder);
+                sgr.clean_custom(builder);
+            }
+            Some(ResetStrategy::None) => (),
+        }
     }
+    /// Writes the codes changing `from` into `to`, `from` then becoming `to`
+    fn transition(
+        &mut self,
+        from: &mut SgrState,
+        to: SgrState,
+        builder: &mut SGRBuilder,
+    ) -> Result<(), W::Error> {
+        builder.clear();
+        from.transition(&to, builder);
+        *from = to;
+        if self.enabled {
+            builder.write_to(self)
+        } else {
+            Ok(())
+        }
+    }
+    /// Returns a scope merging the codes given to it into a single sequence
+    ///
+    /// The sequence is written on [`PartialScope::finish`] or when dropped,
+    /// nothing is written when no codes were given
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color::*, EasySGR, SGRWriter, Style::*};
+    ///
+    ///let mut writer = SGRWriter::from(String::new());
+    ///writer
+    ///    .partial_scope()
+    ///    .partial_sgr(&Bold)
+    ///    .partial_sgr(&RedFg.style(Italic))
+    ///    .finish()
+    ///    .unwrap();
+    ///assert_eq!(writer.internal(), "\x1b[1;31;3m");
+    ///```
+    pub fn partial_scope(&mut self) -> PartialScope<'_, W> {
+        PartialScope {
+            writer: self,
+            builder: SGRBuilder::default(),
+            finished: false,
+        }
+    }
+    /// Places `sgr`, returning a guard that cleans it when dropped
+    ///
+    /// The clean of `sgr` is used, or a reset when it has none.
+    /// Write through the guard while it is alive, it dereferences to this writer.
+    ///
+    /// See [`SgrGuard`] for handling errors on drop
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{CleanKind, Color::*, EasySGR, SGRWriter};
+    ///
+    ///let mut writer = SGRWriter::from(String::new());
+    ///{
+    ///    let mut guard = writer.sgr_guard(&RedFg.clean(CleanKind::Reverse)).unwrap();
+    ///    guard.write_inner("red").unwrap();
+    ///}
+    ///assert_eq!(writer.internal(), "\x1b[31mred\x1b[39m");
+    ///```
+    pub fn sgr_guard(&mut self, sgr: &SGRString) -> Result<SgrGuard<'_, W>, W::Error> {
+        let mut clean = SGRBuilder::default();
+        if self.reset_strategy.is_none() && sgr.clean == crate::CleanKind::None {
+            clean.write_code(0);
+        }
+        self.clean_codes(sgr, &mut clean);
+        self.place_sgr(sgr)?;
+        Ok(SgrGuard {
+            writer: self,
+            clean,
+            raw_cleans: sgr.raw_cleans.clone(),
+            on_error: None,
+            finished: false,
+        })
+    }
+    /// Opens an [OSC 8](https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda)
+    /// hyperlink, text written until [`SGRWriter::close_hyperlink`] links to `url`
+    ///
+    /// `id` lets the terminal treat separately written cells as a single link,
+    /// e.g. a link that is wrapped over several lines
+    ///
+    /// Control & non-ASCII characters are percent-escaped,
+    /// as are `:` & `;` within `id` since they separate the link's parameters
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    pub fn open_hyperlink(&mut self, url: &str, id: Option<&str>) -> Result<(), W::Error> {
+        self.sequence(|seq| seq.open_hyperlink(url, id))
+    }
+    /// Closes a hyperlink opened by [`SGRWriter::open_hyperlink`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    pub fn close_hyperlink(&mut self) -> Result<(), W::Error> {
+        self.sequence(SequenceBuilder::close_hyperlink)
+    }
+    /// Writes `text` as a hyperlink to `url`
+    ///
+    /// See [`SGRWriter::open_hyperlink`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    pub fn hyperlink(&mut self, url: &str, text: &str) -> Result<(), W::Error> {
+        self.open_hyperlink(url, None)?;
+        self.write(text)?;
+        self.close_hyperlink()
+    }
+    /// Writes a [`SGRString`] as a hyperlink to `url`
+    ///
+    /// The string's SGR codes are nested within the link
+    ///
+    /// See [`SGRWriter::open_hyperlink`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    pub fn hyperlink_styled(&mut self, url: &str, text: &SGRString) -> Result<(), W::Error> {
+        self.open_hyperlink(url, None)?;
+        self.place_sgr(text)?;
+        self.write(&text.text)?;
+        self.clean_sgr(text)?;
+        self.close_hyperlink()
+    }
+    /// Writes a single escape sequence this crate doesn't otherwise write,
+    /// such as a DEC private mode
+    ///
+    /// The sequence must start with `ESC`, be properly terminated
+    /// & contain no other control characters, nothing is written otherwise
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EscapeError::Invalid`] if `seq` isn't a single well-formed sequence,
+    /// or [`EscapeError::Write`] if writing fails
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::SGRWriter;
+    ///
+    ///let mut writer = SGRWriter::from(String::new());
+    ///writer.write_escape("\x1b[?25l").unwrap();
+    ///assert!(writer.write_escape("\x1b[?25").is_err());
+    ///
+    ///assert_eq!(writer.internal(), "\x1b[?25l");
+    ///```
+    pub fn write_escape(&mut self, seq: &str) -> Result<(), EscapeError<W::Error>> {
+        crate::escape::validate(seq).map_err(EscapeError::Invalid)?;
+        self.write_escape_unchecked(seq).map_err(EscapeError::Write)
+    }
+    /// Writes an escape sequence without checking that it is well-formed
+    ///
+    /// See [`SGRWriter::write_escape`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    pub fn write_escape_unchecked(&mut self, seq: &str) -> Result<(), W::Error> {
+        if !self.enabled {
+            return Ok(());
+        }
+        self.get_mut().write_escape(seq)
+    }
+    /// Moves the cursor up `n` lines
+    ///
+    /// Nothing is written when `n` is `0`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    pub fn cursor_up(&mut self, n: u16) -> Result<(), W::Error> {
+        self.sequence(|seq| seq.cursor_up(n))
+    }
+    /// Moves the cursor to column `n` of the current line
+    ///
+    /// Columns start at `1`, `0` is treated the same as `1`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    pub fn cursor_to_column(&mut self, n: u16) -> Result<(), W::Error> {
+        self.sequence(|seq| seq.cursor_to_column(n))
+    }
+    /// Erases part of the current line, the cursor does not move
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    pub fn erase_line(&mut self, mode: EraseMode) -> Result<(), W::Error> {
+        self.sequence(|seq| seq.erase_line(mode))
+    }
+    /// Erases part of the display, the cursor does not move
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    pub fn erase_display(&mut self, mode: EraseMode) -> Result<(), W::Error> {
+        self.sequence(|seq| seq.erase_display(mode))
+    }
+    /// Saves the cursor's position, to be returned to by [`SGRWriter::restore_cursor`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    pub fn save_cursor(&mut self) -> Result<(), W::Error> {
+        self.sequence(SequenceBuilder::save_cursor)
+    }
+    /// Moves the cursor to the position saved by [`SGRWriter::save_cursor`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    pub fn restore_cursor(&mut self) -> Result<(), W::Error> {
+        self.sequence(SequenceBuilder::restore_cursor)
+    }
+    /// Hides the cursor
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    pub fn hide_cursor(&mut self) -> Result<(), W::Error> {
+        self.sequence(SequenceBuilder::hide_cursor)
+    }
+    /// Shows the cursor
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    pub fn show_cursor(&mut self) -> Result<(), W::Error> {
+        self.sequence(SequenceBuilder::show_cursor)
+    }
+    /// Writes the sequences queued within `seq` by a single write, clearing it
+    ///
+    /// Nothing is written when writing is disabled, `seq` still being cleared
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color::*, SGRWriter, SequenceBuilder};
+    ///
+    ///let mut seq = SequenceBuilder::default();
+    ///seq.cursor_up(2).cursor_to_column(1).sgr(&RedFg);
+    ///
+    ///let mut writer = SGRWriter::from(String::new());
+    ///writer.write_sequence(&mut seq).unwrap();
+    ///assert!(seq.is_empty());
+    ///
+    ///assert_eq!(writer.internal(), "\x1b[2A\x1b[G\x1b[31m");
+    ///```
+    pub fn write_sequence(&mut self, seq: &mut SequenceBuilder) -> Result<(), W::Error> {
+        if !self.enabled {
+            seq.clear();
+            return Ok(());
+        }
+        seq.flush_to(self.get_mut())
+    }
+    /// Writes the sequences queued by `f`
+    fn sequence(
+        &mut self,
+        f: impl FnOnce(&mut SequenceBuilder) -> &mut SequenceBuilder,
+    ) -> Result<(), W::Error> {
+        if !self.enabled {
+            return Ok(());
+        }
+        f(&mut SequenceBuilder::default()).flush_to(self.get_mut())
+    }
+}
+/// Merges codes from several sources into a single sequence
+///
+/// Created by [`SGRWriter::partial_scope`]
+///
+/// Errors that occur when writing on drop are dropped,
+/// use [`PartialScope::finish`] to handle them
+#[derive(Debug)]
+pub struct PartialScope<'a, W: CapableWriter> {
+    writer: &'a mut SGRWriter<W>,
+    builder: SGRBuilder,
+    finished: bool,
+}
+impl<W: CapableWriter> PartialScope<'_, W> {
+    /// Adds the codes of `sgr` to the sequence
+    pub fn partial_sgr(&mut self, sgr: &impl EasyWrite) -> &mut Self {
+        sgr.sgr(&mut self.builder);
+        self
+    }
+    /// Writes the sequence now, returning any error
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    pub fn finish(&mut self) -> Result<(), W::Error> {
+        if std::mem::replace(&mut self.finished, true) || !self.writer.enabled {
+            return Ok(());
+        }
+        self.builder.write_to(self.writer)
+    }
+}
+impl<W: CapableWriter> Drop for PartialScope<'_, W> {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+/// Cleans a placed style when dropped
+///
+/// Created by [`SGRWriter::sgr_guard`]
+///
+/// Errors can't be returned from [`Drop`], so they are dropped
+/// unless a callback is set through [`SgrGuard::on_error`].
+/// Use [`SgrGuard::finish`] to handle the error directly instead.
+#[must_use]
+pub struct SgrGuard<'a, W: CapableWriter> {
+    writer: &'a mut SGRWriter<W>,
+    clean: SGRBuilder,
+    raw_cleans: Vec<String>,
+    on_error: Option<Box<dyn FnOnce(W::Error) + 'a>>,
+    finished: bool,
+}
+impl<'a, W: CapableWriter> SgrGuard<'a, W> {
+    /// Cleans with a full reset, rather than the clean of the style
+    ///
+    /// Any [`SGRString::raw_cleans`] are still written
+    pub fn with_reset(mut self) -> Self {
+        self.clean.clear();
+        self.clean.write_code(0);
+        self
+    }
+    /// Sets a callback for an error that occurs while cleaning on drop
+    pub fn on_error(mut self, f: impl FnOnce(W::Error) + 'a) -> Self {
+        self.on_error = Some(Box::new(f));
+        self
+    }
+    /// Cleans the style now, returning any error
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    pub fn finish(mut self) -> Result<(), W::Error> {
+        self.clean()
+    }
+    /// Writes the clean, only once
+    fn clean(&mut self) -> Result<(), W::Error> {
+        if std::mem::replace(&mut self.finished, true) || !self.writer.enabled {
+            return Ok(());
+        }
+        self.clean.write_to(self.writer)?;
+        self.raw_cleans
+            .iter()
+            .try_for_each(|seq| self.writer.get_mut().write_escape(seq))
+    }
+}
+impl<W: CapableWriter> std::ops::Deref for SgrGuard<'_, W> {
+    type Target = SGRWriter<W>;
+    fn deref(&self) -> &Self::Target {
+        self.writer
+    }
+}
+impl<W: CapableWriter> std::ops::DerefMut for SgrGuard<'_, W> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.writer
+    }
+}
+impl<W: CapableWriter> Drop for SgrGuard<'_, W> {
+    fn drop(&mut self) {
+        if let Err(e) = self.clean() {
+            if let Some(on_error) = self.on_error.take() {
+                on_error(e);
+            }
+        }
+    }
+}
+impl<W: CapableWriter + fmt::Debug> fmt::Debug for SgrGuard<'_, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SgrGuard")
+            .field("writer", &self.writer)
+            .field("clean", &self.clean)
+            .field("raw_cleans", &self.raw_cleans)
+            .field("finished", &self.finished)
+            .finish_non_exhaustive()
+    }
+}
+/// How an [`SGRWriter`] cleans the styles placed by an [`SGRString`]
+///
+/// Set through [`SGRWriter::set_reset_strategy`].
+/// Any [`SGRString::raw_cleans`] are written whichever is used
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResetStrategy {
+    /// Resets everything, writing `0`
+    ///
+    /// Suits sinks wanting explicit resets between blocks, such as HTML
+    Full,
+    /// Writes the exact inverse of what was placed,
+    /// leaving styles placed by others untouched
+    Targeted,
+    /// Writes nothing, leaving the styles applied
+    None,
+}
+/// The part of a line or display to erase
+///
+/// Used by [`SGRWriter::erase_line`] & [`SGRWriter::erase_display`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EraseMode {
+    /// From the cursor to the end
+    ToEnd = 0,
+    /// From the start up to the cursor
+    ToStart = 1,
+    /// Everything
+    All = 2,
+}
+/// Wraps a [`CapableWriter`], e.g. a [`String`] or [`Vec<u8>`]
+///
+/// Up to `0.1.1` any [`io::Write`] or [`fmt::Write`] converted, now only these
+/// & the common [`io::Write`] types below do, others being wrapped by
+/// [`SGRWriter::io`] or [`SGRWriter::fmt`]
+impl<W: CapableWriter> From<W> for SGRWriter<W> {
+    fn from(value: W) -> Self {
+        Self::capable(value)
+    }
+}
+/// Implements `From` for common [`std::io::Write`] types, wrapping them in an [`IoWriter`]
+///
+/// A fixed list, as a blanket impl would overlap with the one for [`CapableWriter`]s,
+/// any other [`std::io::Write`] is wrapped by [`SGRWriter::io`]
+macro_rules! from_io {
+    ($($ty:ty $(, $generic:tt)?;)*) => {$(
+        /// Wraps the writer as [`SGRWriter::io`] does
+        impl$(<$generic>)? From<$ty> for SGRWriter<IoWriter<$ty>> {
+            fn from(value: $ty) -> Self {
+                Self::io(value)
+            }
+        }
+    )*};
+}
+from_io! {
+    io::Stdout;
+    io::Stderr;
+    io::StdoutLock<'a>, 'a;
+    io::StderrLock<'a>, 'a;
+    std::fs::File;
+    &'a std::fs::File, 'a;
+    std::net::TcpStream;
+    Box<dyn io::Write + Send + 'a>, 'a;
+}
+/// Wraps the writer as [`SGRWriter::io`] does
+impl<W: io::Write> From<io::BufWriter<W>> for SGRWriter<IoWriter<io::BufWriter<W>>> {
+    fn from(value: io::BufWriter<W>) -> Self {
+        Self::io(value)
+    }
+}
+/// Wraps the writer as [`SGRWriter::io`] does
+impl<W: io::Write> From<io::LineWriter<W>> for SGRWriter<IoWriter<io::LineWriter<W>>> {
+    fn from(value: io::LineWriter<W>) -> Self {
+        Self::io(value)
+    }
+}
+/// Lets writers be nested, SGR codes & escapes being dropped while disabled
+impl<W: CapableWriter> CapableWriter for SGRWriter<W> {
+    type Writer = W;
+    type Error = W::Error;
+    #[inline]
+    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.get_mut().write(s)
+    }
+    #[inline]
+    fn write_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        if !self.enabled {
+            return Ok(());
+        }
+        self.get_mut().write_sgr(codes)
+    }
+    #[inline]
+    fn write_partial_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        if !self.enabled {
+            return Ok(());
+        }
+        self.get_mut().write_partial_sgr(codes)
+    }
+    #[inline]
+    fn write_escape(&mut self, seq: &str) -> Result<(), Self::Error> {
+        if !self.enabled {
+            return Ok(());
+        }
+        self.get_mut().write_escape(seq)
+    }
+    #[inline]
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> Result<(), Self::Error> {
+        self.get_mut().write_fmt(args)
+    }
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.get_mut().flush()
+    }
+    #[allow(deprecated)]
+    fn get_writer(self) -> Self::Writer {
+        self.writer
+    }
+}
+/// Lets a writer be lent out to functions taking a [`CapableWriter`] by value
+///
+/// Returns the reference itself as its [`CapableWriter::Writer`]
+impl<'a, W: CapableWriter> CapableWriter for &'a mut W {
+    type Writer = &'a mut W;
+    type Error = W::Error;
+    #[inline]
+    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+        (**self).write(s)
+    }
+    #[inline]
+    fn write_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        (**self).write_sgr(codes)
+    }
+    #[inline]
+    fn write_partial_sgr(&mut self, codes: &[u8]) -> Result<(), Self::Error> {
+        (**self).write_partial_sgr(codes)
+    }
+    #[inline]
+    fn write_escape(&mut self, seq: &str) -> Result<(), Self::Error> {
+        (**self).write_escape(seq)
+    }
+    #[inline]
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> Result<(), Self::Error> {
+        (**self).write_fmt(args)
+    }
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        (**self).flush()
+    }
+    fn get_writer(self) -> Self::Writer {
+        self
+    }
+}
+/// Writes directly into the string
+impl CapableWriter for String {
+    type Writer = Self;
+    type Error = fmt::Error;
+    #[inline]
+    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.push_str(s);
+        Ok(())
+    }
+    #[inline]
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> Result<(), Self::Error> {
+        fmt::Write::write_fmt(self, args)
+    }
+    fn get_writer(self) -> Self::Writer {
+        self
+    }
+}
+/// Writes the UTF-8 bytes directly into the vector
+impl CapableWriter for Vec<u8> {
+    type Writer = Self;
+    type Error = io::Error;
+    #[inline]
+    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.extend_from_slice(s.as_bytes());
+        Ok(())
+    }
+    fn get_writer(self) -> Self::Writer {
+        self
+    }
+}
+/// Lets a [`SGRWriter`] be created from the formatter of a [`Display`](fmt::Display) impl
+impl CapableWriter for fmt::Formatter<'_> {
+    type Writer = Self;
+    type Error = fmt::Error;
+    #[inline]
+    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.write_str(s)
+    }
+    #[inline]
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> Result<(), Self::Error> {
+        fmt::Write::write_fmt(self, args)
+    }
+    fn get_writer(self) -> Self::Writer {
+        self
+    }
+}
+/// Used to implement [`CapableWriter`] for [`std::io::Write`]
+#[derive(Debug, Clone)]
+pub struct IoWriter<W: std::io::Write>(
+    #[deprecated(note = "use `get_ref`, `get_mut` or `into_inner` instead")] pub W,
+);
+impl<W: std::io::Write> IoWriter<W> {
+    /// Returns the writer being written to
+    #[allow(deprecated)]
+    pub fn into_inner(self) -> W {
+        self.0
+    }
+    /// Returns a reference to the writer being written to
+    #[must_use]
+    #[allow(deprecated)]
+    pub const fn get_ref(&self) -> &W {
+        &self.0
+    }
+    /// Returns a mutable reference to the writer being written to
+    #[allow(deprecated)]
+    pub const fn get_mut(&mut self) -> &mut W {
+        &mut self.0
+    }
+}
+impl<W: std::io::Write> CapableWriter for IoWriter<W> {
+    type Writer = W;
+    type Error = io::Error;
+    #[inline]
+    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.get_mut().write_all(s.as_bytes())
+    }
+    #[inline]
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> Result<(), Self::Error> {
+        self.get_mut().write_fmt(args)
+    }
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.get_mut().flush()
+    }
+    fn get_writer(self) -> Self::Writer {
+        self.into_inner()
+    }
+}
+/// Used to implement [`CapableWriter`] for [`std::fmt::Write`]
+#[derive(Debug, Clone)]
+pub struct FmtWriter<W: std::fmt::Write>(
+    #[deprecated(note = "use `get_ref`, `get_mut` or `into_inner` instead")] pub W,
+);
+impl<W: std::fmt::Write> FmtWriter<W> {
+    /// Returns the writer being written to
+    #[allow(deprecated)]
+    pub fn into_inner(self) -> W {
+        self.0
+    }
+    /// Returns a reference to the writer being written to
+    #[must_use]
+    #[allow(deprecated)]
+    pub const fn get_ref(&self) -> &W {
+        &self.0
+    }
+    /// Returns a mutable reference to the writer being written to
+    #[allow(deprecated)]
+    pub const fn get_mut(&mut self) -> &mut W {
+        &mut self.0
+    }
+}
+impl<W: std::fmt::Write> CapableWriter for FmtWriter<W> {
+    type Writer = W;
+    type Error = fmt::Error;
+    #[inline]
+    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.get_mut().write_str(s)
+    }
+    #[inline]
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> Result<(), Self::Error> {
+        self.get_mut().write_fmt(args)
+    }
+    fn get_writer(self) -> Self::Writer {
+        self.into_inner()
+    }
+}
+/// Builds a SGR sequence
+///
+/// Up to [`SGRBuilder::INLINE`] codes are stored inline,
+/// so typical sequences never allocate
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::SGRBuilder;
+///
+///let mut builder = SGRBuilder::default();
+///builder.chain_code(1).chain_codes(&[38, 5, 208]);
+///assert_eq!(builder.codes(), [1, 38, 5, 208]);
+///assert!(!builder.spilled());
+///
+///assert_eq!(builder.to_string(), "\x1b[1;38;5;208m");
+///assert_eq!(format!("{builder:?}"), "SGR[Bold, ByteFg(208)]");
+///```
+#[derive(Default, Clone)]
+pub struct SGRBuilder {
+    codes: Codes,
+}
+/// The codes of a [`SGRBuilder`], moved to the heap once too many to store inline
+#[derive(Debug, Clone)]
+enum Codes {
+    Inline([u8; SGRBuilder::INLINE], u8),
+    Heap(Vec<u8>),
+}
+impl Default for Codes {
+    fn default() -> Self {
+        Self::Inline([0; SGRBuilder::INLINE], 0)
+    }
+}
+
+impl SGRBuilder {
+    /// The number of codes stored without allocating
+    pub const INLINE: usize = 16;
+    /// Writes a code to the internal buffer
+    #[inline]
+    pub fn write_code(&mut self, code: u8) {
+        self.write_codes(&[code]);
+    }
+    /// Writes codes to the internal buffer
+    #[inline]
+    pub fn write_codes(&mut self, codes: &[u8]) {
+        match &mut self.codes {
+            Codes::Inline(buf, len) if *len as usize + codes.len() <= Self::INLINE => {
+                let start = *len as usize;
+                buf[start..start + codes.len()].copy_from_slice(codes);
+                // bounded by `INLINE`, so always fits
+                #[allow(clippy::cast_possible_truncation)]
+                {
+                    *len += codes.len() as u8;
+                }
+            }
+            _ => self.spill(codes.len()).extend_from_slice(codes),
+        }
+    }
+    /// Writes codes from an iterator to the internal buffer
+    ///
+    /// Space is reserved from the iterator's [`Iterator::size_hint`],
+    /// so codes don't have to be collected into a slice first
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::SGRBuilder;
+    ///
+    ///let mut builder = SGRBuilder::default();
+    ///builder.write_codes_iter([1, 2, 3, 4].into_iter().filter(|code| code % 2 == 1));
+    ///assert_eq!(builder.codes(), [1, 3]);
+    ///```
+    pub fn write_codes_iter(&mut self, codes: impl IntoIterator<Item = u8>) {
+        let mut codes = codes.into_iter();
+        if let Codes::Inline(buf, len) = &mut self.codes {
+            if *len as usize + codes.size_hint().0 <= Self::INLINE {
+                while (*len as usize) < Self::INLINE {
+                    let Some(code) = codes.next() else {
+                        return;
+                    };
+                    buf[*len as usize] = code;
+                    *len += 1;
+                }
+            }
+        }
+        // the inline buffer is full, or the codes aren't going to fit
+        let mut codes = codes.peekable();
+        if codes.peek().is_some() {
+            let additional = codes.size_hint().0;
+            self.spill(additional).extend(codes);
+        }
+    }
+    /// Moves the codes to the heap if not already, reserving space for `additional` more
+    fn spill(&mut self, additional: usize) -> &mut Vec<u8> {
+        if let Codes::Inline(buf, len) = &self.codes {
+            let mut heap = Vec::with_capacity((*len as usize + additional).max(Self::INLINE * 2));
+            heap.extend_from_slice(&buf[..*len as usize]);
+            self.codes = Codes::Heap(heap);
+        }
+        match &mut self.codes {
+            Codes::Heap(heap) => {
+                heap.reserve(additional);
+                heap
+            }
+            Codes::Inline(..) => unreachable!("the codes were just moved to the heap"),
+        }
+    }
+    /// Writes a code to the internal buffer
+    ///
+    /// Returns self to allow for chaining
+    #[inline]
+    pub fn chain_code(&mut self, code: u8) -> &mut Self {
+        self.write_code(code);
+        self
+    }
+    /// Writes codes to the internal buffer
+    ///
+    /// Returns self to allow for chaining
+    #[inline]
+    pub fn chain_codes(&mut self, codes: &[u8]) -> &mut Self {
+        self.write_codes(codes);
+        self
+    }
+    /// Writes codes from an iterator to the internal buffer
+    ///
+    /// Returns self to allow for chaining
+    #[inline]
+    pub fn chain_codes_iter(&mut self, codes: impl IntoIterator<Item = u8>) -> &mut Self {
+        self.write_codes_iter(codes);
+        self
+    }
+    /// Returns the codes written so far
+    #[must_use]
+    pub fn codes(&self) -> &[u8] {
+        match &self.codes {
+            Codes::Inline(buf, len) => &buf[..*len as usize],
+            Codes::Heap(heap) => heap,
+        }
+    }
+    /// Returns the number of codes written so far
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.codes().len()
+    }
+    /// Returns whether no codes have been written
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.codes().is_empty()
+    }
+    /// Removes all codes, keeping any allocated capacity
+    pub fn clear(&mut self) {
+        match &mut self.codes {
+            Codes::Inline(_, len) => *len = 0,
+            Codes::Heap(heap) => heap.clear(),
+        }
+    }
+    /// Removes & returns the code at `index`, shifting the codes after it
+    ///
+    /// # Panics
+    ///
+    /// `index` is out of bounds
+    pub fn remove(&mut self, index: usize) -> u8 {
+        match &mut self.codes {
+            Codes::Inline(buf, len) => {
+                assert!(index < *len as usize, "index {index} out of bounds");
+                let code = buf[index];
+                buf.copy_within(index + 1..*len as usize, index);
+                *len -= 1;
+                code
+            }
+            Codes::Heap(heap) => heap.remove(index),
+        }
+    }
+    /// Returns whether the codes have been moved to the heap,
+    /// having ever been more than [`SGRBuilder::INLINE`]
+    #[must_use]
+    pub const fn spilled(&self) -> bool {
+        matches!(self.codes, Codes::Heap(_))
+    }
+    /// Parses the codes of a single SGR sequence, the inverse of [`SGRBuilder::write_to`]
+    ///
+    /// Empty parameters are read as `0`.
+    /// Extended colors written with subparameters, e.g. `38:2::255:0:0`,
+    /// are read as their `;` form, e.g. `38;2;255;0;0`,
+    /// the color space id before their RGB components being dropped
+    ///
+    /// # Errors
+    ///
+    /// `seq` isn't exactly one SGR sequence, one of its parameters isn't a code,
+    /// holds subparameters other than an extended color
+    /// or starts an extended color missing its parameters, e.g. `38;5`
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{SGRBuilder, SgrParseError};
+    ///
+    ///let mut builder = SGRBuilder::parse("\x1b[1;38;5;196m")?;
+    ///assert_eq!(builder.codes(), [1, 38, 5, 196]);
+    ///
+    ///builder.remove(0);
+    ///builder.write_code(4);
+    ///assert_eq!(builder.to_string(), "\x1b[38;5;196;4m");
+    ///
+    ///assert_eq!(SGRBuilder::parse("\x1b[2J"), Err(SgrParseError::NotSgr));
+    ///# Ok::<(), SgrParseError>(())
+    ///```
+    pub fn parse(seq: &str) -> Result<Self, SgrParseError> {
+        crate::escape::validate(seq).map_err(SgrParseError::Escape)?;
+        let params = seq
+            .strip_prefix("\x1b[")
+            .and_then(|seq| seq.strip_suffix('m'))
+            .ok_or(SgrParseError::NotSgr)?;
+        crate::escape::sgr_codes(params)
+            .map(Self::from)
+            .map_err(|i| SgrParseError::InvalidParam(i + 2))
+    }
+    /// Returns the codes of every SGR sequence found within `s`
+    ///
+    /// Other escape sequences & SGR sequences with parameters
+    /// that aren't codes are skipped
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::SGRBuilder;
+    ///
+    ///let builders = SGRBuilder::parse_all("\x1b[1mbold\x1b[2K\x1b[22;31m red");
+    ///assert_eq!(builders, [SGRBuilder::from(&[1][..]), SGRBuilder::from(&[22, 31][..])]);
+    ///```
     #[must_use]
-    fn get_writer(self) -> Self::Writer {
-        self.writer
+    pub fn parse_all(s: &str) -> Vec<Self> {
+        let mut builders = Vec::new();
+        EscapeScanner::default().scan_parts(s, |part| {
+            if let Part::Sgr(codes) = part {
+                builders.push(Self::from(codes));
+            }
+        });
+        builders
+    }
+    /// Returns the complete sequence of the codes, empty when there are none
+    ///
+    /// The same as [`ToString::to_string`], written without a writer
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::SGRBuilder;
+    ///
+    ///let builder = SGRBuilder::from(&[1, 31][..]);
+    ///assert_eq!(format!("[{}]", builder.to_sequence_string()), "[\x1b[1;31m]");
+    ///```
+    #[must_use]
+    pub fn to_sequence_string(&self) -> String {
+        if self.is_empty() {
+            String::new()
+        } else {
+            with_sequence(self.codes(), true, str::to_string)
+        }
+    }
+    /// Writes buffered codes to the provided writer
+    ///
+    /// # Errors
+    ///
+    /// Writing failed
+    pub fn write_to<W: CapableWriter>(&mut self, writer: &mut W) -> Result<(), W::Error> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            writer.write_sgr(self.codes())
+        }
+    }
+    /// Writes buffered codes to the writer
+    ///
+    /// Does not write the escape or end sequences
+    ///
+    /// Performs IO operations with the inputted [`SGRWriter`]
+    ///
+    /// # Errors
+    ///
+    /// Writing failed
+    pub fn write_partial<W: CapableWriter>(&mut self, writer: &mut W) -> Result<(), W::Error> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            writer.write_partial_sgr(self.codes())
+        }
     }
 }
-/// Used to implement [`CapableWriter`] for [`std::io::Write`]
-#[derive(Debug, Clone)]
-pub struct IoWriter<W: std::io::Write>(pub W);
-impl<W: std::io::Write> CapableWriter for IoWriter<W> {
-    type Writer = W;
-    type Error = io::Error;
-    #[inline]
-    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
-        self.0.write_all(s.as_bytes())
+/// Writes the complete sequence of the codes, nothing when there are none
+impl fmt::Display for SGRBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            with_sequence(self.codes(), true, |seq| f.write_str(seq))
+        }
+    }
+}
+/// Writes the codes by the [`Style`] or [`Color`]
+/// they're written by, e.g. `SGR[Bold, RedFg]`,
+/// underline colors as `Underline(ByteFg(1))`, bright colors as `Bright(RedFg)`
+/// & codes without one being written as numbers
+impl fmt::Debug for SGRBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_codes(self.codes(), f)
     }
+}
+/// Writes `codes` as [`SGRBuilder`]'s [`Debug`](fmt::Debug) does
+fn debug_codes(mut codes: &[u8], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("SGR")?;
+    let mut list = f.debug_list();
+    while !codes.is_empty() {
+        let (symbol, rest) = Symbol::split(codes);
+        list.entry(&symbol);
+        codes = rest;
+        // the codes after an incomplete color can't be told apart
+        if matches!(symbol, Symbol::Code(38 | 48 | 58)) {
+            list.entries(codes);
+            break;
+        }
+    }
+    list.finish()
+}
+/// A code or set of codes written by [`SGRBuilder`]'s [`Debug`](fmt::Debug)
+///
+/// Found by the codes [`Style::code`] & [`Color::codes`] give,
+/// so they can't disagree on what a code means
+enum Symbol {
+    Style(Style),
+    /// A color written to its own plane
+    Color(Color),
+    /// A color written to the underline, e.g. `58;5;1`
+    Underline(Color),
+    /// A bright color of the codes `90` to `97` & `100` to `107`,
+    /// by the basic color `60` below it
+    Bright(Color),
+    Code(u8),
+}
+impl Symbol {
+    /// Splits the symbol at the start of `codes` from the rest,
+    /// `codes` being non-empty
+    fn split(codes: &[u8]) -> (Self, &[u8]) {
+        use Color::{ByteBg, ByteFg, DefaultFg, RgbBg, RgbFg};
+        /// Returns the length of `sgr` if `codes` starts with it
+        fn starts_with(codes: &[u8], sgr: impl Iterator<Item = u8>) -> Option<usize> {
+            let sgr: Vec<_> = sgr.collect();
+            codes.starts_with(&sgr).then_some(sgr.len())
+        }
+        let code = codes[0];
+        if let Some(style) = Style::ALL.into_iter().find(|style| style.code() == code) {
+            return (Self::Style(style), &codes[1..]);
+        }
+        // the parameters of an extended color, zero when missing
+        let param = |i: usize| codes.get(i).copied().unwrap_or(0);
+        let byte = param(2);
+        let (r, g, b) = (param(2), param(3), param(4));
 
-    #[must_use]
-    fn get_writer(self) -> Self::Writer {
-        self.0
+        let colors = [ByteFg(byte), RgbFg(r, g, b), ByteBg(byte), RgbBg(r, g, b)];
+        let colors = colors.into_iter().chain(Color::NAMED);
+        let underlines = [ByteFg(byte), RgbFg(r, g, b), DefaultFg].into_iter();
+        let found = colors
+            .filter_map(|color| {
+                Some((
+                    starts_with(codes, color.codes(color.plane()))?,
+                    Self::Color(color),
+                ))
+            })
+            .chain(underlines.filter_map(|color| {
+                Some((
+                    starts_with(codes, color.codes(Plane::Underline))?,
+                    Self::Underline(color),
+                ))
+            }))
+            .chain(code.checked_sub(60).and_then(|basic| {
+                Color::NAMED
+                    .into_iter()
+                    .filter(|color| !matches!(color, Color::DefaultFg | Color::DefaultBg))
+                    .find(|color| color.codes(color.plane()).eq([basic]))
+                    .map(|color| (1, Self::Bright(color)))
+            }))
+            .next();
+        match found {
+            Some((len, symbol)) => (symbol, &codes[len..]),
+            None => (Self::Code(code), &codes[1..]),
+        }
     }
 }
-/// Used to implement [`CapableWriter`] for [`std::fmt::Write`]
-#[derive(Debug, Clone)]
-pub struct FmtWriter<W: std::fmt::Write>(pub W);
-impl<W: std::fmt::Write> CapableWriter for FmtWriter<W> {
-    type Writer = W;
-    type Error = fmt::Error;
-    #[inline]
-    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
-        self.0.write_str(s)
+impl fmt::Debug for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Style(style) => fmt::Debug::fmt(style, f),
+            Self::Color(color) => fmt::Debug::fmt(color, f),
+            Self::Underline(color) => f.debug_tuple("Underline").field(color).finish(),
+            Self::Bright(color) => f.debug_tuple("Bright").field(color).finish(),
+            Self::Code(code) => fmt::Debug::fmt(code, f),
+        }
     }
-    #[must_use]
-    fn get_writer(self) -> Self::Writer {
-        self.0
+}
+/// Builders are equal when their codes are, however they're stored
+impl PartialEq for SGRBuilder {
+    fn eq(&self, other: &Self) -> bool {
+        self.codes() == other.codes()
     }
 }
-/// Builds a SGR sequence
-#[derive(Debug, Default)]
-pub struct SGRBuilder(pub Vec<u8>);
-
-impl SGRBuilder {
-    /// Writes a code to the internal buffer
+impl Eq for SGRBuilder {}
+impl From<&[u8]> for SGRBuilder {
+    fn from(value: &[u8]) -> Self {
+        let mut builder = Self::default();
+        builder.write_codes(value);
+        builder
+    }
+}
+impl From<Vec<u8>> for SGRBuilder {
+    fn from(value: Vec<u8>) -> Self {
+        if value.len() <= Self::INLINE {
+            Self::from(value.as_slice())
+        } else {
+            Self {
+                codes: Codes::Heap(value),
+            }
+        }
+    }
+}
+impl FromIterator<u8> for SGRBuilder {
+    fn from_iter<T: IntoIterator<Item = u8>>(iter: T) -> Self {
+        let mut builder = Self::default();
+        builder.write_codes_iter(iter);
+        builder
+    }
+}
+impl From<SGRBuilder> for Vec<u8> {
+    fn from(value: SGRBuilder) -> Self {
+        match value.codes {
+            Codes::Inline(buf, len) => buf[..len as usize].to_vec(),
+            Codes::Heap(heap) => heap,
+        }
+    }
+}
+/// Where [`DiscreteSGR::write`] pushes its codes
+///
+/// Implemented by both [`SGRBuilder`] & [`ArrayBuilder`],
+/// so the same [`DiscreteSGR`] implementations build sequences with either
+///
+/// Its typed methods push the codes of a [`Style`] or [`Color`],
+/// e.g. with the `38;5` of a 256-color, rather than raw codes
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{CodeSink, Color::*, SGRBuilder, Style::*};
+///
+///let mut builder = SGRBuilder::default();
+///builder.write_style(&Bold);
+///builder.write_fg(&ByteFg(208));
+///builder.write_bg(&RedFg);
+///builder.write_underline_color(&RgbFg(1, 2, 3));
+///builder.clear_style(&Bold);
+///assert_eq!(builder.codes(), [1, 38, 5, 208, 41, 58, 2, 1, 2, 3, 22]);
+///```
+pub trait CodeSink {
+    /// Pushes a single code
+    fn write_code(&mut self, code: u8);
+    /// Pushes several codes, such as the `38;5;<n>` of a single color
+    fn write_codes(&mut self, codes: &[u8]);
+    /// Pushes the code applying `style`, see [`Style::code`]
+    fn write_style(&mut self, style: &Style) {
+        self.write_code(style.code());
+    }
+    /// Pushes the code undoing `style`, see [`Style::clear_code`]
+    fn clear_style(&mut self, style: &Style) {
+        self.write_code(style.clear_code());
+    }
+    /// Pushes the codes of `color` written to `plane`,
+    /// whichever plane it's written to itself, see [`Color::codes`]
+    fn write_color(&mut self, color: &Color, plane: Plane) {
+        let (codes, len) = color.code_array(plane);
+        self.write_codes(&codes[..len]);
+    }
+    /// Pushes the codes of `color` as the foreground
+    fn write_fg(&mut self, color: &Color) {
+        self.write_color(color, Plane::Foreground);
+    }
+    /// Pushes the codes of `color` as the background
+    fn write_bg(&mut self, color: &Color) {
+        self.write_color(color, Plane::Background);
+    }
+    /// Pushes the codes of `color` as the underline's color
+    fn write_underline_color(&mut self, color: &Color) {
+        self.write_color(color, Plane::Underline);
+    }
+}
+impl CodeSink for SGRBuilder {
     #[inline]
-    pub fn write_code(&mut self, code: u8) {
-        self.0.push(code);
+    fn write_code(&mut self, code: u8) {
+        Self::write_code(self, code);
     }
-    /// Writes codes to the internal buffer
     #[inline]
-    pub fn write_codes(&mut self, codes: &[u8]) {
-        self.0.extend_from_slice(codes);
+    fn write_codes(&mut self, codes: &[u8]) {
+        Self::write_codes(self, codes);
+    }
+}
+/// Builds a SGR sequence within a fixed capacity of `N` codes, never allocating
+///
+/// Codes that don't fit are rejected with a [`CapacityError`], none of them being written,
+/// so a color is never left without its parameters.
+/// Codes written through [`CodeSink`], i.e. by [`DiscreteSGR::write`],
+/// are dropped instead, [`ArrayBuilder::overflowed`] then returning `true`
+///
+/// Writers are given the codes the same as by [`SGRBuilder`],
+/// though the default [`CapableWriter::write_sgr`] formats more than
+/// [`SGRBuilder::INLINE`] codes on the heap
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{ArrayBuilder, CapacityError, Color::*, DiscreteSGR, SGRWriter, Style::*};
+///
+///let mut builder = ArrayBuilder::<4>::new();
+///Bold.write(&mut builder);
+///builder.write_codes(&[38, 5, 208])?;
+///assert_eq!(builder.write_code(4), Err(CapacityError));
+///
+///let mut writer = SGRWriter::from(String::new());
+///builder.write_to(&mut writer)?;
+///assert_eq!(writer.internal(), "\x1b[1;38;5;208m");
+///
+///RedFg.write(&mut builder);
+///assert!(builder.overflowed());
+///# Ok::<(), Box<dyn std::error::Error>>(())
+///```
+#[derive(Clone, Copy)]
+pub struct ArrayBuilder<const N: usize> {
+    codes: [u8; N],
+    len: usize,
+    overflowed: bool,
+}
+impl<const N: usize> ArrayBuilder<N> {
+    /// Creates an empty builder
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            codes: [0; N],
+            len: 0,
+            overflowed: false,
+        }
     }
     /// Writes a code to the internal buffer
     ///
-    /// Returns self to allow for chaining
+    /// # Errors
+    ///
+    /// The buffer is full, the code isn't written
     #[inline]
-    pub fn chain_code(&mut self, code: u8) -> &mut Self {
-        self.0.push(code);
-        self
+    pub fn write_code(&mut self, code: u8) -> Result<(), CapacityError> {
+        self.write_codes(&[code])
     }
     /// Writes codes to the internal buffer
     ///
-    /// Returns self to allow for chaining
-    #[inline]
-    pub fn chain_codes(&mut self, codes: &[u8]) -> &mut Self {
-        self.0.extend_from_slice(codes);
-        self
+    /// # Errors
+    ///
+    /// The codes don't all fit, none of them are written
+    pub fn write_codes(&mut self, codes: &[u8]) -> Result<(), CapacityError> {
+        let end = self.len + codes.len();
+        if end > N {
+            return Err(CapacityError);
+        }
+        self.codes[self.len..end].copy_from_slice(codes);
+        self.len = end;
+        Ok(())
+    }
+    /// Returns the codes written so far
+    #[must_use]
+    pub fn codes(&self) -> &[u8] {
+        &self.codes[..self.len]
+    }
+    /// Returns the number of codes written so far
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+    /// Returns whether no codes have been written
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Returns whether codes written through [`CodeSink`] were dropped,
+    /// not fitting within the buffer
+    #[must_use]
+    pub const fn overflowed(&self) -> bool {
+        self.overflowed
+    }
+    /// Removes all codes, along with whether any overflowed
+    pub const fn clear(&mut self) {
+        self.len = 0;
+        self.overflowed = false;
     }
     /// Writes buffered codes to the provided writer
     ///
@@ -227,45 +2118,367 @@ impl SGRBuilder {
     ///
     /// Writing failed
     pub fn write_to<W: CapableWriter>(&mut self, writer: &mut W) -> Result<(), W::Error> {
-        if self.0.is_empty() {
+        if self.is_empty() {
             Ok(())
         } else {
-            writer.write("\x1b[")?;
-            self.codes_inner(writer)?;
-            writer.write("m")
+            writer.write_sgr(self.codes())
         }
     }
     /// Writes buffered codes to the writer
     ///
     /// Does not write the escape or end sequences
     ///
-    /// Performs IO operations with the inputted [`SGRWriter`]
-    ///
     /// # Errors
     ///
     /// Writing failed
     pub fn write_partial<W: CapableWriter>(&mut self, writer: &mut W) -> Result<(), W::Error> {
-        if !self.0.is_empty() {
-            self.codes_inner(writer)?;
+        if self.is_empty() {
+            Ok(())
+        } else {
+            writer.write_partial_sgr(self.codes())
         }
-        Ok(())
     }
-    /// Writes the buffered codes into the inputted writer
-    fn codes_inner<W: CapableWriter>(&mut self, writer: &mut W) -> Result<(), W::Error> {
-        writer.write(&self.0[0].to_string())?;
-
-        for code in &self.0[1..] {
-            writer.write(";")?;
-            writer.write(&code.to_string())?;
+}
+impl<const N: usize> Default for ArrayBuilder<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+/// Drops codes that don't fit, see [`ArrayBuilder::overflowed`]
+impl<const N: usize> CodeSink for ArrayBuilder<N> {
+    #[inline]
+    fn write_code(&mut self, code: u8) {
+        CodeSink::write_codes(self, &[code]);
+    }
+    #[inline]
+    fn write_codes(&mut self, codes: &[u8]) {
+        if Self::write_codes(self, codes).is_err() {
+            self.overflowed = true;
+        }
+    }
+}
+/// Writes the complete sequence of the codes, nothing when there are none
+impl<const N: usize> fmt::Display for ArrayBuilder<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            with_sequence(self.codes(), true, |seq| f.write_str(seq))
+        }
+    }
+}
+/// Writes the codes the same as [`SGRBuilder`]'s [`Debug`](fmt::Debug)
+impl<const N: usize> fmt::Debug for ArrayBuilder<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_codes(self.codes(), f)
+    }
+}
+/// Builders are equal when their codes are
+impl<const N: usize> PartialEq for ArrayBuilder<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.codes() == other.codes()
+    }
+}
+impl<const N: usize> Eq for ArrayBuilder<N> {}
+/// Lets a [`CapableWriter`] be formatted into, keeping the error it returns
+struct FmtAdapter<'a, W: CapableWriter> {
+    writer: &'a mut W,
+    error: Option<W::Error>,
+}
+impl<W: CapableWriter> fmt::Write for FmtAdapter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.writer.write(s).map_err(|e| {
+            self.error = Some(e);
+            fmt::Error
+        })
+    }
+}
+/// Calls `f` with `codes` separated by `;`, within the escape & end when `complete`
+///
+/// Built on the stack for up to [`SGRBuilder::INLINE`] codes, so most sequences don't allocate
+fn with_sequence<R>(codes: &[u8], complete: bool, f: impl FnOnce(&str) -> R) -> R {
+    /// Fits the escape, end & [`SGRBuilder::INLINE`] codes of up to 3 digits & a `;`
+    const CAPACITY: usize = SGRBuilder::INLINE * 4 + 3;
+    if codes.len() > SGRBuilder::INLINE {
+        let mut seq = String::with_capacity(codes.len() * 4 + 3);
+        if complete {
+            seq.push_str("\x1b[");
+        }
+        push_codes(&mut seq, codes);
+        if complete {
+            seq.push('m');
+        }
+        return f(&seq);
+    }
+    let mut buf = [0; CAPACITY];
+    let mut len = 0;
+    let mut push = |byte: u8| {
+        buf[len] = byte;
+        len += 1;
+    };
+    if complete {
+        push(0x1b);
+        push(b'[');
+    }
+    for (i, &code) in codes.iter().enumerate() {
+        if i != 0 {
+            push(b';');
+        }
+        if code >= 100 {
+            push(b'0' + code / 100);
+        }
+        if code >= 10 {
+            push(b'0' + code / 10 % 10);
+        }
+        push(b'0' + code % 10);
+    }
+    if complete {
+        push(b'm');
+    }
+    f(std::str::from_utf8(&buf[..len]).expect("sequences should only be made of ASCII"))
+}
+/// Pushes `codes` separated by `;` onto `seq`
+pub(crate) fn push_codes(seq: &mut String, codes: &[u8]) {
+    for (i, code) in codes.iter().enumerate() {
+        if i != 0 {
+            seq.push(';');
+        }
+        code.append_to(seq);
+    }
+}
+/// Queues escape sequences to be written together
+///
+/// SGR sequences, other control sequences & operating system commands
+/// are kept in the order they're queued, then written by a single write.
+/// [`SGRWriter`]'s cursor & hyperlink methods are built on it,
+/// so they can be batched with style changes, e.g. to redraw a line without flicker
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{Color::*, EraseMode, SequenceBuilder, Style::*};
+///
+///let mut seq = SequenceBuilder::default();
+///seq.save_cursor()
+///    .cursor_up(1)
+///    .erase_line(EraseMode::All)
+///    .sgr(&Bold)
+///    .csi(&[2, 3], 'H');
+///
+///assert_eq!(seq.as_str(), "\x1b7\x1b[A\x1b[2K\x1b[1m\x1b[2;3H");
+///```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SequenceBuilder {
+    seq: String,
+}
+impl SequenceBuilder {
+    /// Queues the codes written by `sgr` as a single SGR sequence
+    ///
+    /// Nothing is queued when no codes are written
+    pub fn sgr(&mut self, sgr: &impl EasyWrite) -> &mut Self {
+        let mut builder = SGRBuilder::default();
+        sgr.sgr(&mut builder);
+        self.sgr_codes(builder.codes())
+    }
+    /// Queues `codes` as a single SGR sequence
+    ///
+    /// Nothing is queued when `codes` is empty
+    pub fn sgr_codes(&mut self, codes: &[u8]) -> &mut Self {
+        if !codes.is_empty() {
+            self.seq.push_str("\x1b[");
+            push_codes(&mut self.seq, codes);
+            self.seq.push('m');
+        }
+        self
+    }
+    /// Queues a control sequence made of `params` separated by `;` & the final byte `end`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `end` isn't a final byte, `@` through `~`
+    pub fn csi(&mut self, params: &[u16], end: char) -> &mut Self {
+        assert!(('@'..='~').contains(&end), "{end:?} isn't a final byte");
+        self.seq.push_str("\x1b[");
+        for (i, param) in params.iter().enumerate() {
+            if i != 0 {
+                self.seq.push(';');
+            }
+            param.append_to(&mut self.seq);
+        }
+        self.seq.push(end);
+        self
+    }
+    /// Queues an operating system command, terminated by `ST`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `payload` contains any control characters
+    pub fn osc(&mut self, payload: &str) -> &mut Self {
+        assert!(
+            !payload.chars().any(char::is_control),
+            "{payload:?} contains control characters"
+        );
+        self.seq.push_str("\x1b]");
+        self.seq.push_str(payload);
+        self.seq.push_str("\x1b\\");
+        self
+    }
+    /// Queues moving the cursor up `n` lines
+    ///
+    /// Nothing is queued when `n` is `0`
+    pub fn cursor_up(&mut self, n: u16) -> &mut Self {
+        if n == 0 {
+            return self;
+        }
+        self.control(n, 1, 'A')
+    }
+    /// Queues moving the cursor to column `n` of the current line
+    ///
+    /// Columns start at `1`, `0` is treated the same as `1`
+    pub fn cursor_to_column(&mut self, n: u16) -> &mut Self {
+        self.control(n.max(1), 1, 'G')
+    }
+    /// Queues erasing part of the current line
+    pub fn erase_line(&mut self, mode: EraseMode) -> &mut Self {
+        self.control(mode as u16, 0, 'K')
+    }
+    /// Queues erasing part of the display
+    pub fn erase_display(&mut self, mode: EraseMode) -> &mut Self {
+        self.control(mode as u16, 0, 'J')
+    }
+    /// Queues saving the cursor's position
+    pub fn save_cursor(&mut self) -> &mut Self {
+        self.seq.push_str("\x1b7");
+        self
+    }
+    /// Queues moving the cursor to the position last saved
+    pub fn restore_cursor(&mut self) -> &mut Self {
+        self.seq.push_str("\x1b8");
+        self
+    }
+    /// Queues hiding the cursor
+    pub fn hide_cursor(&mut self) -> &mut Self {
+        self.seq.push_str("\x1b[?25l");
+        self
+    }
+    /// Queues showing the cursor
+    pub fn show_cursor(&mut self) -> &mut Self {
+        self.seq.push_str("\x1b[?25h");
+        self
+    }
+    /// Queues opening a hyperlink to `url`
+    ///
+    /// See [`SGRWriter::open_hyperlink`]
+    pub fn open_hyperlink(&mut self, url: &str, id: Option<&str>) -> &mut Self {
+        self.seq.push_str("\x1b]8;");
+        if let Some(id) = id {
+            self.seq.push_str("id=");
+            push_escaped(&mut self.seq, id, |b| b == b':' || b == b';');
+        }
+        self.seq.push(';');
+        push_escaped(&mut self.seq, url, |_| false);
+        self.seq.push_str("\x1b\\");
+        self
+    }
+    /// Queues closing a hyperlink
+    pub fn close_hyperlink(&mut self) -> &mut Self {
+        self.seq.push_str("\x1b]8;;\x1b\\");
+        self
+    }
+    /// Returns the queued sequences
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.seq
+    }
+    /// Returns the length in bytes of the queued sequences
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.seq.len()
+    }
+    /// Returns `true` if nothing is queued
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.seq.is_empty()
+    }
+    /// Removes every queued sequence
+    pub fn clear(&mut self) {
+        self.seq.clear();
+    }
+    /// Writes the queued sequences by a single write, then clears them
+    ///
+    /// Nothing is written when nothing is queued
+    ///
+    /// # Errors
+    ///
+    /// Writing failed, the sequences are kept queued
+    pub fn flush_to<W: CapableWriter>(&mut self, writer: &mut W) -> Result<(), W::Error> {
+        if self.is_empty() {
+            return Ok(());
         }
+        writer.write(&self.seq)?;
+        self.clear();
         Ok(())
     }
+    /// Queues a control sequence with a single parameter
+    ///
+    /// The parameter is left out when it equals the sequence's default
+    fn control(&mut self, n: u16, default: u16, end: char) -> &mut Self {
+        self.seq.push_str("\x1b[");
+        if n != default {
+            n.append_to(&mut self.seq);
+        }
+        self.seq.push(end);
+        self
+    }
+}
+/// Writes the queued sequences
+impl fmt::Display for SequenceBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.seq)
+    }
+}
+/// Pushes `s` onto `seq`, percent-escaping any bytes that
+/// aren't printable ASCII or that `escape` returns `true` for
+fn push_escaped(seq: &mut String, s: &str, escape: impl Fn(u8) -> bool) {
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+    for b in s.bytes() {
+        if (b' '..=b'~').contains(&b) && !escape(b) {
+            seq.push(char::from(b));
+        } else {
+            seq.push('%');
+            seq.push(char::from(HEX[usize::from(b >> 4)]));
+            seq.push(char::from(HEX[usize::from(b & 0xf)]));
+        }
+    }
 }
 
 /// Helps to make writing easier
 ///
 /// Allows to use the same method for both
 /// [`SGRString`] and [`DiscreteSGR`] types
+///
+/// Implemented for each of this crate's types rather than every [`DiscreteSGR`],
+/// so types of other crates may implement both traits, each as they see fit.
+/// Most [`DiscreteSGR`] types will write the same codes for both.
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{Color::*, EasyWrite, SGRBuilder, SGRWriter, Style::*};
+///
+///struct Warning;
+///impl EasyWrite for Warning {
+///    fn sgr(&self, builder: &mut SGRBuilder) {
+///        YellowFg.sgr(builder);
+///        Bold.sgr(builder);
+///    }
+///}
+///
+///let mut writer = SGRWriter::from(String::new());
+///writer.styled(&Warning, "careful")?;
+///assert_eq!(writer.internal(), "\x1b[33;1mcareful\x1b[0m");
+///# Ok::<(), std::fmt::Error>(())
+///```
 pub trait EasyWrite {
     /// Writes a set of codes to the builder
     fn sgr(&self, builder: &mut SGRBuilder);
@@ -280,11 +2493,25 @@ impl EasyWrite for SGRString {
     }
 }
 
-impl<D: DiscreteSGR> EasyWrite for D {
-    /// Writes a set of codes to the builder
-    ///
-    /// Uses [`DiscreteSGR::write`]
-    fn sgr(&self, builder: &mut SGRBuilder) {
-        self.write(builder);
-    }
+/// Implements [`EasyWrite`] for [`DiscreteSGR`] types, using [`DiscreteSGR::write`]
+macro_rules! easy_write_discrete {
+    ($($ty:ty $(where [$($generic:ident),*])?),*) => {
+        $(
+            impl$(<$($generic: DiscreteSGR),*>)? EasyWrite for $ty {
+                /// Writes a set of codes to the builder
+                ///
+                /// Uses [`DiscreteSGR::write`]
+                fn sgr(&self, builder: &mut SGRBuilder) {
+                    self.write(builder);
+                }
+            }
+        )*
+    };
 }
+easy_write_discrete!(
+    crate::Color,
+    crate::Style,
+    crate::AnySgr,
+    (A, B) where [A, B],
+    (A, B, C) where [A, B, C]
+);
@@ -0,0 +1,62 @@
+use easy_sgr::{append::AppendToString, Color::*, Style::*};
+
+/// Asserts `value` appends what [`ToString`] would
+fn check<T: AppendToString + ToString>(value: T) {
+    let mut s = String::from("prefix");
+    value.append_to(&mut s);
+    assert_eq!(s, format!("prefix{}", value.to_string()));
+    assert_eq!(value.to_appended_string(), value.to_string());
+}
+#[test]
+fn u8() {
+    (0..=u8::MAX).for_each(check);
+}
+#[test]
+fn u16() {
+    (0..=u16::MAX).for_each(check);
+}
+#[test]
+fn u32() {
+    (0..=u32::MAX).step_by(9973).for_each(check);
+    (0..1000).for_each(check::<u32>);
+    (u32::MAX - 1000..=u32::MAX).for_each(check);
+    (0..10).map(|n| 10_u32.pow(n)).for_each(|n| {
+        check(n - 1);
+        check(n);
+        check(n + 1);
+    });
+}
+#[test]
+fn usize() {
+    (0..1000).for_each(check::<usize>);
+    (usize::MAX - 1000..=usize::MAX).for_each(check);
+    (0..usize::MAX.ilog10())
+        .map(|n| 10_usize.pow(n))
+        .for_each(|n| {
+            check(n - 1);
+            check(n);
+            check(n + 1);
+        });
+}
+#[test]
+fn text() {
+    ["", "text", "multi\nline", "ünïcödé"]
+        .into_iter()
+        .for_each(check);
+    ['a', ';', 'é', '\x1b', '🦀'].into_iter().for_each(check);
+}
+#[test]
+fn sgr() {
+    assert_eq!(RedFg.to_appended_string(), "31");
+    assert_eq!(ByteBg(208).to_appended_string(), "48;5;208");
+    assert_eq!(RgbFg(255, 0, 10).to_appended_string(), "38;2;255;0;10");
+    assert_eq!(Bold.to_appended_string(), "1");
+    assert_eq!(NotStrikethrough.to_appended_string(), "29");
+
+    let mut s = String::from("\x1b[");
+    Bold.append_to(&mut s);
+    s.push(';');
+    ByteFg(1).append_to(&mut s);
+    'm'.append_to(&mut s);
+    assert_eq!(s, "\x1b[1;38;5;1m");
+}
@@ -0,0 +1,72 @@
+use easy_sgr::{
+    color_choice, quick::red, set_color_choice, AnySgr, CleanKind, Color::*, ColorChoice, EasySGR,
+    SGRText, SGRWriter, Style::*,
+};
+
+#[cfg(not(feature = "partial"))]
+const STYLED: &str = "\x1b[31ma\x1b[0m\x1b[31mb|\x1b[31mc\x1b[1m\x1b[53m";
+#[cfg(feature = "partial")]
+const STYLED: &str = "\x1b[31ma\x1b[0m\x1b[31mb|\x1b[31mc153";
+const PLAIN: &str = "ab|c";
+
+fn render() -> String {
+    let text: SGRText = [RedFg.text("a").clean(CleanKind::Reset), red("b")]
+        .into_iter()
+        .collect();
+    format!("{text}|{}{Bold}{}", red("c"), AnySgr::Raw(53))
+}
+fn explicit() -> String {
+    let mut writer = SGRWriter::from(String::new());
+    writer.sgr_string(&RedFg.text("d")).unwrap();
+    writer.internal()
+}
+// the choice is global, so every choice is checked within a single test
+#[test]
+fn color_choice_paths() {
+    assert_eq!(color_choice(), ColorChoice::Always);
+    assert_eq!(render(), STYLED);
+
+    set_color_choice(ColorChoice::Never);
+    assert_eq!(color_choice(), ColorChoice::Never);
+    assert_eq!(render(), PLAIN);
+    assert_eq!(BlueBg.to_string(), "");
+    #[cfg(feature = "ufmt")]
+    {
+        let mut s = String::new();
+        ufmt::uwrite!(s, "{}{}", RedFg.text("e"), BlueBg).unwrap();
+        assert_eq!(s, "e");
+    }
+    // settings made on a writer win over the global choice
+    assert_eq!(explicit(), "\x1b[31md");
+    let mut writer = SGRWriter::stdout();
+    assert!(!writer.is_enabled());
+    writer.set_enabled(true);
+    assert!(writer.is_enabled());
+
+    set_color_choice(ColorChoice::Always);
+    assert_eq!(color_choice(), ColorChoice::Always);
+    assert_eq!(render(), STYLED);
+    #[cfg(feature = "ufmt")]
+    {
+        let mut s = String::new();
+        ufmt::uwrite!(s, "{}{}", RedFg.text("e"), BlueBg).unwrap();
+        assert_eq!(s, "\x1b[31me\x1b[44m");
+    }
+    assert!(SGRWriter::stderr().is_enabled());
+
+    // resolved once, by whether stdout is a terminal & the environment
+    set_color_choice(ColorChoice::Auto);
+    let resolved = color_choice();
+    assert_ne!(resolved, ColorChoice::Auto);
+    assert_eq!(color_choice(), resolved);
+    let expected = if resolved == ColorChoice::Always {
+        STYLED
+    } else {
+        PLAIN
+    };
+    assert_eq!(render(), expected);
+    assert_eq!(explicit(), "\x1b[31md");
+
+    set_color_choice(ColorChoice::Always);
+    assert_eq!(render(), STYLED);
+}
@@ -0,0 +1,134 @@
+use easy_sgr::{consts::*, Color::*, DiscreteSGR, EasySGR, SGRBuilder, Style::*};
+
+/// Returns the complete sequence `sgr` writes
+fn sequence(sgr: &impl DiscreteSGR) -> String {
+    let mut builder = SGRBuilder::default();
+    sgr.write(&mut builder);
+    let mut seq = String::new();
+    builder.write_to(&mut seq).unwrap();
+    seq
+}
+#[test]
+fn styles() {
+    for (constant, style) in [
+        (RESET, Reset),
+        (BOLD, Bold),
+        (DIM, Dim),
+        (ITALIC, Italic),
+        (UNDERLINE, Underline),
+        (BLINKING, Blinking),
+        (INVERSE, Inverse),
+        (HIDDEN, Hidden),
+        (STRIKETHROUGH, Strikethrough),
+        (NO_BOLD, NotBold),
+        (NO_DIM, NotDim),
+        (NO_ITALIC, NotItalic),
+        (NO_UNDERLINE, NotUnderline),
+        (NO_BLINKING, NotBlinking),
+        (NO_INVERSE, NotInverse),
+        (NO_HIDDEN, NotHidden),
+        (NO_STRIKETHROUGH, NotStrikethrough),
+    ] {
+        assert_eq!(constant, sequence(&style), "{style:?}");
+    }
+}
+#[test]
+fn colors() {
+    for (constant, color) in [
+        (BLACK_FG, BlackFg),
+        (RED_FG, RedFg),
+        (GREEN_FG, GreenFg),
+        (YELLOW_FG, YellowFg),
+        (BLUE_FG, BlueFg),
+        (MAGENTA_FG, MagentaFg),
+        (CYAN_FG, CyanFg),
+        (WHITE_FG, WhiteFg),
+        (DEFAULT_FG, DefaultFg),
+        (BLACK_BG, BlackBg),
+        (RED_BG, RedBg),
+        (GREEN_BG, GreenBg),
+        (YELLOW_BG, YellowBg),
+        (BLUE_BG, BlueBg),
+        (MAGENTA_BG, MagentaBg),
+        (CYAN_BG, CyanBg),
+        (WHITE_BG, WhiteBg),
+        (DEFAULT_BG, DefaultBg),
+    ] {
+        assert_eq!(constant, sequence(&color), "{color:?}");
+    }
+}
+#[test]
+fn built() {
+    assert_eq!(fg_256::<0>(), ByteFg(0).to_sgr().to_string());
+    assert_eq!(fg_256::<9>(), ByteFg(9).to_sgr().to_string());
+    assert_eq!(bg_256::<42>(), ByteBg(42).to_sgr().to_string());
+    assert_eq!(bg_256::<255>(), ByteBg(255).to_sgr().to_string());
+    assert_eq!(
+        fg_rgb::<255, 255, 255>(),
+        RgbFg(255, 255, 255).to_sgr().to_string()
+    );
+    assert_eq!(
+        bg_rgb::<0, 10, 100>(),
+        RgbBg(0, 10, 100).to_sgr().to_string()
+    );
+    const CONST: &str = fg_rgb::<1, 2, 3>();
+    assert_eq!(CONST, "\x1b[38;2;1;2;3m");
+}
+#[cfg(feature = "macros")]
+#[test]
+fn keywords() {
+    use easy_sgr::sgr;
+
+    assert_eq!(
+        [
+            RESET,
+            BOLD,
+            DIM,
+            ITALIC,
+            UNDERLINE,
+            BLINKING,
+            INVERSE,
+            HIDDEN,
+            STRIKETHROUGH
+        ]
+        .concat(),
+        sgr!(
+            "{[reset]}{[bold]}{[dim]}{[italic]}{[underline]}{[blink]}{[inverse]}{[hide]}{[strike]}"
+        )
+    );
+    assert_eq!(
+        [
+            NO_BOLD,
+            NO_DIM,
+            NO_ITALIC,
+            NO_UNDERLINE,
+            NO_BLINKING,
+            NO_INVERSE,
+            NO_HIDDEN,
+            NO_STRIKETHROUGH
+        ]
+        .concat(),
+        sgr!(
+            "{[!bold]}{[!dim]}{[!italic]}{[!underline]}{[!blink]}{[!inverse]}{[!hide]}{[!strike]}"
+        )
+    );
+    assert_eq!(
+        [
+            BLACK_FG, RED_FG, GREEN_FG, YELLOW_FG, BLUE_FG, MAGENTA_FG, CYAN_FG, WHITE_FG,
+            DEFAULT_FG
+        ]
+        .concat(),
+        sgr!("{[black]}{[red]}{[green]}{[yellow]}{[blue]}{[magenta]}{[cyan]}{[white]}{[default]}")
+    );
+    assert_eq!(
+        [BLACK_BG, RED_BG, GREEN_BG, YELLOW_BG, BLUE_BG, MAGENTA_BG, CYAN_BG, WHITE_BG, DEFAULT_BG]
+            .concat(),
+        sgr!(
+            "{[on-black]}{[on-red]}{[on-green]}{[on-yellow]}{[on-blue]}{[on-magenta]}{[on-cyan]}{[on-white]}{[on-default]}"
+        )
+    );
+    assert_eq!(
+        [fg_256::<208>(), bg_rgb::<15, 115, 215>()].concat(),
+        sgr!("{[208]}{[on-#0f73d7]}")
+    );
+}
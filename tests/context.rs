@@ -0,0 +1,53 @@
+use std::panic;
+
+use easy_sgr::{
+    context::{default_style, with_default_style},
+    CleanKind,
+    Color::*,
+    EasySGR, SGRText,
+    Style::*,
+};
+
+#[test]
+fn nested() {
+    assert!(default_style().is_none());
+    with_default_style(Dim, || {
+        assert_eq!("text".to_sgr().to_string(), "\x1b[2mtext");
+        with_default_style(BlueFg.clean(CleanKind::Reverse), || {
+            assert_eq!("text".to_sgr().to_string(), "\x1b[34;2mtext\x1b[39;22m");
+            let text: SGRText = ["a", "b"].into_iter().collect();
+            assert_eq!(
+                text.to_string(),
+                "\x1b[34;2ma\x1b[39;22m\x1b[34;2mb\x1b[39;22m"
+            );
+        });
+        assert_eq!("text".to_sgr().to_string(), "\x1b[2mtext");
+    });
+    assert!(default_style().is_none());
+    assert_eq!("text".to_sgr().to_string(), "text");
+}
+#[test]
+fn explicit_fields_win() {
+    with_default_style(RedFg.style(Dim).clean(CleanKind::Reset), || {
+        assert_eq!(
+            GreenFg
+                .style(NotDim)
+                .text("text")
+                .clean(CleanKind::Reverse)
+                .to_string(),
+            "\x1b[32;22mtext\x1b[39;2m"
+        );
+        assert_eq!(BlueBg.text("text").to_string(), "\x1b[31;44;2mtext\x1b[0m");
+    });
+}
+#[test]
+fn panic_unwinding() {
+    with_default_style(Bold, || {
+        let result = panic::catch_unwind(|| {
+            with_default_style(Italic, || panic!("inner context panicked"));
+        });
+        assert!(result.is_err());
+        assert_eq!("text".to_sgr().to_string(), "\x1b[1mtext");
+    });
+    assert!(default_style().is_none());
+}
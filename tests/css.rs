@@ -0,0 +1,81 @@
+use easy_sgr::{css::Palette, Color::*, ColorKind, EasySGR, SGRString, SgrState, Style::*};
+
+#[test]
+fn attributes() {
+    let cases: [(SGRString, &str); 9] = [
+        (RedFg.into(), "color:#cd0000"),
+        (ByteBg(196).into(), "background-color:#ff0000"),
+        (Bold.into(), "font-weight:bold"),
+        (Dim.into(), "opacity:0.5"),
+        (Italic.into(), "font-style:italic"),
+        (Underline.into(), "text-decoration:underline"),
+        (Strikethrough.into(), "text-decoration:line-through"),
+        (Blinking.into(), "text-decoration:blink"),
+        (Hidden.into(), "visibility:hidden"),
+    ];
+    for (sgr, css) in cases {
+        assert_eq!(sgr.to_css(), css);
+    }
+    assert_eq!(DefaultFg.color(DefaultBg).style(NotBold).to_css(), "");
+}
+#[test]
+fn inverse() {
+    assert_eq!(
+        Inverse.to_sgr().to_css(),
+        "color:#000000;background-color:#e5e5e5"
+    );
+    assert_eq!(
+        RgbFg(1, 2, 3).style(Inverse).to_css(),
+        "color:#000000;background-color:#010203"
+    );
+    assert_eq!(
+        GreenFg.color(BlueBg).style(Inverse).to_css(),
+        "color:#0000ee;background-color:#00cd00"
+    );
+}
+#[test]
+fn combined() {
+    let sgr = "text"
+        .to_sgr()
+        .color(RgbFg(255, 0, 0))
+        .color(ByteBg(244))
+        .style(Bold)
+        .style(Italic)
+        .style(Underline)
+        .style(Strikethrough);
+    assert_eq!(
+        sgr.to_css(),
+        "color:#ff0000;background-color:#808080;font-weight:bold;\
+         font-style:italic;text-decoration:underline line-through"
+    );
+    let mut state = SgrState::new();
+    state.apply(&[1, 3, 4, 9, 38, 2, 255, 0, 0, 48, 5, 244]);
+    assert_eq!(state.to_css(), sgr.to_css());
+}
+#[test]
+fn palette() {
+    let palette = Palette {
+        colors: [(1, 1, 1); 16],
+        foreground: (2, 2, 2),
+        background: (3, 3, 3),
+    };
+    assert_eq!(
+        YellowFg.style(Inverse).to_css_with(&palette),
+        "color:#030303;background-color:#010101"
+    );
+    assert_eq!(
+        palette.hex(&ColorKind::Byte(15)).as_deref(),
+        Some("#010101")
+    );
+    assert_eq!(palette.rgb(&ColorKind::Byte(16)), Some((0, 0, 0)));
+    assert_eq!(palette.rgb(&ColorKind::Byte(231)), Some((255, 255, 255)));
+    assert_eq!(palette.rgb(&ColorKind::Byte(232)), Some((8, 8, 8)));
+    assert_eq!(palette.rgb(&ColorKind::Default), None);
+}
+#[test]
+fn css_hex() {
+    assert_eq!(MagentaFg.to_css_hex().as_deref(), Some("#cd00cd"));
+    assert_eq!(ByteBg(9).to_css_hex().as_deref(), Some("#ff0000"));
+    assert_eq!(RgbFg(0, 16, 255).to_css_hex().as_deref(), Some("#0010ff"));
+    assert_eq!(DefaultBg.to_css_hex(), None);
+}
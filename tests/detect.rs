@@ -0,0 +1,126 @@
+use easy_sgr::ColorLevel;
+
+#[test]
+fn color_level() {
+    for (correct, n) in [
+        (ColorLevel::None, 0),
+        (ColorLevel::None, 2),
+        (ColorLevel::Ansi16, 8),
+        (ColorLevel::Ansi16, 16),
+        (ColorLevel::Ansi256, 256),
+        (ColorLevel::Ansi16, 88),
+        (ColorLevel::TrueColor, 0x0100_0000),
+    ] {
+        assert_eq!(correct, ColorLevel::from_max_colors(n), "{n} colors");
+    }
+    assert!(ColorLevel::TrueColor > ColorLevel::Ansi256);
+    assert!(ColorLevel::Ansi256 > ColorLevel::Ansi16);
+    assert!(ColorLevel::Ansi16 > ColorLevel::None);
+}
+#[test]
+fn color_level_from_env() {
+    for (colorterm, term, is_terminal, correct) in [
+        (
+            Some("truecolor"),
+            Some("xterm-256color"),
+            true,
+            ColorLevel::TrueColor,
+        ),
+        (Some("24bit"), Some("xterm"), false, ColorLevel::TrueColor),
+        (Some("truecolor"), None, true, ColorLevel::TrueColor),
+        (
+            Some("yes"),
+            Some("xterm-256color"),
+            true,
+            ColorLevel::Ansi256,
+        ),
+        (None, Some("xterm-256color"), false, ColorLevel::Ansi256),
+        (None, Some("screen-256color"), true, ColorLevel::Ansi256),
+        (None, Some("xterm"), true, ColorLevel::Ansi16),
+        (None, Some("linux"), false, ColorLevel::Ansi16),
+        (None, Some("vt100"), true, ColorLevel::Ansi16),
+        (None, None, true, ColorLevel::Ansi16),
+        (None, Some(""), true, ColorLevel::Ansi16),
+        (None, None, false, ColorLevel::None),
+        (None, Some(""), false, ColorLevel::None),
+        (None, Some("dumb"), true, ColorLevel::None),
+        (Some("truecolor"), Some("dumb"), true, ColorLevel::None),
+        (Some("truecolor"), None, false, ColorLevel::None),
+    ] {
+        let var = |name: &str| match name {
+            "COLORTERM" => colorterm.map(String::from),
+            "TERM" => term.map(String::from),
+            _ => None,
+        };
+        assert_eq!(
+            correct,
+            ColorLevel::from_env(var, is_terminal),
+            "COLORTERM={colorterm:?} TERM={term:?} is_terminal={is_terminal}"
+        );
+    }
+}
+
+#[cfg(feature = "terminfo")]
+mod terminfo {
+    use easy_sgr::{
+        terminfo::{Capabilities, TerminfoError},
+        ColorLevel,
+    };
+
+    #[test]
+    fn fixtures() {
+        for (bytes, color_level, sgr) in [
+            (
+                &include_bytes!("fixtures/terminfo/xterm-256color")[..],
+                ColorLevel::Ansi256,
+                true,
+            ),
+            (
+                &include_bytes!("fixtures/terminfo/xterm-direct")[..],
+                ColorLevel::TrueColor,
+                true,
+            ),
+            (
+                &include_bytes!("fixtures/terminfo/screen")[..],
+                ColorLevel::Ansi16,
+                true,
+            ),
+            (
+                &include_bytes!("fixtures/terminfo/dumb")[..],
+                ColorLevel::None,
+                false,
+            ),
+        ] {
+            assert_eq!(
+                Ok(Capabilities {
+                    color_level,
+                    sgr,
+                    hyperlinks: false
+                }),
+                Capabilities::from_terminfo(bytes)
+            );
+        }
+    }
+    #[test]
+    fn invalid() {
+        let bytes = include_bytes!("fixtures/terminfo/xterm-256color");
+        assert_eq!(
+            Err(TerminfoError::Magic),
+            Capabilities::from_terminfo(&bytes[1..])
+        );
+        assert_eq!(
+            Err(TerminfoError::Truncated),
+            Capabilities::from_terminfo(&bytes[..100])
+        );
+        assert_eq!(
+            Err(TerminfoError::Truncated),
+            Capabilities::from_terminfo(&[])
+        );
+    }
+    #[test]
+    fn detect() {
+        // the result depends on the environment, only checking consistency
+        let caps = Capabilities::detect();
+        assert!(caps.sgr || caps.color_level == ColorLevel::None);
+    }
+}
@@ -0,0 +1,77 @@
+use easy_sgr::{
+    diff::{inline_diff, DiffTheme},
+    CleanKind, EasySGR,
+    Style::*,
+};
+
+/// Returns both sides of the diff rendered with the default theme
+fn diff(old: &str, new: &str) -> (String, String) {
+    let (old, new) = inline_diff(old, new, &DiffTheme::default());
+    (old.to_string(), new.to_string())
+}
+#[test]
+fn changed_word() {
+    assert_eq!(
+        diff("let x = 1;", "let y = 1;"),
+        (
+            "let \x1b[41mx\x1b[49m = 1;".to_string(),
+            "let \x1b[42my\x1b[49m = 1;".to_string()
+        )
+    );
+}
+#[test]
+fn insertion() {
+    assert_eq!(
+        diff("a long line", "a very long line"),
+        (
+            "a long line".to_string(),
+            "a \x1b[42mvery \x1b[49mlong line".to_string()
+        )
+    );
+    assert_eq!(
+        diff("", "added"),
+        (String::new(), "\x1b[42madded\x1b[49m".to_string())
+    );
+}
+#[test]
+fn deletion() {
+    assert_eq!(
+        diff("one two three", "one three"),
+        (
+            "one \x1b[41mtwo \x1b[49mthree".to_string(),
+            "one three".to_string()
+        )
+    );
+    assert_eq!(
+        diff("removed", ""),
+        ("\x1b[41mremoved\x1b[49m".to_string(), String::new())
+    );
+}
+#[test]
+fn identical() {
+    for s in ["", "same", "the same words\nover lines"] {
+        let (old, new) = inline_diff(s, s, &DiffTheme::default());
+        assert_eq!(
+            (old.spans.len(), new.spans.len()),
+            (1.min(s.len()), 1.min(s.len()))
+        );
+        assert_eq!(
+            (old.to_string(), new.to_string()),
+            (s.to_string(), s.to_string())
+        );
+    }
+}
+#[test]
+fn theme() {
+    let theme = DiffTheme {
+        removed: Strikethrough.clean(CleanKind::Reverse),
+        added: Bold.clean(CleanKind::Reverse),
+    };
+    let (old, new) = inline_diff("a b c", "a d c", &theme);
+    assert_eq!(old.to_string(), "a \x1b[9mb\x1b[29m c");
+    assert_eq!(new.to_string(), "a \x1b[1md\x1b[22m c");
+    assert_eq!(
+        (old.plain_text(), new.plain_text()),
+        ("a b c".into(), "a d c".into())
+    );
+}
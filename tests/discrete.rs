@@ -1,10 +1,266 @@
-use easy_sgr::Seq;
+use easy_sgr::{
+    AnySgr, Color, ColorKind, DiscreteSGR, EasySGR, Plane, SGRBuilder, SGRString, SGRWriter, Seq,
+    Style,
+};
 
 #[test]
 fn seq() {
     assert_eq!("\x1b[", Seq::Esc.to_string());
     assert_eq!("m", Seq::End.to_string());
 }
+#[test]
+fn any_sgr() -> Result<(), std::fmt::Error> {
+    let codes: Vec<AnySgr> = vec![
+        Style::Bold.into(),
+        Color::RgbFg(1, 2, 3).into(),
+        53.into(),
+        Style::NotItalic.into(),
+        Color::ON_RED.into(),
+    ];
+    let mut builder = SGRBuilder::default();
+    codes.iter().for_each(|code| code.write(&mut builder));
+    let mut writer = SGRWriter::from(String::new());
+    builder.write_to(&mut writer)?;
+    assert_eq!(writer.internal(), "\x1b[1;38;2;1;2;3;53;23;41m");
+
+    let mut writer = SGRWriter::from(String::new());
+    writer.inline_sgr(&AnySgr::Raw(53))?;
+    writer.sgr(&AnySgr::Style(Style::Bold))?;
+    assert_eq!(writer.internal(), "\x1b[53m\x1b[1m");
+
+    let mut builder = SGRBuilder::default();
+    SGRString::from(AnySgr::Raw(53)).place_all(&mut builder);
+    SGRString::from(AnySgr::Color(Color::RedFg)).place_all(&mut builder);
+    assert_eq!(builder.codes(), [53, 31]);
+    Ok(())
+}
+#[test]
+fn tuples() -> Result<(), std::fmt::Error> {
+    use Color::*;
+    use Style::*;
+
+    let mut writer = SGRWriter::from(String::new());
+    writer.inline_sgr(&(RedFg, Bold))?;
+    writer.inline_sgr(&(ByteFg(208), Italic, NotUnderline))?;
+    writer.inline_sgr(&(RgbFg(1, 2, 3), BlueBg))?;
+    writer.sgr(&(DefaultFg, Reset))?;
+    assert_eq!(
+        writer.internal(),
+        "\x1b[31;1m\x1b[38;5;208;3;24m\x1b[38;2;1;2;3;44m\x1b[39;0m"
+    );
+
+    let mut writer = SGRWriter::from(String::new());
+    writer.inline_sgr_all([(RedFg, Bold), (GreenFg, Dim)])?;
+    writer.partial_sgr(&(WhiteFg, BlackBg))?;
+    assert_eq!(writer.internal(), "\x1b[31;1;32;2m37;40");
+
+    let mut builder = SGRBuilder::default();
+    (CyanFg, Bold).write(&mut builder);
+    SGRString::from((CyanFg, Bold)).place_all(&mut builder);
+    assert_eq!(builder.codes(), [36, 1, 36, 1]);
+    assert_eq!(
+        SGRString::from((CyanFg, Bold, Strikethrough)).to_string(),
+        CyanFg.style(Bold).style(Strikethrough).to_string()
+    );
+    assert_eq!((RedFg, Bold).params().to_string(), "31;1");
+
+    // any DiscreteSGR, nested tuples included
+    let mut writer = SGRWriter::from(String::new());
+    writer.inline_sgr(&(AnySgr::Raw(53), Bold))?;
+    writer.inline_sgr(&(RedFg, AnySgr::Style(Italic)))?;
+    writer.inline_sgr(&(Dim, Underline, AnySgr::Raw(73)))?;
+    writer.inline_sgr(&((RedFg, Bold), (BlueBg, Italic)))?;
+    writer.sgr(&(Bold, (RedFg, Dim), AnySgr::Raw(53)))?;
+    assert_eq!(
+        writer.internal(),
+        "\x1b[53;1m\x1b[31;3m\x1b[2;4;73m\x1b[31;1;44;3m\x1b[1;31;2;53m"
+    );
+    Ok(())
+}
+#[test]
+fn style_codes() {
+    use Style::*;
+
+    for (style, code, clear_code) in [
+        (Reset, 0, 0),
+        (Bold, 1, 22),
+        (Dim, 2, 22),
+        (Italic, 3, 23),
+        (Underline, 4, 24),
+        (Blinking, 5, 25),
+        (Inverse, 7, 27),
+        (Hidden, 8, 28),
+        (Strikethrough, 9, 29),
+        (NotBold, 22, 1),
+        (NotDim, 22, 2),
+        (NotItalic, 23, 3),
+        (NotUnderline, 24, 4),
+        (NotBlinking, 25, 5),
+        (NotInverse, 27, 7),
+        (NotHidden, 28, 8),
+        (NotStrikethrough, 29, 9),
+    ] {
+        assert_eq!(style.code(), code, "{style:?}");
+        assert_eq!(style.clear_code(), clear_code, "{style:?}");
+        let mut builder = SGRBuilder::default();
+        style.write(&mut builder);
+        assert_eq!(builder.codes(), [code]);
+    }
+}
+#[test]
+fn color_codes() {
+    use Color::*;
+
+    let basic = [
+        (BlackFg, BlackBg),
+        (RedFg, RedBg),
+        (GreenFg, GreenBg),
+        (YellowFg, YellowBg),
+        (BlueFg, BlueBg),
+        (MagentaFg, MagentaBg),
+        (CyanFg, CyanBg),
+        (WhiteFg, WhiteBg),
+    ];
+    let mut colors = Vec::new();
+    for (index, pair) in (0..).zip(basic) {
+        colors.push((
+            pair,
+            [vec![30 + index], vec![40 + index], vec![58, 5, index]],
+        ));
+    }
+    for n in [0, 208] {
+        colors.push((
+            (ByteFg(n), ByteBg(n)),
+            [vec![38, 5, n], vec![48, 5, n], vec![58, 5, n]],
+        ));
+    }
+    colors.push((
+        (RgbFg(1, 2, 3), RgbBg(1, 2, 3)),
+        [
+            vec![38, 2, 1, 2, 3],
+            vec![48, 2, 1, 2, 3],
+            vec![58, 2, 1, 2, 3],
+        ],
+    ));
+    colors.push(((DefaultFg, DefaultBg), [vec![39], vec![49], vec![59]]));
+
+    for ((fg, bg), [fg_codes, bg_codes, underline_codes]) in colors {
+        for color in [&fg, &bg] {
+            for (plane, codes) in [
+                (Plane::Foreground, &fg_codes),
+                (Plane::Background, &bg_codes),
+                (Plane::Underline, &underline_codes),
+            ] {
+                assert!(
+                    color.codes(plane).eq(codes.iter().copied()),
+                    "{color:?} {plane:?}"
+                );
+            }
+        }
+        assert_eq!(fg.plane(), Plane::Foreground);
+        assert_eq!(bg.plane(), Plane::Background);
+        for (color, codes) in [(fg, fg_codes), (bg, bg_codes)] {
+            let mut builder = SGRBuilder::default();
+            color.write(&mut builder);
+            assert_eq!(builder.codes(), codes, "{color:?}");
+        }
+    }
+}
+#[cfg(feature = "serde")]
+#[test]
+fn any_sgr_serde() {
+    let codes: Vec<AnySgr> = vec![Style::Bold.into(), Color::ByteBg(208).into(), 53.into()];
+    let json = serde_json::to_string(&codes).unwrap();
+    assert_eq!(
+        json,
+        r#"[{"style":"Bold"},{"color":{"ByteBg":208}},{"raw":53}]"#
+    );
+    assert_eq!(serde_json::from_str::<Vec<AnySgr>>(&json).unwrap(), codes);
+}
+#[test]
+fn palette_consts() {
+    let codes = |sgr: &dyn Fn(&mut SGRBuilder)| {
+        let mut builder = SGRBuilder::default();
+        sgr(&mut builder);
+        Vec::from(builder)
+    };
+    for (fg, bg, kind, expected) in [
+        (Color::BLACK, Color::ON_BLACK, ColorKind::BLACK, &[30][..]),
+        (Color::RED, Color::ON_RED, ColorKind::RED, &[31]),
+        (Color::GREEN, Color::ON_GREEN, ColorKind::GREEN, &[32]),
+        (Color::YELLOW, Color::ON_YELLOW, ColorKind::YELLOW, &[33]),
+        (Color::BLUE, Color::ON_BLUE, ColorKind::BLUE, &[34]),
+        (Color::MAGENTA, Color::ON_MAGENTA, ColorKind::MAGENTA, &[35]),
+        (Color::CYAN, Color::ON_CYAN, ColorKind::CYAN, &[36]),
+        (Color::WHITE, Color::ON_WHITE, ColorKind::WHITE, &[37]),
+        (
+            Color::BRIGHT_BLACK,
+            Color::ON_BRIGHT_BLACK,
+            ColorKind::BRIGHT_BLACK,
+            &[38, 5, 8],
+        ),
+        (
+            Color::BRIGHT_RED,
+            Color::ON_BRIGHT_RED,
+            ColorKind::BRIGHT_RED,
+            &[38, 5, 9],
+        ),
+        (
+            Color::BRIGHT_GREEN,
+            Color::ON_BRIGHT_GREEN,
+            ColorKind::BRIGHT_GREEN,
+            &[38, 5, 10],
+        ),
+        (
+            Color::BRIGHT_YELLOW,
+            Color::ON_BRIGHT_YELLOW,
+            ColorKind::BRIGHT_YELLOW,
+            &[38, 5, 11],
+        ),
+        (
+            Color::BRIGHT_BLUE,
+            Color::ON_BRIGHT_BLUE,
+            ColorKind::BRIGHT_BLUE,
+            &[38, 5, 12],
+        ),
+        (
+            Color::BRIGHT_MAGENTA,
+            Color::ON_BRIGHT_MAGENTA,
+            ColorKind::BRIGHT_MAGENTA,
+            &[38, 5, 13],
+        ),
+        (
+            Color::BRIGHT_CYAN,
+            Color::ON_BRIGHT_CYAN,
+            ColorKind::BRIGHT_CYAN,
+            &[38, 5, 14],
+        ),
+        (
+            Color::BRIGHT_WHITE,
+            Color::ON_BRIGHT_WHITE,
+            ColorKind::BRIGHT_WHITE,
+            &[38, 5, 15],
+        ),
+        (Color::DEFAULT, Color::ON_DEFAULT, ColorKind::DEFAULT, &[39]),
+    ] {
+        // backgrounds are the foreground's codes moved by `10`
+        let mut bg_expected = expected.to_vec();
+        bg_expected[0] += 10;
+        assert_eq!(codes(&|b| fg.write(b)), expected);
+        assert_eq!(codes(&|b| bg.write(b)), bg_expected);
+
+        let as_fg = SGRString {
+            foreground: kind,
+            ..Default::default()
+        };
+        let as_bg = SGRString {
+            background: kind,
+            ..Default::default()
+        };
+        assert_eq!(codes(&|b| as_fg.place_colors(b)), expected);
+        assert_eq!(codes(&|b| as_bg.place_colors(b)), bg_expected);
+    }
+}
 
 #[cfg(not(feature = "partial"))]
 mod normal {
@@ -34,6 +290,30 @@ mod normal {
         }
     }
     #[test]
+    fn style_undo() {
+        use easy_sgr::{DiscreteSGR, SGRBuilder};
+        for (style, undo) in [
+            (Reset, Reset),
+            (Bold, NotBold),
+            (Dim, NotDim),
+            (Italic, NotItalic),
+            (Underline, NotUnderline),
+            (Blinking, NotBlinking),
+            (Inverse, NotInverse),
+            (Hidden, NotHidden),
+            (Strikethrough, NotStrikethrough),
+        ] {
+            assert_eq!(style.undo(), undo);
+            assert_eq!(undo.undo(), style);
+            for style in [style, undo] {
+                let mut builder = SGRBuilder::default();
+                style.write(&mut builder);
+                assert_eq!(style.to_string(), builder.to_string());
+                assert_eq!(style.params().to_string(), builder.codes()[0].to_string());
+            }
+        }
+    }
+    #[test]
     fn standard_colors() {
         for (correct, color) in [
             ("\x1b[30m", BlackFg),
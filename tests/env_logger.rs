@@ -0,0 +1,107 @@
+#![cfg(feature = "env-logger")]
+use std::{
+    io::{self, Write},
+    sync::{Arc, Mutex},
+};
+
+use easy_sgr::{env_logger::StyledFormat, Color::*};
+use env_logger::{Builder, Target};
+use log::{Level, LevelFilter, Log, Record};
+
+#[derive(Clone, Default)]
+struct Capture(Arc<Mutex<Vec<u8>>>);
+impl Write for Capture {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+fn log_all(format: StyledFormat, message: &str) -> String {
+    let capture = Capture::default();
+    let logger = Builder::new()
+        .format(format.into_fn())
+        .filter_level(LevelFilter::Trace)
+        .target(Target::Pipe(Box::new(capture.clone())))
+        .build();
+    for level in [
+        Level::Error,
+        Level::Warn,
+        Level::Info,
+        Level::Debug,
+        Level::Trace,
+    ] {
+        logger.log(
+            &Record::builder()
+                .level(level)
+                .target("app")
+                .args(format_args!("{message}"))
+                .build(),
+        );
+    }
+    let out = capture.0.lock().unwrap().clone();
+    String::from_utf8(out).unwrap().replace('\x1b', "\\x1b")
+}
+#[test]
+fn levels() {
+    assert_eq!(
+        log_all(StyledFormat::new().color(true).timestamp(false), "hi"),
+        "\\x1b[31;1mERROR\\x1b[39;22m \\x1b[2mapp\\x1b[22m: hi\n\
+         \\x1b[33mWARN\\x1b[39m  \\x1b[2mapp\\x1b[22m: hi\n\
+         \\x1b[32mINFO\\x1b[39m  \\x1b[2mapp\\x1b[22m: hi\n\
+         \\x1b[34mDEBUG\\x1b[39m \\x1b[2mapp\\x1b[22m: hi\n\
+         \\x1b[2mTRACE\\x1b[22m \\x1b[2mapp\\x1b[22m: hi\n"
+    );
+}
+#[test]
+fn multi_line() {
+    let format = StyledFormat::new()
+        .color(true)
+        .timestamp(false)
+        .level_style(Level::Info, CyanFg);
+    let out = log_all(format, "first\nsecond");
+    assert_eq!(
+        out.lines().skip(4).take(2).collect::<Vec<_>>(),
+        [
+            "\\x1b[36mINFO\\x1b[0m  \\x1b[2mapp\\x1b[22m: first",
+            "  \\x1b[2m|\\x1b[22m second",
+        ]
+    );
+}
+#[test]
+fn no_color() {
+    let out = log_all(StyledFormat::new().color(false).timestamp(false), "a\nb");
+    assert_eq!(
+        out,
+        "ERROR app: a\n  | b\n\
+         WARN  app: a\n  | b\n\
+         INFO  app: a\n  | b\n\
+         DEBUG app: a\n  | b\n\
+         TRACE app: a\n  | b\n"
+    );
+}
+#[test]
+fn timestamp() {
+    let out = log_all(StyledFormat::new().color(false), "hi");
+    for line in out.lines() {
+        let (timestamp, rest) = line.split_once(' ').unwrap();
+        assert!(timestamp.ends_with('Z'), "{timestamp}");
+        assert!(rest.ends_with("app: hi"), "{rest}");
+    }
+}
+#[test]
+fn color_choice() {
+    use easy_sgr::{set_color_choice, ColorChoice};
+
+    set_color_choice(ColorChoice::Never);
+    let out = log_all(StyledFormat::new().timestamp(false), "hi");
+    assert!(out.starts_with("ERROR app: hi\n"), "{out}");
+    // set on the format, winning over the global choice
+    let out = log_all(StyledFormat::new().color(true).timestamp(false), "hi");
+    assert!(out.contains("\\x1b["), "{out}");
+
+    set_color_choice(ColorChoice::Always);
+    let out = log_all(StyledFormat::new().timestamp(false), "hi");
+    assert!(out.contains("\\x1b["), "{out}");
+}
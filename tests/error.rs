@@ -0,0 +1,76 @@
+use std::{error::Error, fmt, io};
+
+use easy_sgr::{
+    Color::*, ErasedWriter, EscapeError, InvalidEscape, SGRError, SGRWriter, Tee, TeeError,
+};
+
+#[test]
+fn conversions() {
+    let error = SGRError::from(io::Error::other("closed"));
+    assert!(matches!(error, SGRError::Io(_)));
+    assert_eq!(error.to_string(), "IO error: closed");
+    assert_eq!(error.source().unwrap().to_string(), "closed");
+
+    let error = SGRError::from(fmt::Error);
+    assert!(matches!(error, SGRError::Fmt(_)));
+    assert!(error.source().unwrap().is::<fmt::Error>());
+
+    let error = SGRError::from(TeeError::<fmt::Error, io::Error>::Second(io::Error::other(
+        "closed",
+    )));
+    assert!(matches!(error, SGRError::Other(_)));
+    let tee = error.source().unwrap();
+    assert!(tee.is::<TeeError<fmt::Error, io::Error>>());
+    assert_eq!(tee.source().unwrap().to_string(), "closed");
+
+    let error = SGRError::from(EscapeError::<fmt::Error>::Invalid(
+        InvalidEscape::Unterminated,
+    ));
+    assert!(matches!(
+        error,
+        SGRError::Escape(InvalidEscape::Unterminated)
+    ));
+    let error = SGRError::from(EscapeError::Write(fmt::Error));
+    assert!(matches!(error, SGRError::Fmt(_)));
+
+    let error = SGRError::other("custom");
+    assert_eq!(error.to_string(), "custom");
+    assert!(error.source().is_some());
+}
+#[cfg(feature = "from-str")]
+#[test]
+fn parse_conversions() {
+    use easy_sgr::{from_str::ParseError, Color, Style};
+
+    let error = SGRError::from("Boldest".parse::<Style>().unwrap_err());
+    assert!(matches!(error, SGRError::Parse(ParseError::Style(_))));
+    assert_eq!(
+        error.to_string(),
+        "Parsing error: Error parsing style: Invalid style"
+    );
+
+    let error = SGRError::from("ByteFg(300)".parse::<Color>().unwrap_err());
+    let parse = error.source().unwrap();
+    assert!(parse.is::<ParseError>());
+    assert!(parse
+        .source()
+        .unwrap()
+        .to_string()
+        .starts_with("Error parsing int"));
+}
+#[test]
+fn erased_writer() -> Result<(), SGRError> {
+    let mut string = SGRWriter::from(ErasedWriter::new(String::new()));
+    let mut bytes = SGRWriter::from(ErasedWriter::new(Vec::new()));
+    let mut tee = SGRWriter::from(ErasedWriter::new(Tee::new(String::new(), Vec::new())));
+    string.styled(&RedFg, "text")?;
+    bytes.styled(&RedFg, "text")?;
+    tee.styled(&RedFg, "text")?;
+    tee.flush()?;
+
+    assert_eq!(string.internal(), "\x1b[31mtext\x1b[0m");
+    assert_eq!(bytes.internal(), b"\x1b[31mtext\x1b[0m");
+    let (first, second) = tee.internal();
+    assert_eq!(first.as_bytes(), second);
+    Ok(())
+}
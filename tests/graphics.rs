@@ -1,6 +1,8 @@
 use std::default::Default;
 
-use easy_sgr::{CleanKind, Color::*, ColorKind, EasySGR, SGRString, Style::*, StyleKind};
+use easy_sgr::{
+    CleanKind, Color::*, ColorKind, EasySGR, SGRString, SGRText, SGRWriter, Style::*, StyleKind,
+};
 
 #[test]
 fn general() {
@@ -36,7 +38,7 @@ fn general() {
 #[test]
 fn fully_loaded() {
     assert_eq!(
-        "\x1b[0;31;41;1;2;3;4;5;7;8;9;100mtest\x1b[39;49;22;22;23;24;25;27;28;29;100m",
+        "\x1b[0;31;41;1;2;3;4;5;7;8;9;100mtest\x1b[39;49;22;23;24;25;27;28;29;100m",
         SGRString {
             text: "test".to_string(),
             clean: CleanKind::Reverse,
@@ -52,7 +54,8 @@ fn fully_loaded() {
             blinking: StyleKind::Place,
             inverse: StyleKind::Place,
             hidden: StyleKind::Place,
-            strikethrough: StyleKind::Place
+            strikethrough: StyleKind::Place,
+            ..Default::default()
         }
         .to_string()
     );
@@ -73,7 +76,8 @@ fn fully_loaded() {
             blinking: StyleKind::Clean,
             inverse: StyleKind::Clean,
             hidden: StyleKind::Clean,
-            strikethrough: StyleKind::Clean
+            strikethrough: StyleKind::Clean,
+            ..Default::default()
         }
         .to_string()
     );
@@ -101,7 +105,7 @@ fn colors() {
         assert_eq!(
             correct,
             SGRString {
-                foreground: color.clone(),
+                foreground: color,
                 background: color,
                 ..Default::default()
             }
@@ -176,3 +180,527 @@ fn easy_sgr_color() {
         assert_eq!(correct, "".color(color).to_string())
     }
 }
+#[test]
+fn map_text() {
+    let sgr = RedFg.style(Bold).text("header").clean(CleanKind::Reverse);
+    assert_eq!(
+        "\x1b[31;1mHEADER\x1b[39;22m",
+        sgr.clone().map_text(str::to_uppercase).to_string()
+    );
+    assert_eq!(
+        "\x1b[31;1m******\x1b[39;22m",
+        sgr.clone()
+            .map_text(|text| text.replace(|_| true, "*"))
+            .to_string()
+    );
+    assert_eq!(
+        sgr.to_string(),
+        sgr.clone().map_text(str::to_owned).to_string()
+    );
+}
+#[test]
+fn map_text_in_place() {
+    let mut sgr = BlueFg.text("/a/long/path");
+    sgr.map_text_in_place(|text| text.make_ascii_uppercase());
+    assert_eq!("\x1b[34m/A/LONG/PATH", sgr.to_string());
+    // an identity map keeps the text's allocation
+    let ptr = sgr.text.as_ptr();
+    sgr.map_text_in_place(|_| ());
+    assert_eq!(ptr, sgr.text.as_ptr());
+    assert_eq!("\x1b[34m/A/LONG/PATH", sgr.to_string());
+}
+#[test]
+fn split_at_visible() {
+    let sgr = RedFg.style(Bold).text("column").clean(CleanKind::Reverse);
+    let (left, right) = sgr.clone().split_at_visible(3);
+    assert_eq!("\x1b[31;1mcol\x1b[39;22m", left.to_string());
+    assert_eq!("\x1b[31;1mumn\x1b[39;22m", right.to_string());
+
+    let (left, right) = sgr.clone().split_at_visible(0);
+    assert_eq!(("", "column"), (&*left.text, &*right.text));
+    for idx in [6, 7, usize::MAX] {
+        let (left, right) = sgr.clone().split_at_visible(idx);
+        assert_eq!(("column", ""), (&*left.text, &*right.text));
+        assert_eq!(sgr.to_string(), left.to_string());
+    }
+}
+#[test]
+fn split_at_visible_escapes() {
+    let sgr = "ab\x1b[4mcd\x1b[24m".to_sgr();
+    let (left, right) = sgr.clone().split_at_visible(2);
+    assert_eq!(("ab", "\x1b[4mcd\x1b[24m"), (&*left.text, &*right.text));
+    let (left, right) = sgr.clone().split_at_visible(3);
+    assert_eq!(("ab\x1b[4mc", "d\x1b[24m"), (&*left.text, &*right.text));
+    let (left, right) = sgr.split_at_visible(4);
+    assert_eq!(("ab\x1b[4mcd", "\x1b[24m"), (&*left.text, &*right.text));
+}
+#[test]
+#[cfg(feature = "width")]
+fn split_at_visible_wide() {
+    let sgr = GreenFg.text("日本語");
+    let (left, right) = sgr.clone().split_at_visible(2);
+    assert_eq!(("日", "本語"), (&*left.text, &*right.text));
+    // `本` straddles column 3, so goes right
+    let (left, right) = sgr.clone().split_at_visible(3);
+    assert_eq!(("日", "本語"), (&*left.text, &*right.text));
+    assert_eq!("\x1b[32m本語", right.to_string());
+    let (left, right) = sgr.split_at_visible(6);
+    assert_eq!(("日本語", ""), (&*left.text, &*right.text));
+}
+#[test]
+fn per_field_clean() {
+    let status = RedFg.color(WhiteBg).style(Bold).text("status");
+    assert_eq!(
+        "\x1b[31;47;1mstatus\x1b[39;22m",
+        status
+            .clone()
+            .clean(CleanKind::Reverse)
+            .clean_bg(CleanKind::None)
+            .to_string()
+    );
+    assert_eq!(
+        "\x1b[31;47;1mstatus\x1b[49m",
+        status.clone().clean_bg(CleanKind::Reverse).to_string()
+    );
+    assert_eq!(
+        "\x1b[31;47;1mstatus",
+        status
+            .clone()
+            .clean(CleanKind::Reverse)
+            .clean_fg(CleanKind::None)
+            .clean_bg(CleanKind::None)
+            .clean_style(CleanKind::None)
+            .to_string()
+    );
+    assert_eq!(
+        "\x1b[31;47;1mstatus\x1b[0m",
+        status
+            .clone()
+            .clean(CleanKind::Reverse)
+            .clean_style(CleanKind::Reset)
+            .to_string()
+    );
+    // without overrides the clean is unchanged
+    for kind in [CleanKind::None, CleanKind::Reset, CleanKind::Reverse] {
+        let mut overridden = status.clone().clean(CleanKind::None);
+        overridden.foreground_clean = Some(kind.clone());
+        overridden.background_clean = Some(kind.clone());
+        overridden.styles_clean = Some(kind.clone());
+        assert_eq!(
+            status.clone().clean(kind).to_string(),
+            overridden.to_string()
+        );
+    }
+}
+#[test]
+fn tuple_conversions() {
+    fn render(sgr: &SGRString) -> String {
+        let mut w = SGRWriter::from(String::new());
+        w.sgr_string(sgr).unwrap();
+        w.internal()
+    }
+    let check = |tuple: SGRString, chained: SGRString| {
+        assert_eq!(format!("{tuple:?}"), format!("{chained:?}"));
+        assert_eq!(render(&tuple), render(&chained));
+    };
+    check((ColorKind::Red, "text").into(), RedFg.text("text"));
+    check(
+        (
+            ColorKind::Byte(208),
+            ColorKind::Rgb(1, 2, 3),
+            String::from("text"),
+        )
+            .into(),
+        ByteFg(208).color(RgbBg(1, 2, 3)).text("text"),
+    );
+    check((Bold, "text").into(), Bold.text("text"));
+    check(
+        ([Bold, NotItalic], "text").into(),
+        Bold.style(NotItalic).text("text"),
+    );
+    check(
+        (ColorKind::Blue, Underline, "text").into(),
+        BlueFg.style(Underline).text("text"),
+    );
+    check(
+        (ColorKind::Default, [Dim, Strikethrough], "text").into(),
+        DefaultFg.style(Dim).style(Strikethrough).text("text"),
+    );
+    assert_eq!(
+        render(&(ColorKind::Red, Bold, "text").into()),
+        "\x1b[31;1mtext"
+    );
+}
+#[test]
+fn debug() {
+    assert_eq!(
+        format!("{:?}", SGRString::from("plain")),
+        r#"SGRString { text: "plain" }"#
+    );
+    assert_eq!(
+        format!(
+            "{:?}",
+            RedFg
+                .style(Bold)
+                .style(Underline)
+                .clean(CleanKind::Reverse)
+                .text("error")
+        ),
+        r#"SGRString { text: "error", fg: Red, styles: Bold|Underline, clean: Reverse }"#
+    );
+    assert_eq!(
+        format!(
+            "{:?}",
+            ByteFg(208)
+                .color(RgbBg(255, 128, 0))
+                .style(NotItalic)
+                .custom(5)
+                .text("warn")
+        ),
+        r#"SGRString { text: "warn", fg: Byte(208), bg: #ff8000, styles: NotItalic, custom_places: [5] }"#
+    );
+    let mut sgr = SGRString {
+        reset: true,
+        background: ColorKind::Default,
+        foreground_clean: Some(CleanKind::Reset),
+        ..Default::default()
+    };
+    sgr.raw_places.push("\x1b]8;;url\x1b\\".into());
+    assert_eq!(
+        format!("{sgr:?}"),
+        r#"SGRString { text: "", bg: Default, reset: true, fg_clean: Reset, raw_places: ["\u{1b}]8;;url\u{1b}\\"] }"#
+    );
+    assert_eq!(
+        format!("{:#?}", GreenFg.text("ok")),
+        "SGRString {\n    text: \"ok\",\n    fg: Green,\n}"
+    );
+}
+#[test]
+fn debug_text() {
+    let text: SGRText = [GreenFg.text("ok"), SGRString::from(" done")]
+        .into_iter()
+        .collect();
+    assert_eq!(
+        format!("{text:?}"),
+        r#"SGRText[SGRString { text: "ok", fg: Green }, SGRString { text: " done" }]"#
+    );
+    assert_eq!(format!("{:?}", SGRText::new()), "SGRText[]");
+}
+#[test]
+fn bare_conversions() {
+    let write = |sgr: SGRString| {
+        let mut w = SGRWriter::from(String::new());
+        w.place_sgr(&sgr).unwrap();
+        w.write_inner("|").unwrap();
+        w.sgr_string(&sgr.clean(CleanKind::Reverse)).unwrap();
+        w.internal()
+    };
+    assert_eq!(write(RedFg.into()), "\x1b[31m|\x1b[31m\x1b[39m");
+    assert_eq!(write(YellowBg.into()), "\x1b[43m|\x1b[43m\x1b[49m");
+    assert_eq!(write(Bold.into()), "\x1b[1m|\x1b[1m\x1b[22m");
+    assert_eq!(
+        write([Italic, Underline].into()),
+        "\x1b[3;4m|\x1b[3;4m\x1b[23;24m"
+    );
+    assert_eq!(
+        write((RedFg, WhiteBg).into()),
+        "\x1b[31;47m|\x1b[31;47m\x1b[39;49m"
+    );
+    assert_eq!(SGRString::from((RedFg, WhiteBg)).text, "");
+
+    let mut w = SGRWriter::from(String::new());
+    w.with_sgr(&SGRString::from([Bold]).clean(CleanKind::Reset), |w| {
+        w.write_inner("bold")
+    })
+    .unwrap();
+    assert_eq!(w.internal(), "\x1b[1mbold\x1b[0m");
+    assert_eq!(
+        SGRString::from((GreenFg, BlackBg)).text("ok").to_string(),
+        "\x1b[32;40mok"
+    );
+}
+#[test]
+fn merge() {
+    let base = SGRString {
+        text: "base".into(),
+        clean: CleanKind::Reverse,
+        foreground_clean: Some(CleanKind::Reset),
+        background_clean: Some(CleanKind::Reverse),
+        custom_places: vec![53],
+        custom_cleans: vec![55],
+        raw_places: vec!["\x1b]8;;a\x1b\\".into()],
+        foreground: ColorKind::Red,
+        background: ColorKind::Blue,
+        bold: StyleKind::Place,
+        italic: StyleKind::Place,
+        underline: StyleKind::Clean,
+        ..Default::default()
+    };
+    // unset fields inherit everything
+    let merged = base.merge(&SGRString::default());
+    assert_eq!(format!("{merged:?}"), format!("{base:?}"));
+
+    let over = SGRString {
+        clean: CleanKind::Reset,
+        // explicitly set to their defaults, unlike being left unset
+        foreground_clean: Some(CleanKind::None),
+        foreground: ColorKind::Default,
+        custom_places: vec![73],
+        reset: true,
+        italic: StyleKind::Clean,
+        dim: StyleKind::Place,
+        ..Default::default()
+    };
+    let merged = base.merge(&over);
+    assert_eq!(merged.text, "base");
+    assert_eq!(merged.clean, CleanKind::Reset);
+    assert_eq!(merged.foreground_clean, Some(CleanKind::None));
+    assert_eq!(merged.background_clean, Some(CleanKind::Reverse));
+    assert_eq!(merged.styles_clean, None);
+    assert_eq!(merged.custom_places, [73]);
+    assert_eq!(merged.custom_cleans, [55]);
+    assert_eq!(merged.raw_places, ["\x1b]8;;a\x1b\\"]);
+    assert_eq!(merged.foreground, ColorKind::Default);
+    assert_eq!(merged.background, ColorKind::Blue);
+    assert!(merged.reset);
+    // styles are joined, with the removals of `over` applied last
+    assert_eq!(merged.bold, StyleKind::Place);
+    assert_eq!(merged.dim, StyleKind::Place);
+    assert_eq!(merged.italic, StyleKind::Clean);
+    assert_eq!(merged.underline, StyleKind::Clean);
+    assert_eq!(merged.strikethrough, StyleKind::None);
+    assert_eq!(
+        merged.to_string(),
+        "\x1b]8;;a\x1b\\\x1b[0;39;44;1;2;23;24;73mbase\x1b[0;55m"
+    );
+
+    let mut merged = base.clone();
+    merged.merge_into(&"over".into());
+    assert_eq!(merged.text, "over");
+    assert_eq!(merged.foreground, ColorKind::Red);
+}
+#[test]
+fn intensity_clean() {
+    use easy_sgr::{SGRBuilder, SgrState};
+
+    let mut outer = SgrState::new();
+    outer.apply(&[2, 31]);
+    let inner = Bold.text("inner").clean(CleanKind::Reverse);
+
+    // without knowing the outer state, `22` ends the dim as well
+    let mut builder = SGRBuilder::default();
+    inner.clean_all(&mut builder);
+    assert_eq!(builder.codes(), [22]);
+    let mut state = outer;
+    state.apply(&[1]);
+    state.apply(builder.codes());
+    assert!(!state.dim);
+
+    let mut builder = SGRBuilder::default();
+    inner.clean_within(&outer, &mut builder);
+    assert_eq!(builder.codes(), [22, 2]);
+    let mut state = outer;
+    state.apply(&[1]);
+    state.apply(builder.codes());
+    assert_eq!(state, outer);
+
+    // colors & custom cleans are returned from too
+    let mut builder = SGRBuilder::default();
+    BlueFg
+        .style(Dim)
+        .custom_clean(55)
+        .clean_within(&outer, &mut builder);
+    assert_eq!(builder.codes(), [31, 55]);
+
+    // `22` is written before re-placing a bold this cleaned
+    let sgr = Dim.style(NotBold).clean(CleanKind::Reverse);
+    let mut builder = SGRBuilder::default();
+    sgr.clean_all(&mut builder);
+    assert_eq!(builder.codes(), [22, 1]);
+    let mut placed = SGRBuilder::default();
+    sgr.place_all(&mut placed);
+    let mut state = SgrState::new();
+    state.apply(&[1]);
+    state.apply(placed.codes());
+    state.apply(builder.codes());
+    assert!(state.bold && !state.dim);
+}
+#[test]
+fn clear_styles() {
+    let mut styled = RedFg
+        .color(BlueBg)
+        .style(Bold)
+        .style(NotItalic)
+        .text("plain")
+        .custom(53)
+        .custom_clean(55)
+        .clean(CleanKind::Reverse);
+    styled
+        .raw_places
+        .push(String::from("\x1b]8;;https://example.com\x1b\\"));
+    styled.raw_cleans.push(String::from("\x1b]8;;\x1b\\"));
+    let mut writer = SGRWriter::from(String::new());
+    writer.sgr_string(&styled).unwrap();
+    assert!(writer.internal().contains('\x1b'));
+
+    let plain = styled.clone().into_plain();
+    styled.clear_styles();
+    let mut writer = SGRWriter::from(String::new());
+    writer.sgr_string(&styled).unwrap();
+    assert_eq!(writer.internal(), "plain");
+    assert!(!styled.to_string().contains('\x1b'));
+    assert_eq!(plain, "plain");
+
+    let mut text: SGRText = [
+        GreenFg.text("ok").clean(CleanKind::Reset),
+        SGRString::from(": "),
+        Bold.text("done").custom(53),
+    ]
+    .into_iter()
+    .collect();
+    assert!(text.to_string().contains('\x1b'));
+    let flattened = text.clone().into_plain();
+    text.clear_styles();
+    assert_eq!(text.spans.len(), 3);
+    assert_eq!(text.to_string(), "ok: done");
+    assert_eq!(flattened, "ok: done");
+    assert_eq!(SGRText::new().into_plain(), "");
+}
+#[test]
+fn eq_visible_text() {
+    let (red, bold) = (RedFg.text("done"), Bold.style(Underline).text("done"));
+    assert_eq!(red, "done");
+    assert_eq!(bold, "done");
+    assert_eq!("done", red);
+    assert_eq!(*"done", bold);
+    assert!(red == *"done" && red != "don" && red != "done!");
+    assert_ne!(red, red.to_string().as_str());
+    // styles are only compared through what is displayed
+    assert_ne!(red.to_string(), bold.to_string());
+    assert_eq!(red.text, bold.text);
+    assert_eq!(SGRString::default(), "");
+
+    let text: SGRText = [GreenFg.text("ok"), SGRString::from(": "), Bold.text("done")]
+        .into_iter()
+        .collect();
+    assert_eq!(text, "ok: done");
+    assert_eq!("ok: done", text);
+    assert_ne!(text, "ok: don");
+    assert_ne!(text, "ok: done!");
+    assert_ne!(text, "ok:");
+    assert_eq!(SGRText::new(), "");
+    assert_ne!(SGRText::new(), "a");
+    assert_eq!(SGRText::from(vec![SGRString::default(), "a".into()]), "a");
+}
+#[test]
+fn chars_styled() {
+    use easy_sgr::SgrState;
+
+    let text: SGRText = [
+        RedFg.style(Bold).text("ab"),
+        BlueBg.text(""),
+        SGRString::from("c"),
+        Italic.color(GreenFg).text("de"),
+    ]
+    .into_iter()
+    .collect();
+    let chars: Vec<_> = text.chars_styled().collect();
+    assert_eq!(chars.iter().map(|(ch, _)| *ch).collect::<String>(), "abcde");
+    let changes: Vec<_> = (1..chars.len())
+        .filter(|&i| chars[i].1 != chars[i - 1].1)
+        .collect();
+    assert_eq!(changes, [2, 3]);
+    assert_eq!(chars[0].1.foreground, ColorKind::Red);
+    assert!(chars[1].1.bold);
+    assert!(chars[2].1.is_default());
+    assert_eq!(chars[3].1.foreground, ColorKind::Green);
+    assert!(chars[4].1.italic && !chars[4].1.bold);
+    assert!(chars
+        .iter()
+        .all(|(_, state)| state.background == ColorKind::None));
+
+    // the iterator can be resumed from a clone
+    let mut iter = text.chars_styled();
+    iter.nth(1);
+    assert_eq!(iter.clone().count(), 3);
+    assert_eq!(iter.next(), Some(('c', SgrState::new())));
+
+    let span = CyanFg.style(Underline).text("xyz").clean(CleanKind::Reset);
+    let state = SgrState::from(&span);
+    assert!(span.chars_styled().all(|(_, s)| s == state));
+    assert_eq!(span.chars_styled().count(), 3);
+    assert_eq!(SGRText::new().chars_styled().next(), None);
+    assert_eq!(SGRString::default().chars_styled().next(), None);
+}
+#[test]
+fn fmt_write() -> std::fmt::Result {
+    use std::fmt::Write;
+
+    let mut sgr = RedFg.style(Bold).clean(CleanKind::Reverse);
+    let styled = sgr.clone();
+    for i in 1..=3 {
+        write!(sgr, "{i},")?;
+    }
+    sgr.write_char('!')?;
+    assert_eq!(sgr.text, "1,2,3,!");
+    assert_eq!(sgr.to_string(), "\x1b[31;1m1,2,3,!\x1b[39;22m");
+    assert_eq!(format!("{:?}", sgr.text("")), format!("{styled:?}"));
+
+    let mut text = SGRText::new();
+    write!(text, "{} of {}", 1, 2)?;
+    assert_eq!(text.spans.len(), 1);
+    text.push(GreenFg.text("[").clean(CleanKind::Reset));
+    write!(text, "{:>3}%", 50)?;
+    text.push("");
+    writeln!(text, "]")?;
+    assert_eq!(text.spans.len(), 3);
+    assert_eq!(text.to_string(), "1 of 2\x1b[32m[ 50%\x1b[0m]\n");
+    assert_eq!(
+        format!("{:?}", text.spans[1].clone().text("")),
+        format!("{:?}", GreenFg.clean(CleanKind::Reset))
+    );
+    Ok(())
+}
+#[test]
+fn mutation() {
+    let mut sgr = RedFg.style(Bold).text("ab").clean(CleanKind::Reverse);
+    let render = |sgr: &SGRString| sgr.to_string();
+    sgr.push_str("cd");
+    assert_eq!(render(&sgr), "\x1b[31;1mabcd\x1b[39;22m");
+    sgr.push('é');
+    assert_eq!(render(&sgr), "\x1b[31;1mabcdé\x1b[39;22m");
+    assert_eq!((sgr.len(), easy_sgr::measure_width(&sgr.text)), (6, 5));
+    sgr.insert_str(0, "> ");
+    sgr.insert_str(sgr.len(), "!");
+    assert_eq!(render(&sgr), "\x1b[31;1m> abcdé!\x1b[39;22m");
+    sgr.truncate(100);
+    sgr.truncate(6);
+    assert_eq!(render(&sgr), "\x1b[31;1m> abcd\x1b[39;22m");
+    sgr.clear_text();
+    assert!(sgr.is_empty());
+    assert_eq!(render(&sgr), "\x1b[31;1m\x1b[39;22m");
+
+    let mut line = SGRString::with_capacity(16).color(BlueFg);
+    let capacity = line.text.capacity();
+    assert!(capacity >= 16);
+    for i in 0..10 {
+        line.clear_text();
+        line.push_str("line ");
+        line.push_str(&i.to_string());
+        assert_eq!(line.to_string(), format!("\x1b[34mline {i}"));
+        assert_eq!(line.text.capacity(), capacity);
+    }
+    line.reserve(100);
+    assert!(line.text.capacity() >= line.len() + 100);
+    assert!(SGRString::default().is_empty() && !"x".to_sgr().is_empty());
+}
+#[test]
+#[should_panic]
+fn mutation_insert_boundary() {
+    RedFg.text("é").insert_str(1, "x");
+}
+#[test]
+#[should_panic]
+fn mutation_truncate_boundary() {
+    RedFg.text("é").truncate(1);
+}
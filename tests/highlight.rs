@@ -0,0 +1,84 @@
+// single ranges are given as slices of ranges
+#![allow(clippy::single_range_in_vec_init)]
+use std::ops::Range;
+
+use easy_sgr::{
+    highlight::{highlight_matches, highlight_ranges, HighlightError},
+    CleanKind, EasySGR, SGRString, SGRText,
+    Style::*,
+};
+
+fn style() -> SGRString {
+    Bold.clean(CleanKind::Reverse)
+}
+fn texts(text: &SGRText) -> Vec<&str> {
+    text.spans.iter().map(|span| span.text.as_str()).collect()
+}
+
+#[test]
+fn ranges() {
+    let text = highlight_ranges("one two three", &[4..7], &style()).unwrap();
+    assert_eq!(texts(&text), ["one ", "two", " three"]);
+    assert_eq!(text.to_string(), "one \x1b[1mtwo\x1b[22m three");
+    assert_eq!(text.plain_text(), "one two three");
+}
+#[test]
+fn ranges_overlapping() {
+    let text = highlight_ranges("abcdefgh", &[5..7, 1..3, 2..4, 4..5, 6..6], &style()).unwrap();
+    assert_eq!(texts(&text), ["a", "bcdefg", "h"]);
+    assert_eq!(text.to_string(), "a\x1b[1mbcdefg\x1b[22mh");
+}
+#[test]
+fn ranges_edges() {
+    let text = highlight_ranges("start end", &[0..5, 6..9], &style()).unwrap();
+    assert_eq!(texts(&text), ["start", " ", "end"]);
+    assert_eq!(text.to_string(), "\x1b[1mstart\x1b[22m \x1b[1mend\x1b[22m");
+
+    let text = highlight_ranges("all", &[0..3], &style()).unwrap();
+    assert_eq!(texts(&text), ["all"]);
+    assert_eq!(text.to_string(), "\x1b[1mall\x1b[22m");
+}
+#[test]
+fn ranges_multibyte() {
+    let text = highlight_ranges("héllo wörld", &[1..3, 8..10], &style()).unwrap();
+    assert_eq!(texts(&text), ["h", "é", "llo w", "ö", "rld"]);
+
+    assert_eq!(
+        highlight_ranges("héllo", &[0..2], &style()).unwrap_err(),
+        HighlightError::NotCharBoundary(2)
+    );
+    assert_eq!(
+        highlight_ranges("héllo", &[0..7], &style()).unwrap_err(),
+        HighlightError::OutOfBounds(0..7)
+    );
+    let reversed = Range { start: 3, end: 1 };
+    assert_eq!(
+        highlight_ranges("héllo", std::slice::from_ref(&reversed), &style()).unwrap_err(),
+        HighlightError::OutOfBounds(reversed)
+    );
+}
+#[test]
+fn ranges_none() {
+    let text = highlight_ranges("plain", &[], &style()).unwrap();
+    assert_eq!(texts(&text), ["plain"]);
+    assert_eq!(text.to_string(), "plain");
+    assert_eq!(
+        texts(&highlight_ranges("", &[0..0], &style()).unwrap()),
+        [""]
+    );
+}
+#[test]
+fn matches() {
+    let text = highlight_matches("aaa bab", "a", &style());
+    assert_eq!(texts(&text), ["aaa", " b", "a", "b"]);
+    assert_eq!(text.to_string(), "\x1b[1maaa\x1b[22m b\x1b[1ma\x1b[22mb");
+
+    let text = highlight_matches("aaaa", "aa", &style());
+    assert_eq!(texts(&text), ["aaaa"]);
+    assert_eq!(text.spans.len(), 1);
+
+    let text = highlight_matches("no matches", "needle", &style());
+    assert_eq!(texts(&text), ["no matches"]);
+    assert_eq!(text.to_string(), "no matches");
+    assert_eq!(texts(&highlight_matches("text", "", &style())), ["text"]);
+}
@@ -0,0 +1,93 @@
+use easy_sgr::html::{ansi_to_html, HtmlOptions};
+
+const BARE: HtmlOptions = HtmlOptions {
+    palette: easy_sgr::css::Palette::XTERM,
+    classes: false,
+    pre: false,
+};
+
+#[test]
+fn diagnostic() {
+    let input = include_str!("fixtures/html/diagnostic.ansi");
+    assert_eq!(
+        ansi_to_html(input, &HtmlOptions::default()),
+        include_str!("fixtures/html/diagnostic.html")
+    );
+    let classes = HtmlOptions {
+        classes: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        ansi_to_html(input, &classes),
+        include_str!("fixtures/html/diagnostic-classes.html")
+    );
+}
+#[test]
+fn malformed() {
+    for (input, html) in [
+        ("", ""),
+        ("plain", "plain"),
+        // unfinished at the end
+        ("\x1b[31mred\x1b[1", "<span style=\"color:#cd0000\">red</span>"),
+        ("a\x1b", "a"),
+        ("a\x1b]8;;https://example.com", "a"),
+        // never reset, never closed
+        (
+            "\x1b]8;;https://example.com\x07\x1b[4mlink",
+            "<a href=\"https://example.com\"><span style=\"text-decoration:underline\">link</span></a>",
+        ),
+        // closing what was never opened
+        ("\x1b]8;;\x07\x1b[0mtext\x1b[m", "text"),
+        // unknown & invalid sequences
+        ("\x1b[2J\x1b(Ba\x1b[999mb\x1b[?25lc", "abc"),
+        // scripts aren't linked to
+        ("\x1b]8;;javascript:alert(1)\x07x\x1b]8;;\x07", "x"),
+        (
+            "\x1b]8;id=1;https://e.com/?a=\"<b>\"\x1b\\x\x1b]8;;\x1b\\",
+            "<a href=\"https://e.com/?a=&quot;&lt;b&gt;&quot;\">x</a>",
+        ),
+        // links opened within styled text end the span
+        (
+            "\x1b[1ma\x1b]8;;file:///tmp\x07b\x1b]8;;\x07c",
+            "<span style=\"font-weight:bold\">a</span><a href=\"file:///tmp\">\
+             <span style=\"font-weight:bold\">b</span></a>\
+             <span style=\"font-weight:bold\">c</span>",
+        ),
+        // a state left unchanged keeps its span
+        (
+            "\x1b[31ma\x1b[31mb\x1b[39;31mc",
+            "<span style=\"color:#cd0000\">abc</span>",
+        ),
+    ] {
+        assert_eq!(ansi_to_html(input, &BARE), html, "{input:?}");
+    }
+}
+#[test]
+fn options() {
+    let input = "\x1b[7;31;48;2;1;2;3mx\x1b[0;9;5;38;5;208my";
+    assert_eq!(
+        ansi_to_html(input, &BARE),
+        "<span style=\"color:#010203;background-color:#cd0000\">x</span>\
+         <span style=\"color:#ff8700;text-decoration:line-through blink\">y</span>"
+    );
+    let mut palette = easy_sgr::css::Palette::XTERM;
+    palette.colors[1] = (0xff, 0x55, 0x55);
+    let options = HtmlOptions { palette, ..BARE };
+    assert_eq!(
+        ansi_to_html("\x1b[31mx", &options),
+        "<span style=\"color:#ff5555\">x</span>"
+    );
+    let options = HtmlOptions {
+        classes: true,
+        ..BARE
+    };
+    assert_eq!(
+        ansi_to_html(input, &options),
+        "<span class=\"sgr-fg-red sgr-inverse\" style=\"background-color:#010203\">x</span>\
+         <span class=\"sgr-blink sgr-strike\" style=\"color:#ff8700\">y</span>"
+    );
+    assert_eq!(
+        ansi_to_html("<", &HtmlOptions::default()),
+        "<pre>&lt;</pre>"
+    );
+}
@@ -0,0 +1,646 @@
+/// Returns the state the SGR sequences of `rendered` leave a terminal in
+#[allow(dead_code)]
+fn state(rendered: &str) -> easy_sgr::SgrState {
+    let mut state = easy_sgr::SgrState::new();
+    for seq in rendered.split('\x1b').filter(|seq| !seq.is_empty()) {
+        let codes = seq
+            .strip_prefix('[')
+            .and_then(|seq| seq.strip_suffix('m'))
+            .unwrap_or_else(|| panic!("{seq:?} isn't an SGR sequence"));
+        let codes: Vec<u8> = codes.split(';').map(|c| c.parse().unwrap()).collect();
+        state.apply(&codes);
+    }
+    state
+}
+
+#[cfg(feature = "ansi-term")]
+mod ansi_term {
+    use ansi_term::{Colour, Style};
+    use easy_sgr::{interop::ansi_term::Paint, Color::*, ColorKind, EasySGR, SGRString};
+
+    use super::state;
+
+    #[test]
+    fn rendered() {
+        let colors = [
+            None,
+            Some(Colour::Purple),
+            Some(Colour::White),
+            Some(Colour::Fixed(208)),
+            Some(Colour::RGB(1, 2, 3)),
+        ];
+        let styles = [
+            Style::new(),
+            Style::new().bold(),
+            Style::new().dimmed().italic(),
+            Style::new().underline().blink().reverse(),
+            Style::new().hidden().strikethrough().bold(),
+        ];
+        for fg in colors {
+            for bg in colors {
+                for style in styles {
+                    let style = Style {
+                        foreground: fg,
+                        background: bg,
+                        ..style
+                    };
+                    let ours = Paint::paint(style, "text").to_string();
+                    let theirs = style.paint("text").to_string();
+                    let (ours, ours_suffix) = ours.split_once("text").unwrap();
+                    let (theirs, theirs_suffix) = theirs.split_once("text").unwrap();
+                    assert_eq!(state(ours), state(theirs), "{style:?}");
+                    assert_eq!(ours_suffix, theirs_suffix, "{style:?}");
+                }
+            }
+        }
+    }
+    #[test]
+    fn colour() {
+        assert_eq!(ColorKind::from(Colour::Purple), ColorKind::Magenta);
+        assert_eq!(ColorKind::from(Colour::Fixed(9)), ColorKind::Byte(9));
+        assert_eq!(
+            ColorKind::from(Colour::RGB(1, 2, 3)),
+            ColorKind::Rgb(1, 2, 3)
+        );
+        assert_eq!(
+            Paint::paint(Colour::Red, "text").to_string(),
+            Colour::Red.paint("text").to_string()
+        );
+        assert_eq!(
+            Colour::Fixed(208).color(BlueBg).to_string(),
+            "\x1b[38;5;208;44m"
+        );
+    }
+    #[test]
+    fn paint() {
+        assert_eq!(RedFg.paint("text").to_string(), "\x1b[31mtext\x1b[0m");
+        assert_eq!(SGRString::default().paint("text").to_string(), "text");
+        assert_eq!(
+            Paint::paint(Style::new(), "text").to_string(),
+            Style::new().paint("text").to_string()
+        );
+    }
+}
+
+#[cfg(feature = "anstyle")]
+mod anstyle {
+    use anstyle::{Ansi256Color, AnsiColor, Color, Effects, RgbColor, Style};
+    use easy_sgr::{Color::*, ColorKind, EasySGR, SGRString, Style::*};
+
+    use super::state;
+
+    #[test]
+    fn round_trip() {
+        let colors = [
+            None,
+            Some(Color::from(AnsiColor::Red)),
+            Some(AnsiColor::White.into()),
+            Some(Ansi256Color(208).into()),
+            Some(RgbColor(1, 2, 3).into()),
+        ];
+        let effects = [
+            Effects::new(),
+            Effects::BOLD,
+            Effects::DIMMED | Effects::ITALIC,
+            Effects::UNDERLINE | Effects::BLINK | Effects::INVERT,
+            Effects::HIDDEN | Effects::STRIKETHROUGH | Effects::BOLD,
+        ];
+        for fg in colors {
+            for bg in colors {
+                for effects in effects {
+                    let style = Style::new().fg_color(fg).bg_color(bg).effects(effects);
+                    let sgr = SGRString::from(style);
+                    assert_eq!(Style::from(&sgr), style);
+                    assert_eq!(
+                        state(&sgr.to_string()),
+                        state(&style.render().to_string()),
+                        "{style:?}"
+                    );
+                }
+            }
+        }
+    }
+    #[test]
+    fn from_sgr_string() {
+        let sgr = RedFg.color(ByteBg(4)).style(Underline).style(NotBold);
+        let style = Style::from(&sgr);
+        assert_eq!(
+            style,
+            Style::new()
+                .fg_color(Some(AnsiColor::Red.into()))
+                .bg_color(Some(Ansi256Color(4).into()))
+                .underline()
+        );
+        assert_eq!(state(&style.render().to_string()), state(&sgr.to_string()));
+        assert_eq!(Style::from(DefaultFg.to_sgr()), Style::new());
+    }
+    #[test]
+    fn dropped() {
+        let style = Style::new()
+            .effects(Effects::CURLY_UNDERLINE)
+            .underline_color(Some(AnsiColor::Red.into()))
+            .fg_color(Some(AnsiColor::BrightRed.into()));
+        let sgr = SGRString::from(style);
+        assert_eq!(sgr.foreground, ColorKind::Byte(9));
+        assert_eq!(
+            Style::from(&sgr),
+            Style::new()
+                .underline()
+                .fg_color(Some(Ansi256Color(9).into()))
+        );
+    }
+}
+
+#[cfg(feature = "clap")]
+mod clap {
+    use clap::builder::Styles;
+    use easy_sgr::{Color::*, EasySGR, Style::*, Theme};
+
+    use super::state;
+
+    #[test]
+    fn to_styles() {
+        let theme = Theme::new()
+            .with("header", YellowFg.style(Bold).style(Underline))
+            .with("error", RedFg.style(Bold))
+            .with("usage", ByteFg(208))
+            .with("literal", CyanFg)
+            .with("placeholder", RgbFg(1, 2, 3).style(Italic))
+            .with("valid", GreenFg)
+            .with("invalid", MagentaBg);
+        let styles = theme.to_clap_styles();
+        for (name, style) in [
+            ("header", styles.get_header()),
+            ("error", styles.get_error()),
+            ("usage", styles.get_usage()),
+            ("literal", styles.get_literal()),
+            ("placeholder", styles.get_placeholder()),
+            ("valid", styles.get_valid()),
+            ("invalid", styles.get_invalid()),
+        ] {
+            assert_eq!(
+                state(&style.render().to_string()),
+                state(&theme.get(name).unwrap().to_string()),
+                "{name}"
+            );
+        }
+        let theme = Theme::from_clap_styles(&styles);
+        assert_eq!(theme.iter().count(), 7);
+        assert_eq!(theme.to_clap_styles().get_usage(), styles.get_usage());
+    }
+    #[test]
+    fn fallbacks() {
+        let theme = Theme::new()
+            .with("header", BlueFg.style(Bold))
+            .with("warn", YellowFg);
+        let styles = theme.to_clap_styles();
+        assert_eq!(styles.get_usage(), styles.get_header());
+        assert_eq!(
+            state(&styles.get_invalid().render().to_string()),
+            state(&YellowFg.to_sgr().to_string())
+        );
+        let defaults = Styles::styled();
+        assert_eq!(styles.get_error(), defaults.get_error());
+        assert_eq!(styles.get_literal(), defaults.get_literal());
+        assert_eq!(styles.get_valid(), defaults.get_valid());
+    }
+}
+#[cfg(feature = "crossterm")]
+mod crossterm {
+    use crossterm::style::{Attribute, Attributes, Color, ContentStyle};
+    use easy_sgr::{CleanKind, Color::*, ColorKind, EasySGR, SGRString, Style::*, StyleKind};
+
+    #[test]
+    fn round_trip() {
+        let colors = [
+            None,
+            Some(Color::Reset),
+            Some(Color::DarkRed),
+            Some(Color::Grey),
+            Some(Color::White),
+            Some(Color::AnsiValue(208)),
+            Some(Color::Rgb { r: 1, g: 2, b: 3 }),
+        ];
+        let attributes = [
+            &[][..],
+            &[Attribute::Bold],
+            &[Attribute::Dim, Attribute::Italic, Attribute::NoUnderline],
+            &[
+                Attribute::Underlined,
+                Attribute::SlowBlink,
+                Attribute::Reverse,
+            ],
+            &[
+                Attribute::Hidden,
+                Attribute::CrossedOut,
+                Attribute::NormalIntensity,
+            ],
+            &[Attribute::Reset, Attribute::NoBlink, Attribute::Fraktur],
+            &[
+                Attribute::NoReverse,
+                Attribute::NoHidden,
+                Attribute::NotCrossedOut,
+            ],
+        ];
+        for foreground_color in colors {
+            for background_color in colors {
+                for attributes in attributes {
+                    let style = ContentStyle {
+                        foreground_color,
+                        background_color,
+                        underline_color: None,
+                        attributes: Attributes::from(attributes),
+                    };
+                    assert_eq!(ContentStyle::from(SGRString::from(style)), style);
+                }
+            }
+        }
+    }
+    #[test]
+    fn from_sgr_string() {
+        let sgr = RedFg.color(ByteBg(12)).style(Underline).style(NotItalic);
+        let style = ContentStyle::from(&sgr);
+        assert_eq!(style.foreground_color, Some(Color::DarkRed));
+        assert_eq!(style.background_color, Some(Color::Blue));
+        assert_eq!(
+            style.attributes,
+            Attributes::from(&[Attribute::Underlined, Attribute::NoItalic][..])
+        );
+    }
+    #[test]
+    fn mapped() {
+        let mut style = ContentStyle::new();
+        style.attributes = Attributes::from(
+            &[
+                Attribute::Undercurled,
+                Attribute::RapidBlink,
+                Attribute::OverLined,
+            ][..],
+        );
+        style.underline_color = Some(Color::Red);
+        let sgr = SGRString::from(style);
+        assert_eq!(sgr.underline, StyleKind::Place);
+        assert_eq!(sgr.blinking, StyleKind::Place);
+        assert_eq!(sgr.custom_places, [53]);
+        assert_eq!(ColorKind::from(Color::DarkGrey), ColorKind::Byte(8));
+        assert_eq!(sgr.to_string(), "\x1b[4;5;53m");
+    }
+    #[test]
+    fn command() {
+        let sgr = "styled"
+            .to_sgr()
+            .color(GreenFg)
+            .style(Bold)
+            .clean(CleanKind::Reverse);
+        let mut out = Vec::new();
+        crossterm::queue!(out, sgr.clone(), &sgr).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), sgr.to_string().repeat(2));
+    }
+}
+
+#[cfg(feature = "owo-colors")]
+mod owo_colors {
+    use easy_sgr::{Color::*, ColorKind, EasySGR, SGRString, Style::*};
+    use owo_colors::{AnsiColors, CssColors, DynColors, Effect, OwoColorize, Style, XtermColors};
+
+    use super::state;
+
+    /// Styles text through owo's `.style()`, which `EasySGR::style` shadows
+    fn owo(style: Style) -> owo_colors::Styled<&'static &'static str> {
+        OwoColorize::style(&"owo", style)
+    }
+
+    #[test]
+    fn render() {
+        let styles = [
+            Style::new().red(),
+            Style::new().on_blue().bold(),
+            Style::new()
+                .color(XtermColors::from(208))
+                .italic()
+                .underline(),
+            Style::new()
+                .truecolor(1, 2, 3)
+                .on_truecolor(4, 5, 6)
+                .dimmed(),
+            Style::new().blink().reversed().hidden().strikethrough(),
+        ];
+        for style in styles {
+            assert_eq!(
+                SGRString::from(owo(style)).to_string(),
+                owo(style).to_string()
+            );
+            assert_eq!(Style::from(SGRString::from(style)), style, "{style:?}");
+        }
+        assert_eq!(SGRString::from(owo(Style::new())).to_string(), "owo");
+    }
+    #[test]
+    fn converted() {
+        let styles = [
+            Style::new().bright_green().on_bright_black(),
+            Style::new()
+                .color(CssColors::Coral)
+                .effect(Effect::BlinkFast),
+            Style::new().default_color().on_default_color(),
+        ];
+        for style in styles {
+            let mut expected = state(&style.prefix_formatter().to_string());
+            // fast blinking becomes blinking
+            if expected.rapid_blinking {
+                (expected.blinking, expected.rapid_blinking) = (true, false);
+            }
+            assert_eq!(
+                state(&SGRString::from(style).to_string()),
+                expected,
+                "{style:?}"
+            );
+        }
+        assert_eq!(
+            ColorKind::from(DynColors::Css(CssColors::Coral)),
+            ColorKind::Rgb(255, 127, 80)
+        );
+        assert_eq!(
+            ColorKind::from(DynColors::Ansi(AnsiColors::BrightRed)),
+            ColorKind::Byte(9)
+        );
+    }
+    #[test]
+    fn from_sgr_string() {
+        let sgr = RedFg.color(ByteBg(4)).style(Underline).style(NotBold);
+        let style = Style::from(&sgr);
+        assert_eq!(
+            style,
+            Style::new()
+                .red()
+                .on_color(XtermColors::from(4))
+                .underline()
+        );
+        assert_eq!(
+            state(&style.prefix_formatter().to_string()),
+            state(&sgr.to_string())
+        );
+        assert_eq!(Style::from(DefaultFg.to_sgr()), Style::new());
+    }
+}
+
+#[cfg(feature = "ratatui")]
+mod ratatui {
+    use easy_sgr::{
+        interop::ratatui::text_from_ansi, Color::*, ColorKind, EasySGR, SGRString, Style::*,
+    };
+    use ratatui::{
+        style::{Color, Modifier, Style},
+        text::{Line, Span},
+    };
+
+    #[test]
+    fn round_trip() {
+        let colors = [
+            None,
+            Some(Color::Reset),
+            Some(Color::Red),
+            Some(Color::Gray),
+            Some(Color::LightCyan),
+            Some(Color::Indexed(208)),
+            Some(Color::Rgb(1, 2, 3)),
+        ];
+        let modifiers = [
+            (Modifier::empty(), Modifier::empty()),
+            (Modifier::BOLD, Modifier::empty()),
+            (Modifier::DIM | Modifier::ITALIC, Modifier::UNDERLINED),
+            (Modifier::SLOW_BLINK | Modifier::REVERSED, Modifier::HIDDEN),
+            (Modifier::CROSSED_OUT, Modifier::BOLD | Modifier::SLOW_BLINK),
+        ];
+        for fg in colors {
+            for bg in colors {
+                for (add, sub) in modifiers {
+                    let mut style = Style::new().add_modifier(add).remove_modifier(sub);
+                    style.fg = fg;
+                    style.bg = bg;
+                    assert_eq!(Style::from(SGRString::from(style)), style);
+                }
+            }
+        }
+    }
+    #[test]
+    fn from_sgr_string() {
+        let sgr = RedFg.color(ByteBg(12)).style(Underline).style(NotItalic);
+        assert_eq!(
+            Style::from(&sgr),
+            Style::new()
+                .fg(Color::Red)
+                .bg(Color::LightBlue)
+                .add_modifier(Modifier::UNDERLINED)
+                .remove_modifier(Modifier::ITALIC)
+        );
+        assert_eq!(
+            Style::from(Reset.to_sgr().color(GreenFg)),
+            Style::reset().fg(Color::Green)
+        );
+        let sgr = SGRString::from(Style::new().add_modifier(Modifier::RAPID_BLINK));
+        assert_eq!(sgr.to_string(), "\x1b[5m");
+        assert_eq!(ColorKind::from(Color::White), ColorKind::Byte(15));
+    }
+    #[test]
+    fn text() {
+        let captured = "\x1b[1mwarning\x1b[22m: unused \x1b[33;4mx\x1b[24m\x1b[K\x1b[33m!\r\n\
+            \x1b[0m\n\
+            \x1b[38;5;208m  --> \x1b[39msrc/lib.rs\n";
+        let text = text_from_ansi(captured);
+        let yellow = Style::new().fg(Color::Yellow);
+        assert_eq!(
+            text.lines,
+            [
+                Line::from(vec![
+                    Span::styled("warning", Style::new().add_modifier(Modifier::BOLD)),
+                    Span::raw(": unused "),
+                    Span::styled("x", yellow.add_modifier(Modifier::UNDERLINED)),
+                    Span::styled("!", yellow),
+                ]),
+                Line::default(),
+                Line::from(vec![
+                    Span::styled("  --> ", Style::new().fg(Color::Indexed(208))),
+                    Span::raw("src/lib.rs"),
+                ]),
+            ]
+        );
+        assert!(text_from_ansi("\x1b[31m").lines.is_empty());
+    }
+}
+
+#[cfg(feature = "termcolor")]
+mod termcolor {
+    use easy_sgr::{Color::*, ColorKind, EasySGR, SGRString, SGRWriter, Style::*};
+    use termcolor::{Ansi, Color, ColorSpec, WriteColor};
+
+    use super::state;
+
+    fn specs() -> Vec<ColorSpec> {
+        let mut specs = Vec::new();
+        for (fg, bg) in [
+            (None, None),
+            (Some(Color::Red), None),
+            (Some(Color::White), Some(Color::Blue)),
+            (Some(Color::Ansi256(208)), Some(Color::Black)),
+            (None, Some(Color::Rgb(1, 2, 3))),
+        ] {
+            for intense in [false, true] {
+                for reset in [false, true] {
+                    let mut spec = ColorSpec::new();
+                    spec.set_fg(fg)
+                        .set_bg(bg)
+                        .set_intense(intense)
+                        .set_reset(reset)
+                        .set_bold(intense)
+                        .set_dimmed(reset)
+                        .set_italic(fg.is_some())
+                        .set_underline(bg.is_some())
+                        .set_strikethrough(intense && reset);
+                    specs.push(spec);
+                }
+            }
+        }
+        specs
+    }
+    #[test]
+    fn render() {
+        for spec in specs() {
+            let mut ansi = Ansi::new(Vec::new());
+            ansi.set_color(&spec).unwrap();
+            let expected = String::from_utf8(ansi.into_inner()).unwrap();
+
+            let mut writer = SGRWriter::from(String::new());
+            writer.place_sgr(&SGRString::from(&spec)).unwrap();
+            assert_eq!(state(&writer.internal()), state(&expected), "{spec:?}");
+        }
+    }
+    #[test]
+    fn round_trip() {
+        for spec in specs() {
+            let converted = SGRString::from(&spec).to_color_spec();
+            let named = |color: Option<&Color>| {
+                color.is_some_and(|color| !matches!(color, Color::Ansi256(_) | Color::Rgb(..)))
+            };
+            if named(spec.fg()) || named(spec.bg()) {
+                assert_eq!(converted, spec);
+            } else {
+                // intense only affects named colors
+                let mut plain = spec.clone();
+                plain.set_intense(false);
+                assert_eq!(converted, plain);
+            }
+        }
+    }
+    /// Returns each visible char along with the state it is written in
+    fn styled_chars(rendered: &str) -> Vec<(char, easy_sgr::SgrState)> {
+        use easy_sgr::{CapableWriter, EventWriter, StyleEvent};
+
+        let mut chars = Vec::new();
+        let mut current = easy_sgr::SgrState::new();
+        EventWriter::new(|event| match event {
+            StyleEvent::Text(text) => chars.extend(text.chars().map(|ch| (ch, current))),
+            StyleEvent::SgrChanged(state) => current = state,
+            StyleEvent::Escape(seq) => panic!("{seq:?} isn't an SGR sequence"),
+        })
+        .write(rendered)
+        .unwrap();
+        chars
+    }
+    /// Writes a diagnostic the way `codespan-reporting` does
+    fn diagnostic(w: &mut impl WriteColor) -> std::io::Result<()> {
+        let mut header = ColorSpec::new();
+        header
+            .set_fg(Some(Color::Red))
+            .set_bold(true)
+            .set_intense(true);
+        let mut gutter = ColorSpec::new();
+        gutter.set_fg(Some(Color::Blue)).set_intense(true);
+        let mut label = ColorSpec::new();
+        label.set_fg(Some(Color::Red)).set_reset(false);
+        let mut bold = ColorSpec::new();
+        bold.set_bold(true);
+
+        w.set_color(&header)?;
+        write!(w, "error[E0308]")?;
+        w.reset()?;
+        w.set_color(&bold)?;
+        writeln!(w, ": mismatched types")?;
+        w.reset()?;
+        w.set_color(&gutter)?;
+        write!(w, "  ┌─ ")?;
+        w.reset()?;
+        writeln!(w, "src/main.rs:2:5")?;
+        w.set_color(&gutter)?;
+        write!(w, "2 │ ")?;
+        w.reset()?;
+        write!(w, "    ")?;
+        w.set_color(&label)?;
+        write!(w, "\"a\"")?;
+        w.set_color(&header)?;
+        w.set_color(&gutter)?;
+        writeln!(w, " ^^^ expected `u8`")?;
+        w.reset()
+    }
+    #[test]
+    fn compat() {
+        use std::io::Write;
+
+        use easy_sgr::{interop::termcolor::TermcolorCompat, StripWriter};
+
+        let mut ansi = Ansi::new(Vec::new());
+        diagnostic(&mut ansi).unwrap();
+        let expected = String::from_utf8(ansi.into_inner()).unwrap();
+
+        let mut compat = TermcolorCompat::new(SGRWriter::from(String::new()));
+        assert!(compat.supports_color() && compat.supports_hyperlinks());
+        diagnostic(&mut compat).unwrap();
+        let rendered = compat.into_inner().internal();
+        assert_eq!(styled_chars(&rendered), styled_chars(&expected));
+        assert!(rendered.ends_with("\x1b[0m"));
+
+        let mut compat = TermcolorCompat::new(SGRWriter::from(StripWriter::new(String::new())));
+        diagnostic(&mut compat).unwrap();
+        let plain = compat.into_inner().internal();
+        assert_eq!(
+            plain,
+            styled_chars(&expected)
+                .into_iter()
+                .map(|(ch, _)| ch)
+                .collect::<String>()
+        );
+
+        let mut writer = SGRWriter::from(String::new());
+        writer.set_enabled(false);
+        let mut compat = TermcolorCompat::from(writer);
+        assert!(!compat.supports_color());
+        diagnostic(&mut compat).unwrap();
+        assert_eq!(compat.into_inner().internal(), plain);
+
+        let mut compat = TermcolorCompat::new(SGRWriter::from(String::new()));
+        compat
+            .set_hyperlink(&termcolor::HyperlinkSpec::open(b"https://example.com"))
+            .unwrap();
+        compat.write_all("link".as_bytes()).unwrap();
+        compat
+            .set_hyperlink(&termcolor::HyperlinkSpec::close())
+            .unwrap();
+        assert!(compat.write(&[0xff]).is_err());
+        assert_eq!(compat.write("é".as_bytes()).unwrap(), 2);
+        assert_eq!(compat.write(&[b'a', 0xc3]).unwrap(), 1);
+        assert_eq!(
+            compat.into_inner().internal(),
+            "\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\éa"
+        );
+    }
+    #[test]
+    fn intense() {
+        let mut spec = ColorSpec::new();
+        spec.set_fg(Some(Color::Red)).set_intense(true);
+        assert_eq!(SGRString::from(&spec).foreground, ColorKind::Byte(9));
+
+        let spec = RedFg.color(ByteBg(9)).style(Blinking).to_color_spec();
+        assert!(!spec.intense());
+        assert_eq!(spec.bg(), Some(&Color::Ansi256(9)));
+        assert_eq!(spec.fg(), Some(&Color::Red));
+    }
+}
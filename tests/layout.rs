@@ -0,0 +1,189 @@
+use easy_sgr::{
+    layout::{kv_block, rewrap_ansi, KvOptions},
+    measure_width, measure_width_upto, CleanKind,
+    Color::*,
+    EasySGR, SGRString,
+    Style::*,
+};
+
+#[test]
+fn mixed_keys() {
+    let pairs = [
+        (SGRString::from("Name"), SGRString::from("easy-sgr")),
+        (
+            GreenFg
+                .style(Bold)
+                .text("Version")
+                .clean(CleanKind::Reverse),
+            SGRString::from("0.1.1"),
+        ),
+        (
+            "\x1b[4mLicense\x1b[24m".into(),
+            CyanFg.text("MIT").clean(CleanKind::Reverse),
+        ),
+    ];
+    assert_eq!(
+        kv_block(&pairs, &KvOptions::default()),
+        "Name:    easy-sgr\n\
+         \x1b[32;1mVersion\x1b[39;22m: 0.1.1\n\
+         \x1b[4mLicense\x1b[24m: \x1b[36mMIT\x1b[39m\n"
+    );
+}
+#[test]
+fn separator() {
+    let pairs = [("a".into(), "1".into()), ("bcd".into(), "2".into())];
+    let opts = KvOptions {
+        separator: Dim.text(" =").clean(CleanKind::Reverse),
+        gap: 2,
+    };
+    assert_eq!(
+        kv_block(&pairs, &opts),
+        "a\x1b[2m =\x1b[22m    1\nbcd\x1b[2m =\x1b[22m  2\n"
+    );
+    let opts = KvOptions {
+        separator: SGRString::default(),
+        gap: 1,
+    };
+    assert_eq!(kv_block(&pairs, &opts), "a   1\nbcd 2\n");
+    assert_eq!(kv_block(&[], &opts), "");
+}
+#[test]
+fn multi_line_value() {
+    let pairs = [
+        ("Authors".into(), RedFg.text("one\ntwo\nthree")),
+        ("Id".into(), "1".into()),
+    ];
+    assert_eq!(
+        kv_block(&pairs, &KvOptions::default()),
+        "Authors: \x1b[31mone\n         \x1b[31mtwo\n         \x1b[31mthree\nId:      1\n"
+    );
+}
+#[test]
+#[cfg(feature = "width")]
+fn wide_keys() {
+    let pairs = [("名前".into(), "a".into()), ("Name".into(), "b".into())];
+    assert_eq!(
+        kv_block(&pairs, &KvOptions::default()),
+        "名前: a\nName: b\n"
+    );
+    let pairs = [("名".into(), "a\nb".into()), ("abc".into(), "c".into())];
+    assert_eq!(
+        kv_block(&pairs, &KvOptions::default()),
+        "名:  a\n     b\nabc: c\n"
+    );
+}
+#[test]
+fn measure() {
+    assert_eq!(measure_width(""), 0);
+    assert_eq!(measure_width("plain"), 5);
+    assert_eq!(
+        measure_width("\x1b[1;31m\x1b[0m\x1b[2J\x1b]0;title\x07\x1b(B"),
+        0
+    );
+    assert_eq!(
+        measure_width("see \x1b]8;;https://example.com\x1b\\docs\x1b]8;;\x1b\\!"),
+        9
+    );
+    assert_eq!(measure_width("a\tb\x1b[3"), 2);
+    // a lone `ESC` before a char ends without taking any of it
+    assert_eq!(measure_width("\x1bé"), 1);
+    assert_eq!(rewrap_ansi("\x1bé \x1bé", 1), "\x1bé\x1b\né");
+    assert_eq!(
+        measure_width(&RedFg.style(Bold).text("error").to_string()),
+        5
+    );
+
+    let s = "\x1b[1mab\x1b[0m\x1b[2mcd";
+    assert_eq!(measure_width_upto(s, 0), (0, 4));
+    assert_eq!(measure_width_upto(s, 2), (2, 14));
+    assert_eq!(&s[..measure_width_upto(s, 2).1], "\x1b[1mab\x1b[0m\x1b[2m");
+    assert_eq!(measure_width_upto(s, 3), (3, 15));
+    assert_eq!(measure_width_upto(s, 4), (4, s.len()));
+    assert_eq!(measure_width_upto(s, 100), (4, s.len()));
+    assert_eq!(measure_width_upto("\x1b[31m", 0), (0, 5));
+}
+#[test]
+#[cfg(feature = "width")]
+fn measure_wide() {
+    let s = "\x1b[31m名前\x1b[0m: e\u{301}";
+    assert_eq!(measure_width(s), 7);
+    assert_eq!(measure_width("\u{200b}\u{301}"), 0);
+    // a wide character not fitting is cut whole
+    assert_eq!(measure_width_upto(s, 3), (2, 8));
+    assert_eq!(measure_width_upto(s, 4), (4, 15));
+    // zero-width characters stay with what they follow
+    assert_eq!(measure_width_upto(s, 7), (7, s.len()));
+    assert_eq!(measure_width_upto(s, 6), (6, 17));
+}
+/// Returns each visible char along with the state it is written in
+fn styled_chars(s: &str) -> Vec<(char, easy_sgr::SgrState)> {
+    use easy_sgr::{CapableWriter, EventWriter, StyleEvent};
+
+    let mut chars = Vec::new();
+    let mut current = easy_sgr::SgrState::new();
+    EventWriter::new(|event| match event {
+        StyleEvent::Text(text) => chars.extend(text.chars().map(|ch| (ch, current))),
+        StyleEvent::SgrChanged(state) => current = state,
+        StyleEvent::Escape(_) => (),
+    })
+    .write(s)
+    .unwrap();
+    chars
+}
+#[test]
+fn rewrap() {
+    const CARGO: &str = "\x1b[1m\x1b[32m   Compiling\x1b[0m easy-sgr v0.1.1 (/root/crate)\n\
+        \x1b[1m\x1b[33mwarning\x1b[0m\x1b[1m: unused variable: `x`\x1b[0m\n\
+        \x20 \x1b[1m\x1b[94m-->\x1b[0m src/lib.rs:1:5\n\
+        \x1b[1m\x1b[94m|\x1b[0m  \x1b[1;33mhelp: prefix it with an underscore: `_x`\n\
+        still yellow\x1b[0m \x1b]8;;https://example.com\x07a link spanning words\x1b]8;;\x07\n\
+        \x1b[1m\x1b[32m    Finished\x1b[0m `dev` profile [unoptimized + debuginfo] target(s) in 0.52s";
+    let strip = |s: &str| {
+        styled_chars(s)
+            .into_iter()
+            .map(|(ch, _)| ch)
+            .collect::<String>()
+    };
+    let words = |s: &str| {
+        styled_chars(s)
+            .into_iter()
+            .filter(|(ch, _)| !ch.is_whitespace())
+            .collect::<Vec<_>>()
+    };
+    for width in [1, 4, 10, 16, 30, 80] {
+        let wrapped = rewrap_ansi(CARGO, width);
+        assert_eq!(
+            strip(&wrapped),
+            rewrap_ansi(&strip(CARGO), width),
+            "{width}"
+        );
+        assert_eq!(words(&wrapped), words(CARGO), "{width}");
+        let standalone: Vec<_> = wrapped.split('\n').flat_map(styled_chars).collect();
+        let mut in_context = styled_chars(&wrapped);
+        in_context.retain(|(ch, _)| *ch != '\n');
+        assert_eq!(standalone, in_context, "{width}");
+        for line in wrapped.split('\n') {
+            assert!(
+                measure_width(line) <= width,
+                "{line:?} is wider than {width}"
+            );
+        }
+    }
+    assert_eq!(strip(&rewrap_ansi(CARGO, 200)), strip(CARGO));
+}
+#[test]
+fn rewrap_plain() {
+    assert_eq!(rewrap_ansi("", 5), "");
+    assert_eq!(rewrap_ansi("one two three", 7), "one two\nthree");
+    assert_eq!(rewrap_ansi("one  two   three", 8), "one  two\nthree");
+    assert_eq!(rewrap_ansi("  indented words", 10), "  indented\nwords");
+    assert_eq!(rewrap_ansi("abcdefgh ij", 3), "abc\ndef\ngh\nij");
+    assert_eq!(rewrap_ansi("trailing   \nnext", 9), "trailing\nnext");
+    assert_eq!(rewrap_ansi("a\n\nb", 1), "a\n\nb");
+    // escapes are never split, unfinished ones dropped
+    assert_eq!(rewrap_ansi("ab\x1b[2Jcd\x1b[3", 2), "ab\x1b[2J\ncd");
+    assert_eq!(
+        rewrap_ansi("\x1b[31mab cd\x1b[0m ef", 2),
+        "\x1b[31mab\x1b[0m\n\x1b[31mcd\x1b[0m\nef"
+    );
+}
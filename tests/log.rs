@@ -0,0 +1,88 @@
+#![cfg(feature = "log")]
+use easy_sgr::{log::StyledLogger, Color::*, SGRWriter, Theme};
+use log::{Level, LevelFilter, Log, Record};
+
+fn log_all(logger: &StyledLogger<String>, message: &str) {
+    for level in [
+        Level::Error,
+        Level::Warn,
+        Level::Info,
+        Level::Debug,
+        Level::Trace,
+    ] {
+        logger.log(
+            &Record::builder()
+                .level(level)
+                .target("app")
+                .args(format_args!("{message}"))
+                .build(),
+        );
+    }
+}
+fn capture(logger: StyledLogger<String>) -> String {
+    logger.into_writer().internal().replace('\x1b', "\\x1b")
+}
+#[test]
+fn levels() {
+    let logger = StyledLogger::new(SGRWriter::from(String::new()));
+    log_all(&logger, "hi");
+    assert_eq!(
+        capture(logger),
+        "\\x1b[31;1mERROR app: hi\\x1b[39;22m\n\
+         \\x1b[33mWARN  app: hi\\x1b[39m\n\
+         \\x1b[32mINFO  app: hi\\x1b[39m\n\
+         \\x1b[34mDEBUG app: hi\\x1b[39m\n\
+         \\x1b[2mTRACE app: hi\\x1b[22m\n"
+    );
+}
+#[test]
+fn multi_line() {
+    let logger = StyledLogger::new(SGRWriter::from(String::new())).level(LevelFilter::Error);
+    log_all(&logger, "first\nsecond");
+    assert_eq!(
+        capture(logger),
+        "\\x1b[31;1mERROR app: first\\x1b[39;22m\n\
+         \\x1b[31;1msecond\\x1b[39;22m\n"
+    );
+}
+#[test]
+fn empty() {
+    let logger = StyledLogger::new(SGRWriter::from(String::new()))
+        .format(|f, record| write!(f, "{}", record.args()))
+        .level(LevelFilter::Warn);
+    log_all(&logger, "");
+    assert_eq!(capture(logger), "\n\n");
+}
+#[test]
+fn theme() {
+    let logger = StyledLogger::new(SGRWriter::from(String::new()))
+        .theme(Theme::new().with("warn", MagentaFg))
+        .level(LevelFilter::Info);
+    log_all(&logger, "hi");
+    assert_eq!(
+        capture(logger),
+        "ERROR app: hi\n\
+         \\x1b[35mWARN  app: hi\\x1b[0m\n\
+         INFO  app: hi\n"
+    );
+}
+#[test]
+fn format() {
+    let logger = StyledLogger::new(SGRWriter::from(String::new()))
+        .format(|f, record| write!(f, "[{}] {}", record.level(), record.args()))
+        .level(LevelFilter::Warn);
+    log_all(&logger, "hi");
+    assert_eq!(
+        capture(logger),
+        "\\x1b[31;1m[ERROR] hi\\x1b[39;22m\n\
+         \\x1b[33m[WARN] hi\\x1b[39m\n"
+    );
+}
+#[test]
+fn no_color() {
+    let logger = StyledLogger::new(SGRWriter::from(String::new()))
+        .color(false)
+        .level(LevelFilter::Warn);
+    log_all(&logger, "a\nb");
+    assert_eq!(capture(logger), "ERROR app: a\nb\nWARN  app: a\nb\n");
+}
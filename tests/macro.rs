@@ -27,7 +27,10 @@ mod macros {
     fn raw_strings() {
         sgr_tests!(
             r"Not much to test for this one maybe, this can't really fail" =
-                "Not much to test for this one maybe, this can't really fail"
+                "Not much to test for this one maybe, this can't really fail",
+            r"{[bold]} \x1b {}" = "{[bold]} \\x1b {}",
+            r#"quoted "{[red]}""# = "quoted \"{[red]}\"",
+            r##"hashes "#{[]}"#"## = "hashes \"#{[]}\"#"
         );
     }
     #[test]
@@ -103,6 +106,72 @@ mod macros {
             "{[on-#ffffff]}" = "\x1b[48;2;255;255;255m"
         );
     }
+    #[test]
+    fn runtime_args() {
+        use easy_sgr::{Color::*, DiscreteSGR, Style::*};
+
+        let (color, style) = (RgbFg(255, 0, 10), Italic);
+        assert_eq!(
+            format!("{[bold (color)]}text{[]}", color = color.params()),
+            "\x1b[1;38;2;255;0;10mtext\x1b[0m"
+        );
+        assert_eq!(
+            format!(
+                "{[(0) (1)]}{2}",
+                ByteBg(208).params(),
+                style.params(),
+                "text"
+            ),
+            "\x1b[48;5;208;3mtext"
+        );
+        assert_eq!(
+            format!(
+                "{[()]}text{[(style)]}",
+                color.params(),
+                style = NotItalic.params()
+            ),
+            "\x1b[38;2;255;0;10mtext\x1b[23m"
+        );
+        // `sgr!` leaves a template for `std`'s macros
+        assert_eq!(sgr!("{[on-red (color)]}"), "\x1b[41;{color}m");
+        assert_eq!(
+            std::format!("\x1b[41;{color}m", color = BlueFg.params()),
+            format!("{[on-red (color)]}", color = BlueFg.params())
+        );
+
+        let mut written_to = String::new();
+        writeln!(written_to, "{[(style)]}text", style = Underline.params()).unwrap();
+        assert_eq!(written_to, "\x1b[4mtext\n");
+    }
+    #[test]
+    fn styled_placeholders() {
+        let path = "src/lib.rs";
+        assert_eq!(
+            format!("{path[cyan underline]} changed"),
+            "\x1b[36;4msrc/lib.rs\x1b[24;39m changed"
+        );
+        assert_eq!(
+            format!("[{path[bold]:>12}]"),
+            "[\x1b[1m  src/lib.rs\x1b[22m]"
+        );
+        assert_eq!(
+            format!("{0[red]}: {1[dim]:.3}", "error", 1.23456),
+            "\x1b[31merror\x1b[39m: \x1b[2m1.235\x1b[22m"
+        );
+        assert_eq!(
+            format!("{[on-blue]:<4}|{[italic]:}", 1, "two"),
+            "\x1b[44m1   \x1b[49m|\x1b[3mtwo\x1b[23m"
+        );
+        assert_eq!(sgr!("{path[red]}"), "\x1b[31m{path}\x1b[39m");
+    }
+    #[test]
+    fn raw_sequences() {
+        assert_eq!(
+            sgr!("\x1b[01m{[red]}text\x1b[m"),
+            "\x1b[1m\x1b[31mtext\x1b[0m"
+        );
+        assert_eq!(format!("\x1b[;04m{}{[]}", "text"), "\x1b[0;4mtext\x1b[0m");
+    }
     /// really just for improving coverage numbers
     /// the other tests cover pretty much everything
     #[test]
@@ -142,4 +211,177 @@ mod macros {
             "\nNormal\u{1b}[32m now this is green\u{1b}[0m and this is not\n"
         );
     }
+    #[test]
+    fn forwarded() {
+        macro_rules! literal {
+            ($s:literal) => {
+                sgr!($s)
+            };
+        }
+        macro_rules! expr {
+            ($s:expr) => {
+                sgr!($s)
+            };
+        }
+        macro_rules! twice_literal {
+            ($s:literal) => {
+                literal!($s)
+            };
+        }
+        macro_rules! twice_expr {
+            ($s:expr) => {
+                expr!($s)
+            };
+        }
+        macro_rules! mixed {
+            ($s:literal) => {
+                expr!($s)
+            };
+        }
+        macro_rules! write_expr {
+            ($w:expr, $s:expr) => {
+                write!($w, $s)
+            };
+        }
+        assert_eq!(literal!("{[red]}err"), "\x1b[31merr");
+        assert_eq!(expr!("{[red]}err"), "\x1b[31merr");
+        assert_eq!(twice_literal!("{[bold]}a"), "\x1b[1ma");
+        assert_eq!(twice_expr!("{[bold]}a"), "\x1b[1ma");
+        assert_eq!(mixed!("{[!bold]}b"), "\x1b[22mb");
+        assert_eq!(expr!(r"{[italic]}\n"), r"{[italic]}\n");
+
+        let mut written_to = String::new();
+        write_expr!(written_to, "{[green]}ok").unwrap();
+        assert_eq!(written_to, "\x1b[32mok");
+    }
+    #[test]
+    fn both() {
+        use easy_sgr::{sgr_both, SGRWriter, StripWriter};
+
+        fn strip(s: &str) -> String {
+            let mut writer = SGRWriter::from(StripWriter::new(String::new()));
+            writer.write_inner(s).unwrap();
+            writer.internal()
+        }
+        let (styled, plain) = sgr_both!("{[red bold]}fail{[]}: {code} {}");
+        assert_eq!(styled, "\x1b[31;1mfail\x1b[0m: {code} {}");
+        assert_eq!(plain, "fail: {code} {}");
+        // the same arguments render both alike
+        let code = 3;
+        let rendered_styled = std::format!("\x1b[31;1mfail\x1b[0m: {code} {}", 4);
+        let rendered_plain = std::format!("fail: {code} {}", 4);
+        assert_eq!(strip(&rendered_styled), rendered_plain);
+
+        let name = "x";
+        let (styled, plain) = sgr_both!("{name[italic]:>3}\x1b[2J{[on-#ff8000]}");
+        assert_eq!(
+            styled,
+            "\x1b[3m{name:>3}\x1b[23m\x1b[2J\x1b[48;2;255;128;0m"
+        );
+        assert_eq!(plain, "{name:>3}\x1b[2J");
+        assert_eq!(
+            strip(&format!("{name[italic]:>3}{[on-#ff8000]}")),
+            std::format!("{name:>3}")
+        );
+
+        assert_eq!(sgr_both!(r"{[red]}"), (r"{[red]}", r"{[red]}"));
+    }
+    #[test]
+    fn len() {
+        use easy_sgr::sgr_len;
+
+        const GUTTER: usize = sgr_len!("{[red]}ERROR{[]}");
+        assert_eq!(GUTTER, 5);
+        let gutter = [b' '; sgr_len!("{[red]}ERROR{[]}")];
+        assert_eq!(gutter.len(), 5);
+
+        assert_eq!(sgr_len!(""), 0);
+        assert_eq!(sgr_len!("plain text"), 10);
+        assert_eq!(sgr_len!("☀ ☁ \u{1f604}"), 5);
+        assert_eq!(sgr_len!("\t{[bold on-#ff8000]}a\x1b[2J\x1b[mb{[]}"), 3);
+        assert_eq!(
+            sgr_len!("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x07"),
+            4
+        );
+        assert_eq!(sgr_len!("{{braces}}"), 8);
+        // raw strings are counted as written
+        assert_eq!(sgr_len!(r"\x1b {{"), 7);
+        assert_eq!(sgr_len!(r"{{}}"), sgr!(r"{{}}").chars().count());
+        assert_eq!(sgr_len!(r"{[red]}{}"), 9);
+    }
+    #[test]
+    fn escaped() {
+        use easy_sgr::sgr_escaped;
+
+        macro_rules! escaped_tests {
+            ($($input:literal),*) => {
+                $(
+                    assert_eq!(sgr_escaped!($input), sgr!($input).replace('\x1b', "\\x1b"));
+                    assert_eq!(sgr_escaped!($input, e), sgr!($input).replace('\x1b', "\\e"));
+                )*
+            };
+        }
+        escaped_tests!(
+            "",
+            "plain text",
+            "{[]}",
+            "{[red bold]}fail{[]}: {code}",
+            "{name[italic]:>3}{[on-#ff8000]}",
+            "\x1b[2J{[0,0,0]}{{[]}",
+            "\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x07"
+        );
+        assert_eq!(sgr_escaped!("{[red]}err{[]}"), r"\x1b[31merr\x1b[0m");
+        assert_eq!(sgr_escaped!("{[!bold]}", e), r"\e[22m");
+        assert_eq!(sgr_escaped!(r"{[red]}\x1b"), r"{[red]}\x1b");
+    }
+    #[test]
+    fn args() {
+        use easy_sgr::sgr_args;
+        use std::{fmt::Write as _, io::Write as _};
+
+        /// Counts the writes made to it, keeping what was written
+        struct Counting(Vec<u8>, usize);
+        impl std::io::Write for Counting {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.1 += 1;
+                self.0.write(buf)
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let (ts, msg) = ("12:00", String::from("started"));
+        let expected = format!("{[dim]}{ts}{[]} {msg[bold]}", ts = ts, msg = msg);
+        assert_eq!(expected, "\x1b[2m12:00\x1b[0m \x1b[1mstarted\x1b[22m");
+
+        let mut written = String::new();
+        std::fmt::write(
+            &mut written,
+            sgr_args!("{[dim]}{ts}{[]} {msg[bold]}", ts = ts, msg = msg),
+        )
+        .unwrap();
+        assert_eq!(written, expected);
+        written.clear();
+        write!(
+            written,
+            "{}",
+            sgr_args!("{[dim]}{}{[]} {msg[bold]}", ts, msg = msg)
+        )
+        .unwrap();
+        assert_eq!(written, expected);
+
+        let mut counting = Counting(Vec::new(), 0);
+        counting
+            .write_fmt(sgr_args!("{[dim]}{ts}{[]} {msg[bold]}", ts = ts, msg = msg))
+            .unwrap();
+        assert_eq!(counting.0, expected.as_bytes());
+        // written piece by piece, without being formatted into a string first
+        assert!(counting.1 > 1);
+        // temporaries live as long as they do within `std::format_args!`
+        assert_eq!(
+            std::fmt::format(sgr_args!("{[red]}{}", String::from("temp"))),
+            "\x1b[31mtemp"
+        );
+    }
 }
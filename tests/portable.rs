@@ -0,0 +1,125 @@
+use easy_sgr::{
+    portable::{PortableAttributes, PortableColor, PortableStyle},
+    Color::*,
+    EasySGR, SGRString, SgrState,
+    Style::*,
+};
+
+fn sample() -> PortableStyle {
+    PortableStyle {
+        foreground: Some(PortableColor::Red),
+        background: Some(PortableColor::Indexed { index: 208 }),
+        underline_color: Some(PortableColor::Rgb { r: 1, g: 2, b: 3 }),
+        attributes: PortableAttributes {
+            bold: true,
+            underline: true,
+            ..PortableAttributes::new()
+        },
+        ..PortableStyle::new()
+    }
+}
+#[test]
+fn sgr_string() {
+    let sgr = SGRString::from(sample());
+    assert_eq!(sgr.to_string(), "\x1b[31;48;5;208;1;4;58;2;1;2;3m");
+    assert_eq!(PortableStyle::from(&sgr), sample());
+
+    let sgr = RgbFg(1, 2, 3)
+        .color(DefaultBg)
+        .style(Italic)
+        .style(NotBold)
+        .custom(59)
+        .text("dropped");
+    assert_eq!(
+        PortableStyle::from(sgr),
+        PortableStyle {
+            foreground: Some(PortableColor::Rgb { r: 1, g: 2, b: 3 }),
+            background: Some(PortableColor::Default),
+            underline_color: Some(PortableColor::Default),
+            attributes: PortableAttributes {
+                italic: true,
+                ..PortableAttributes::new()
+            },
+            ..PortableStyle::new()
+        }
+    );
+}
+#[test]
+fn underline_color() {
+    let named = PortableStyle {
+        underline_color: Some(PortableColor::Cyan),
+        ..PortableStyle::new()
+    };
+    let sgr = SGRString::from(&named);
+    assert_eq!(sgr.custom_places, [58, 5, 6]);
+    assert_eq!(
+        PortableStyle::from(sgr).underline_color,
+        Some(PortableColor::Indexed { index: 6 })
+    );
+    // the arguments of other colors aren't read as underline colors
+    let sgr = SGRString {
+        custom_places: vec![38, 5, 58, 48, 2, 59, 1, 1],
+        ..SGRString::default()
+    };
+    assert_eq!(PortableStyle::from(sgr).underline_color, None);
+}
+#[test]
+fn state() {
+    let mut state = SgrState::new();
+    state.apply(&[1, 38, 5, 9, 7, 58, 5, 4]);
+    let style = PortableStyle::from(&state);
+    assert_eq!(style.foreground, Some(PortableColor::Indexed { index: 9 }));
+    assert_eq!(style.background, None);
+    assert_eq!(
+        style.attributes,
+        PortableAttributes {
+            bold: true,
+            inverse: true,
+            ..PortableAttributes::new()
+        }
+    );
+}
+#[cfg(feature = "serde")]
+#[test]
+fn json() {
+    let json = sample().to_json().unwrap();
+    assert_eq!(
+        json,
+        r#"{"version":1,"foreground":{"type":"red"},"background":{"type":"indexed","index":208},"underline_color":{"type":"rgb","r":1,"g":2,"b":3},"attributes":{"bold":true,"underline":true}}"#
+    );
+    assert_eq!(PortableStyle::from_json(&json).unwrap(), sample());
+    assert_eq!(
+        PortableStyle::new().to_json().unwrap(),
+        r#"{"version":1,"attributes":{}}"#
+    );
+    assert_eq!(
+        PortableStyle::from_json("{}").unwrap(),
+        PortableStyle::new()
+    );
+}
+#[cfg(feature = "serde")]
+#[test]
+fn forward_compat() {
+    let style = PortableStyle::from_json(
+        r#"{
+            "version": 1,
+            "foreground": { "type": "red", "shade": "dark" },
+            "attributes": { "bold": true, "overline": true },
+            "font": "mono"
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(
+        style,
+        PortableStyle {
+            foreground: Some(PortableColor::Red),
+            attributes: PortableAttributes {
+                bold: true,
+                ..PortableAttributes::new()
+            },
+            ..PortableStyle::new()
+        }
+    );
+    assert!(PortableStyle::from_json(r#"{"version":2}"#).is_err());
+    assert!(PortableStyle::from_json(r#"{"foreground":{"type":"mauve"}}"#).is_err());
+}
@@ -0,0 +1,16 @@
+use std::error::Error;
+
+use easy_sgr::{
+    eprint_sgr_string, eprint_styled, print_sgr_string, print_styled, Color::*, EasySGR,
+};
+
+// the bytes written are checked by the unit tests of `print`,
+// these only check that the real streams can be written to
+#[test]
+fn print() -> Result<(), Box<dyn Error>> {
+    print_styled(&RedFg, "")?;
+    eprint_styled(&RedFg, "")?;
+    print_sgr_string(&"".color(RedFg))?;
+    eprint_sgr_string(&"".color(RedFg))?;
+    Ok(())
+}
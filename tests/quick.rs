@@ -0,0 +1,40 @@
+use easy_sgr::{quick::*, CleanKind, Color::*, ColorKind, EasySGR, Style::*, StyleKind};
+
+#[test]
+fn single() {
+    assert_eq!(red("text").to_string(), "\x1b[31mtext");
+    assert_eq!(on_blue("text").to_string(), "\x1b[44mtext");
+    assert_eq!(bold("text").to_string(), "\x1b[1mtext");
+    assert_eq!(strikethrough("text").to_string(), "\x1b[9mtext");
+    assert_eq!(bright_red("text").to_string(), "\x1b[38;5;9mtext");
+    assert_eq!(on_bright_white("text").to_string(), "\x1b[48;5;15mtext");
+    assert_eq!(italic(String::from("owned")).text, "owned");
+}
+#[test]
+fn nested() {
+    let sgr = bold(underline(red(on_black("text"))));
+    assert_eq!(sgr.foreground, ColorKind::Red);
+    assert_eq!(sgr.background, ColorKind::Black);
+    assert_eq!(sgr.bold, StyleKind::Place);
+    assert_eq!(sgr.underline, StyleKind::Place);
+    assert_eq!(sgr.to_string(), "\x1b[31;40;1;4mtext");
+
+    // the outer call wins
+    assert_eq!(red(green("text")).foreground, ColorKind::Red);
+    assert_eq!(on_red(on_green("text")).background, ColorKind::Red);
+    assert_eq!(
+        format!("{:?}", bold(red("text"))),
+        format!("{:?}", "text".color(RedFg).style(Bold))
+    );
+}
+#[test]
+fn composition() {
+    let sgr = red("text").color(BlueBg).clean(CleanKind::Reverse);
+    assert_eq!(sgr.to_string(), "\x1b[31;44mtext\x1b[39;49m");
+    assert_eq!(
+        bold("text").style(NotItalic).color(GreenFg).to_string(),
+        "\x1b[32;1;23mtext"
+    );
+    // builder methods called afterwards override the function
+    assert_eq!(red("text").color(YellowFg).to_string(), "\x1b[33mtext");
+}
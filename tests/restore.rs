@@ -0,0 +1,60 @@
+use std::{
+    io::Write,
+    panic,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use easy_sgr::{
+    install_panic_restore, install_panic_restore_with, set_color_choice, ColorChoice,
+    RestoreOptions,
+};
+
+/// Panics in a new thread, returning what was written while panicking
+fn panic_captured(captured: &Mutex<Vec<u8>>) -> String {
+    captured.lock().unwrap().clear();
+    assert!(thread::spawn(|| panic!("boom")).join().is_err());
+    String::from_utf8(captured.lock().unwrap().clone()).unwrap()
+}
+// the hook is global, so each step is run in order by a single test
+#[test]
+fn panic_restore() {
+    let captured = Arc::new(Mutex::new(Vec::new()));
+    let hook_captured = Arc::clone(&captured);
+    // stands in for the default hook, or one installed by another crate
+    panic::set_hook(Box::new(move |info| {
+        let message = info.payload().downcast_ref::<&str>().unwrap();
+        write!(hook_captured.lock().unwrap(), "panicked: {message}").unwrap();
+    }));
+    assert_eq!(panic_captured(&captured), "panicked: boom");
+
+    let options = RestoreOptions {
+        show_cursor: true,
+        output: Some(captured.clone()),
+        ..Default::default()
+    };
+    install_panic_restore_with(options.clone());
+    let restore = install_panic_restore_with(options);
+    assert_eq!(panic_captured(&captured), "\x1b[0m\x1b[?25hpanicked: boom");
+
+    install_panic_restore_with(RestoreOptions {
+        close_hyperlink: true,
+        output: Some(captured.clone()),
+        ..Default::default()
+    });
+    assert_eq!(
+        panic_captured(&captured),
+        "\x1b[0m\x1b]8;;\x1b\\panicked: boom"
+    );
+
+    set_color_choice(ColorChoice::Never);
+    assert_eq!(panic_captured(&captured), "panicked: boom");
+    set_color_choice(ColorChoice::Always);
+
+    restore.uninstall();
+    assert_eq!(panic_captured(&captured), "panicked: boom");
+
+    // writes to stdout & stderr, not the captured output
+    install_panic_restore();
+    assert_eq!(panic_captured(&captured), "panicked: boom");
+}
@@ -0,0 +1,192 @@
+#![cfg(feature = "test-util")]
+use std::{error::Error, panic};
+
+use easy_sgr::{
+    sgr_assert_eq, sgr_assert_styled_eq,
+    test::{normalize_sgr, replay, strip_escapes, Event, RecordingWriter, ReplayError, TestWriter},
+    CapableWriter, CleanKind, Color, EasySGR, SGRWriter, Style,
+};
+
+#[test]
+fn sgr_writer() -> Result<(), Box<dyn Error>> {
+    let mut w = SGRWriter::from(TestWriter::new());
+    w.write_inner("test")?;
+    w.sgr(&Color::RedFg.style(Style::Italic))?;
+    w.sgr(&Style::Bold)?;
+
+    w.get_ref().assert_sequence(&[
+        Event::Text("test".into()),
+        Event::Codes(vec![31, 3]),
+        Event::Codes(vec![1]),
+    ]);
+    assert_eq!(w.get_ref().codes_flat(), [31, 3, 1]);
+    assert_eq!(w.get_ref().text_concat(), "test");
+    Ok(())
+}
+
+#[test]
+fn sgr_builder() -> Result<(), Box<dyn Error>> {
+    let mut w = SGRWriter::from(TestWriter::new());
+
+    w.builder().write_to(&mut w)?;
+    w.builder().write_partial(&mut w)?;
+    w.get_ref().assert_sequence(&[]);
+
+    let mut builder = w.builder();
+    builder
+        .chain_code(0)
+        .chain_codes(&[1, 2])
+        .write_to(&mut w)?;
+    builder.write_partial(&mut w)?;
+
+    w.get_ref().assert_sequence(&[
+        Event::Codes(vec![0, 1, 2]),
+        Event::PartialCodes(vec![0, 1, 2]),
+    ]);
+    Ok(())
+}
+
+#[test]
+fn partial_sgr() -> Result<(), Box<dyn Error>> {
+    let mut w = SGRWriter::from(TestWriter::new());
+    w.write_inner("\x1b[")?;
+    w.partial_sgr(&Style::Italic)?;
+    w.write_inner(";")?;
+    w.partial_sgr(&Color::RedFg)?;
+    w.write_inner("m")?;
+
+    w.get_ref().assert_sequence(&[
+        Event::Text("\x1b[".into()),
+        Event::PartialCodes(vec![3]),
+        Event::Text(";".into()),
+        Event::PartialCodes(vec![31]),
+        Event::Text("m".into()),
+    ]);
+    Ok(())
+}
+
+#[test]
+fn hyperlink_styled() -> Result<(), Box<dyn Error>> {
+    let mut w = SGRWriter::from(TestWriter::new());
+    w.hyperlink_styled(
+        "https://example.com",
+        &"example".color(Color::RedFg).clean(CleanKind::Reverse),
+    )?;
+
+    w.get_ref().assert_sequence(&[
+        Event::Text("\x1b]8;;https://example.com\x1b\\".into()),
+        Event::Codes(vec![31]),
+        Event::Text("example".into()),
+        Event::Codes(vec![39]),
+        Event::Text("\x1b]8;;\x1b\\".into()),
+    ]);
+    Ok(())
+}
+
+#[test]
+fn internal() {
+    let mut w = SGRWriter::from(TestWriter::new());
+    w.write_inner("a").unwrap();
+    w.write_inner("b").unwrap();
+    assert_eq!(w.internal(), [Event::Text("ab".into())]);
+}
+
+/// Writes a bit of everything
+fn session<W: CapableWriter>(w: &mut SGRWriter<W>) -> Result<(), W::Error>
+where
+    W::Error: std::fmt::Debug,
+{
+    w.sgr(&Color::RedFg.style(Style::Bold))?;
+    w.write_inner("error")?;
+    w.inline_sgr(&Style::Reset)?;
+    w.write_inner(": \"bad\" \\ input\n")?;
+    w.write_inner("\x1b[")?;
+    w.partial_sgr(&Style::Italic)?;
+    w.write_inner("m")?;
+    w.write_inner("tab\there\r\n")?;
+    w.write_escape("\x1b]0;title\x07").unwrap();
+    w.write_inner("bell\x07 ünï")?;
+    w.reset()
+}
+#[test]
+fn record_replay() -> Result<(), Box<dyn Error>> {
+    let mut recorded = SGRWriter::from(RecordingWriter::new());
+    session(&mut recorded)?;
+    let transcript = recorded.internal();
+    assert_eq!(
+        transcript,
+        include_str!("fixtures/transcripts/session.txt").trim_end()
+    );
+
+    let mut direct = SGRWriter::from(Vec::new());
+    session(&mut direct)?;
+    let mut replayed = Vec::new();
+    replay(&transcript, &mut replayed)?;
+    assert_eq!(replayed, direct.internal());
+    Ok(())
+}
+#[test]
+fn replay_malformed() {
+    let mut written = String::new();
+    for (transcript, at) in [
+        ("SGR[1] TEXT\"a", 7),
+        ("SGR[1;x]", 0),
+        ("TEXT\"a\"SGR[0]", 7),
+        ("TEXT\"\\q\"", 0),
+        ("SGR[0] BOLD", 7),
+    ] {
+        assert_eq!(
+            replay(transcript, &mut written),
+            Err(ReplayError::Transcript(at)),
+            "{transcript:?}"
+        );
+    }
+    assert!(written.is_empty());
+}
+#[test]
+fn sgr_assert() {
+    assert_eq!(
+        strip_escapes("\x1b[1mbold\x1b]8;;url\x1b\\link\x1b[0m"),
+        "boldlink"
+    );
+    assert_eq!(normalize_sgr("a\x1b[1m\x1b[mb\x1b[2K"), "a\x1b[1;0mb");
+
+    sgr_assert_eq!(Color::RedFg.text("error"), "error");
+    sgr_assert_eq!("\x1b[1mbold", "\x1b[2mbold\x1b[0m");
+    sgr_assert_styled_eq!("\x1b[1m\x1b[31mbold", "\x1b[1;31mbold");
+    sgr_assert_styled_eq!(
+        Style::Bold.color(Color::RedFg).text("bold"),
+        "\x1b[31;1mbold"
+    );
+}
+#[test]
+#[should_panic(expected = "text differs at char 3")]
+fn sgr_assert_text_differs() {
+    sgr_assert_eq!("\x1b[1mbold", "\x1b[1mbolt");
+}
+#[test]
+#[should_panic(expected = "styling differs")]
+fn sgr_assert_styling_differs() {
+    sgr_assert_styled_eq!("\x1b[1mbold", "\x1b[31mbold");
+}
+#[test]
+fn sgr_assert_messages() {
+    let message = |f: fn()| {
+        let payload = panic::catch_unwind(f).unwrap_err();
+        payload.downcast::<String>().map(|s| *s).unwrap()
+    };
+    assert_eq!(
+        message(|| sgr_assert_eq!("\x1b[1mbold\x1b[0m", "\x1b[1mbol")),
+        "assertion `left == right` failed: text differs at char 3\n  \
+         left text: \"bold\"\n right text: \"bol\"\n       \
+         left: \"\\e[1mbold\\e[0m\"\n      right: \"\\e[1mbol\""
+    );
+    assert_eq!(
+        message(|| sgr_assert_styled_eq!("\x1b[1m\x1b[mbold", "\x1b[1mbold")),
+        "assertion `left == right` failed: styling differs\n \
+         left normalized: \"\\e[1;0mbold\"\nright normalized: \"\\e[1mbold\"\n            \
+         left: \"\\e[1m\\e[mbold\"\n           right: \"\\e[1mbold\""
+    );
+    // the text is compared first
+    assert!(message(|| sgr_assert_styled_eq!("\x1b[1ma", "\x1b[2mb")).contains("text differs"));
+}
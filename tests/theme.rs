@@ -0,0 +1,94 @@
+use easy_sgr::{Color::*, ColorKind, EasySGR, Style::*, StyleKind, Theme};
+
+#[test]
+fn merge() {
+    let defaults = Theme::new()
+        .with("error", RedFg.style(Bold))
+        .with("note", BlueFg);
+    let theme = defaults.merge(Theme::new().with("note", CyanFg).with("hint", Dim));
+
+    let names: Vec<_> = theme.iter().map(|(name, _)| name).collect();
+    assert_eq!(names, ["error", "hint", "note"]);
+    assert_eq!(theme.get("error").unwrap().foreground, ColorKind::Red);
+    assert_eq!(theme.get("note").unwrap().foreground, ColorKind::Cyan);
+    assert_eq!(theme.get("hint").unwrap().dim, StyleKind::Place);
+}
+#[cfg(feature = "from-str")]
+mod from_vars {
+    use easy_sgr::{ColorKind, StyleKind, Theme, ThemeVarError};
+
+    #[test]
+    fn entries() {
+        let (theme, errors) = Theme::from_vars(
+            "MYAPP",
+            [
+                ("MYAPP_COLOR_ERROR", "#ff5555 bold"),
+                ("MYAPP_COLOR_WARN", "  yellow   on-#20  "),
+                ("MYAPP_COLOR_NOTE", "RgbFg(1,2,3) Italic"),
+                ("MYAPP_COLOR_LINE_NUMBER", "!bold 15 on-0,0,0"),
+                ("MYAPP_COLOR_PLAIN", ""),
+                ("MYAPP_COLOR_", "red"),
+                ("MYAPP_OTHER", "red"),
+                ("OTHER_COLOR_ERROR", "green"),
+                ("PATH", "/usr/bin"),
+            ],
+        );
+        assert!(errors.is_empty(), "{errors:?}");
+        let names: Vec<_> = theme.iter().map(|(name, _)| name).collect();
+        assert_eq!(names, ["error", "line_number", "note", "plain", "warn"]);
+
+        let error = theme.get("error").unwrap();
+        assert_eq!(error.foreground, ColorKind::Rgb(255, 85, 85));
+        assert_eq!(error.bold, StyleKind::Place);
+        let warn = theme.get("warn").unwrap();
+        assert_eq!(warn.foreground, ColorKind::Yellow);
+        assert_eq!(warn.background, ColorKind::Byte(0x20));
+        let note = theme.get("note").unwrap();
+        assert_eq!(note.foreground, ColorKind::Rgb(1, 2, 3));
+        assert_eq!(note.italic, StyleKind::Place);
+        let line_number = theme.get("line_number").unwrap();
+        assert_eq!(line_number.bold, StyleKind::Clean);
+        assert_eq!(line_number.foreground, ColorKind::Byte(15));
+        assert_eq!(line_number.background, ColorKind::Rgb(0, 0, 0));
+        assert_eq!(theme.get("plain").unwrap().to_string(), "");
+    }
+    #[test]
+    fn errors() {
+        let (theme, errors) = Theme::from_vars(
+            "APP",
+            vec![
+                ("APP_COLOR_OK".to_string(), "green".to_string()),
+                ("APP_COLOR_TYPO".to_string(), "bold rde".to_string()),
+                ("APP_COLOR_HEX".to_string(), "#fff".to_string()),
+                ("APP_COLOR_BYTE".to_string(), "256".to_string()),
+                ("APP_COLOR_RGB".to_string(), "on-1,2".to_string()),
+            ],
+        );
+        let names: Vec<_> = theme.iter().map(|(name, _)| name).collect();
+        assert_eq!(names, ["ok"]);
+        let error = |var: &str, word: &str| ThemeVarError {
+            var: var.to_string(),
+            word: word.to_string(),
+        };
+        assert_eq!(
+            errors,
+            [
+                error("APP_COLOR_TYPO", "rde"),
+                error("APP_COLOR_HEX", "#fff"),
+                error("APP_COLOR_BYTE", "256"),
+                error("APP_COLOR_RGB", "on-1,2"),
+            ]
+        );
+        assert_eq!(
+            errors[0].to_string(),
+            "invalid style `rde` in `APP_COLOR_TYPO`"
+        );
+    }
+    #[test]
+    fn layered() {
+        let defaults = Theme::new().with("error", easy_sgr::Color::RedFg);
+        let (overrides, _) = Theme::from_vars("APP", [("APP_COLOR_ERROR", "magenta")]);
+        let theme = defaults.merge(overrides);
+        assert_eq!(theme.get("error").unwrap().foreground, ColorKind::Magenta);
+    }
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "tracing")]
+use std::{
+    io,
+    sync::{Arc, Mutex},
+};
+
+use easy_sgr::{tracing::StyledFormat, Color::*, Theme};
+use tracing::Level;
+
+#[derive(Clone, Default)]
+struct Capture(Arc<Mutex<Vec<u8>>>);
+impl io::Write for Capture {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+fn capture(format: StyledFormat, f: impl FnOnce()) -> String {
+    let out = Capture::default();
+    let writer = out.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(Level::TRACE)
+        .with_writer(move || writer.clone())
+        .event_format(format.clone())
+        .fmt_fields(format)
+        .finish();
+    tracing::subscriber::with_default(subscriber, f);
+    let bytes = out.0.lock().unwrap().clone();
+    String::from_utf8(bytes).unwrap().replace('\x1b', "\\x1b")
+}
+#[test]
+fn level_only() {
+    let format = StyledFormat::new()
+        .theme(Theme::new())
+        .level_style(Level::INFO, GreenFg)
+        .level_style(Level::ERROR, RedFg)
+        .color(true);
+    let out = capture(format, || {
+        tracing::info!(target: "app", count = 3, "hello");
+        tracing::error!(target: "app", "oh no");
+        tracing::warn!(target: "app", "plain");
+    });
+    assert_eq!(
+        out,
+        " \\x1b[32mINFO\\x1b[0m app: hello count=3\n\
+         \\x1b[31mERROR\\x1b[0m app: oh no\n \
+         WARN app: plain\n"
+    );
+}
+#[test]
+fn default_theme() {
+    let out = capture(StyledFormat::new().color(true), || {
+        tracing::debug!(target: "app", name = "x", "hi");
+    });
+    assert_eq!(
+        out,
+        "\\x1b[34mDEBUG\\x1b[39m \\x1b[2mapp\\x1b[22m: hi \\x1b[3mname\\x1b[23m=\"x\"\n"
+    );
+}
+#[test]
+fn spans() {
+    let format = StyledFormat::new()
+        .theme(Theme::new().with("span", CyanFg))
+        .color(true);
+    let run = || {
+        let span = tracing::info_span!("outer", id = 1);
+        let _entered = span.enter();
+        tracing::trace!(target: "app", "inside");
+    };
+    assert_eq!(
+        capture(format.clone(), run),
+        "TRACE \\x1b[36mouter\\x1b[0m{id=1}: app: inside\n"
+    );
+    assert_eq!(
+        capture(format.style_spans(false), run),
+        "TRACE outer{id=1}: app: inside\n"
+    );
+}
+#[test]
+fn no_color() {
+    let out = capture(StyledFormat::new().color(false), || {
+        tracing::info!(target: "app", a = true, "hi");
+    });
+    assert_eq!(out, " INFO app: hi a=true\n");
+}
+#[test]
+fn color_choice() {
+    use easy_sgr::{set_color_choice, ColorChoice};
+
+    let event = || tracing::info!(target: "app", "hi");
+    set_color_choice(ColorChoice::Never);
+    assert_eq!(capture(StyledFormat::new(), event), " INFO app: hi\n");
+    // set on the format, winning over the global choice
+    assert!(capture(StyledFormat::new().color(true), event).contains("\\x1b["));
+
+    set_color_choice(ColorChoice::Always);
+    assert!(capture(StyledFormat::new(), event).contains("\\x1b["));
+}
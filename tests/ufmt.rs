@@ -0,0 +1,56 @@
+#![cfg(feature = "ufmt")]
+use easy_sgr::{ufmt::UfmtWriter, CleanKind, Color::*, EasySGR, SGRString, SGRWriter, Style::*};
+use ufmt::{uDisplay, uwrite};
+
+/// Formats `value` through `ufmt` into a `String`
+fn ufmt(value: &impl uDisplay) -> String {
+    let mut s = String::new();
+    uwrite!(s, "{}", value).unwrap();
+    s
+}
+
+#[test]
+fn sgr_string() {
+    let strings: [SGRString; 4] = [
+        "plain".into(),
+        "styled"
+            .to_sgr()
+            .color(RedFg)
+            .color(RgbBg(1, 2, 3))
+            .style(Bold)
+            .clean(CleanKind::Reverse),
+        "reset".to_sgr().style(Italic).clean(CleanKind::Reset),
+        "".to_sgr().color(ByteFg(208)).custom(53),
+    ];
+    for sgr in strings {
+        assert_eq!(ufmt(&sgr).as_bytes(), sgr.to_string().as_bytes());
+    }
+}
+#[test]
+fn discrete() {
+    for color in [BlackFg, ByteBg(100), RgbFg(255, 0, 10), DefaultBg] {
+        assert_eq!(ufmt(&color), color.to_string());
+    }
+    for style in [Reset, Bold, NotStrikethrough] {
+        assert_eq!(ufmt(&style), style.to_string());
+    }
+}
+#[test]
+fn writer() {
+    let sgr = "styled".to_sgr().color(GreenFg).style(Underline);
+    let mut ufmt_writer = SGRWriter::from(UfmtWriter(String::new()));
+    let mut std_writer = SGRWriter::from(String::new());
+    ufmt_writer.sgr(&sgr).unwrap();
+    std_writer.sgr(&sgr).unwrap();
+    ufmt_writer.styled(&BlueBg, " text").unwrap();
+    std_writer.styled(&BlueBg, " text").unwrap();
+    assert_eq!(ufmt_writer.internal(), std_writer.internal());
+}
+#[test]
+fn default_style() {
+    let sgr = "styled".to_sgr().color(GreenFg);
+    easy_sgr::context::with_default_style(Bold, || {
+        assert_eq!(ufmt(&sgr), sgr.to_string());
+        assert!(ufmt(&sgr).contains("\x1b[32;1m"));
+    });
+}
@@ -0,0 +1,17 @@
+use easy_sgr::windows::enable_vt;
+
+#[cfg(not(windows))]
+#[test]
+fn enable_vt_no_op() {
+    assert!(enable_vt().unwrap());
+}
+
+#[cfg(windows)]
+#[test]
+fn enable_vt_exercised() {
+    // the outcome depends on the console the tests are ran in
+    match enable_vt() {
+        Ok(_) => assert!(enable_vt().is_ok(), "enabling twice should not fail"),
+        Err(e) => assert!(e.raw_os_error().is_some()),
+    }
+}
@@ -0,0 +1,407 @@
+use std::sync::Arc;
+
+use easy_sgr::{
+    CapableWriter, CleanKind, Color::*, ColorKind, CountingWriter, EasySGR, EventWriter,
+    FilterWriter, FmtWriter, LineResetWriter, PrefixWriter, SGRWriter, SgrState, SharedError,
+    SharedWriter, StripReader, StripWriter, Style::*, StyleEvent, Tee, TeeError, TeePolicy,
+};
+
+#[test]
+fn counting() {
+    let mut writer = SGRWriter::from(CountingWriter::new(String::new()));
+    writer.sgr(&RedFg.style(Bold)).unwrap();
+    writer.write_inner("error").unwrap();
+    writer.inline_sgr(&Reset).unwrap();
+    writer.write_inner(": bad").unwrap();
+    assert_eq!(writer.get_ref().current_column(), 10);
+    assert_eq!(writer.get_ref().total_visible_chars(), 10);
+
+    writer.write_inner("\nnext \x1b[1mline\x1b[0m").unwrap();
+    assert_eq!(writer.get_ref().current_column(), 9);
+    assert_eq!(writer.get_ref().total_visible_chars(), 19);
+
+    writer.hyperlink("https://example.com", "link").unwrap();
+    assert_eq!(writer.get_ref().current_column(), 13);
+    assert_eq!(writer.get_ref().total_visible_chars(), 23);
+}
+#[test]
+fn counting_split() {
+    let mut writer = CountingWriter::new(String::new());
+    for part in ["ab\x1b", "[3", "1mcd\x1b]8;;url\x1b", "\\ef\r", "g"] {
+        writer.write(part).unwrap();
+    }
+    assert_eq!(writer.current_column(), 1);
+    assert_eq!(writer.total_visible_chars(), 7);
+    assert_eq!(writer.into_inner(), "ab\x1b[31mcd\x1b]8;;url\x1b\\ef\rg");
+}
+#[test]
+fn counting_wide() {
+    let mut writer = SGRWriter::from(CountingWriter::new(String::new()));
+    writer.write_inner("日本").unwrap();
+    let expected = if cfg!(feature = "width") { 4 } else { 2 };
+    assert_eq!(writer.get_ref().current_column(), expected);
+    assert_eq!(writer.internal(), "日本");
+}
+#[test]
+fn line_reset() {
+    let mut writer = SGRWriter::from(LineResetWriter::new(String::new()));
+    writer.sgr(&RedFg.style(Bold)).unwrap();
+    writer.write_inner("error\ndetail").unwrap();
+    writer.inline_sgr(&Reset).unwrap();
+    let written = writer.internal();
+    assert_eq!(written, "\x1b[31;1merror\x1b[0m\n\x1b[31;1mdetail\x1b[0m");
+    for line in written.lines() {
+        assert!(line.starts_with("\x1b[31;1m"));
+        assert!(line.ends_with("\x1b[0m"));
+    }
+}
+#[test]
+fn line_reset_split() {
+    let mut writer = LineResetWriter::new(String::new());
+    for part in ["one\x1b[3", "1m two", "\n", "\nthree\x1b[0m\n"] {
+        writer.write(part).unwrap();
+    }
+    assert!(writer.state().is_default());
+    assert_eq!(
+        writer.into_inner(),
+        "one\x1b[31m two\x1b[0m\n\n\x1b[31mthree\x1b[0m\n"
+    );
+
+    let mut writer = SGRWriter::from(LineResetWriter::new(String::new()));
+    writer.inline_sgr(&RedFg).unwrap();
+    writer.write_inner("a\n").unwrap();
+    writer.inline_sgr(&DefaultFg).unwrap();
+    writer.write_inner("b").unwrap();
+    assert_eq!(writer.internal(), "\x1b[31ma\x1b[0m\nb");
+}
+#[test]
+fn prefix() {
+    let prefix = "> ".color(BlueFg).clean(CleanKind::Reverse);
+    let mut writer = SGRWriter::from(PrefixWriter::new(String::new(), prefix));
+    writer.inline_sgr(&RedFg).unwrap();
+    writer.write_inner("a\nb").unwrap();
+    writer.inline_sgr(&DefaultFg).unwrap();
+    writer.write_inner("\nc\n").unwrap();
+    assert_eq!(writer.get_ref().lines(), 3);
+    assert_eq!(
+        writer.internal(),
+        "\x1b[31m\x1b[0;34m> \x1b[39;31ma\n\x1b[0;34m> \x1b[39;31mb\x1b[39m\n\x1b[34m> \x1b[39mc\n"
+    );
+}
+#[test]
+fn prefix_fn() {
+    let mut writer = PrefixWriter::with_prefix_fn(String::new(), |line| {
+        format!("{line:>2} ").style(Dim).clean(CleanKind::Reverse)
+    });
+    for part in ["x\x1b[1", "m\n", "\ny\x1b[0m"] {
+        writer.write(part).unwrap();
+    }
+    assert_eq!(
+        writer.into_inner(),
+        "\x1b[2m 1 \x1b[22mx\x1b[1m\n\x1b[0;2m 2 \x1b[22;1m\n\x1b[0;2m 3 \x1b[22;1my\x1b[0m"
+    );
+
+    let mut writer = PrefixWriter::new(String::new(), "#".color(RedFg));
+    writer.write("a\nb").unwrap();
+    assert_eq!(writer.into_inner(), "\x1b[31m#\x1b[0ma\n\x1b[31m#\x1b[0mb");
+}
+
+/// Fails every write after the first `n`
+#[derive(Debug, Default)]
+struct FailAfter(usize);
+impl CapableWriter for FailAfter {
+    type Writer = usize;
+    type Error = std::fmt::Error;
+    fn write(&mut self, _: &str) -> Result<(), Self::Error> {
+        self.0 = self.0.checked_sub(1).ok_or(std::fmt::Error)?;
+        Ok(())
+    }
+    fn get_writer(self) -> Self::Writer {
+        self.0
+    }
+}
+#[test]
+fn strip() {
+    let mut writer = SGRWriter::from(StripWriter::new(String::new()));
+    writer.sgr(&RedFg.style(Bold)).unwrap();
+    writer.write_inner("a\x1b[").unwrap();
+    writer.write_inner("1mb").unwrap();
+    writer.partial_sgr(&Italic).unwrap();
+    writer.hyperlink("https://example.com", "c").unwrap();
+    writer.write_inner("\n").unwrap();
+    assert_eq!(writer.internal(), "abc\n");
+}
+#[test]
+fn events() {
+    #[derive(Debug, PartialEq)]
+    enum Event {
+        Text(String),
+        Sgr(SgrState),
+        Escape(String),
+    }
+    let state = |codes: &[u8]| {
+        let mut state = SgrState::new();
+        state.apply(codes);
+        state
+    };
+    let mut events = Vec::new();
+    let mut writer = SGRWriter::from(EventWriter::new(|event| {
+        events.push(match event {
+            StyleEvent::Text(text) => Event::Text(text.to_owned()),
+            StyleEvent::SgrChanged(state) => Event::Sgr(state),
+            StyleEvent::Escape(seq) => Event::Escape(seq.to_owned()),
+        });
+    }));
+    writer.sgr(&RedFg.style(Bold)).unwrap();
+    writer.write_inner("a\x1b[").unwrap();
+    writer.write_inner("4mb\x1b[2Jc").unwrap();
+    writer.partial_sgr(&NotBold).unwrap();
+    writer.hyperlink("https://example.com", "d").unwrap();
+    writer.write_inner("\x1b[;32m").unwrap();
+    assert_eq!(writer.get_ref().state().foreground, ColorKind::Green);
+    assert!(!writer.get_ref().state().bold);
+    drop(writer);
+
+    assert_eq!(
+        events,
+        [
+            Event::Sgr(state(&[31, 1])),
+            Event::Text(String::from("a")),
+            Event::Sgr(state(&[31, 1, 4])),
+            Event::Text(String::from("b")),
+            Event::Escape(String::from("\x1b[2J")),
+            Event::Text(String::from("c")),
+            Event::Sgr(state(&[31, 4])),
+            Event::Escape(String::from("\x1b]8;;https://example.com\x1b\\")),
+            Event::Text(String::from("d")),
+            Event::Escape(String::from("\x1b]8;;\x1b\\")),
+            Event::Sgr(state(&[32])),
+        ]
+    );
+}
+/// A reader giving back each of its chunks through a separate read
+struct ChunkedReader<'a>(std::collections::VecDeque<&'a [u8]>);
+impl std::io::Read for ChunkedReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let Some(chunk) = self.0.front_mut() else {
+            return Ok(0);
+        };
+        let len = chunk.len().min(buf.len());
+        buf[..len].copy_from_slice(&chunk[..len]);
+        *chunk = &chunk[len..];
+        if chunk.is_empty() {
+            self.0.pop_front();
+        }
+        Ok(len)
+    }
+}
+#[test]
+fn strip_reader() {
+    use std::io::{BufRead, Read};
+
+    const SAMPLE: &[u8] =
+        b"\x1b[1;31merr\xff\x00\x1b[2K\x1b]8;;url\x1b\\x\x1b]8;;\x07\x1b[m\n\x1b(Bok";
+    let read = |chunks: Vec<&[u8]>, all: bool| {
+        let mut read = Vec::new();
+        let chunks = chunks.into_iter().filter(|chunk| !chunk.is_empty());
+        StripReader::new(ChunkedReader(chunks.collect()))
+            .strip_all(all)
+            .read_to_end(&mut read)
+            .unwrap();
+        read
+    };
+    let sgr_stripped = b"err\xff\x00\x1b[2K\x1b]8;;url\x1b\\x\x1b]8;;\x07\n\x1b(Bok";
+    let all_stripped = b"err\xff\x00x\nok";
+    for i in 0..=SAMPLE.len() {
+        for j in i..=SAMPLE.len() {
+            let chunks = || vec![&SAMPLE[..i], &SAMPLE[i..j], &SAMPLE[j..]];
+            assert_eq!(read(chunks(), false), sgr_stripped, "split at {i} & {j}");
+            assert_eq!(read(chunks(), true), all_stripped, "split at {i} & {j}");
+        }
+    }
+    let bytes: Vec<_> = SAMPLE.iter().map(std::slice::from_ref).collect();
+    assert_eq!(read(bytes.clone(), false), sgr_stripped);
+    assert_eq!(read(bytes, true), all_stripped);
+
+    // a chunk of only sequences doesn't end the stream
+    let mut reader = StripReader::new(ChunkedReader(
+        vec![&b"a"[..], b"\x1b[31m\x1b[1m", b"\x1b[0m", b"b"].into(),
+    ));
+    let mut buf = [0; 16];
+    assert_eq!(reader.read(&mut buf).unwrap(), 1);
+    assert_eq!(reader.read(&mut buf).unwrap(), 1);
+    assert_eq!(buf[0], b'b');
+    assert_eq!(reader.read(&mut buf).unwrap(), 0);
+
+    // a sequence unfinished at the end of the stream is dropped
+    assert_eq!(read(vec![b"text\x1b[3"], false), b"text");
+    assert_eq!(read(vec![b"text\x1b]8;;ur"], false), b"text");
+    assert_eq!(read(vec![b"text\x1b"], true), b"text");
+
+    let reader = StripReader::new(ChunkedReader(
+        vec![&b"\x1b[32mone\x1b[0m\ntw"[..], b"o\x1b[", b"0m\n"].into(),
+    ));
+    let lines: Vec<_> = reader.lines().map(Result::unwrap).collect();
+    assert_eq!(lines, ["one", "two"]);
+}
+#[test]
+fn inner_access() {
+    let mut writer = SGRWriter::from(StripWriter::new(FmtWriter(String::new())));
+    writer.styled(&RedFg, "error").unwrap();
+    writer.get_mut().get_mut().get_mut().push_str(": \x1b[1m");
+    writer.styled(&BlueFg, "info").unwrap();
+    assert_eq!(writer.get_ref().get_ref().get_ref(), "error: \x1b[1minfo");
+
+    let written: String = writer.into_inner().into_inner().into_inner();
+    assert_eq!(written, "error: \x1b[1minfo");
+
+    let mut writer = SGRWriter::from(CountingWriter::new(String::new()));
+    writer.write_inner("ab").unwrap();
+    writer.get_mut().get_mut().push_str("cd");
+    assert_eq!(writer.get_ref().total_visible_chars(), 2);
+    assert_eq!(writer.internal(), "abcd");
+}
+#[test]
+fn tee() {
+    let tee = Tee::new(String::new(), StripWriter::new(String::new()));
+    let mut writer = SGRWriter::from(tee);
+    writer.sgr(&RedFg.style(Bold)).unwrap();
+    writer.write_inner("error").unwrap();
+    writer.inline_sgr(&Reset).unwrap();
+    writer.write_inner(": \x1b[3mbad\x1b[23m\n").unwrap();
+
+    let (colored, plain) = writer.internal();
+    assert_eq!(colored, "\x1b[31;1merror\x1b[0m: \x1b[3mbad\x1b[23m\n");
+    assert_eq!(plain, "error: bad\n");
+}
+#[test]
+fn tee_errors() {
+    let mut fail_fast = Tee::new(FailAfter(0), String::new());
+    assert_eq!(fail_fast.write("a"), Err(TeeError::First(std::fmt::Error)));
+    assert_eq!(fail_fast.get_ref().1, "");
+
+    let mut cont = Tee::new(FailAfter(0), String::new()).policy(TeePolicy::Continue);
+    assert_eq!(cont.write("a"), Err(TeeError::First(std::fmt::Error)));
+    assert_eq!(cont.get_ref().1, "a");
+
+    let mut second = Tee::new(String::new(), FailAfter(1));
+    assert_eq!(second.write("a"), Ok(()));
+    assert_eq!(second.write("b"), Err(TeeError::Second(std::fmt::Error)));
+    assert_eq!(second.get_ref().0, "ab");
+
+    let mut both = Tee::new(FailAfter(0), FailAfter(0)).policy(TeePolicy::Continue);
+    let err = both.write("a").unwrap_err();
+    assert_eq!(err, TeeError::Both(std::fmt::Error, std::fmt::Error));
+    assert!(std::error::Error::source(&err).is_some());
+    assert_eq!(
+        err.to_string(),
+        "Writing to both legs failed: an error occurred when formatting an argument; \
+         an error occurred when formatting an argument"
+    );
+}
+#[test]
+fn filter_monochrome() {
+    let mut writer = SGRWriter::from(FilterWriter::monochrome(String::new()));
+    writer.sgr(&RedFg.style(Bold)).unwrap();
+    writer.write_inner("bold").unwrap();
+    writer.inline_sgr(&DefaultFg).unwrap();
+    writer.partial_sgr(&GreenBg.style(Italic)).unwrap();
+    assert_eq!(writer.internal(), "\x1b[1mbold3");
+
+    let mut writer = FilterWriter::monochrome(String::new());
+    writer
+        .write("a\x1b[31;1mb\x1b[38;2;1;2;3;4;48;5;208m\x1b[58;5;1;59;9mc\x1b[91;101m\x1b[m")
+        .unwrap();
+    assert_eq!(writer.into_inner(), "a\x1b[1mb\x1b[4m\x1b[9mc\x1b[0m");
+}
+#[test]
+fn filter_escapes() {
+    let mut writer = FilterWriter::monochrome(String::new());
+    for part in [
+        "\x1b]8;;url\x1b",
+        "\\link\x1b[3",
+        "1;1",
+        "m \x1b[2K",
+        "\x1b[3",
+        "1m.",
+    ] {
+        writer.write(part).unwrap();
+    }
+    assert_eq!(writer.into_inner(), "\x1b]8;;url\x1b\\link\x1b[1m \x1b[2K.");
+
+    let mut writer = SGRWriter::from(FilterWriter::new(String::new(), |code| code != 1));
+    writer.sgr(&Bold.color(RgbFg(1, 2, 3))).unwrap();
+    writer.write_escape("\x1b[?25l").unwrap();
+    assert_eq!(writer.internal(), "\x1b[38;2;1;2;3m\x1b[?25l");
+}
+#[test]
+fn shared() {
+    let shared = SharedWriter::new(String::new());
+    let handles: Vec<_> = [RedFg, GreenFg, BlueFg, MagentaFg]
+        .into_iter()
+        .enumerate()
+        .map(|(thread, color)| {
+            let shared = shared.clone();
+            std::thread::spawn(move || {
+                for line in 0..200 {
+                    let mut writer = shared.lock().unwrap();
+                    writer.inline_sgr(&color).unwrap();
+                    writer.write_inner(&format!("thread {thread} ")).unwrap();
+                    writer.styled(&Bold, &format!("line {line}")).unwrap();
+                    writer.reset().unwrap();
+                    writer.write_inner("\n").unwrap();
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    let written = shared.lock().unwrap().internal().clone();
+    let mut counts = [0; 4];
+    for line in written.lines() {
+        let thread: usize = line.split(' ').nth(1).unwrap().parse().unwrap();
+        let color = [31, 32, 34, 35][thread];
+        let n = counts[thread];
+        assert_eq!(
+            line,
+            format!("\x1b[{color}mthread {thread} \x1b[1mline {n}\x1b[0m\x1b[0m")
+        );
+        counts[thread] += 1;
+    }
+    assert_eq!(counts, [200; 4]);
+
+    // each call made through the writer itself is locked separately
+    let mut writer = SGRWriter::from(shared.clone());
+    writer.styled(&Italic, "per call").unwrap();
+    let mutex = writer.internal();
+    assert!(mutex.lock().unwrap().ends_with("\x1b[3mper call\x1b[0m"));
+    drop(mutex);
+
+    // the mutex shared by each clone
+    let mut shared = shared;
+    assert!(Arc::ptr_eq(shared.get_ref(), &shared.clone().into_inner()));
+    let mutex = Arc::get_mut(shared.get_mut()).expect("no other clones are left");
+    assert!(mutex.get_mut().unwrap().ends_with("per call\x1b[0m"));
+}
+#[test]
+fn shared_poisoned() {
+    let shared = SharedWriter::new(String::new());
+    let poisoner = shared.clone();
+    std::thread::spawn(move || {
+        let _writer = poisoner.lock().unwrap();
+        panic!("poisoning the writer");
+    })
+    .join()
+    .unwrap_err();
+
+    assert!(matches!(shared.lock(), Err(SharedError::Poisoned)));
+    let mut writer = SGRWriter::from(shared);
+    let err = writer.write_inner("text").unwrap_err();
+    assert_eq!(err, SharedError::Poisoned);
+    assert_eq!(err.to_string(), "The shared writer was poisoned");
+    assert_eq!(
+        easy_sgr::SGRError::from(err).to_string(),
+        "The shared writer was poisoned"
+    );
+}
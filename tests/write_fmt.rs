@@ -0,0 +1,72 @@
+//! Kept apart from the other tests as it replaces the global allocator
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt,
+};
+
+use easy_sgr::{CapableWriter, Color::*, SGRWriter};
+
+/// Counts the allocations made by the current thread
+struct CountingAlloc;
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|n| n.set(n.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Captures text into a fixed buffer
+struct Capture {
+    buf: [u8; 64],
+    len: usize,
+}
+impl CapableWriter for Capture {
+    type Writer = Self;
+    type Error = fmt::Error;
+    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+        let end = self.len + s.len();
+        self.buf.get_mut(self.len..end).ok_or(fmt::Error)?;
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+    fn get_writer(self) -> Self::Writer {
+        self
+    }
+}
+
+#[test]
+fn write_fmt_without_allocating() {
+    let mut writer = SGRWriter::from(Capture {
+        buf: [0; 64],
+        len: 0,
+    });
+    writer.inline_sgr(&RedFg).unwrap();
+    let before = ALLOCATIONS.with(Cell::get);
+    write!(writer, "{} + {:.2} = {}", 40, 2.5_f64, -42.5_f32).unwrap();
+    write!(writer, "{:>4}", 7_u8).unwrap();
+    assert_eq!(ALLOCATIONS.with(Cell::get), before);
+
+    let capture = writer.internal();
+    assert_eq!(
+        std::str::from_utf8(&capture.buf[..capture.len]).unwrap(),
+        "\x1b[31m40 + 2.50 = -42.5   7"
+    );
+}
+#[test]
+fn write_fmt_error() {
+    let mut writer = SGRWriter::from(Capture {
+        buf: [0; 64],
+        len: 60,
+    });
+    assert_eq!(write!(writer, "{}", 12345), Err(fmt::Error));
+}
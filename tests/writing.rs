@@ -1,6 +1,11 @@
 use std::error::Error;
 
-use easy_sgr::{Color, EasySGR, SGRWriter, Style};
+use easy_sgr::{
+    AnySgr, ArrayBuilder, CapableWriter, CapacityError, CleanKind, CodeSink, Color, ColorKind,
+    CountingWriter, DiscreteSGR, EasySGR, EraseMode, EscapeError, FmtWriter, InvalidEscape,
+    LineResetWriter, Plane, ResetStrategy, SGRBuilder, SGRString, SGRWriter, SequenceBuilder,
+    SgrParseError, StripWriter, Style,
+};
 
 #[test]
 fn sgr_writer() -> Result<(), Box<dyn Error>> {
@@ -18,7 +23,7 @@ fn sgr_builder() -> Result<(), Box<dyn Error>> {
     let mut w = SGRWriter::from(String::new());
 
     w.builder().write_to(&mut w)?;
-    assert_eq!("", w.writer.0);
+    assert_eq!("", *w.get_ref());
 
     let mut builder = w.builder();
     builder.write_code(0);
@@ -32,3 +37,1167 @@ fn sgr_builder() -> Result<(), Box<dyn Error>> {
     assert_eq!("\x1b[0;1;2;3;4;5m", w.internal());
     Ok(())
 }
+
+#[test]
+fn sgr_builder_spill() -> Result<(), Box<dyn Error>> {
+    let mut builder = SGRBuilder::default();
+    builder.write_codes(&[38, 2, 1, 2, 3, 48, 2, 4, 5, 6, 1, 3, 4, 7, 8]);
+    builder.write_code(9);
+    assert_eq!(builder.len(), SGRBuilder::INLINE);
+    assert!(!builder.spilled());
+
+    builder.chain_code(5).chain_codes(&[38, 5, 208]);
+    assert!(builder.spilled());
+    assert_eq!(
+        builder.codes(),
+        [38, 2, 1, 2, 3, 48, 2, 4, 5, 6, 1, 3, 4, 7, 8, 9, 5, 38, 5, 208]
+    );
+
+    let mut w = SGRWriter::from(String::new());
+    builder.write_to(&mut w)?;
+    assert_eq!(
+        "\x1b[38;2;1;2;3;48;2;4;5;6;1;3;4;7;8;9;5;38;5;208m",
+        w.internal()
+    );
+
+    builder.clear();
+    assert!(builder.is_empty() && builder.spilled());
+    builder.write_code(1);
+    assert_eq!(Vec::from(builder), [1]);
+
+    let codes: Vec<u8> = (0..40).collect();
+    assert_eq!(SGRBuilder::from(codes.clone()).codes(), codes);
+    assert_eq!(
+        SGRBuilder::from(&codes[..3]),
+        SGRBuilder::from(vec![0, 1, 2])
+    );
+    // equality ignores how codes are stored & what was cleared
+    let mut cleared = SGRBuilder::from(&[4, 5][..]);
+    cleared.clear();
+    cleared.write_code(1);
+    assert_eq!(cleared, SGRBuilder::from(&[1][..]));
+    let mut spilled = SGRBuilder::from(codes);
+    spilled.clear();
+    spilled.write_code(1);
+    assert_eq!(spilled, cleared);
+    Ok(())
+}
+#[test]
+fn array_builder() -> Result<(), Box<dyn Error>> {
+    let mut builder = ArrayBuilder::<6>::new();
+    assert!(builder.is_empty());
+    builder.write_code(1)?;
+    builder.write_codes(&[38, 2, 1, 2, 3])?;
+    assert_eq!(builder.len(), 6);
+    assert_eq!(builder.write_code(4), Err(CapacityError));
+    assert_eq!(builder.codes(), [1, 38, 2, 1, 2, 3]);
+    assert!(!builder.overflowed());
+
+    builder.clear();
+    builder.write_codes(&[1, 3, 4])?;
+    // none of the codes are written when they don't all fit
+    assert_eq!(builder.write_codes(&[48, 5, 208, 9]), Err(CapacityError));
+    assert_eq!(builder.codes(), [1, 3, 4]);
+    Color::ByteBg(208).write(&mut builder);
+    Color::RgbFg(1, 2, 3).write(&mut builder);
+    Style::Bold.write(&mut builder);
+    assert!(builder.overflowed());
+    assert_eq!(builder.codes(), [1, 3, 4, 48, 5, 208]);
+
+    let mut w = SGRWriter::from(String::new());
+    builder.write_to(&mut w)?;
+    w.write_inner("|")?;
+    builder.write_partial(&mut w)?;
+    assert_eq!(w.internal(), "\x1b[1;3;4;48;5;208m|1;3;4;48;5;208");
+    assert_eq!(
+        format!("{builder:?}"),
+        "SGR[Bold, Italic, Underline, ByteBg(208)]"
+    );
+
+    builder.clear();
+    assert!(builder.is_empty() && !builder.overflowed());
+    assert_eq!(builder.to_string(), "");
+    Ok(())
+}
+#[test]
+fn array_builder_matches() -> Result<(), Box<dyn Error>> {
+    let sgrs: Vec<AnySgr> = vec![
+        Style::Bold.into(),
+        Color::RgbFg(255, 128, 0).into(),
+        Color::ByteBg(17).into(),
+        Style::Strikethrough.into(),
+        Color::DefaultFg.into(),
+        53.into(),
+    ];
+    for end in 0..=sgrs.len() {
+        let mut vec = SGRBuilder::default();
+        let mut array = ArrayBuilder::<{ SGRBuilder::INLINE }>::default();
+        for sgr in &sgrs[..end] {
+            sgr.write(&mut vec);
+            sgr.write(&mut array);
+        }
+        (Color::RedFg, Style::Italic).write(&mut vec);
+        (Color::RedFg, Style::Italic).write(&mut array);
+        assert!(!array.overflowed());
+        assert_eq!(array.codes(), vec.codes());
+        assert_eq!(array.to_string(), vec.to_string());
+
+        let (mut vec_out, mut array_out) = (Vec::new(), Vec::new());
+        vec.write_to(&mut vec_out)?;
+        array.write_to(&mut array_out)?;
+        vec.write_partial(&mut vec_out)?;
+        array.write_partial(&mut array_out)?;
+        assert_eq!(array_out, vec_out);
+    }
+    Ok(())
+}
+#[test]
+fn typed_codes() {
+    use Color::*;
+    use Style::*;
+
+    let push = |f: &dyn Fn(&mut SGRBuilder)| {
+        let mut builder = SGRBuilder::default();
+        f(&mut builder);
+        builder.codes().to_vec()
+    };
+    assert_eq!(push(&|b| b.write_style(&Bold)), [1]);
+    assert_eq!(push(&|b| b.write_style(&NotItalic)), [23]);
+    assert_eq!(push(&|b| b.write_style(&Reset)), [0]);
+    assert_eq!(push(&|b| b.clear_style(&Bold)), [22]);
+    assert_eq!(push(&|b| b.clear_style(&Strikethrough)), [29]);
+    assert_eq!(push(&|b| b.clear_style(&NotUnderline)), [4]);
+    for (color, fg, bg, underline) in [
+        (RedFg, vec![31], vec![41], vec![58, 5, 1]),
+        (WhiteBg, vec![37], vec![47], vec![58, 5, 7]),
+        (
+            ByteFg(208),
+            vec![38, 5, 208],
+            vec![48, 5, 208],
+            vec![58, 5, 208],
+        ),
+        (
+            ByteBg(17),
+            vec![38, 5, 17],
+            vec![48, 5, 17],
+            vec![58, 5, 17],
+        ),
+        (
+            RgbFg(1, 2, 3),
+            vec![38, 2, 1, 2, 3],
+            vec![48, 2, 1, 2, 3],
+            vec![58, 2, 1, 2, 3],
+        ),
+        (
+            RgbBg(4, 5, 6),
+            vec![38, 2, 4, 5, 6],
+            vec![48, 2, 4, 5, 6],
+            vec![58, 2, 4, 5, 6],
+        ),
+        (DefaultBg, vec![39], vec![49], vec![59]),
+    ] {
+        assert_eq!(push(&|b| b.write_fg(&color)), fg, "{color:?}");
+        assert_eq!(push(&|b| b.write_bg(&color)), bg, "{color:?}");
+        assert_eq!(
+            push(&|b| b.write_underline_color(&color)),
+            underline,
+            "{color:?}"
+        );
+        assert_eq!(
+            push(&|b| b.write_color(&color, Plane::Background)),
+            bg,
+            "{color:?}"
+        );
+    }
+
+    let mut array = ArrayBuilder::<8>::new();
+    array.write_style(&Bold);
+    array.write_fg(&RgbBg(1, 2, 3));
+    array.write_bg(&ByteFg(9));
+    assert!(array.overflowed());
+    assert_eq!(array.codes(), [1, 38, 2, 1, 2, 3]);
+
+    let sgr = RgbFg(1, 2, 3)
+        .color(ByteBg(17))
+        .style(Bold)
+        .style(NotItalic)
+        .custom(53);
+    let mut builder = SGRBuilder::default();
+    sgr.place_all(&mut builder);
+    assert_eq!(builder.codes(), [38, 2, 1, 2, 3, 48, 5, 17, 1, 23, 53]);
+}
+#[test]
+fn sgr_builder_parse() -> Result<(), Box<dyn Error>> {
+    for seq in [
+        "\x1b[0m",
+        "\x1b[1;31m",
+        "\x1b[38;5;196;48;2;1;2;3m",
+        "\x1b[1;2;3;4;5;7;8;9;22;23;24;25;27;28;29;39;49m",
+    ] {
+        let mut builder = SGRBuilder::parse(seq)?;
+        let mut w = SGRWriter::from(String::new());
+        builder.write_to(&mut w)?;
+        assert_eq!(w.internal(), seq);
+    }
+    assert_eq!(SGRBuilder::parse("\x1b[m")?.codes(), [0]);
+    assert_eq!(SGRBuilder::parse("\x1b[;1m")?.codes(), [0, 1]);
+    assert_eq!(SGRBuilder::parse("\x1b[38:5:1m")?.codes(), [38, 5, 1]);
+    // the color space id is dropped, with or without one
+    assert_eq!(
+        SGRBuilder::parse("\x1b[1;38:2::1:2:3;48:2:4:5:6m")?.codes(),
+        [1, 38, 2, 1, 2, 3, 48, 2, 4, 5, 6]
+    );
+    assert_eq!(
+        SGRBuilder::parse("\x1b[58:2:0:7:8:9m")?.codes(),
+        [58, 2, 7, 8, 9]
+    );
+    assert_eq!(SGRBuilder::parse("\x1b[38;5;38m")?.codes(), [38, 5, 38]);
+
+    let mut builder = SGRBuilder::parse("\x1b[1;4;31m")?;
+    assert_eq!(builder.remove(1), 4);
+    builder.write_code(44);
+    assert_eq!(builder.to_string(), "\x1b[1;31;44m");
+    let mut spilled = SGRBuilder::from((0..20).collect::<Vec<_>>());
+    assert_eq!(spilled.remove(19), 19);
+    assert_eq!(spilled.len(), 19);
+
+    for (seq, err) in [
+        ("", SgrParseError::Escape(InvalidEscape::MissingEscape)),
+        ("[1m", SgrParseError::Escape(InvalidEscape::MissingEscape)),
+        ("\x1b[1", SgrParseError::Escape(InvalidEscape::Unterminated)),
+        (
+            "\x1b[1mtext",
+            SgrParseError::Escape(InvalidEscape::Trailing(4)),
+        ),
+        (
+            "\x1b[1m\x1b[2m",
+            SgrParseError::Escape(InvalidEscape::Trailing(4)),
+        ),
+        ("\x1b[2J", SgrParseError::NotSgr),
+        ("\x1b]0;title\x07", SgrParseError::NotSgr),
+        ("\x1b(B", SgrParseError::NotSgr),
+        ("\x1b[1;256m", SgrParseError::InvalidParam(4)),
+        ("\x1b[?1m", SgrParseError::InvalidParam(2)),
+        ("\x1b[1 m", SgrParseError::InvalidParam(2)),
+        // subparameters other than an extended color
+        ("\x1b[1;4:3m", SgrParseError::InvalidParam(4)),
+        ("\x1b[38:5m", SgrParseError::InvalidParam(2)),
+        ("\x1b[38:2:1:2m", SgrParseError::InvalidParam(2)),
+        // extended colors missing their parameters
+        ("\x1b[38;5m", SgrParseError::InvalidParam(2)),
+        ("\x1b[1;48;2;1;2m", SgrParseError::InvalidParam(4)),
+        ("\x1b[58m", SgrParseError::InvalidParam(2)),
+        ("\x1b[38;7;1m", SgrParseError::InvalidParam(2)),
+    ] {
+        assert_eq!(SGRBuilder::parse(seq), Err(err), "{seq:?}");
+    }
+
+    let builders = SGRBuilder::parse_all(
+        "\x1b[1mbold\x1b[2K\x1b[22;38;5;1m red\x1b[?1m\x1b[38:2::1:2:3m\x1b[38;5m\x1b[0m",
+    );
+    assert_eq!(
+        builders.iter().map(SGRBuilder::codes).collect::<Vec<_>>(),
+        [&[1][..], &[22, 38, 5, 1], &[38, 2, 1, 2, 3], &[0]]
+    );
+    assert!(SGRBuilder::parse_all("plain text").is_empty());
+    Ok(())
+}
+#[test]
+fn sgr_builder_display() {
+    let check = |codes: &[u8], display: &str, debug: &str| {
+        let builder = SGRBuilder::from(codes);
+        assert_eq!(builder.to_string(), display);
+        assert_eq!(builder.to_sequence_string(), display);
+        assert_eq!(format!("{builder:?}"), debug);
+    };
+    check(&[], "", "SGR[]");
+    check(&[1, 31], "\x1b[1;31m", "SGR[Bold, RedFg]");
+    check(
+        &[0, 22, 49],
+        "\x1b[0;22;49m",
+        "SGR[Reset, NotBold, DefaultBg]",
+    );
+    check(
+        &[38, 5, 208, 48, 2, 1, 2, 3],
+        "\x1b[38;5;208;48;2;1;2;3m",
+        "SGR[ByteFg(208), RgbBg(1, 2, 3)]",
+    );
+    check(
+        &[58, 5, 1, 58, 2, 1, 2, 3, 59],
+        "\x1b[58;5;1;58;2;1;2;3;59m",
+        "SGR[Underline(ByteFg(1)), Underline(RgbFg(1, 2, 3)), Underline(DefaultFg)]",
+    );
+    check(
+        &[91, 97, 100, 107],
+        "\x1b[91;97;100;107m",
+        "SGR[Bright(RedFg), Bright(WhiteFg), Bright(BlackBg), Bright(WhiteBg)]",
+    );
+    // codes without a name, & incomplete colors, are written as numbers
+    check(&[6, 99, 38, 5], "\x1b[6;99;38;5m", "SGR[6, 99, 38, 5]");
+    check(&[58, 2, 1, 2], "\x1b[58;2;1;2m", "SGR[58, 2, 1, 2]");
+}
+
+#[test]
+fn hyperlink() -> Result<(), Box<dyn Error>> {
+    let mut w = SGRWriter::from(String::new());
+    w.hyperlink("https://example.com", "example")?;
+
+    assert_eq!(
+        "\x1b]8;;https://example.com\x1b\\example\x1b]8;;\x1b\\",
+        w.internal()
+    );
+    Ok(())
+}
+
+#[test]
+fn hyperlink_styled() -> Result<(), Box<dyn Error>> {
+    let mut w = SGRWriter::from(String::new());
+    w.hyperlink_styled(
+        "https://example.com",
+        &"example".color(Color::RedFg).clean(CleanKind::Reverse),
+    )?;
+
+    assert_eq!(
+        "\x1b]8;;https://example.com\x1b\\\x1b[31mexample\x1b[39m\x1b]8;;\x1b\\",
+        w.internal()
+    );
+    Ok(())
+}
+
+#[test]
+fn hyperlink_id() -> Result<(), Box<dyn Error>> {
+    let mut w = SGRWriter::from(String::new());
+    w.open_hyperlink("https://example.com", Some("cell"))?;
+    w.write_inner("first")?;
+    w.close_hyperlink()?;
+    w.open_hyperlink("https://example.com", Some("cell"))?;
+    w.write_inner("second")?;
+    w.close_hyperlink()?;
+
+    assert_eq!(
+        "\x1b]8;id=cell;https://example.com\x1b\\first\x1b]8;;\x1b\\\
+        \x1b]8;id=cell;https://example.com\x1b\\second\x1b]8;;\x1b\\",
+        w.internal()
+    );
+    Ok(())
+}
+
+#[test]
+fn hyperlink_escapes() -> Result<(), Box<dyn Error>> {
+    let mut w = SGRWriter::from(String::new());
+    w.open_hyperlink("https://example.com/a b\x1b\u{e9};c", Some("a:b;c"))?;
+
+    assert_eq!(
+        "\x1b]8;id=a%3Ab%3Bc;https://example.com/a b%1B%C3%A9;c\x1b\\",
+        w.internal()
+    );
+    Ok(())
+}
+
+#[test]
+fn control() -> Result<(), Box<dyn Error>> {
+    type Method = fn(&mut SGRWriter<String>) -> Result<(), std::fmt::Error>;
+    let table: [(&str, Method); 18] = [
+        ("", |w| w.cursor_up(0)),
+        ("\x1b[A", |w| w.cursor_up(1)),
+        ("\x1b[2A", |w| w.cursor_up(2)),
+        ("\x1b[65535A", |w| w.cursor_up(u16::MAX)),
+        ("\x1b[G", |w| w.cursor_to_column(0)),
+        ("\x1b[G", |w| w.cursor_to_column(1)),
+        ("\x1b[10G", |w| w.cursor_to_column(10)),
+        ("\x1b[K", |w| w.erase_line(EraseMode::ToEnd)),
+        ("\x1b[1K", |w| w.erase_line(EraseMode::ToStart)),
+        ("\x1b[2K", |w| w.erase_line(EraseMode::All)),
+        ("\x1b[J", |w| w.erase_display(EraseMode::ToEnd)),
+        ("\x1b[1J", |w| w.erase_display(EraseMode::ToStart)),
+        ("\x1b[2J", |w| w.erase_display(EraseMode::All)),
+        ("\x1b7", SGRWriter::save_cursor),
+        ("\x1b8", SGRWriter::restore_cursor),
+        ("\x1b[?25l", SGRWriter::hide_cursor),
+        ("\x1b[?25h", SGRWriter::show_cursor),
+        ("\x1b[100A", |w| w.cursor_up(100)),
+    ];
+    for (correct, method) in table {
+        let mut w = SGRWriter::from(String::new());
+        method(&mut w)?;
+        assert_eq!(correct, w.internal());
+    }
+    Ok(())
+}
+
+#[test]
+fn with_sgr() -> Result<(), Box<dyn Error>> {
+    let red = Color::RedFg.clean(CleanKind::Reverse);
+    let mut w = SGRWriter::from(String::new());
+    let n = w.with_sgr(&red, |w| w.write_inner("red").map(|()| 3))?;
+
+    assert_eq!(n, 3);
+    assert_eq!("\x1b[31mred\x1b[39m", w.internal());
+    Ok(())
+}
+
+#[test]
+fn with_sgr_error() {
+    let red = Color::RedFg.clean(CleanKind::Reverse);
+    let mut w = SGRWriter::from(String::new());
+    let result: Result<(), _> = w.with_sgr(&red, |w| {
+        w.write_inner("red")?;
+        Err(std::fmt::Error)
+    });
+
+    assert!(result.is_err());
+    assert_eq!("\x1b[31mred\x1b[39m", w.internal());
+}
+
+#[test]
+fn with_sgr_nested() -> Result<(), Box<dyn Error>> {
+    let red = Color::RedFg.clean(CleanKind::Reverse);
+    let bold = Style::Bold.clean(CleanKind::Reverse);
+    let mut w = SGRWriter::from(String::new());
+    w.with_sgr(&red, |w| {
+        w.write_inner("a")?;
+        w.with_sgr(&bold, |w| w.write_inner("b"))?;
+        w.write_inner("c")
+    })?;
+    w.with_sgr_clean(&Style::Italic, &Style::NotItalic, |w| w.write_inner("d"))?;
+
+    assert_eq!(
+        "\x1b[31ma\x1b[1mb\x1b[22mc\x1b[39m\x1b[3md\x1b[23m",
+        w.internal()
+    );
+    Ok(())
+}
+
+#[test]
+fn styled() -> Result<(), Box<dyn Error>> {
+    let mut w = SGRWriter::from(String::new());
+    w.styled(&Color::RedFg.style(Style::Bold), "a")?;
+    w.sgr_string(&"b".color(Color::BlueFg).clean(CleanKind::Reverse))?;
+    w.sgr_string(&"c".into())?;
+
+    assert_eq!("\x1b[31;1ma\x1b[0m\x1b[34mb\x1b[39mc", w.internal());
+    Ok(())
+}
+
+#[test]
+fn mut_ref_writer() -> Result<(), Box<dyn Error>> {
+    fn label(writer: impl CapableWriter<Error = std::fmt::Error>, text: &str) -> std::fmt::Result {
+        let mut writer = SGRWriter::from(writer);
+        writer.styled(&Color::GreenFg, text)
+    }
+    let mut w = SGRWriter::from(String::new());
+    label(&mut w, "a")?;
+    label(&mut w, "b")?;
+    label(w.get_mut(), "c")?;
+
+    assert_eq!(
+        "\x1b[32ma\x1b[0m\x1b[32mb\x1b[0m\x1b[32mc\x1b[0m",
+        w.internal()
+    );
+    Ok(())
+}
+
+#[test]
+fn bare_sinks() -> Result<(), Box<dyn Error>> {
+    let mut w = SGRWriter::from(String::new());
+    w.styled(&Color::RedFg, "red")?;
+    let string: String = w.internal();
+    assert_eq!("\x1b[31mred\x1b[0m", string);
+
+    let mut w = SGRWriter::from(Vec::new());
+    w.styled(&Color::RedFg, "red")?;
+    assert_eq!(b"\x1b[31mred\x1b[0m", w.internal().as_slice());
+
+    let mut string = String::from("> ");
+    SGRWriter::from(&mut string).styled(&Style::Bold, "bold")?;
+    assert_eq!("> \x1b[1mbold\x1b[0m", string);
+    Ok(())
+}
+
+#[test]
+fn sink_constructors() -> Result<(), Box<dyn Error>> {
+    // each compiles without annotating the writer's type
+    let _ = SGRWriter::from(String::new());
+    let _ = SGRWriter::from(Vec::new());
+    let _ = SGRWriter::from(std::io::stdout());
+    let file = std::fs::File::open(file!())?;
+    let _ = SGRWriter::from(&file);
+    let _ = SGRWriter::from(file);
+    // & through each constructor taking them
+    let _ = SGRWriter::io(Vec::new());
+    let _ = SGRWriter::io(std::io::stdout());
+    let _ = SGRWriter::io(std::fs::File::open(file!())?);
+    let _ = SGRWriter::fmt(String::new());
+    let _ = SGRWriter::capable(String::new());
+    let _ = SGRWriter::capable(Vec::new());
+
+    let mut w = SGRWriter::io(std::io::Cursor::new(Vec::new()));
+    w.styled(&Color::RedFg, "io")?;
+    assert_eq!(b"\x1b[31mio\x1b[0m", w.internal().into_inner().as_slice());
+
+    let mut w = SGRWriter::fmt(String::new());
+    w.styled(&Color::RedFg, "fmt")?;
+    assert_eq!("\x1b[31mfmt\x1b[0m", w.internal());
+
+    let mut w = SGRWriter::capable(Vec::new());
+    w.styled(&Color::RedFg, "capable")?;
+    assert_eq!(b"\x1b[31mcapable\x1b[0m", w.internal().as_slice());
+    Ok(())
+}
+#[test]
+fn sgr_guard() -> Result<(), Box<dyn Error>> {
+    let red = Color::RedFg.clean(CleanKind::Reverse);
+    let mut w = SGRWriter::from(String::new());
+    {
+        let mut guard = w.sgr_guard(&red)?;
+        guard.write_inner("a")?;
+    }
+    w.sgr_guard(&Style::Bold.into())?.with_reset().finish()?;
+    drop(w.sgr_guard(&Style::Italic.into())?);
+
+    assert_eq!(
+        "\x1b[31ma\x1b[39m\x1b[1m\x1b[0m\x1b[3m\x1b[0m",
+        w.internal()
+    );
+    Ok(())
+}
+
+#[test]
+fn sgr_guard_early_return() {
+    fn inner(w: &mut SGRWriter<String>) -> std::fmt::Result {
+        let mut guard = w.sgr_guard(&Color::RedFg.clean(CleanKind::Reverse))?;
+        guard.write_inner("a")?;
+        Err(std::fmt::Error)
+    }
+    let mut w = SGRWriter::from(String::new());
+    assert!(inner(&mut w).is_err());
+    assert_eq!("\x1b[31ma\x1b[39m", w.internal());
+}
+
+/// Fails every write once `fail` is set
+#[derive(Debug, Default)]
+struct Flaky {
+    out: String,
+    fail: bool,
+}
+impl CapableWriter for Flaky {
+    type Writer = String;
+    type Error = std::fmt::Error;
+    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+        if self.fail {
+            return Err(std::fmt::Error);
+        }
+        self.out.push_str(s);
+        Ok(())
+    }
+    fn get_writer(self) -> Self::Writer {
+        self.out
+    }
+}
+
+#[test]
+fn sgr_guard_errors() -> Result<(), Box<dyn Error>> {
+    let red = Color::RedFg.clean(CleanKind::Reverse);
+    let mut w = SGRWriter::from(Flaky::default());
+    let mut guard = w.sgr_guard(&red)?;
+    guard.get_mut().fail = true;
+    assert!(guard.finish().is_err());
+
+    let mut errors = 0;
+    w.get_mut().fail = false;
+    {
+        let mut guard = w.sgr_guard(&red)?.on_error(|_| errors += 1);
+        guard.get_mut().fail = true;
+    }
+    assert_eq!(errors, 1);
+    assert_eq!("\x1b[31m\x1b[31m", w.internal());
+    Ok(())
+}
+
+#[test]
+fn partial_scope() -> Result<(), Box<dyn Error>> {
+    let mut w = SGRWriter::from(String::new());
+    w.partial_scope()
+        .partial_sgr(&Color::RedFg.style(Style::Bold))
+        .partial_sgr(&Style::Italic)
+        .partial_sgr(&Color::BlueBg.to_sgr())
+        .finish()?;
+    w.write_inner("|")?;
+    w.partial_scope();
+    w.write_inner("|")?;
+    {
+        let mut scope = w.partial_scope();
+        scope.partial_sgr(&Style::Reset);
+    }
+
+    assert_eq!("\x1b[31;1;3;44m||\x1b[0m", w.internal());
+    Ok(())
+}
+
+#[test]
+fn set_enabled() -> Result<(), Box<dyn Error>> {
+    let mut w = SGRWriter::from(String::new());
+    assert!(w.is_enabled());
+    w.styled(&Color::RedFg, "a")?;
+
+    w.set_enabled(false);
+    assert!(!w.is_enabled());
+    w.styled(&Color::RedFg, "b")?;
+    w.hyperlink("https://example.com", "c")?;
+    w.cursor_up(1)?;
+    w.partial_scope().partial_sgr(&Style::Bold);
+
+    w.set_enabled(true);
+    w.styled(&Color::RedFg, "d")?;
+
+    assert_eq!("\x1b[31ma\x1b[0mbc\x1b[31md\x1b[0m", w.internal());
+    Ok(())
+}
+
+#[test]
+fn set_enabled_nested() -> Result<(), Box<dyn Error>> {
+    let mut inner = SGRWriter::from(String::new());
+    inner.set_enabled(false);
+    let mut w = SGRWriter::capable(inner);
+    w.styled(&Color::RedFg, "x")?;
+    w.write_escape("\x1b[?25l")?;
+    w.partial_scope().partial_sgr(&Style::Bold);
+    // plain text is written as is
+    w.write_inner("\x1b[1m")?;
+    assert_eq!("x\x1b[1m", w.internal());
+
+    let mut inner = SGRWriter::from(String::new());
+    inner.set_enabled(false);
+    let mut w = SGRWriter::from(LineResetWriter::new(inner));
+    w.styled(&Color::RedFg, "a\nb")?;
+    assert_eq!("a\nb", w.internal());
+    Ok(())
+}
+#[test]
+fn single_write_per_sequence() -> Result<(), Box<dyn Error>> {
+    /// Counts the calls to `write`
+    #[derive(Default)]
+    struct Calls(usize, String);
+    impl CapableWriter for Calls {
+        type Writer = (usize, String);
+        type Error = std::fmt::Error;
+        fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+            self.0 += 1;
+            self.1.push_str(s);
+            Ok(())
+        }
+        fn get_writer(self) -> Self::Writer {
+            (self.0, self.1)
+        }
+    }
+    let mut w = SGRWriter::from(Calls::default());
+    w.builder()
+        .chain_codes(&[0, 9, 10, 38, 5, 208, 255])
+        .write_to(&mut w)?;
+    w.builder().chain_codes(&[1, 31]).write_partial(&mut w)?;
+    w.builder().write_to(&mut w)?;
+
+    assert_eq!(
+        (2, String::from("\x1b[0;9;10;38;5;208;255m1;31")),
+        w.internal()
+    );
+    Ok(())
+}
+#[test]
+fn flush() -> Result<(), Box<dyn Error>> {
+    let mut w = SGRWriter::from(std::io::BufWriter::new(Vec::new()));
+    w.styled(&Color::RedFg, "text")?;
+    assert!(w.get_ref().get_ref().get_ref().is_empty());
+    w.flush()?;
+    assert_eq!(w.get_ref().get_ref().get_ref(), b"\x1b[31mtext\x1b[0m");
+
+    let buffered = easy_sgr::IoWriter(std::io::BufWriter::new(Vec::new()));
+    let mut w = SGRWriter::from(CountingWriter::new(buffered));
+    w.styled(&Style::Bold, "text")?;
+    CapableWriter::flush(&mut &mut w)?;
+    let written = w.into_inner().into_inner().into_inner().into_inner()?;
+    assert_eq!(written, b"\x1b[1mtext\x1b[0m");
+    Ok(())
+}
+#[test]
+fn write_fmt() -> Result<(), Box<dyn Error>> {
+    let mut w = SGRWriter::from(String::new());
+    w.sgr(&Style::Bold)?;
+    write!(w, "{}/{}", 1, 2.5)?;
+    assert_eq!(w.internal(), "\x1b[1m1/2.5");
+
+    let mut w = SGRWriter::from(CountingWriter::new(Vec::new()));
+    writeln!(w, "{:>3}", 'x')?;
+    assert_eq!(w.get_ref().total_visible_chars(), 3);
+    Ok(())
+}
+/// Fails to format while writing succeeds
+struct BadDisplay;
+impl std::fmt::Display for BadDisplay {
+    fn fmt(&self, _: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Err(std::fmt::Error)
+    }
+}
+#[test]
+#[should_panic = "a formatting trait implementation returned an error"]
+fn write_fmt_bad_display() {
+    let mut w = SGRWriter::from(CountingWriter::new(Vec::new()));
+    let _ = write!(w, "{BadDisplay}");
+}
+#[test]
+fn reset() -> Result<(), Box<dyn Error>> {
+    let mut w = SGRWriter::from(String::new());
+    w.reset()?;
+    w.reset_colors()?;
+    assert_eq!(w.internal(), "\x1b[0m\x1b[39;49m");
+
+    let mut w = SGRWriter::from(LineResetWriter::new(String::new()));
+    w.sgr(&Color::RedFg.color(Color::BlueBg).style(Style::Bold))?;
+    w.reset_colors()?;
+    assert_eq!(w.get_ref().state().foreground, ColorKind::None);
+    assert!(w.get_ref().state().bold);
+    w.reset()?;
+    assert!(w.get_ref().state().is_default());
+    Ok(())
+}
+#[test]
+fn write_escape() -> Result<(), Box<dyn Error>> {
+    let mut w = SGRWriter::from(String::new());
+    w.write_escape("\x1b[?1049h")?;
+    w.write_escape("\x1b]0;title\x07")?;
+    w.write_escape("\x1b]8;;https://example.com\x1b\\")?;
+    w.write_escape("\x1b(B")?;
+    w.write_escape("\x1b7")?;
+    assert_eq!(
+        w.internal(),
+        "\x1b[?1049h\x1b]0;title\x07\x1b]8;;https://example.com\x1b\\\x1b(B\x1b7"
+    );
+
+    let mut w = SGRWriter::from(String::new());
+    for (seq, invalid) in [
+        ("[31m", InvalidEscape::MissingEscape),
+        ("\x1b", InvalidEscape::Unterminated),
+        ("\x1b[?25", InvalidEscape::Unterminated),
+        ("\x1b]0;title", InvalidEscape::Unterminated),
+        ("\x1b]0;title\x1b", InvalidEscape::Unterminated),
+        ("\x1b[3\n1m", InvalidEscape::InvalidByte(3)),
+        ("\x1b]0;ti\rtle\x07", InvalidEscape::InvalidByte(6)),
+        ("\x1b[31mtext", InvalidEscape::Trailing(5)),
+    ] {
+        match w.write_escape(seq) {
+            Err(EscapeError::Invalid(e)) => assert_eq!(e, invalid, "{seq:?}"),
+            other => panic!("{seq:?} gave {other:?}"),
+        }
+    }
+    w.write_escape_unchecked("\x1b[?25")?;
+    assert_eq!(w.internal(), "\x1b[?25");
+    Ok(())
+}
+#[test]
+fn write_escape_wrapped() -> Result<(), Box<dyn Error>> {
+    let mut w = SGRWriter::from(StripWriter::new(String::new()));
+    w.write_inner("a")?;
+    w.write_escape("\x1b[?25l")?;
+    w.write_escape_unchecked("\x1b[?25")?;
+    w.write_inner("b")?;
+    assert_eq!(w.internal(), "ab");
+
+    let mut w = SGRWriter::from(LineResetWriter::new(String::new()));
+    w.write_escape("\x1b[1m")?;
+    assert!(w.get_ref().state().is_default());
+    w.write_inner("a\nb")?;
+    assert_eq!(w.internal(), "\x1b[1ma\nb");
+    Ok(())
+}
+#[test]
+fn raw_affixes() -> Result<(), Box<dyn Error>> {
+    const OPEN: &str = "\x1b]8;;https://example.com\x1b\\";
+    const CLOSE: &str = "\x1b]8;;\x1b\\";
+    let link = Color::BlueFg
+        .style(Style::Underline)
+        .text("link")
+        .clean(CleanKind::Reverse)
+        .raw_place(OPEN)
+        .raw_clean(CLOSE);
+
+    let mut w = SGRWriter::from(String::new());
+    w.sgr_string(&link)?;
+    assert_eq!(
+        w.internal(),
+        format!("{OPEN}\x1b[34;4mlink\x1b[39;24m{CLOSE}")
+    );
+    assert_eq!(
+        link.to_string(),
+        format!("{OPEN}\x1b[34;4mlink\x1b[39;24m{CLOSE}")
+    );
+
+    // cursor shapes carried by unstyled text
+    let prompt = "> ".raw_place("\x1b[5 q").raw_clean("\x1b[0 q");
+    assert_eq!(prompt.to_string(), "\x1b[5 q> \x1b[0 q");
+
+    let mut w = SGRWriter::from(StripWriter::new(String::new()));
+    w.sgr_string(&link)?;
+    assert_eq!(w.internal(), "link");
+
+    let mut w = SGRWriter::from(LineResetWriter::new(String::new()));
+    w.place_sgr(&link)?;
+    assert!(w.get_ref().state().underline);
+    w.write_inner("link")?;
+    w.clean_sgr(&link)?;
+    assert!(w.get_ref().state().is_default());
+    assert_eq!(
+        w.internal(),
+        format!("{OPEN}\x1b[34;4mlink\x1b[39;24m{CLOSE}")
+    );
+
+    let mut w = SGRWriter::from(String::new());
+    w.sgr_guard(&link)?.with_reset().write_inner("link")?;
+    assert_eq!(w.internal(), format!("{OPEN}\x1b[34;4mlink\x1b[0m{CLOSE}"));
+
+    let mut w = SGRWriter::from(String::new());
+    w.write_all_styled([&Color::RedFg.text("a"), &link], None)?;
+    assert_eq!(
+        w.internal(),
+        format!("\x1b[31ma\x1b[39m{OPEN}\x1b[34;4mlink\x1b[39;24m{CLOSE}")
+    );
+    Ok(())
+}
+#[test]
+fn write_all_styled() -> Result<(), Box<dyn Error>> {
+    use Color::*;
+    use Style::*;
+
+    let items = [
+        RedFg.style(Bold).text("one"),
+        RedFg.style(Bold).text("two").clean(CleanKind::Reset),
+        BlueBg.style(Italic).text("three"),
+    ];
+    let mut w = SGRWriter::from(String::new());
+    w.write_all_styled(&items, None)?;
+    assert_eq!(
+        w.internal(),
+        "\x1b[31;1monetwo\x1b[39;44;22;3mthree\x1b[49;23m"
+    );
+
+    let mut w = SGRWriter::from(String::new());
+    w.write_all_styled(&items, Some(", "))?;
+    assert_eq!(
+        w.internal(),
+        "\x1b[31;1mone\x1b[39;22m, \x1b[31;1mtwo\x1b[39;22m, \x1b[44;3mthree\x1b[49;23m"
+    );
+
+    // custom codes aren't tracked, so are placed & cleaned per item,
+    // `b` leaving bold for `c` as it has no clean
+    let items = [
+        Bold.text("a"),
+        Bold.text("b").custom_place(53).custom_clean(55),
+        Bold.text("c"),
+    ];
+    let mut w = SGRWriter::from(String::new());
+    w.write_all_styled(&items, None)?;
+    assert_eq!(w.internal(), "\x1b[1ma\x1b[22m\x1b[1;53mb\x1b[55mc\x1b[22m");
+
+    let mut w = SGRWriter::from(String::new());
+    w.set_enabled(false);
+    w.write_all_styled(&items, Some(" "))?;
+    assert_eq!(w.internal(), "a b c");
+
+    let mut w = SGRWriter::from(String::new());
+    w.write_all_styled([], Some(", "))?;
+    assert_eq!(w.internal(), "");
+    Ok(())
+}
+#[test]
+fn state_transition() {
+    use easy_sgr::SgrState;
+
+    for (from, to, codes) in [
+        (&[][..], &[][..], &[][..]),
+        (&[1, 31], &[1, 31], &[]),
+        (&[1, 31], &[], &[39, 22]),
+        (&[1, 2], &[2], &[22, 2]),
+        (&[1], &[2], &[22, 2]),
+        (&[], &[1, 2], &[1, 2]),
+        (&[3, 4, 9], &[4, 5], &[23, 5, 29]),
+        (&[38, 5, 1, 44], &[38, 2, 1, 2, 3, 44], &[38, 2, 1, 2, 3]),
+        (&[7, 8], &[0], &[27, 28]),
+        (&[5], &[6], &[6]),
+        (&[6, 3], &[5], &[23, 5]),
+        (&[6], &[], &[25]),
+        (&[5, 6], &[6], &[]),
+    ] {
+        let mut from_state = SgrState::new();
+        from_state.apply(from);
+        let mut to_state = SgrState::new();
+        to_state.apply(to);
+        let mut builder = SGRBuilder::default();
+        from_state.transition(&to_state, &mut builder);
+        assert_eq!(builder.codes(), codes, "{from:?} -> {to:?}");
+
+        // applying the transition reaches the same state
+        from_state.apply(builder.codes());
+        assert_eq!(from_state, to_state);
+    }
+}
+#[test]
+fn state_blinking() {
+    use easy_sgr::SgrState;
+
+    let mut state = SgrState::new();
+    state.apply(&[5]);
+    assert!(state.blinking && !state.rapid_blinking);
+    state.apply(&[6]);
+    assert!(!state.blinking && state.rapid_blinking);
+    state.apply(&[5, 6, 5]);
+    assert!(state.blinking && !state.rapid_blinking);
+    state.apply(&[6, 25]);
+    assert!(state.is_default());
+
+    state.apply(&[6, 1]);
+    let mut builder = SGRBuilder::default();
+    state.place(&mut builder);
+    assert_eq!(builder.codes(), [1, 6]);
+
+    // cleaning either writes the single code ending both
+    let mut builder = SGRBuilder::default();
+    state.transition(&SgrState::new(), &mut builder);
+    assert_eq!(builder.codes(), [22, 25]);
+}
+#[test]
+fn inline_sgr_all() -> Result<(), Box<dyn Error>> {
+    let mut w = SGRWriter::from(String::new());
+    w.inline_sgr_all(std::iter::empty::<Style>())?;
+    assert_eq!("", *w.get_ref());
+
+    let severity = 2;
+    w.inline_sgr_all(
+        [Style::Bold, Style::Dim, Style::Underline]
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| *i != severity)
+            .map(|(_, style)| style),
+    )?;
+    assert_eq!("\x1b[1;2m", *w.get_ref());
+
+    w.inline_sgr_all(
+        [Color::RedFg, Color::RgbBg(1, 2, 3)]
+            .into_iter()
+            .map(AnySgr::from)
+            .chain([AnySgr::from(Style::Italic), AnySgr::Raw(53)]),
+    )?;
+    assert_eq!("\x1b[1;2m\x1b[31;48;2;1;2;3;3;53m", w.internal());
+
+    let mut w = SGRWriter::from(String::new());
+    w.set_enabled(false);
+    w.inline_sgr_all([Style::Bold])?;
+    assert_eq!("", w.internal());
+    Ok(())
+}
+#[test]
+fn sgr_builder_iter() -> Result<(), Box<dyn Error>> {
+    let codes: Vec<u8> = (0..40).collect();
+    let filter = |code: &u8| !(10..20).contains(code);
+
+    let mut from_iter = SGRBuilder::default();
+    from_iter.write_codes_iter(codes.iter().copied().filter(filter));
+    let mut from_slice = SGRBuilder::default();
+    from_slice.write_codes(&codes.iter().copied().filter(filter).collect::<Vec<_>>());
+    assert_eq!(from_iter, from_slice);
+    assert!(from_iter.spilled());
+
+    let mut builder = SGRBuilder::default();
+    builder
+        .chain_code(1)
+        .chain_codes_iter([38, 5, 208])
+        .chain_codes_iter((0..12).filter(|_| true));
+    assert_eq!(builder.len(), SGRBuilder::INLINE);
+    assert!(!builder.spilled());
+    builder.write_codes_iter(std::iter::empty());
+    assert!(!builder.spilled());
+    builder.write_codes_iter([9]);
+    assert!(builder.spilled());
+    assert_eq!(
+        builder.codes(),
+        [1, 38, 5, 208, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 9]
+    );
+
+    let collected: SGRBuilder = [1, 31].into_iter().collect();
+    assert_eq!(collected, SGRBuilder::from(&[1, 31][..]));
+
+    let mut w = SGRWriter::from(String::new());
+    SGRBuilder::from_iter(std::iter::empty()).write_to(&mut w)?;
+    let mut builder = SGRBuilder::default();
+    builder.write_codes_iter(Vec::new());
+    builder.write_to(&mut w)?;
+    assert_eq!("", w.internal());
+    Ok(())
+}
+#[test]
+fn reset_strategy() -> Result<(), Box<dyn Error>> {
+    let sgr = Color::RedFg
+        .style(Style::Bold)
+        .custom_clean(55)
+        .text("text");
+    let clean = |strategy| -> Result<String, Box<dyn Error>> {
+        let mut w = SGRWriter::from(String::new());
+        w.set_reset_strategy(strategy);
+        w.clean_sgr(&sgr)?;
+        Ok(w.internal())
+    };
+    assert_eq!(clean(None)?, "\x1b[55m");
+    assert_eq!(clean(Some(ResetStrategy::Full))?, "\x1b[0;55m");
+    assert_eq!(clean(Some(ResetStrategy::Targeted))?, "\x1b[39;22;55m");
+    assert_eq!(clean(Some(ResetStrategy::None))?, "");
+
+    let mut w = SGRWriter::from(String::new());
+    w.set_reset_strategy(Some(ResetStrategy::Full));
+    assert_eq!(w.reset_strategy(), Some(ResetStrategy::Full));
+    w.with_sgr(&Style::Italic.clean(CleanKind::Reverse), |w| {
+        w.write_inner("a")
+    })?;
+    w.set_reset_strategy(Some(ResetStrategy::Targeted));
+    w.sgr_guard(&Style::Underline.into())?.write_inner("b")?;
+    w.set_reset_strategy(Some(ResetStrategy::None));
+    w.sgr_guard(&Style::Underline.into())?.write_inner("c")?;
+    assert_eq!(w.internal(), "\x1b[3ma\x1b[0m\x1b[4mb\x1b[24m\x1b[4mc");
+    Ok(())
+}
+/// Records the length of each write
+#[derive(Default)]
+struct Chunks(Vec<usize>, String);
+impl CapableWriter for Chunks {
+    type Writer = (Vec<usize>, String);
+    type Error = std::convert::Infallible;
+    fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.0.push(s.len());
+        self.1.push_str(s);
+        Ok(())
+    }
+    fn get_writer(self) -> Self::Writer {
+        (self.0, self.1)
+    }
+}
+#[test]
+fn write_repeated() -> Result<(), Box<dyn Error>> {
+    let mut w = SGRWriter::from(Chunks::default());
+    w.write_repeated('-', 10_000, None::<&Style>)?;
+    let (chunks, written) = w.internal();
+    assert_eq!(written.len(), 10_000);
+    assert!(written.bytes().all(|b| b == b'-'));
+    assert!(chunks.iter().all(|&len| len <= 256));
+    assert_eq!(chunks.len(), 10_000_usize.div_ceil(256));
+
+    let mut w = SGRWriter::from(Chunks::default());
+    w.write_repeated('─', 10_000, Some(&Color::RedFg.style(Style::Bold)))?;
+    let (chunks, written) = w.internal();
+    let rule = written
+        .strip_prefix("\x1b[31;1m")
+        .and_then(|rule| rule.strip_suffix("\x1b[0m"))
+        .unwrap();
+    assert_eq!(rule.len(), 30_000);
+    assert!(rule.chars().all(|c| c == '─'));
+    assert_eq!(written.matches('\x1b').count(), 2);
+    // the codes are written in their own chunks, around those of the rule
+    let rule_chunks = &chunks[1..chunks.len() - 1];
+    assert!(rule_chunks.iter().all(|&len| len <= 256 && len % 3 == 0));
+
+    let mut w = SGRWriter::from(String::new());
+    w.write_repeated('🦀', 2, Some(&Style::Italic))?;
+    w.write_repeated('x', 0, Some(&Style::Bold))?;
+    assert_eq!(w.internal(), "\x1b[3m🦀🦀\x1b[0m");
+    Ok(())
+}
+#[test]
+fn sequence_builder() -> Result<(), Box<dyn Error>> {
+    let mut seq = SequenceBuilder::default();
+    seq.sgr(&Color::RedFg.style(Style::Bold))
+        .cursor_up(3)
+        .cursor_up(0)
+        .cursor_to_column(0)
+        .open_hyperlink("https://example.com/a b", Some("x;1"))
+        .sgr(&SGRString::default())
+        .csi(&[], 'H')
+        .osc("0;title")
+        .close_hyperlink();
+    let expected = "\x1b[31;1m\x1b[3A\x1b[G\x1b]8;id=x%3B1;https://example.com/a b\x1b\\\
+                    \x1b[H\x1b]0;title\x1b\\\x1b]8;;\x1b\\";
+    assert_eq!(seq.as_str(), expected);
+    assert_eq!(seq.to_string(), expected);
+
+    let mut w = SGRWriter::from(Chunks::default());
+    w.write_sequence(&mut seq)?;
+    assert!(seq.is_empty());
+    w.write_sequence(&mut seq)?;
+    let (chunks, written) = w.internal();
+    assert_eq!(chunks, [expected.len()]);
+    assert_eq!(written, expected);
+
+    // the writer's methods each write their sequence at once
+    let mut w = SGRWriter::from(Chunks::default());
+    w.hyperlink("https://example.com", "link")?;
+    w.erase_display(EraseMode::ToEnd)?;
+    let (chunks, written) = w.internal();
+    assert_eq!(chunks, [26, 4, 7, 3]);
+    assert_eq!(
+        written,
+        "\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\\x1b[J"
+    );
+
+    let mut w = SGRWriter::from(String::new());
+    w.set_enabled(false);
+    seq.hide_cursor();
+    w.write_sequence(&mut seq)?;
+    assert!(seq.is_empty());
+    assert_eq!(w.internal(), "");
+    Ok(())
+}
+#[test]
+#[should_panic = "isn't a final byte"]
+fn sequence_builder_final_byte() {
+    SequenceBuilder::default().csi(&[1], '1');
+}
+#[test]
+fn trait_object_sinks() -> Result<(), Box<dyn Error>> {
+    use std::{
+        fmt,
+        io::{self, Write},
+        sync::{Arc, Mutex},
+    };
+    /// Shares the written bytes with the test
+    struct Shared(Arc<Mutex<Vec<u8>>>);
+    impl Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+    let bytes = Arc::new(Mutex::new(Vec::new()));
+    let sink: Box<dyn Write + Send> = Box::new(Shared(Arc::clone(&bytes)));
+    let mut w = SGRWriter::boxed_io(sink);
+    w.styled(&Color::RedFg.style(Style::Bold), "boxed")?;
+    w.flush()?;
+    let sink: Box<dyn Write + Send> = Box::new(Shared(Arc::clone(&bytes)));
+    let mut w = SGRWriter::from(sink);
+    w.sgr_string(&Color::BlueFg.text("from"))?;
+    assert_eq!(
+        String::from_utf8(bytes.lock().unwrap().clone())?,
+        "\x1b[31;1mboxed\x1b[0m\x1b[34mfrom"
+    );
+
+    let mut borrowed = Vec::new();
+    let mut w = SGRWriter::boxed_io(Box::new(&mut borrowed));
+    w.styled(&Style::Italic, "borrowed")?;
+    drop(w);
+    assert_eq!(borrowed, b"\x1b[3mborrowed\x1b[0m");
+
+    let mut s = String::new();
+    let sink: &mut dyn fmt::Write = &mut s;
+    let mut w = SGRWriter::from(FmtWriter(sink));
+    w.styled(&Color::GreenBg, "fmt")?;
+    w.inline_sgr(&Style::Underline)?;
+    assert_eq!(s, "\x1b[42mfmt\x1b[0m\x1b[4m");
+    Ok(())
+}